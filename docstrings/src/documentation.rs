@@ -7,5 +7,6 @@ pub use module::*;
 
 /// Represents a compiled project's entire documentation.
 pub struct Documentation {
-    modules: Vec<Module>,
+    /// The documented modules, beginning with the root module followed by its submodules.
+    pub modules: Vec<Module>,
 }
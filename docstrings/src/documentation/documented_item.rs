@@ -2,6 +2,25 @@ use super::ItemType;
 
 /// Represents an item that has been documented.
 pub struct DocumentedItem {
-    item_type: ItemType,
-    documentation: String,
+    /// The kind of the item.
+    pub item_type: ItemType,
+    /// The name of the item, or for an `impl`, the name of the type that it's for.
+    pub name: String,
+    /// The source code declaring the item, excluding any body, e.g. the signature of a function.
+    pub declaration: String,
+    /// The text of the item's doc comments.
+    pub documentation: String,
+    /// The documented contents of the item, such as the methods of an ABI, trait or `impl`.
+    pub members: Vec<DocumentedItem>,
+}
+
+impl DocumentedItem {
+    /// The first paragraph of the documentation, used as a summary of the item.
+    pub fn summary(&self) -> &str {
+        self.documentation
+            .split("\n\n")
+            .next()
+            .unwrap_or_default()
+            .trim()
+    }
 }
@@ -1,9 +1,64 @@
 /// The type of the item being documented.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
 pub enum ItemType {
     /// A Sway function, starting with `fn`, that is _not_ a method, abi method, or interface surface item.
     Function,
     /// A Sway method, starting with `fn`, that is _not_ a top-level function or ABI method.
     Method,
+    /// A method of an ABI, starting with `fn`.
+    AbiMethod,
+    /// A method that a trait requires of its implementations, which is declared but not defined.
+    InterfaceSurfaceItem,
     /// A Sway struct, denoted with `struct`.
     Struct,
+    /// A Sway enum, denoted with `enum`.
+    Enum,
+    /// A Sway trait, denoted with `trait`.
+    Trait,
+    /// A contract ABI, denoted with `abi`.
+    Abi,
+    /// The storage declaration of a contract, denoted with `storage`.
+    Storage,
+    /// A Sway constant, denoted with `const`.
+    Constant,
+    /// An alias for another type, denoted with `type`.
+    TypeAlias,
+    /// A type that a trait requires of its implementations, denoted with `type`.
+    AssociatedType,
+    /// An implementation of methods or a trait for a type, denoted with `impl`.
+    Impl,
+}
+
+impl ItemType {
+    /// A short, lowercase name for the kind of item, e.g. `struct`.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ItemType::Function => "fn",
+            ItemType::Method => "method",
+            ItemType::AbiMethod => "abi method",
+            ItemType::InterfaceSurfaceItem => "required method",
+            ItemType::Struct => "struct",
+            ItemType::Enum => "enum",
+            ItemType::Trait => "trait",
+            ItemType::Abi => "abi",
+            ItemType::Storage => "storage",
+            ItemType::Constant => "const",
+            ItemType::TypeAlias => "type",
+            ItemType::AssociatedType => "associated type",
+            ItemType::Impl => "impl",
+        }
+    }
+
+    /// Whether the item is a type which may be the target of an `impl`, or be named within the
+    /// declarations of other items.
+    pub fn is_type(&self) -> bool {
+        matches!(
+            self,
+            ItemType::Struct
+                | ItemType::Enum
+                | ItemType::Trait
+                | ItemType::Abi
+                | ItemType::TypeAlias
+        )
+    }
 }
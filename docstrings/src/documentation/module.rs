@@ -9,7 +9,7 @@ pub struct Module {
     /// The name of a module.
     /// e.g. if module `root` contains a submodule `foo`, this would be
     /// vec!["root", "foo"]
-    name: Vec<String>,
+    pub name: Vec<String>,
     /// The documented items contained in this module.
-    documented_items: Vec<DocumentedItem>,
+    pub documented_items: Vec<DocumentedItem>,
 }
@@ -1,6 +1,12 @@
 mod source_code_module;
 use crate::*;
 pub use source_code_module::*;
+use sway_parse::{
+    attribute::{Annotated, AttributeDecl},
+    keywords::SemicolonToken,
+    Braces, FnSignature, Item, ItemFn, ItemImpl, ItemImplItem, ItemKind, ItemTraitItem, Ty,
+};
+use sway_types::{Span, Spanned};
 
 /// A documentation parser and generator.
 pub struct Documenter {
@@ -8,13 +14,411 @@ pub struct Documenter {
 }
 
 impl Documenter {
-    /// Given input sway source code, generates [Documention] for it.
-    pub fn generate_documentation(input: &str) -> Result<Documentation, DocumentationError> {
-        todo!("{}", input)
+    /// Parse each of the modules, generating [Documentation] for the public items and their doc
+    /// comments.
+    ///
+    /// Functions, structs, enums, traits and constants are only documented when they're `pub`.
+    /// ABIs, storage and `impl`s have no visibility and are always documented.
+    pub fn generate_documentation(&self) -> Result<Documentation, DocumentationError> {
+        let modules = self
+            .modules
+            .iter()
+            .map(document_module)
+            .collect::<Result<_, _>>()?;
+        Ok(Documentation { modules })
     }
 
     /// Create a new [Documenter] from a mapping of module names to their source code.
-    pub fn new(_raw: impl Into<Vec<SourceCodeModule>>) -> Self {
-        todo!()
+    pub fn new(raw: impl Into<Vec<SourceCodeModule>>) -> Self {
+        Self {
+            modules: raw.into(),
+        }
+    }
+}
+
+fn document_module(module: &SourceCodeModule) -> Result<Module, DocumentationError> {
+    let parsed =
+        sway_parse::parse_file(module.source.clone(), module.path.clone()).map_err(|error| {
+            DocumentationError {
+                module: module.name.clone(),
+                error: Box::new(error),
+            }
+        })?;
+    Ok(Module {
+        name: module.name.clone(),
+        documented_items: parsed.items.iter().filter_map(document_item).collect(),
+    })
+}
+
+fn document_item(item: &Item) -> Option<DocumentedItem> {
+    let documentation = documentation(&item.attribute_list);
+    let documented = match &item.value {
+        ItemKind::Fn(item_fn) if item_fn.fn_signature.visibility.is_some() => DocumentedItem {
+            item_type: ItemType::Function,
+            name: item_fn.fn_signature.name.as_str().to_string(),
+            declaration: declaration(item_fn.fn_signature.span()),
+            documentation,
+            members: vec![],
+        },
+        ItemKind::Struct(item_struct) if item_struct.visibility.is_some() => DocumentedItem {
+            item_type: ItemType::Struct,
+            name: item_struct.name.as_str().to_string(),
+            declaration: declaration(item_struct.span()),
+            documentation,
+            members: vec![],
+        },
+        ItemKind::Enum(item_enum) if item_enum.visibility.is_some() => DocumentedItem {
+            item_type: ItemType::Enum,
+            name: item_enum.name.as_str().to_string(),
+            declaration: declaration(item_enum.span()),
+            documentation,
+            members: vec![],
+        },
+        ItemKind::Trait(item_trait) if item_trait.visibility.is_some() => {
+            let mut members = document_trait_items(item_trait.trait_items.get());
+            members.extend(document_fns(
+                item_trait.trait_defs_opt.as_ref(),
+                ItemType::Method,
+            ));
+            DocumentedItem {
+                item_type: ItemType::Trait,
+                name: item_trait.name.as_str().to_string(),
+                declaration: declaration_before(item_trait.span(), &item_trait.trait_items),
+                documentation,
+                members,
+            }
+        }
+        ItemKind::Abi(item_abi) => {
+            let mut members = document_signatures(item_abi.abi_items.get(), ItemType::AbiMethod);
+            members.extend(document_fns(
+                item_abi.abi_defs_opt.as_ref(),
+                ItemType::AbiMethod,
+            ));
+            DocumentedItem {
+                item_type: ItemType::Abi,
+                name: item_abi.name.as_str().to_string(),
+                declaration: declaration_before(item_abi.span(), &item_abi.abi_items),
+                documentation,
+                members,
+            }
+        }
+        ItemKind::Storage(item_storage) => DocumentedItem {
+            item_type: ItemType::Storage,
+            name: "storage".to_string(),
+            declaration: declaration(item_storage.span()),
+            documentation,
+            members: vec![],
+        },
+        ItemKind::Const(item_const) if item_const.visibility.is_some() => DocumentedItem {
+            item_type: ItemType::Constant,
+            name: item_const.name.as_str().to_string(),
+            declaration: declaration(item_const.span()),
+            documentation,
+            members: vec![],
+        },
+        ItemKind::TypeAlias(item_type_alias) if item_type_alias.visibility.is_some() => {
+            DocumentedItem {
+                item_type: ItemType::TypeAlias,
+                name: item_type_alias.name.as_str().to_string(),
+                declaration: declaration(item_type_alias.span()),
+                documentation,
+                members: vec![],
+            }
+        }
+        ItemKind::Impl(item_impl) => DocumentedItem {
+            item_type: ItemType::Impl,
+            name: impl_type_name(item_impl),
+            declaration: declaration_before(item_impl.span(), &item_impl.contents),
+            documentation,
+            members: document_impl_items(item_impl.contents.get()),
+        },
+        _ => return None,
+    };
+    Some(documented)
+}
+
+fn document_signatures(
+    signatures: &[(Annotated<FnSignature>, SemicolonToken)],
+    item_type: ItemType,
+) -> Vec<DocumentedItem> {
+    signatures
+        .iter()
+        .map(|(sig, _)| document_fn(&sig.attribute_list, &sig.value, item_type))
+        .collect()
+}
+
+fn document_trait_items(
+    trait_items: &[(Annotated<ItemTraitItem>, SemicolonToken)],
+) -> Vec<DocumentedItem> {
+    trait_items
+        .iter()
+        .map(|(item, _)| match &item.value {
+            ItemTraitItem::Fn(fn_signature) => document_fn(
+                &item.attribute_list,
+                fn_signature,
+                ItemType::InterfaceSurfaceItem,
+            ),
+            ItemTraitItem::Const(trait_const) => document_associated_item(
+                &item.attribute_list,
+                trait_const.name.as_str(),
+                trait_const.span(),
+                ItemType::Constant,
+            ),
+            ItemTraitItem::Type(trait_type) => document_associated_item(
+                &item.attribute_list,
+                trait_type.name.as_str(),
+                trait_type.span(),
+                ItemType::AssociatedType,
+            ),
+        })
+        .collect()
+}
+
+fn document_impl_items(impl_items: &[Annotated<ItemImplItem>]) -> Vec<DocumentedItem> {
+    impl_items
+        .iter()
+        .map(|item| match &item.value {
+            ItemImplItem::Fn(item_fn) => document_fn(
+                &item.attribute_list,
+                &item_fn.fn_signature,
+                ItemType::Method,
+            ),
+            ItemImplItem::Const(item_const) => document_associated_item(
+                &item.attribute_list,
+                item_const.name.as_str(),
+                item_const.span(),
+                ItemType::Constant,
+            ),
+            ItemImplItem::Type(impl_type) => document_associated_item(
+                &item.attribute_list,
+                impl_type.name.as_str(),
+                impl_type.span(),
+                ItemType::AssociatedType,
+            ),
+        })
+        .collect()
+}
+
+fn document_associated_item(
+    attribute_list: &[AttributeDecl],
+    name: &str,
+    span: Span,
+    item_type: ItemType,
+) -> DocumentedItem {
+    DocumentedItem {
+        item_type,
+        name: name.to_string(),
+        declaration: declaration(span),
+        documentation: documentation(attribute_list),
+        members: vec![],
+    }
+}
+
+fn document_fns(
+    fns: Option<&Braces<Vec<Annotated<ItemFn>>>>,
+    item_type: ItemType,
+) -> Vec<DocumentedItem> {
+    fns.map(Braces::get)
+        .into_iter()
+        .flatten()
+        .map(|item_fn| {
+            document_fn(
+                &item_fn.attribute_list,
+                &item_fn.value.fn_signature,
+                item_type,
+            )
+        })
+        .collect()
+}
+
+fn document_fn(
+    attribute_list: &[AttributeDecl],
+    fn_signature: &FnSignature,
+    item_type: ItemType,
+) -> DocumentedItem {
+    DocumentedItem {
+        item_type,
+        name: fn_signature.name.as_str().to_string(),
+        declaration: declaration(fn_signature.span()),
+        documentation: documentation(attribute_list),
+        members: vec![],
+    }
+}
+
+/// The name of the type that an `impl` is for, without any path or generic arguments.
+fn impl_type_name(item_impl: &ItemImpl) -> String {
+    match &item_impl.ty {
+        Ty::Path(path_type) => path_type
+            .suffix
+            .last()
+            .map(|(_, segment)| segment)
+            .unwrap_or(&path_type.prefix)
+            .name
+            .as_str()
+            .to_string(),
+        ty => ty.span().as_str().to_string(),
+    }
+}
+
+/// The text of the doc comments within an attribute list, one line per comment.
+fn documentation(attribute_list: &[AttributeDecl]) -> String {
+    attribute_list
+        .iter()
+        .filter_map(AttributeDecl::doc_comment_text)
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+/// The source of a declaration, with the indentation of each line following the first reduced by
+/// that of the first, so that declarations within an `impl` or `abi` line up.
+fn declaration(span: Span) -> String {
+    let src = span.src();
+    let line_start = src[..span.start()].rfind('\n').map_or(0, |ix| ix + 1);
+    let indent =
+        src[line_start..span.start()].len() - src[line_start..span.start()].trim_start().len();
+    span.as_str()
+        .lines()
+        .enumerate()
+        .map(|(ix, line)| {
+            let line = line.trim_end();
+            if ix == 0 {
+                return line;
+            }
+            let leading = line.len() - line.trim_start().len();
+            &line[leading.min(indent)..]
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+/// The source of a declaration up to the body of the item, e.g. `impl Foo for Bar`.
+fn declaration_before<T>(span: Span, body: &Braces<T>) -> String {
+    let end = body.span().start();
+    let span =
+        Span::new(span.src().clone(), span.start(), end, span.path().cloned()).unwrap_or(span);
+    declaration(span).trim_end().to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn document(source: &str) -> Documentation {
+        let module = SourceCodeModule::new(vec!["lib".to_string()], source);
+        Documenter::new(vec![module])
+            .generate_documentation()
+            .unwrap()
+    }
+
+    #[test]
+    fn documents_public_items() {
+        let documentation = document(
+            r#"library lib;
+
+/// A point.
+///
+/// With a second paragraph.
+pub struct Point {
+    x: u64,
+}
+
+/// Not public.
+struct Hidden {}
+
+/// An amount of coins.
+pub type Balance = u64;
+
+impl Point {
+    /// Create a point.
+    pub fn new(x: u64) -> Point {
+        Point { x }
+    }
+}
+"#,
+        );
+        let items = &documentation.modules[0].documented_items;
+        assert_eq!(items.len(), 3);
+
+        assert_eq!(items[0].item_type, ItemType::Struct);
+        assert_eq!(items[0].name, "Point");
+        assert_eq!(items[0].declaration, "pub struct Point {\n    x: u64,\n}");
+        assert_eq!(
+            items[0].documentation,
+            "A point.\n\nWith a second paragraph."
+        );
+        assert_eq!(items[0].summary(), "A point.");
+
+        assert_eq!(items[1].item_type, ItemType::TypeAlias);
+        assert_eq!(items[1].name, "Balance");
+        assert_eq!(items[1].declaration, "pub type Balance = u64;");
+        assert_eq!(items[1].documentation, "An amount of coins.");
+
+        assert_eq!(items[2].item_type, ItemType::Impl);
+        assert_eq!(items[2].name, "Point");
+        assert_eq!(items[2].declaration, "impl Point");
+        assert_eq!(items[2].members.len(), 1);
+        assert_eq!(
+            items[2].members[0].declaration,
+            "pub fn new(x: u64) -> Point"
+        );
+        assert_eq!(items[2].members[0].documentation, "Create a point.");
+    }
+
+    #[test]
+    fn documents_abi_methods() {
+        let documentation = document(
+            r#"contract;
+
+/// A counter.
+abi Counter {
+    /// Increment the counter.
+    #[storage(read, write)]
+    fn increment(
+        amount: u64,
+    ) -> u64;
+    fn get() -> u64;
+}
+"#,
+        );
+        let abi = &documentation.modules[0].documented_items[0];
+        assert_eq!(abi.item_type, ItemType::Abi);
+        assert_eq!(abi.declaration, "abi Counter");
+        assert_eq!(abi.documentation, "A counter.");
+        assert_eq!(abi.members.len(), 2);
+        assert_eq!(abi.members[0].item_type, ItemType::AbiMethod);
+        assert_eq!(
+            abi.members[0].declaration,
+            "fn increment(\n    amount: u64,\n) -> u64"
+        );
+        assert_eq!(abi.members[0].documentation, "Increment the counter.");
+        assert!(abi.members[1].documentation.is_empty());
+    }
+
+    #[test]
+    fn documents_associated_items() {
+        let documentation = document(
+            r#"library lib;
+
+pub trait Container<T> {
+    /// The type of the items.
+    type Item;
+    /// The maximum number of items.
+    const CAPACITY: u64 = 4;
+    fn first(self) -> Self::Item;
+}
+"#,
+        );
+        let container = &documentation.modules[0].documented_items[0];
+        assert_eq!(container.declaration, "pub trait Container<T>");
+        assert_eq!(container.members.len(), 3);
+        assert_eq!(container.members[0].item_type, ItemType::AssociatedType);
+        assert_eq!(container.members[0].name, "Item");
+        assert_eq!(container.members[0].documentation, "The type of the items.");
+        assert_eq!(container.members[1].item_type, ItemType::Constant);
+        assert_eq!(container.members[1].declaration, "const CAPACITY: u64 = 4");
+        assert_eq!(
+            container.members[2].item_type,
+            ItemType::InterfaceSurfaceItem
+        );
     }
 }
@@ -1,9 +1,32 @@
+use std::{path::PathBuf, sync::Arc};
+
 /// Represents a Sway module and its contents as a string.
 pub struct SourceCodeModule {
     /// The name of a module.
     /// e.g. if module `root` contains a submodule `foo`, this would be
     /// vec!["root", "foo"]
-    name: Vec<String>,
+    pub name: Vec<String>,
     /// The raw source code contained in the module.
-    source: String,
+    pub source: Arc<str>,
+    /// The path of the file containing the module, if any.
+    pub path: Option<Arc<PathBuf>>,
+}
+
+impl SourceCodeModule {
+    /// Create a module with the given name from its source code.
+    pub fn new(name: Vec<String>, source: impl Into<Arc<str>>) -> Self {
+        Self {
+            name,
+            source: source.into(),
+            path: None,
+        }
+    }
+
+    /// Set the path of the file containing the module, which is used within error messages.
+    pub fn with_path(self, path: PathBuf) -> Self {
+        Self {
+            path: Some(Arc::new(path)),
+            ..self
+        }
+    }
 }
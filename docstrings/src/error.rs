@@ -1,2 +1,12 @@
+use sway_parse::ParseFileError;
+use thiserror::Error;
+
 /// An error generated while documenting Sway code.
-pub struct DocumentationError {}
+#[derive(Debug, Error)]
+#[error("failed to parse module `{}`: {error}", .module.join("::"))]
+pub struct DocumentationError {
+    /// The name of the module that could not be documented.
+    pub module: Vec<String>,
+    /// The error encountered while parsing the module.
+    pub error: Box<ParseFileError>,
+}
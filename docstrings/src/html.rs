@@ -0,0 +1,499 @@
+//! Rendering of [Documentation] as static HTML pages.
+//!
+//! Every module and documented item is given its own page within a single directory, alongside
+//! a stylesheet and a search index, so that pages link to one another by file name alone.
+use crate::*;
+use std::{collections::HashMap, fmt::Write};
+
+/// A file of the rendered documentation.
+pub struct HtmlFile {
+    /// The name of the file, relative to the documentation directory.
+    pub file_name: String,
+    /// The contents of the file.
+    pub contents: String,
+}
+
+impl Documentation {
+    /// Render the documentation of the project with the given name as static HTML.
+    ///
+    /// The root module is rendered as `index.html`.
+    pub fn to_html(&self, project_name: &str) -> Vec<HtmlFile> {
+        let renderer = Renderer::new(self, project_name);
+        let mut files = vec![
+            HtmlFile {
+                file_name: STYLE_FILE_NAME.to_string(),
+                contents: STYLE.to_string(),
+            },
+            HtmlFile {
+                file_name: SEARCH_FILE_NAME.to_string(),
+                contents: SEARCH.to_string(),
+            },
+            HtmlFile {
+                file_name: SEARCH_INDEX_FILE_NAME.to_string(),
+                contents: renderer.search_index(),
+            },
+        ];
+        for module in &self.modules {
+            files.push(HtmlFile {
+                file_name: module_file_name(module),
+                contents: renderer.module_page(module),
+            });
+            for item in page_items(module) {
+                files.push(HtmlFile {
+                    file_name: item_file_name(module, item),
+                    contents: renderer.item_page(module, item),
+                });
+            }
+        }
+        files
+    }
+}
+
+const STYLE_FILE_NAME: &str = "style.css";
+const SEARCH_FILE_NAME: &str = "search.js";
+const SEARCH_INDEX_FILE_NAME: &str = "search-index.js";
+
+const STYLE: &str = r#"body { font-family: sans-serif; margin: 0; display: flex; color: #222; }
+nav { width: 16rem; padding: 1rem; background: #f5f5f5; min-height: 100vh; box-sizing: border-box; }
+nav input { width: 100%; box-sizing: border-box; margin: 1rem 0; padding: 0.3rem; }
+nav ul { list-style: none; padding: 0; }
+main { padding: 1rem 2rem; max-width: 60rem; }
+a { color: #2a6ebb; text-decoration: none; }
+a:hover { text-decoration: underline; }
+pre { background: #f5f5f5; padding: 0.7rem; overflow-x: auto; }
+code { font-family: monospace; }
+.kind { color: #888; font-weight: normal; }
+.member { margin-left: 1rem; }
+.member > pre { margin-bottom: 0.3rem; }
+td { padding: 0.2rem 1rem 0.2rem 0; vertical-align: top; }
+"#;
+
+const SEARCH: &str = r#"function search(query) {
+    var results = document.getElementById("search-results");
+    results.innerHTML = "";
+    query = query.toLowerCase();
+    if (!query) {
+        return;
+    }
+    searchIndex
+        .filter(function (item) {
+            return item.path.toLowerCase().indexOf(query) !== -1;
+        })
+        .slice(0, 50)
+        .forEach(function (item) {
+            var link = document.createElement("a");
+            link.href = item.file;
+            link.textContent = item.path + " (" + item.kind + ")";
+            var entry = document.createElement("li");
+            entry.appendChild(link);
+            results.appendChild(entry);
+        });
+}
+"#;
+
+/// The items of a module which have their own page, which excludes `impl`s as they're rendered
+/// on the page of their type.
+fn page_items(module: &Module) -> impl Iterator<Item = &DocumentedItem> {
+    module
+        .documented_items
+        .iter()
+        .filter(|item| item.item_type != ItemType::Impl)
+}
+
+fn module_path(module: &Module) -> String {
+    module.name.join("::")
+}
+
+fn module_file_name(module: &Module) -> String {
+    if module.name.len() <= 1 {
+        "index.html".to_string()
+    } else {
+        format!("{}.html", module.name.join("."))
+    }
+}
+
+fn item_file_name(module: &Module, item: &DocumentedItem) -> String {
+    format!(
+        "{}.{}.{}.html",
+        module.name.join("."),
+        item.item_type.as_str().replace(' ', "-"),
+        item.name
+    )
+}
+
+struct Renderer<'a> {
+    documentation: &'a Documentation,
+    project_name: &'a str,
+    /// The pages of the types by name, to which their names are linked within declarations.
+    type_links: HashMap<&'a str, String>,
+}
+
+impl<'a> Renderer<'a> {
+    fn new(documentation: &'a Documentation, project_name: &'a str) -> Self {
+        let mut type_links = HashMap::new();
+        for module in &documentation.modules {
+            for item in page_items(module).filter(|item| item.item_type.is_type()) {
+                type_links
+                    .entry(item.name.as_str())
+                    .or_insert_with(|| item_file_name(module, item));
+            }
+        }
+        Self {
+            documentation,
+            project_name,
+            type_links,
+        }
+    }
+
+    fn search_index(&self) -> String {
+        let mut index = String::from("var searchIndex = [\n");
+        for module in &self.documentation.modules {
+            let _ = writeln!(
+                index,
+                "    {{ path: {}, kind: \"mod\", file: {} }},",
+                js_string(&module_path(module)),
+                js_string(&module_file_name(module)),
+            );
+            for item in page_items(module) {
+                let _ = writeln!(
+                    index,
+                    "    {{ path: {}, kind: {}, file: {} }},",
+                    js_string(&format!("{}::{}", module_path(module), item.name)),
+                    js_string(item.item_type.as_str()),
+                    js_string(&item_file_name(module, item)),
+                );
+            }
+        }
+        index.push_str("];\n");
+        index
+    }
+
+    fn module_page(&self, module: &Module) -> String {
+        let mut content = String::new();
+        let _ = writeln!(
+            content,
+            "<h1><span class=\"kind\">Module</span> {}</h1>",
+            escape(&module_path(module))
+        );
+        let submodules = self
+            .documentation
+            .modules
+            .iter()
+            .filter(|other| {
+                other.name.len() == module.name.len() + 1 && other.name.starts_with(&module.name)
+            })
+            .collect::<Vec<_>>();
+        if !submodules.is_empty() {
+            content.push_str("<h2>Modules</h2>\n<table>\n");
+            for submodule in submodules {
+                let _ = writeln!(
+                    content,
+                    "<tr><td><a href=\"{}\">{}</a></td></tr>",
+                    module_file_name(submodule),
+                    escape(submodule.name.last().unwrap()),
+                );
+            }
+            content.push_str("</table>\n");
+        }
+        let sections = [
+            (ItemType::Abi, "ABIs"),
+            (ItemType::Storage, "Storage"),
+            (ItemType::Struct, "Structs"),
+            (ItemType::Enum, "Enums"),
+            (ItemType::Trait, "Traits"),
+            (ItemType::TypeAlias, "Type Aliases"),
+            (ItemType::Function, "Functions"),
+            (ItemType::Constant, "Constants"),
+        ];
+        for (item_type, heading) in sections {
+            let items = page_items(module)
+                .filter(|item| item.item_type == item_type)
+                .collect::<Vec<_>>();
+            if items.is_empty() {
+                continue;
+            }
+            let _ = writeln!(content, "<h2>{}</h2>\n<table>", heading);
+            for item in items {
+                let _ = writeln!(
+                    content,
+                    "<tr><td><a href=\"{}\">{}</a></td><td>{}</td></tr>",
+                    item_file_name(module, item),
+                    escape(&item.name),
+                    inline_markdown(item.summary()),
+                );
+            }
+            content.push_str("</table>\n");
+        }
+        self.page(&module_path(module), &content)
+    }
+
+    fn item_page(&self, module: &Module, item: &DocumentedItem) -> String {
+        let mut content = String::new();
+        let _ = writeln!(
+            content,
+            "<h1><span class=\"kind\">{}</span> <a href=\"{}\">{}</a>::{}</h1>",
+            escape(item.item_type.as_str()),
+            module_file_name(module),
+            escape(&module_path(module)),
+            escape(&item.name),
+        );
+        self.write_item(&mut content, module, item);
+        if !item.members.is_empty() {
+            content.push_str("<h2>Methods</h2>\n");
+            for member in &item.members {
+                self.write_member(&mut content, module, member);
+            }
+        }
+        if item.item_type.is_type() {
+            let impls = self
+                .documentation
+                .modules
+                .iter()
+                .flat_map(|module| {
+                    module
+                        .documented_items
+                        .iter()
+                        .map(move |item| (module, item))
+                })
+                .filter(|(_, other)| other.item_type == ItemType::Impl && other.name == item.name)
+                .collect::<Vec<_>>();
+            if !impls.is_empty() {
+                content.push_str("<h2>Implementations</h2>\n");
+            }
+            for (impl_module, item_impl) in impls {
+                self.write_item(&mut content, impl_module, item_impl);
+                for member in &item_impl.members {
+                    self.write_member(&mut content, impl_module, member);
+                }
+            }
+        }
+        self.page(&format!("{}::{}", module_path(module), item.name), &content)
+    }
+
+    /// Write the declaration of an item followed by its documentation.
+    fn write_item(&self, content: &mut String, module: &Module, item: &DocumentedItem) {
+        let _ = writeln!(
+            content,
+            "<pre><code>{}</code></pre>",
+            self.link_types(module, &item.declaration)
+        );
+        content.push_str(&markdown(&item.documentation));
+    }
+
+    fn write_member(&self, content: &mut String, module: &Module, member: &DocumentedItem) {
+        let _ = writeln!(
+            content,
+            "<div class=\"member\" id=\"{}.{}\">",
+            member.item_type.as_str().replace(' ', "-"),
+            escape(&member.name),
+        );
+        self.write_item(content, module, member);
+        content.push_str("</div>\n");
+    }
+
+    /// Escape source code, linking the names of documented types to their pages. Types of the
+    /// given module take precedence over those of the same name within other modules.
+    fn link_types(&self, module: &Module, code: &str) -> String {
+        let mut linked = String::new();
+        let mut ident = String::new();
+        for c in code.chars().map(Some).chain([None]) {
+            match c {
+                Some(c) if c.is_alphanumeric() || c == '_' => ident.push(c),
+                _ => {
+                    let file_name = page_items(module)
+                        .find(|item| item.item_type.is_type() && item.name == ident)
+                        .map(|item| item_file_name(module, item))
+                        .or_else(|| self.type_links.get(ident.as_str()).cloned());
+                    match file_name {
+                        Some(file_name) => {
+                            let _ = write!(linked, "<a href=\"{}\">{}</a>", file_name, ident);
+                        }
+                        None => linked.push_str(&ident),
+                    }
+                    ident.clear();
+                    if let Some(c) = c {
+                        linked.push_str(&escape(&c.to_string()));
+                    }
+                }
+            }
+        }
+        linked
+    }
+
+    fn page(&self, title: &str, content: &str) -> String {
+        format!(
+            r#"<!DOCTYPE html>
+<html lang="en">
+<head>
+<meta charset="utf-8">
+<title>{title}</title>
+<link rel="stylesheet" href="{STYLE_FILE_NAME}">
+<script src="{SEARCH_INDEX_FILE_NAME}"></script>
+<script src="{SEARCH_FILE_NAME}"></script>
+</head>
+<body>
+<nav>
+<a href="index.html"><strong>{project_name}</strong></a>
+<input type="search" placeholder="Search..." oninput="search(this.value)">
+<ul id="search-results"></ul>
+</nav>
+<main>
+{content}</main>
+</body>
+</html>
+"#,
+            title = escape(title),
+            project_name = escape(self.project_name),
+        )
+    }
+}
+
+/// Escape text for use within HTML.
+fn escape(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            c => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+/// A JavaScript string literal with the given contents.
+fn js_string(text: &str) -> String {
+    let mut literal = String::from('"');
+    for c in text.chars() {
+        match c {
+            '"' => literal.push_str("\\\""),
+            '\\' => literal.push_str("\\\\"),
+            c if c.is_control() => {
+                let _ = write!(literal, "\\u{:04x}", c as u32);
+            }
+            c => literal.push(c),
+        }
+    }
+    literal.push('"');
+    literal
+}
+
+/// Render the subset of markdown commonly used within doc comments: paragraphs, headings,
+/// bulleted lists, fenced code blocks and inline code.
+fn markdown(text: &str) -> String {
+    let mut html = String::new();
+    let mut paragraph: Vec<&str> = vec![];
+    let mut in_list = false;
+    let mut in_code_block = false;
+    let flush = |html: &mut String, paragraph: &mut Vec<&str>, in_list: &mut bool| {
+        if !paragraph.is_empty() {
+            let _ = writeln!(html, "<p>{}</p>", inline_markdown(&paragraph.join(" ")));
+            paragraph.clear();
+        }
+        if *in_list {
+            html.push_str("</ul>\n");
+            *in_list = false;
+        }
+    };
+    for line in text.lines() {
+        let trimmed = line.trim();
+        if trimmed.starts_with("```") {
+            if in_code_block {
+                html.push_str("</code></pre>\n");
+            } else {
+                flush(&mut html, &mut paragraph, &mut in_list);
+                html.push_str("<pre><code>");
+            }
+            in_code_block = !in_code_block;
+        } else if in_code_block {
+            html.push_str(&escape(line));
+            html.push('\n');
+        } else if trimmed.is_empty() {
+            flush(&mut html, &mut paragraph, &mut in_list);
+        } else if let Some(heading) = trimmed.strip_prefix('#') {
+            flush(&mut html, &mut paragraph, &mut in_list);
+            let level = (heading.len() - heading.trim_start_matches('#').len() + 3).min(6);
+            let heading = heading.trim_start_matches('#').trim();
+            let _ = writeln!(html, "<h{0}>{1}</h{0}>", level, inline_markdown(heading));
+        } else if let Some(entry) = trimmed
+            .strip_prefix("* ")
+            .or_else(|| trimmed.strip_prefix("- "))
+        {
+            if !in_list {
+                flush(&mut html, &mut paragraph, &mut in_list);
+                html.push_str("<ul>\n");
+                in_list = true;
+            }
+            let _ = writeln!(html, "<li>{}</li>", inline_markdown(entry));
+        } else {
+            paragraph.push(trimmed);
+        }
+    }
+    if in_code_block {
+        html.push_str("</code></pre>\n");
+    }
+    flush(&mut html, &mut paragraph, &mut in_list);
+    html
+}
+
+/// Escape a line of markdown, rendering any `inline code`.
+fn inline_markdown(text: &str) -> String {
+    let parts = text.split('`').collect::<Vec<_>>();
+    let num_parts = parts.len();
+    parts
+        .into_iter()
+        .enumerate()
+        .map(|(ix, part)| match ix % 2 {
+            0 => escape(part),
+            // An unclosed backtick is kept as it is.
+            _ if ix + 1 == num_parts => format!("`{}", escape(part)),
+            _ => format!("<code>{}</code>", escape(part)),
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn renders_markdown() {
+        let html = markdown(
+            "Returns the `value`.\n\n# Examples\n\n```sway\nlet x = a < b;\n```\n* one\n* two",
+        );
+        assert_eq!(
+            html,
+            "<p>Returns the <code>value</code>.</p>\n\
+             <h3>Examples</h3>\n\
+             <pre><code>let x = a &lt; b;\n</code></pre>\n\
+             <ul>\n<li>one</li>\n<li>two</li>\n</ul>\n"
+        );
+    }
+
+    #[test]
+    fn links_types() {
+        let documentation = Documentation {
+            modules: vec![Module {
+                name: vec!["lib".to_string(), "point".to_string()],
+                documented_items: vec![DocumentedItem {
+                    item_type: ItemType::Struct,
+                    name: "Point".to_string(),
+                    declaration: "pub struct Point {}".to_string(),
+                    documentation: String::new(),
+                    members: vec![],
+                }],
+            }],
+        };
+        let renderer = Renderer::new(&documentation, "lib");
+        let other_module = Module {
+            name: vec!["lib".to_string()],
+            documented_items: vec![],
+        };
+        assert_eq!(
+            renderer.link_types(&other_module, "fn origin() -> Point<T>"),
+            "fn origin() -&gt; <a href=\"lib.point.struct.Point.html\">Point</a>&lt;T&gt;"
+        );
+    }
+}
@@ -1,9 +1,11 @@
 #![deny(missing_docs)]
-#![allow(dead_code)]
 //! This crate provides tooling for generating documentation and docstrings for Sway.
 
 mod documentation;
 mod documenter;
 mod error;
+mod html;
 pub use documentation::*;
+pub use documenter::*;
 pub use error::*;
+pub use html::*;
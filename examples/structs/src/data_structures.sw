@@ -2,6 +2,6 @@ library data_structures;
 
 // Declare a struct type
 pub struct Foo {
-    bar: u64,
-    baz: bool,
+    pub bar: u64,
+    pub baz: bool,
 }
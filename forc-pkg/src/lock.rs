@@ -34,6 +34,9 @@ pub struct PkgLock {
     version: Option<semver::Version>,
     // Short-hand string describing where this package is sourced from.
     source: String,
+    // Checksum of the package's source, used to verify local checkouts of git packages.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    checksum: Option<String>,
     dependencies: Vec<PkgDepLine>,
 }
 
@@ -81,6 +84,7 @@ impl PkgLock {
             name,
             version,
             source,
+            checksum: None,
             dependencies,
         }
     }
@@ -110,17 +114,51 @@ impl Lock {
         toml::de::from_str(&string).map_err(|e| anyhow!("failed to parse lock file: {}", e))
     }
 
-    /// Given a graph of pinned packages, create a `Lock` representing the `Forc.lock` file
-    /// structure.
-    pub fn from_graph(graph: &pkg::Graph) -> Self {
+    /// Given a build plan, create a `Lock` representing the `Forc.lock` file structure.
+    ///
+    /// A checksum of the source of each git package is recorded so that local checkouts can be
+    /// verified on subsequent builds.
+    pub fn from_plan(plan: &pkg::BuildPlan) -> Result<Self> {
+        let graph = plan.graph();
         let names = graph.node_indices().map(|n| &graph[n].name[..]);
         let disambiguate: HashSet<_> = names_requiring_disambiguation(names).collect();
         // Collect the packages.
-        let package: BTreeSet<_> = graph
+        let package = graph
             .node_indices()
-            .map(|node| PkgLock::from_node(graph, node, &disambiguate))
-            .collect();
-        Self { package }
+            .map(|node| {
+                let mut pkg_lock = PkgLock::from_node(graph, node, &disambiguate);
+                let pinned = &graph[node];
+                if let pkg::SourcePinned::Git(_) = pinned.source {
+                    let path = &plan.path_map()[&pinned.id()];
+                    pkg_lock.checksum = Some(pkg::source_checksum(path)?);
+                }
+                Ok(pkg_lock)
+            })
+            .collect::<Result<BTreeSet<_>>>()?;
+        Ok(Self { package })
+    }
+
+    /// The checksums recorded for each package, keyed by the package's pinned ID.
+    pub fn checksums(&self) -> Result<pkg::ChecksumMap> {
+        self.package
+            .iter()
+            .filter_map(|pkg| pkg.checksum.as_ref().map(|checksum| (pkg, checksum)))
+            .map(|(pkg, checksum)| {
+                let name = pkg.name.clone();
+                let source: pkg::SourcePinned = pkg.source.parse().map_err(|e| {
+                    anyhow!("invalid 'source' entry for package {} lock: {:?}", name, e)
+                })?;
+                let pinned = pkg::Pinned { name, source };
+                Ok((pinned.id(), checksum.clone()))
+            })
+            .collect()
+    }
+
+    /// Whether or not any git package in the lock is missing its source checksum.
+    pub fn has_missing_checksums(&self) -> bool {
+        self.package.iter().any(|pkg| {
+            pkg.checksum.is_none() && pkg.source.starts_with(pkg::SourceGitPinned::PREFIX)
+        })
     }
 
     /// Given a `Lock` loaded from a `Forc.lock` file, produce the graph of pinned dependencies.
@@ -1,14 +1,16 @@
 use crate::pkg::{manifest_file_missing, parsing_failed, wrong_program_type};
 use anyhow::{anyhow, bail, Result};
 use forc_util::{find_manifest_dir, println_yellow_err, validate_name};
+use fuel_tx::Salt;
 use serde::{Deserialize, Serialize};
 use std::{
     collections::BTreeMap,
     path::{Path, PathBuf},
+    str::FromStr,
     sync::Arc,
 };
 
-use sway_core::{parse, TreeType};
+use sway_core::{parse, OptLevel, TreeType};
 use sway_utils::constants;
 
 type PatchMap = BTreeMap<String, Dependency>;
@@ -29,6 +31,7 @@ pub struct Manifest {
     pub project: Project,
     pub network: Option<Network>,
     pub dependencies: Option<BTreeMap<String, Dependency>>,
+    pub contract_dependencies: Option<BTreeMap<String, ContractDependency>>,
     pub patch: Option<BTreeMap<String, PatchMap>>,
     build_profile: Option<BTreeMap<String, BuildProfile>>,
 }
@@ -64,6 +67,19 @@ pub enum Dependency {
     Detailed(DependencyDetails),
 }
 
+/// A dependency on a contract package.
+///
+/// The contract is built before the dependent package, which may refer to the ID of the contract
+/// through the `CONTRACT_ID` constant of the module named after the dependency.
+#[derive(Serialize, Deserialize, Debug)]
+#[serde(rename_all = "kebab-case")]
+pub struct ContractDependency {
+    #[serde(flatten)]
+    pub dependency: Dependency,
+    /// The salt used to compute the contract ID, as a 32-byte hex string. Defaults to zero.
+    pub salt: Option<String>,
+}
+
 #[derive(Serialize, Deserialize, Debug, Default)]
 #[serde(rename_all = "kebab-case")]
 pub struct DependencyDetails {
@@ -85,6 +101,12 @@ pub struct BuildProfile {
     pub print_intermediate_asm: bool,
     pub silent: bool,
     pub time_phases: bool,
+    /// The optimizations applied to the IR and ASM: `none`, `size` or `speed`.
+    #[serde(default = "default_optimization_level")]
+    pub optimization_level: OptLevel,
+    /// Whether a source map relating the bytecode back to the source is produced.
+    #[serde(default = "default_include_debug_info")]
+    pub include_debug_info: bool,
 }
 
 impl Dependency {
@@ -237,6 +259,18 @@ impl Manifest {
         if let Some(ref org) = self.project.organization {
             validate_name(org, "organization name")?;
         }
+        for (dep_name, contract_dep) in self.contract_deps() {
+            if self.dep(dep_name).is_some() {
+                bail!(
+                    "dependency `{}` is declared under both [dependencies] and \
+                    [contract-dependencies]",
+                    dep_name
+                );
+            }
+            contract_dep.salt().map_err(|e| {
+                anyhow!("invalid salt for contract dependency `{}`: {}", dep_name, e)
+            })?;
+        }
         Ok(())
     }
 
@@ -258,6 +292,22 @@ impl Manifest {
             .flat_map(|deps| deps.iter())
     }
 
+    /// Produce an iterator yielding all listed contract dependencies.
+    pub fn contract_deps(&self) -> impl Iterator<Item = (&String, &ContractDependency)> {
+        self.contract_dependencies
+            .as_ref()
+            .into_iter()
+            .flat_map(|deps| deps.iter())
+    }
+
+    /// Produce an iterator yielding both the listed dependencies and contract dependencies.
+    pub fn all_deps(&self) -> impl Iterator<Item = (&String, &Dependency)> {
+        let contract_deps = self
+            .contract_deps()
+            .map(|(dep_name, contract_dep)| (dep_name, &contract_dep.dependency));
+        self.deps().chain(contract_deps)
+    }
+
     /// Produce an iterator yielding all listed build profiles.
     pub fn build_profiles(&self) -> impl Iterator<Item = (&String, &BuildProfile)> {
         self.build_profile
@@ -334,6 +384,13 @@ impl Manifest {
             .and_then(|deps| deps.get(dep_name))
     }
 
+    /// Retrieve a reference to the contract dependency with the given name.
+    pub fn contract_dep(&self, dep_name: &str) -> Option<&ContractDependency> {
+        self.contract_dependencies
+            .as_ref()
+            .and_then(|deps| deps.get(dep_name))
+    }
+
     /// Finds and returns the name of the dependency associated with a package of the specified
     /// name if there is one.
     ///
@@ -348,6 +405,16 @@ impl Manifest {
     }
 }
 
+impl ContractDependency {
+    /// The salt used to compute the ID of the contract.
+    pub fn salt(&self) -> Result<Salt> {
+        match self.salt {
+            Some(ref salt) => Salt::from_str(salt).map_err(|e| anyhow!("{}", e)),
+            None => Ok(Salt::zeroed()),
+        }
+    }
+}
+
 impl BuildProfile {
     pub const DEBUG: &'static str = "debug";
     pub const RELEASE: &'static str = "release";
@@ -360,6 +427,8 @@ impl BuildProfile {
             print_intermediate_asm: false,
             silent: false,
             time_phases: false,
+            optimization_level: OptLevel::Size,
+            include_debug_info: true,
         }
     }
 
@@ -370,6 +439,8 @@ impl BuildProfile {
             print_intermediate_asm: false,
             silent: false,
             time_phases: false,
+            optimization_level: OptLevel::Speed,
+            include_debug_info: false,
         }
     }
 }
@@ -405,3 +476,11 @@ fn default_entry() -> String {
 fn default_url() -> String {
     constants::DEFAULT_NODE_URL.into()
 }
+
+fn default_optimization_level() -> OptLevel {
+    BuildProfile::debug().optimization_level
+}
+
+fn default_include_debug_info() -> bool {
+    BuildProfile::debug().include_debug_info
+}
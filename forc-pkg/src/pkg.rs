@@ -7,24 +7,26 @@ use forc_util::{
     find_file_name, git_checkouts_directory, kebab_to_snake_case, print_on_failure,
     print_on_success, print_on_success_library, println_yellow_err,
 };
-use fuel_tx::StorageSlot;
+use fuel_tx::{Address, Bytes32, Contract, ContractId, Salt, StorageSlot};
 use fuels_types::JsonABI;
 use petgraph::{
     self,
     visit::{EdgeRef, IntoNodeReferences},
     Directed, Direction,
 };
+use rayon::prelude::*;
 use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
 use std::{
-    collections::{hash_map, BTreeSet, HashMap, HashSet},
+    collections::{hash_map, BTreeMap, BTreeSet, HashMap, HashSet},
     fmt, fs,
     hash::{Hash, Hasher},
     path::{Path, PathBuf},
     str::FromStr,
 };
 use sway_core::{
-    semantic_analysis::namespace, source_map::SourceMap, types::*, BytecodeCompilationResult,
-    CompileAstResult, CompileError, TreeType,
+    bytecode_layout::BytecodeLayout, semantic_analysis::namespace, source_map::SourceMap, types::*,
+    BytecodeCompilationResult, CompileAstResult, CompileError, TreeType,
 };
 use sway_utils::constants;
 use tracing::info;
@@ -37,6 +39,22 @@ pub type Graph = petgraph::stable_graph::StableGraph<Node, Edge, Directed, Graph
 pub type NodeIx = petgraph::graph::NodeIndex<GraphIx>;
 pub type PathMap = HashMap<PinnedId, PathBuf>;
 
+/// Previously pinned git sources that should be reused rather than resolved again.
+///
+/// Used by targeted updates to keep all packages other than the one being updated at their locked
+/// commits.
+type LockedPins = HashMap<Pkg, SourceGitPinned>;
+
+/// A map from pinned package IDs to the checksums of their sources as recorded in `Forc.lock`.
+pub type ChecksumMap = HashMap<PinnedId, String>;
+
+/// The name of the file within a git checkout that records the checksum of the package source at
+/// the time the checkout was fetched.
+const CHECKOUT_CHECKSUM_FILE_NAME: &str = ".forc-checksum";
+
+/// The name of the directory within a project to which `forc vendor` copies git dependencies.
+pub const VENDOR_DIR_NAME: &str = "vendor";
+
 /// A unique ID for a pinned package.
 ///
 /// The internal value is produced by hashing the package's name and `SourcePinned`.
@@ -48,6 +66,8 @@ pub struct Compiled {
     pub json_abi: JsonABI,
     pub storage_slots: Vec<StorageSlot>,
     pub bytecode: Vec<u8>,
+    /// Describes the location of entry points and data within the `bytecode`.
+    pub bytecode_layout: BytecodeLayout,
     pub tree_type: TreeType,
 }
 
@@ -218,7 +238,49 @@ impl BuildPlan {
     /// Create a new build plan for the project by fetching and pinning dependenies.
     pub fn new(manifest: &ManifestFile, sway_git_tag: &str, offline: bool) -> Result<Self> {
         let path = manifest.dir().to_path_buf();
-        let (graph, path_map) = fetch_deps(path, manifest, sway_git_tag, offline)?;
+        let locked = LockedPins::new();
+        let (graph, path_map) = fetch_deps(path, manifest, &locked, sway_git_tag, offline)?;
+        let compilation_order = compilation_order(&graph)?;
+        Ok(Self {
+            graph,
+            path_map,
+            compilation_order,
+        })
+    }
+
+    /// Create a new build plan from an existing one, re-pinning only the packages named
+    /// `pkg_name`.
+    ///
+    /// All other git dependencies remain pinned to the commits recorded in the existing plan. Any
+    /// packages that are newly required as a result of the update are fetched and pinned.
+    pub fn update_package(
+        &self,
+        manifest: &ManifestFile,
+        pkg_name: &str,
+        sway_git_tag: &str,
+        offline: bool,
+    ) -> Result<Self> {
+        if !self
+            .graph
+            .node_weights()
+            .any(|pinned| pinned.name == pkg_name)
+        {
+            bail!(
+                "package `{}` could not be found in the dependency graph",
+                pkg_name
+            );
+        }
+        let locked: LockedPins = self
+            .graph
+            .node_weights()
+            .filter(|pinned| pinned.name != pkg_name)
+            .filter_map(|pinned| match &pinned.source {
+                SourcePinned::Git(git) => Some((pinned.unpinned(&self.path_map), git.clone())),
+                _ => None,
+            })
+            .collect();
+        let path = manifest.dir().to_path_buf();
+        let (graph, path_map) = fetch_deps(path, manifest, &locked, sway_git_tag, offline)?;
         let compilation_order = compilation_order(&graph)?;
         Ok(Self {
             graph,
@@ -242,28 +304,45 @@ impl BuildPlan {
         sway_git_tag: &str,
     ) -> Result<Self> {
         let lock_path = forc_util::lock_path(manifest.dir());
-        let plan_result = BuildPlan::from_lock_file(&lock_path, sway_git_tag);
-
-        // Retrieve the old lock file state so we can produce a diff.
-        let old_lock = plan_result
-            .as_ref()
-            .ok()
-            .map(|plan| Lock::from_graph(plan.graph()))
-            .unwrap_or_default();
+        let plan_result = BuildPlan::from_lock_file(&lock_path, locked, sway_git_tag);
+
+        // Retrieve the old lock file state so we can produce a diff. Read the lock file directly
+        // in case the graph could not be reconstructed from it, e.g. due to a removed dependency.
+        let old_lock = Lock::from_path(&lock_path).ok().unwrap_or_default();
+
+        let lock_update_required = |cause: &Error| {
+            anyhow!(
+                "The lock file {} needs to be updated (Cause: {}) \
+                but --locked was passed to prevent this.",
+                lock_path.to_string_lossy(),
+                cause,
+            )
+        };
 
         // Check if there are any errors coming from the BuildPlan generation from the lock file
         // If there are errors we will need to create the BuildPlan from scratch, i.e fetch & pin everything
         let mut new_lock_cause = None;
         let mut plan = plan_result.or_else(|e| -> Result<BuildPlan> {
-            new_lock_cause = if e.to_string().contains("No such file or directory") {
-                Some(anyhow!("lock file did not exist"))
+            let cause = if e.to_string().contains("No such file or directory") {
+                anyhow!("lock file did not exist")
             } else {
-                Some(e)
+                e
             };
+            // Avoid fetching and pinning everything only to produce an error afterwards.
+            if locked {
+                return Err(lock_update_required(&cause));
+            }
+            new_lock_cause = Some(cause);
             let plan = BuildPlan::new(manifest, sway_git_tag, offline)?;
             Ok(plan)
         })?;
 
+        // Lock files written prior to checksums being recorded are updated to include them. They
+        // remain valid as they are, so `--locked` builds continue to use them unchanged.
+        if new_lock_cause.is_none() && !locked && old_lock.has_missing_checksums() {
+            new_lock_cause = Some(anyhow!("lock file is missing source checksums"));
+        }
+
         // If there are no issues with the BuildPlan generated from the lock file
         // Check and apply the diff.
         if new_lock_cause.is_none() {
@@ -276,12 +355,7 @@ impl BuildPlan {
 
         if let Some(cause) = new_lock_cause {
             if locked {
-                bail!(
-                    "The lock file {} needs to be updated (Cause: {}) \
-                    but --locked was passed to prevent this.",
-                    lock_path.to_string_lossy(),
-                    cause,
-                );
+                return Err(lock_update_required(&cause));
             }
 
             info!("  Creating a new `Forc.lock` file. (Cause: {})", cause);
@@ -333,10 +407,27 @@ impl BuildPlan {
     }
 
     /// Attempt to load the build plan from the `Lock`.
-    pub fn from_lock(proj_path: &Path, lock: &Lock, sway_git_tag: &str) -> Result<Self> {
+    ///
+    /// The local checkout of each git dependency is verified against the checksum recorded in the
+    /// lock. Checkouts that fail verification are fetched again, or produce an error if `locked`
+    /// is `true`.
+    pub fn from_lock(
+        proj_path: &Path,
+        lock: &Lock,
+        locked: bool,
+        sway_git_tag: &str,
+    ) -> Result<Self> {
         let graph = lock.to_graph()?;
+        let checksums = lock.checksums()?;
         let compilation_order = compilation_order(&graph)?;
-        let path_map = graph_to_path_map(proj_path, &graph, &compilation_order, sway_git_tag)?;
+        let path_map = graph_to_path_map(
+            proj_path,
+            &graph,
+            &compilation_order,
+            &checksums,
+            locked,
+            sway_git_tag,
+        )?;
         Ok(Self {
             graph,
             path_map,
@@ -345,10 +436,10 @@ impl BuildPlan {
     }
 
     /// Attempt to load the build plan from the `Forc.lock` file.
-    pub fn from_lock_file(lock_path: &Path, sway_git_tag: &str) -> Result<Self> {
+    pub fn from_lock_file(lock_path: &Path, locked: bool, sway_git_tag: &str) -> Result<Self> {
         let proj_path = lock_path.parent().unwrap();
         let lock = Lock::from_path(lock_path)?;
-        Self::from_lock(proj_path, &lock, sway_git_tag)
+        Self::from_lock(proj_path, &lock, locked, sway_git_tag)
     }
 
     /// Ensure that the build plan is valid for the given manifest.
@@ -376,7 +467,7 @@ impl BuildPlan {
         let proj_id = self.graph[proj_node].id();
         let proj_path = &self.path_map[&proj_id];
         let manifest_dep_pkgs = manifest
-            .deps()
+            .all_deps()
             .map(|(dep_name, dep)| {
                 // NOTE: Temporarily warn about `version` until we have support for registries.
                 if let Dependency::Detailed(det) = dep {
@@ -487,20 +578,32 @@ fn add_deps(
         .last()
         .ok_or_else(|| anyhow!("Invalid Graph"))?;
     let proj_id = graph[proj_node].id();
-    let proj_path = &path_map[&proj_id];
+    let proj_path = path_map[&proj_id].clone();
     let fetch_ts = std::time::Instant::now();
-    let fetch_id = fetch_id(proj_path, fetch_ts);
+    let fetch_id = fetch_id(&proj_path, fetch_ts);
     let path_root = proj_id;
+    let locked = LockedPins::new();
     for (added_dep_name, added_package) in to_add {
-        let pinned_pkg = pin_pkg(fetch_id, proj_id, added_package, path_map, sway_git_tag)?;
+        let pinned_pkg = pin_pkg(
+            fetch_id,
+            &proj_path,
+            offline_mode,
+            proj_id,
+            added_package,
+            &locked,
+            path_map,
+            sway_git_tag,
+        )?;
         let manifest = Manifest::from_dir(&path_map[&pinned_pkg.id()], sway_git_tag)?;
         let added_package_node = graph.add_node(pinned_pkg.clone());
         fetch_children(
             fetch_id,
+            &proj_path,
             offline_mode,
             added_package_node,
             &manifest,
             path_root,
+            &locked,
             sway_git_tag,
             graph,
             path_map,
@@ -811,10 +914,17 @@ pub fn compilation_order(graph: &Graph) -> Result<Vec<NodeIx>> {
 
 /// Given graph of pinned dependencies and the directory for the root node, produce a path map
 /// containing the path to the local source for every node in the graph.
+///
+/// Git dependencies that have been vendored within the project directory are resolved to their
+/// vendored copy. All other git dependencies are resolved to their checkout within the user's forc
+/// directory. In both cases the source is verified against its entry in `checksums` where present.
+/// See [fetch_git_pkg] for details.
 pub fn graph_to_path_map(
     proj_manifest_dir: &Path,
     graph: &Graph,
     compilation_order: &[NodeIx],
+    checksums: &ChecksumMap,
+    locked: bool,
     sway_git_tag: &str,
 ) -> Result<PathMap> {
     let mut path_map = PathMap::new();
@@ -840,18 +950,18 @@ pub fn graph_to_path_map(
         let dep_path = match &dep.source {
             SourcePinned::Root => bail!("more than one root package detected in graph"),
             SourcePinned::Git(git) => {
-                let repo_path = git_commit_path(&dep.name, &git.source.repo, &git.commit_hash);
-                if !repo_path.exists() {
-                    info!("  Fetching {}", git.to_string());
-                    fetch_git(fetch_id, &dep.name, git)?;
+                let expected = checksums.get(&dep.id()).map(String::as_str);
+                let vendored_path = vendored_git_commit_path(
+                    proj_manifest_dir,
+                    &dep.name,
+                    &git.source.repo,
+                    &git.commit_hash,
+                );
+                if vendored_path.exists() {
+                    find_vendored_git_pkg(&vendored_path, &dep.name, expected, sway_git_tag)?
+                } else {
+                    fetch_git_pkg(fetch_id, &dep.name, git, expected, locked, sway_git_tag)?
                 }
-                find_dir_within(&repo_path, &dep.name, sway_git_tag).ok_or_else(|| {
-                    anyhow!(
-                        "failed to find package `{}` in {}",
-                        dep.name,
-                        git.to_string()
-                    )
-                })?
             }
             SourcePinned::Path(path) => {
                 // This is already checked during `Graph::from_lock`, but we check again here just
@@ -870,9 +980,9 @@ pub fn graph_to_path_map(
                 let parent_path = &path_map[&parent.id()];
                 let parent_manifest = ManifestFile::from_dir(parent_path, sway_git_tag)?;
                 let detailed = parent_manifest
-                    .dependencies
-                    .as_ref()
-                    .and_then(|deps| deps.get(&dep_name))
+                    .all_deps()
+                    .find(|(name, _)| **name == dep_name)
+                    .map(|(_, dep)| dep)
                     .ok_or_else(|| {
                         anyhow!(
                             "dependency required for path reconstruction \
@@ -962,6 +1072,7 @@ pub(crate) fn validate_path_root(
 pub(crate) fn fetch_deps(
     proj_manifest_dir: PathBuf,
     proj_manifest: &Manifest,
+    locked: &LockedPins,
     sway_git_tag: &str,
     offline_mode: bool,
 ) -> Result<(Graph, PathMap)> {
@@ -974,7 +1085,7 @@ pub(crate) fn fetch_deps(
     let source = SourcePinned::Root;
     let pkg = Pinned { name, source };
     let pkg_id = pkg.id();
-    path_map.insert(pkg_id, path);
+    path_map.insert(pkg_id, path.clone());
     let root = graph.add_node(pkg);
 
     // The set of visited packages, starting with the root.
@@ -989,10 +1100,12 @@ pub(crate) fn fetch_deps(
     let path_root = pkg_id;
     fetch_children(
         fetch_id,
+        &path,
         offline_mode,
         root,
         &manifest,
         path_root,
+        locked,
         sway_git_tag,
         &mut graph,
         &mut path_map,
@@ -1045,13 +1158,17 @@ pub fn fetch_id(path: &Path, timestamp: std::time::Instant) -> u64 {
 }
 
 /// Fetch children nodes of the given node and add unvisited nodes to the graph.
+///
+/// Git dependencies vendored within the project at `proj_dir` are used in place of fetching them.
 #[allow(clippy::too_many_arguments)]
 fn fetch_children(
     fetch_id: u64,
+    proj_dir: &Path,
     offline_mode: bool,
     node: NodeIx,
     manifest: &Manifest,
     path_root: PinnedId,
+    locked: &LockedPins,
     sway_git_tag: &str,
     graph: &mut Graph,
     path_map: &mut PathMap,
@@ -1060,7 +1177,8 @@ fn fetch_children(
     let parent = &graph[node];
     let parent_id = parent.id();
     let parent_path = path_map[&parent_id].clone();
-    for (dep_name, dep) in manifest.deps() {
+    let mut deps = vec![];
+    for (dep_name, dep) in manifest.all_deps() {
         let name = dep.package().unwrap_or(dep_name).to_string();
         let source = apply_patch(
             &name,
@@ -1068,11 +1186,39 @@ fn fetch_children(
             manifest,
             &parent_path,
         )?;
-        if offline_mode && !matches!(source, Source::Path(_)) {
+        // Git dependencies may still be pinned from their vendored sources in offline mode.
+        if offline_mode && !matches!(source, Source::Path(_) | Source::Git(_)) {
             bail!("Unable to fetch pkg {:?} in offline mode", source);
         }
-        let pkg = Pkg { name, source };
-        let pinned = pin_pkg(fetch_id, path_root, &pkg, path_map, sway_git_tag)?;
+        deps.push((dep_name, Pkg { name, source }));
+    }
+
+    // Pin and fetch the git dependencies up front so that independent sources are fetched
+    // concurrently. The graph itself is still built in the order the dependencies are declared.
+    let pinned_git = pin_git_pkgs(
+        fetch_id,
+        proj_dir,
+        offline_mode,
+        deps.iter().map(|(_, pkg)| pkg),
+        locked,
+        path_map,
+        sway_git_tag,
+    )?;
+
+    for (dep_name, pkg) in deps {
+        let pinned = match pinned_git.get(&pkg) {
+            Some(pinned) => pinned.clone(),
+            None => pin_pkg(
+                fetch_id,
+                proj_dir,
+                offline_mode,
+                path_root,
+                &pkg,
+                locked,
+                path_map,
+                sway_git_tag,
+            )?,
+        };
         let pkg_id = pinned.id();
         let path_root = match pkg.source {
             Source::Root | Source::Git(_) | Source::Registry(_) => pkg_id,
@@ -1093,10 +1239,12 @@ fn fetch_children(
             entry.insert(node);
             fetch_children(
                 fetch_id,
+                proj_dir,
                 offline_mode,
                 node,
                 &manifest,
                 path_root,
+                locked,
                 sway_git_tag,
                 graph,
                 path_map,
@@ -1140,6 +1288,20 @@ fn tmp_git_repo_dir(fetch_id: u64, name: &str, repo: &Url) -> PathBuf {
     git_checkouts_directory().join("tmp").join(repo_dir_name)
 }
 
+/// A temporary directory to which a pinned commit is checked out before being moved into place.
+///
+/// The resulting directory is:
+///
+/// ```ignore
+/// $HOME/.forc/git/checkouts/tmp/<fetch_id>-name-<repo_url_hash>-checkout
+/// ```
+fn tmp_git_checkout_dir(fetch_id: u64, name: &str, repo: &Url) -> PathBuf {
+    let checkout_dir_name = format!("{:x}-{}-checkout", fetch_id, git_repo_dir_name(name, repo));
+    git_checkouts_directory()
+        .join("tmp")
+        .join(checkout_dir_name)
+}
+
 /// Given a git reference, build a list of `refspecs` required for the fetch opration.
 ///
 /// Also returns whether or not our reference implies we require fetching tags.
@@ -1232,10 +1394,17 @@ pub fn pin_git(fetch_id: u64, name: &str, source: SourceGit) -> Result<SourceGit
 ///
 /// The `path_root` is required for `Path` dependencies and must specify the package that is the
 /// root of the current subgraph of path dependencies.
+///
+/// Git sources present in `locked` reuse their existing pinned commit rather than resolving the
+/// reference again. See [pin_git_pkg] for how vendored git sources are pinned.
+#[allow(clippy::too_many_arguments)]
 fn pin_pkg(
     fetch_id: u64,
+    proj_dir: &Path,
+    offline: bool,
     path_root: PinnedId,
     pkg: &Pkg,
+    locked: &LockedPins,
     path_map: &mut PathMap,
     sway_git_tag: &str,
 ) -> Result<Pinned> {
@@ -1251,31 +1420,18 @@ fn pin_pkg(
             pinned
         }
         Source::Git(ref git_source) => {
-            let pinned_git = pin_git(fetch_id, &name, git_source.clone())?;
-            let repo_path =
-                git_commit_path(&name, &pinned_git.source.repo, &pinned_git.commit_hash);
-            let source = SourcePinned::Git(pinned_git.clone());
-            let pinned = Pinned { name, source };
-            let id = pinned.id();
-            if let hash_map::Entry::Vacant(entry) = path_map.entry(id) {
-                // TODO: Here we assume that if the local path already exists, that it contains the full and
-                // correct source for that commit and hasn't been tampered with. This is probably fine for most
-                // cases as users should never be touching these directories, however we should add some code
-                // to validate this. E.g. can we recreate the git hash by hashing the directory or something
-                // along these lines using git?
-                if !repo_path.exists() {
-                    info!("  Fetching {}", pinned_git.to_string());
-                    fetch_git(fetch_id, &pinned.name, &pinned_git)?;
-                }
-                let path =
-                    find_dir_within(&repo_path, &pinned.name, sway_git_tag).ok_or_else(|| {
-                        anyhow!(
-                            "failed to find package `{}` in {}",
-                            pinned.name,
-                            pinned_git.to_string()
-                        )
-                    })?;
-                entry.insert(path);
+            let (pinned, fetched_path) = pin_git_pkg(
+                fetch_id,
+                proj_dir,
+                offline,
+                pkg,
+                git_source,
+                locked,
+                path_map,
+                sway_git_tag,
+            )?;
+            if let Some(path) = fetched_path {
+                path_map.insert(pinned.id(), path);
             }
             pinned
         }
@@ -1288,6 +1444,167 @@ fn pin_pkg(
     Ok(pinned)
 }
 
+/// Pin the given git package, fetching the pinned commit unless `path_map` already contains it.
+///
+/// A pinned commit vendored within the project at `proj_dir` is used rather than fetching it. In
+/// `offline` mode, packages not present in `locked` are pinned to their vendored commit, and the
+/// pinned commit must either be vendored or already be checked out.
+///
+/// Returns the pinned package along with the path of the checkout if it had to be fetched.
+#[allow(clippy::too_many_arguments)]
+fn pin_git_pkg(
+    fetch_id: u64,
+    proj_dir: &Path,
+    offline: bool,
+    pkg: &Pkg,
+    git_source: &SourceGit,
+    locked: &LockedPins,
+    path_map: &PathMap,
+    sway_git_tag: &str,
+) -> Result<(Pinned, Option<PathBuf>)> {
+    let name = pkg.name.clone();
+    let pinned_git = match locked.get(pkg) {
+        Some(pinned_git) => pinned_git.clone(),
+        None if offline => vendored_git_pin(proj_dir, &name, git_source)?,
+        None => pin_git(fetch_id, &name, git_source.clone())?,
+    };
+    let source = SourcePinned::Git(pinned_git.clone());
+    let pinned = Pinned { name, source };
+    if path_map.contains_key(&pinned.id()) {
+        return Ok((pinned, None));
+    }
+    let vendored_path = vendored_git_commit_path(
+        proj_dir,
+        &pinned.name,
+        &pinned_git.source.repo,
+        &pinned_git.commit_hash,
+    );
+    let path = if vendored_path.exists() {
+        find_vendored_git_pkg(&vendored_path, &pinned.name, None, sway_git_tag)?
+    } else if offline {
+        let repo_path = git_commit_path(
+            &pinned.name,
+            &pinned_git.source.repo,
+            &pinned_git.commit_hash,
+        );
+        verify_git_checkout(&repo_path, &pinned.name, None, sway_git_tag).map_err(|e| {
+            anyhow!(
+                "Unable to fetch {} in offline mode, and no valid checkout exists ({})",
+                pinned_git,
+                e,
+            )
+        })?
+    } else {
+        fetch_git_pkg(
+            fetch_id,
+            &pinned.name,
+            &pinned_git,
+            None,
+            false,
+            sway_git_tag,
+        )?
+    };
+    Ok((pinned, Some(path)))
+}
+
+/// Determine the commit to which the git package `name` is vendored within the project at
+/// `proj_dir`, allowing the package to be pinned without access to its repository.
+///
+/// Only a `rev` reference identifies a vendored commit. The commit that a branch or tag refers to
+/// may have changed since the package was vendored, so these can only be pinned offline from the
+/// lock file.
+fn vendored_git_pin(proj_dir: &Path, name: &str, source: &SourceGit) -> Result<SourceGitPinned> {
+    let rev = match &source.reference {
+        GitReference::Rev(rev) => rev,
+        reference => bail!(
+            "Unable to pin package `{}` from {} ({}) in offline mode, as it is not recorded in \
+             the lock file. Only git dependencies that specify a `rev` can be pinned to their \
+             vendored source.",
+            name,
+            source.repo,
+            reference,
+        ),
+    };
+    let repo_dir = proj_dir
+        .join(VENDOR_DIR_NAME)
+        .join(git_repo_dir_name(name, &source.repo));
+    let commit_hash = fs::read_dir(repo_dir)
+        .into_iter()
+        .flatten()
+        .filter_map(|entry| entry.ok())
+        .filter(|entry| entry.path().is_dir())
+        .map(|entry| entry.file_name().to_string_lossy().into_owned())
+        .find(|commit| commit.starts_with(rev.as_str()))
+        .ok_or_else(|| {
+            anyhow!(
+                "Unable to pin package `{}` from {} in offline mode as rev {} has not been vendored",
+                name,
+                source.repo,
+                rev,
+            )
+        })?;
+    Ok(SourceGitPinned {
+        source: source.clone(),
+        commit_hash,
+    })
+}
+
+/// Pin and fetch the given git packages concurrently, adding the paths of fetched checkouts to
+/// `path_map`.
+///
+/// Packages with the same name and repository share a temporary git directory during fetching, so
+/// these are handled one after the other within the same task.
+fn pin_git_pkgs<'a>(
+    fetch_id: u64,
+    proj_dir: &Path,
+    offline: bool,
+    pkgs: impl IntoIterator<Item = &'a Pkg>,
+    locked: &LockedPins,
+    path_map: &mut PathMap,
+    sway_git_tag: &str,
+) -> Result<HashMap<Pkg, Pinned>> {
+    let mut groups: BTreeMap<(&str, &Url), BTreeSet<(&Pkg, &SourceGit)>> = BTreeMap::new();
+    for pkg in pkgs {
+        if let Source::Git(ref git_source) = pkg.source {
+            groups
+                .entry((&pkg.name, &git_source.repo))
+                .or_default()
+                .insert((pkg, git_source));
+        }
+    }
+    let shared_path_map: &PathMap = path_map;
+    let groups: Vec<_> = groups.into_values().collect();
+    let pinned_groups = groups
+        .into_par_iter()
+        .map(|group| {
+            group
+                .into_iter()
+                .map(|(pkg, git_source)| {
+                    let (pinned, fetched_path) = pin_git_pkg(
+                        fetch_id,
+                        proj_dir,
+                        offline,
+                        pkg,
+                        git_source,
+                        locked,
+                        shared_path_map,
+                        sway_git_tag,
+                    )?;
+                    Ok((pkg.clone(), pinned, fetched_path))
+                })
+                .collect::<Result<Vec<_>>>()
+        })
+        .collect::<Result<Vec<_>>>()?;
+    let mut pinned_pkgs = HashMap::new();
+    for (pkg, pinned, fetched_path) in pinned_groups.into_iter().flatten() {
+        if let Some(path) = fetched_path {
+            path_map.insert(pinned.id(), path);
+        }
+        pinned_pkgs.insert(pkg, pinned);
+    }
+    Ok(pinned_pkgs)
+}
+
 /// The path to which a git package commit should be checked out.
 ///
 /// The resulting directory is:
@@ -1306,31 +1623,340 @@ pub fn git_commit_path(name: &str, repo: &Url, commit_hash: &str) -> PathBuf {
 
 /// Fetch the repo at the given git package's URL and checkout the pinned commit.
 ///
+/// The commit is first checked out to a temporary directory and then moved into place, so that an
+/// interrupted fetch never leaves a partially written checkout behind.
+///
 /// Returns the location of the checked out commit.
 pub fn fetch_git(fetch_id: u64, name: &str, pinned: &SourceGitPinned) -> Result<PathBuf> {
     let path = git_commit_path(name, &pinned.source.repo, &pinned.commit_hash);
+    let tmp_path = tmp_git_checkout_dir(fetch_id, name, &pinned.source.repo);
 
-    // Checkout the pinned hash to the path.
+    // Checkout the pinned hash to the temporary path.
     with_tmp_git_repo(fetch_id, name, &pinned.source, |repo| {
         // Change HEAD to point to the pinned commit.
         let id = git2::Oid::from_str(&pinned.commit_hash)?;
         repo.set_head_detached(id)?;
 
-        if path.exists() {
-            let _ = std::fs::remove_dir_all(&path);
+        if tmp_path.exists() {
+            let _ = fs::remove_dir_all(&tmp_path);
         }
-        std::fs::create_dir_all(&path)?;
+        fs::create_dir_all(&tmp_path)?;
 
-        // Checkout HEAD to the target directory.
+        // Checkout HEAD to the temporary directory.
         let mut checkout = git2::build::CheckoutBuilder::new();
-        checkout.force().target_dir(&path);
+        checkout.force().target_dir(&tmp_path);
         repo.checkout_head(Some(&mut checkout))?;
         Ok(())
     })?;
 
+    // Move the complete checkout into place.
+    if path.exists() {
+        let _ = fs::remove_dir_all(&path);
+    }
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent)?;
+    }
+    fs::rename(&tmp_path, &path).map_err(|e| {
+        anyhow!(
+            "failed to move checkout of {} into place at \"{}\": {}",
+            pinned,
+            path.display(),
+            e
+        )
+    })?;
+
+    Ok(path)
+}
+
+/// Locate the package `name` within the local checkout of the pinned git source, fetching the
+/// checkout if it is missing or fails verification.
+///
+/// A checkout is verified by comparing the checksum of the package source against the checksum
+/// written when the checkout was fetched and, if given, the `expected` checksum from the lock file.
+/// If `locked` is `true`, a checkout that fails verification produces an error rather than being
+/// fetched again.
+///
+/// Returns the path to the package within the checkout.
+fn fetch_git_pkg(
+    fetch_id: u64,
+    name: &str,
+    pinned: &SourceGitPinned,
+    expected: Option<&str>,
+    locked: bool,
+    sway_git_tag: &str,
+) -> Result<PathBuf> {
+    let repo_path = git_commit_path(name, &pinned.source.repo, &pinned.commit_hash);
+    if repo_path.exists() {
+        match verify_git_checkout(&repo_path, name, expected, sway_git_tag) {
+            Ok(path) => return Ok(path),
+            Err(e) if locked => bail!(
+                "the checkout of {} failed verification ({}); run `forc clean --cache` to remove \
+                invalid checkouts",
+                pinned,
+                e,
+            ),
+            Err(e) => info!("  Checkout of {} failed verification ({})", pinned, e),
+        }
+    }
+
+    info!("  Fetching {}", pinned);
+    fetch_git(fetch_id, name, pinned)?;
+    let path = find_git_pkg_dir(&repo_path, name, sway_git_tag)?;
+    let checksum = source_checksum(&path)?;
+    fs::write(repo_path.join(CHECKOUT_CHECKSUM_FILE_NAME), &checksum)?;
+    if let Some(expected) = expected {
+        if expected != checksum {
+            bail!(
+                "the checksum of package `{}` fetched from {} does not match the lock file \
+                (expected {}, found {})",
+                name,
+                pinned,
+                expected,
+                checksum,
+            );
+        }
+    }
+    Ok(path)
+}
+
+/// Verify that the checkout at `repo_path` contains an unmodified copy of the package `name`,
+/// returning the path to the package within the checkout.
+///
+/// Checkouts fetched before checksums were recorded have no checksum file. These are accepted as
+/// long as they match the `expected` checksum (if any), and their checksum is recorded so that
+/// later modifications are detected.
+pub fn verify_git_checkout(
+    repo_path: &Path,
+    name: &str,
+    expected: Option<&str>,
+    sway_git_tag: &str,
+) -> Result<PathBuf> {
+    let path = find_git_pkg_dir(repo_path, name, sway_git_tag)?;
+    let checksum = source_checksum(&path)?;
+    let checksum_path = repo_path.join(CHECKOUT_CHECKSUM_FILE_NAME);
+    let recorded = fs::read_to_string(&checksum_path).ok();
+    if matches!(&recorded, Some(recorded) if recorded.trim() != checksum) {
+        bail!("source does not match the checksum recorded when it was fetched");
+    }
+    if matches!(expected, Some(expected) if expected != checksum) {
+        bail!("source does not match the checksum recorded in the lock file");
+    }
+    if recorded.is_none() {
+        fs::write(&checksum_path, &checksum)?;
+    }
     Ok(path)
 }
 
+fn find_git_pkg_dir(repo_path: &Path, name: &str, sway_git_tag: &str) -> Result<PathBuf> {
+    find_dir_within(repo_path, name, sway_git_tag).ok_or_else(|| {
+        anyhow!(
+            "failed to find package `{}` in \"{}\"",
+            name,
+            repo_path.display()
+        )
+    })
+}
+
+/// Compute a checksum over the source of the package at `pkg_path`.
+///
+/// The checksum covers the relative path and contents of every file within the directory, visited
+/// in sorted order. `.git` directories and the checkout checksum file itself are ignored.
+pub fn source_checksum(pkg_path: &Path) -> Result<String> {
+    let mut hasher = Sha256::new();
+    let entries = walkdir::WalkDir::new(pkg_path)
+        .sort_by_file_name()
+        .into_iter()
+        .filter_entry(|entry| {
+            let name = entry.file_name();
+            name != ".git" && name != CHECKOUT_CHECKSUM_FILE_NAME
+        });
+    for entry in entries {
+        let entry = entry?;
+        let contents = if entry.file_type().is_file() {
+            fs::read(entry.path())?
+        } else if entry.path_is_symlink() {
+            let target = fs::read_link(entry.path())?;
+            target.to_string_lossy().into_owned().into_bytes()
+        } else {
+            continue;
+        };
+        let rel_path = entry
+            .path()
+            .strip_prefix(pkg_path)?
+            .components()
+            .map(|component| component.as_os_str().to_string_lossy())
+            .collect::<Vec<_>>()
+            .join("/");
+        hasher.update(rel_path.as_bytes());
+        hasher.update([0]);
+        hasher.update((contents.len() as u64).to_be_bytes());
+        hasher.update(&contents);
+    }
+    Ok(format!("{:x}", hasher.finalize()))
+}
+
+/// The path to which a git package commit is vendored within the project at `proj_dir`.
+///
+/// The vendor directory mirrors the layout of the git checkouts directory:
+///
+/// ```ignore
+/// <proj_dir>/vendor/name-<repo_url_hash>/<commit_hash>
+/// ```
+///
+/// Only the packages required by the project are copied, at the same relative location as within
+/// the checkout. This ensures relative `path` dependencies between packages of the same
+/// repository still resolve.
+pub fn vendored_git_commit_path(
+    proj_dir: &Path,
+    name: &str,
+    repo: &Url,
+    commit_hash: &str,
+) -> PathBuf {
+    proj_dir
+        .join(VENDOR_DIR_NAME)
+        .join(git_repo_dir_name(name, repo))
+        .join(commit_hash)
+}
+
+/// Locate the package `name` within its vendored commit directory, verifying it against the
+/// `expected` checksum from the lock file if given.
+fn find_vendored_git_pkg(
+    vendored_path: &Path,
+    name: &str,
+    expected: Option<&str>,
+    sway_git_tag: &str,
+) -> Result<PathBuf> {
+    let path = find_git_pkg_dir(vendored_path, name, sway_git_tag)?;
+    if let Some(expected) = expected {
+        if source_checksum(&path)? != expected {
+            bail!(
+                "the vendored source of package `{}` at \"{}\" does not match the checksum \
+                recorded in the lock file; run `forc vendor` to vendor it again",
+                name,
+                path.display(),
+            );
+        }
+    }
+    Ok(path)
+}
+
+/// Copy the source of every package in the build plan that was fetched from git into the
+/// `vendor` directory of the project, replacing any previously vendored sources.
+///
+/// Returns the vendored packages along with the paths to which they were copied.
+pub fn vendor(plan: &BuildPlan) -> Result<Vec<(Pinned, PathBuf)>> {
+    let proj_node = *plan
+        .compilation_order
+        .last()
+        .ok_or_else(|| anyhow!("Invalid Graph"))?;
+    let proj_dir = &plan.path_map[&plan.graph[proj_node].id()];
+    let vendor_dir = proj_dir.join(VENDOR_DIR_NAME);
+    let checkouts_dir = git_checkouts_directory();
+    let checkouts_dir = checkouts_dir.canonicalize().unwrap_or(checkouts_dir);
+
+    // Copy into a temporary directory first, as the plan may refer to sources within the current
+    // vendor directory.
+    let tmp_vendor_dir = proj_dir.join(format!(".{}.tmp", VENDOR_DIR_NAME));
+    if tmp_vendor_dir.exists() {
+        fs::remove_dir_all(&tmp_vendor_dir)?;
+    }
+    fs::create_dir_all(&tmp_vendor_dir)?;
+
+    let mut vendored = vec![];
+    for &node in &plan.compilation_order {
+        let pinned = &plan.graph[node];
+        let src = plan.path_map[&pinned.id()].canonicalize()?;
+        // Packages fetched from git, along with any path dependencies within their repositories,
+        // are found either in the user's git checkouts or the current vendor directory.
+        let rel_path = match src
+            .strip_prefix(&checkouts_dir)
+            .or_else(|_| src.strip_prefix(&vendor_dir))
+        {
+            Ok(rel_path) => rel_path,
+            Err(_) => continue,
+        };
+        let dst = tmp_vendor_dir.join(rel_path);
+        copy_pkg_source(&src, &dst)?;
+        vendored.push((pinned.clone(), vendor_dir.join(rel_path)));
+    }
+
+    if vendor_dir.exists() {
+        fs::remove_dir_all(&vendor_dir)?;
+    }
+    fs::rename(&tmp_vendor_dir, &vendor_dir)?;
+    Ok(vendored)
+}
+
+/// Recursively copy a package's source from `src` to `dst`, skipping `.git` directories and
+/// checkout checksum files.
+fn copy_pkg_source(src: &Path, dst: &Path) -> Result<()> {
+    let entries = walkdir::WalkDir::new(src)
+        .into_iter()
+        .filter_entry(|entry| {
+            let name = entry.file_name();
+            name != ".git" && name != CHECKOUT_CHECKSUM_FILE_NAME
+        });
+    for entry in entries {
+        let entry = entry?;
+        let target = dst.join(entry.path().strip_prefix(src)?);
+        if entry.file_type().is_dir() {
+            fs::create_dir_all(&target)?;
+        } else {
+            fs::copy(entry.path(), &target).with_context(|| {
+                format!(
+                    "failed to copy {} to {}",
+                    entry.path().display(),
+                    target.display()
+                )
+            })?;
+        }
+    }
+    Ok(())
+}
+
+/// Remove all git checkouts that fail verification from the user's forc directory.
+///
+/// The `tmp` directory is left untouched, as it may be in use by a concurrent fetch.
+///
+/// Returns the paths of the removed directories.
+pub fn prune_git_checkouts(sway_git_tag: &str) -> Result<Vec<PathBuf>> {
+    let mut removed = vec![];
+    let checkouts_dir = git_checkouts_directory();
+    if !checkouts_dir.exists() {
+        return Ok(removed);
+    }
+    for repo_entry in fs::read_dir(&checkouts_dir)? {
+        let repo_dir = repo_entry?.path();
+        let dir_name = repo_dir
+            .file_name()
+            .map(|name| name.to_string_lossy().into_owned())
+            .unwrap_or_default();
+        if dir_name == "tmp" {
+            continue;
+        }
+        // Checkout directories are named `<name>-<repo_url_hash>`.
+        let pkg_name = match dir_name.rsplit_once('-') {
+            Some((pkg_name, _)) if repo_dir.is_dir() => pkg_name.to_string(),
+            _ => {
+                fs::remove_dir_all(&repo_dir)?;
+                removed.push(repo_dir);
+                continue;
+            }
+        };
+        for commit_entry in fs::read_dir(&repo_dir)? {
+            let commit_dir = commit_entry?.path();
+            if !commit_dir.is_dir() {
+                fs::remove_file(&commit_dir)?;
+                removed.push(commit_dir);
+            } else if verify_git_checkout(&commit_dir, &pkg_name, None, sway_git_tag).is_err() {
+                fs::remove_dir_all(&commit_dir)?;
+                removed.push(commit_dir);
+            }
+        }
+    }
+    Ok(removed)
+}
+
 /// Given the path to a package and a `Dependency` parsed from one of its forc dependencies,
 /// produce the `Source` for that dependendency.
 fn dep_to_source(pkg_path: &Path, dep: &Dependency) -> Result<Source> {
@@ -1388,19 +2014,27 @@ pub fn sway_build_config(
     )
     .print_finalized_asm(build_profile.print_finalized_asm)
     .print_intermediate_asm(build_profile.print_intermediate_asm)
-    .print_ir(build_profile.print_ir);
+    .print_ir(build_profile.print_ir)
+    .optimization_level(build_profile.optimization_level)
+    .include_debug_info(build_profile.include_debug_info);
     Ok(build_config)
 }
 
 /// Builds the dependency namespace for the package at the given node index within the graph.
 ///
+/// The namespaces of all libraries the package depends on, directly or indirectly, are added as
+/// submodules. Each contract dependency declared in the package's `manifest` is added as a
+/// submodule containing only its `CONTRACT_ID`, computed with the salt given in the manifest.
+///
 /// This function is designed to be called for each node in order of compilation.
 pub fn dependency_namespace(
     namespace_map: &HashMap<NodeIx, namespace::Module>,
+    compiled_contracts: &HashMap<NodeIx, Compiled>,
     graph: &Graph,
     compilation_order: &[NodeIx],
     node: NodeIx,
-) -> namespace::Module {
+    manifest: &Manifest,
+) -> Result<namespace::Module> {
     use petgraph::visit::{Dfs, Walker};
 
     // Find all nodes that are a dependency of this one with a depth-first search.
@@ -1412,19 +2046,64 @@ pub fn dependency_namespace(
         if dep_node == node {
             break;
         }
-        // Add the namespace once for each of its names.
-        let dep_namespace = &namespace_map[&dep_node];
-        let dep_names: BTreeSet<_> = graph
-            .edges_directed(dep_node, Direction::Incoming)
-            .map(|e| e.weight())
-            .collect();
-        for dep_name in dep_names {
-            let dep_name = kebab_to_snake_case(dep_name);
-            namespace.insert_submodule(dep_name.to_string(), dep_namespace.clone());
+
+        // Contracts are only visible to the packages that declare them as contract dependencies.
+        let compiled_contract = compiled_contracts
+            .get(&dep_node)
+            .filter(|compiled| matches!(compiled.tree_type, TreeType::Contract));
+        let dep_edges = graph
+            .edges_directed(node, Direction::Outgoing)
+            .filter(|e| e.target() == dep_node);
+        for edge in dep_edges {
+            let dep_name = edge.weight();
+            match (manifest.contract_dep(dep_name), compiled_contract) {
+                (Some(contract_dep), Some(compiled)) => {
+                    let salt = contract_dep.salt()?;
+                    let id = contract_id(&compiled.bytecode, &compiled.storage_slots, &salt);
+                    let dep_name = kebab_to_snake_case(dep_name);
+                    let contract_namespace = contract_id_namespace(&dep_name, &id)?;
+                    namespace.insert_submodule(dep_name, contract_namespace);
+                }
+                (Some(_), None) => bail!("contract dependency `{}` is not a contract", dep_name),
+                (None, Some(_)) => bail!(
+                    "dependency `{}` is a contract and must be declared under \
+                    [contract-dependencies]",
+                    dep_name
+                ),
+                (None, None) => (),
+            }
+        }
+
+        // Add the namespace of libraries once for each of their names.
+        if let Some(dep_namespace) = namespace_map.get(&dep_node) {
+            let dep_names: BTreeSet<_> = graph
+                .edges_directed(dep_node, Direction::Incoming)
+                .map(|e| e.weight())
+                .collect();
+            for dep_name in dep_names {
+                let dep_name = kebab_to_snake_case(dep_name);
+                namespace.insert_submodule(dep_name.to_string(), dep_namespace.clone());
+            }
         }
     }
 
-    namespace
+    Ok(namespace)
+}
+
+/// Compile a library module named `dep_name` that declares the given ID as `CONTRACT_ID`.
+fn contract_id_namespace(dep_name: &str, id: &ContractId) -> Result<namespace::Module> {
+    let source = format!(
+        "library {};\n\npub const CONTRACT_ID: b256 = 0x{};\n",
+        dep_name, id
+    );
+    match sway_core::compile_to_ast(source.into(), namespace::Module::default(), None) {
+        CompileAstResult::Success { typed_program, .. } => Ok(typed_program.root.namespace),
+        CompileAstResult::Failure { errors, .. } => bail!(
+            "failed to generate the `CONTRACT_ID` of contract dependency `{}`: {:?}",
+            dep_name,
+            errors
+        ),
+    }
 }
 
 /// Compiles the package to an AST.
@@ -1518,6 +2197,7 @@ pub fn compile(
                         json_abi,
                         storage_slots,
                         bytecode,
+                        bytecode_layout: BytecodeLayout::default(),
                         tree_type,
                     };
                     Ok((compiled, Some(lib_namespace.into())))
@@ -1534,13 +2214,18 @@ pub fn compile(
                         sway_core::asm_to_bytecode(asm_res, source_map)
                     );
                     match bc_res {
-                        BytecodeCompilationResult::Success { bytes, warnings } => {
+                        BytecodeCompilationResult::Success {
+                            bytes,
+                            layout,
+                            warnings,
+                        } => {
                             print_on_success(silent_mode, &pkg.name, &warnings, &tree_type);
                             let bytecode = bytes;
                             let compiled = Compiled {
                                 json_abi,
                                 storage_slots,
                                 bytecode,
+                                bytecode_layout: layout,
                                 tree_type,
                             };
                             Ok((compiled, None))
@@ -1559,9 +2244,57 @@ pub fn compile(
     }
 }
 
+/// Group the nodes of the compilation order into waves of packages that may be compiled
+/// concurrently.
+///
+/// The dependencies of each package within a wave are all found within previous waves. Packages
+/// retain their relative position in the compilation order within each wave.
+fn compilation_waves(graph: &Graph, compilation_order: &[NodeIx]) -> Vec<Vec<NodeIx>> {
+    let mut depths: HashMap<NodeIx, usize> = HashMap::new();
+    let mut waves: Vec<Vec<NodeIx>> = vec![];
+    for &node in compilation_order {
+        let depth = graph
+            .neighbors_directed(node, Direction::Outgoing)
+            .map(|dep| depths[&dep] + 1)
+            .max()
+            .unwrap_or(0);
+        depths.insert(node, depth);
+        if waves.len() <= depth {
+            waves.resize_with(depth + 1, Vec::new);
+        }
+        waves[depth].push(node);
+    }
+    waves
+}
+
+/// Compile the package at the given node against the namespaces of its compiled dependencies.
+fn compile_node(
+    plan: &BuildPlan,
+    profile: &BuildProfile,
+    namespace_map: &HashMap<NodeIx, namespace::Module>,
+    compiled_map: &HashMap<NodeIx, Compiled>,
+    node: NodeIx,
+    source_map: &mut SourceMap,
+    sway_git_tag: &str,
+) -> Result<(Compiled, Option<namespace::Root>)> {
+    let pkg = &plan.graph[node];
+    let manifest = ManifestFile::from_dir(&plan.path_map[&pkg.id()], sway_git_tag)?;
+    let dep_namespace = dependency_namespace(
+        namespace_map,
+        compiled_map,
+        &plan.graph,
+        &plan.compilation_order,
+        node,
+        &manifest,
+    )?;
+    compile(pkg, &manifest, profile, dep_namespace, source_map)
+}
+
 /// Build an entire forc package and return the compiled output.
 ///
 /// This compiles all packages (including dependencies) in the order specified by the `BuildPlan`.
+/// Packages that do not depend on one another are compiled concurrently, while the JSON ABI and
+/// storage slots are always collected in compilation order.
 ///
 /// Also returns the resulting `sway_core::SourceMap` which may be useful for debugging purposes.
 pub fn build(
@@ -1569,33 +2302,81 @@ pub fn build(
     profile: &BuildProfile,
     sway_git_tag: &str,
 ) -> anyhow::Result<(Compiled, SourceMap)> {
-    let mut namespace_map = Default::default();
+    let mut namespace_map = HashMap::new();
+    let mut compiled_map = HashMap::new();
     let mut source_map = SourceMap::new();
+    if let Some(&proj_node) = plan.compilation_order.last() {
+        source_map.set_project_dir(&plan.path_map[&plan.graph[proj_node].id()]);
+    }
+    for &node in &plan.compilation_order {
+        source_map.insert_dependency(plan.path_map[&plan.graph[node].id()].clone());
+    }
+    for wave in compilation_waves(&plan.graph, &plan.compilation_order) {
+        // The project is always alone in the final wave and is the only package that writes to
+        // the source map, so all other packages each get a scratch source map.
+        let results = match wave[..] {
+            [node] if plan.compilation_order.last() == Some(&node) => {
+                let res = compile_node(
+                    plan,
+                    profile,
+                    &namespace_map,
+                    &compiled_map,
+                    node,
+                    &mut source_map,
+                    sway_git_tag,
+                )?;
+                vec![res]
+            }
+            _ => wave
+                .par_iter()
+                .map(|&node| {
+                    let mut source_map = SourceMap::new();
+                    compile_node(
+                        plan,
+                        profile,
+                        &namespace_map,
+                        &compiled_map,
+                        node,
+                        &mut source_map,
+                        sway_git_tag,
+                    )
+                })
+                .collect::<Result<Vec<_>>>()?,
+        };
+        for (&node, (compiled, maybe_namespace)) in wave.iter().zip(results) {
+            if let Some(namespace) = maybe_namespace {
+                namespace_map.insert(node, namespace.into());
+            }
+            compiled_map.insert(node, compiled);
+        }
+    }
+
+    // Collect the output in compilation order so that it does not depend on scheduling. Contract
+    // dependencies are deployed separately, so only libraries contribute to the project's output.
     let mut json_abi = vec![];
     let mut storage_slots = vec![];
     let mut bytecode = vec![];
+    let mut bytecode_layout = BytecodeLayout::default();
     let mut tree_type = None;
-    for &node in &plan.compilation_order {
-        let dep_namespace =
-            dependency_namespace(&namespace_map, &plan.graph, &plan.compilation_order, node);
-        let pkg = &plan.graph[node];
-        let path = &plan.path_map[&pkg.id()];
-        let manifest = ManifestFile::from_dir(path, sway_git_tag)?;
-        let res = compile(pkg, &manifest, profile, dep_namespace, &mut source_map)?;
-        let (compiled, maybe_namespace) = res;
-        if let Some(namespace) = maybe_namespace {
-            namespace_map.insert(node, namespace.into());
+    for (i, node) in plan.compilation_order.iter().enumerate() {
+        let compiled = compiled_map
+            .remove(node)
+            .ok_or_else(|| anyhow!("package was not compiled"))?;
+        let is_project = i == plan.compilation_order.len() - 1;
+        if !is_project && !matches!(compiled.tree_type, TreeType::Library { .. }) {
+            continue;
         }
         json_abi.extend(compiled.json_abi);
         storage_slots.extend(compiled.storage_slots);
         bytecode = compiled.bytecode;
+        bytecode_layout = compiled.bytecode_layout;
         tree_type = Some(compiled.tree_type);
-        source_map.insert_dependency(path.clone());
     }
     let tree_type =
         tree_type.ok_or_else(|| anyhow!("build plan must contain at least one package"))?;
     let compiled = Compiled {
         bytecode,
+        bytecode_layout,
         json_abi,
         storage_slots,
         tree_type,
@@ -1604,6 +2385,13 @@ pub fn build(
 }
 
 /// Compile the entire forc package and return a CompileAstResult.
+///
+/// Contract dependencies are type checked but not compiled to bytecode, so the `CONTRACT_ID`
+/// exposed to their dependents is a placeholder rather than the ID they would be deployed under.
+/// As with [build], packages that do not depend on one another are type-checked concurrently.
+///
+/// If a dependency fails to type check, its result is returned in place of the project's, as its
+/// dependents cannot be type checked without it.
 pub fn check(
     plan: &BuildPlan,
     silent_mode: bool,
@@ -1614,28 +2402,86 @@ pub fn check(
         ..BuildProfile::debug()
     };
 
-    let mut namespace_map = Default::default();
+    let mut namespace_map = HashMap::new();
+    let mut compiled_contracts = HashMap::new();
     let mut source_map = SourceMap::new();
-    for (i, &node) in plan.compilation_order.iter().enumerate() {
-        let dep_namespace =
-            dependency_namespace(&namespace_map, &plan.graph, &plan.compilation_order, node);
-        let pkg = &plan.graph[node];
-        let path = &plan.path_map[&pkg.id()];
-        let manifest = ManifestFile::from_dir(path, sway_git_tag)?;
-        let ast_res = compile_ast(&manifest, &profile, dep_namespace)?;
-        if let CompileAstResult::Success { typed_program, .. } = &ast_res {
-            if let TreeType::Library { .. } = typed_program.kind.tree_type() {
-                namespace_map.insert(node, typed_program.root.namespace.clone());
+    let mut proj_ast_res = None;
+    for wave in compilation_waves(&plan.graph, &plan.compilation_order) {
+        let results = wave
+            .par_iter()
+            .map(|&node| {
+                let pkg = &plan.graph[node];
+                let manifest = ManifestFile::from_dir(&plan.path_map[&pkg.id()], sway_git_tag)?;
+                let dep_namespace = dependency_namespace(
+                    &namespace_map,
+                    &compiled_contracts,
+                    &plan.graph,
+                    &plan.compilation_order,
+                    node,
+                    &manifest,
+                )?;
+                compile_ast(&manifest, &profile, dep_namespace)
+            })
+            .collect::<Result<Vec<_>>>()?;
+        for (&node, ast_res) in wave.iter().zip(results) {
+            let is_project = plan.compilation_order.last() == Some(&node);
+            let typed_program = match &ast_res {
+                CompileAstResult::Success { typed_program, .. } => typed_program,
+                CompileAstResult::Failure { .. } => return Ok(ast_res),
+            };
+            match typed_program.kind.tree_type() {
+                TreeType::Library { .. } => {
+                    namespace_map.insert(node, typed_program.root.namespace.clone());
+                }
+                // Type checking dependents of a contract only requires its `CONTRACT_ID` to exist,
+                // so rather than generating its bytecode, the ID is derived from empty bytecode.
+                TreeType::Contract if !is_project => {
+                    let placeholder = Compiled {
+                        bytecode: vec![],
+                        bytecode_layout: BytecodeLayout::default(),
+                        json_abi: vec![],
+                        storage_slots: vec![],
+                        tree_type: TreeType::Contract,
+                    };
+                    compiled_contracts.insert(node, placeholder);
+                }
+                _ => (),
             }
-        }
-        source_map.insert_dependency(path.clone());
+            source_map.insert_dependency(plan.path_map[&plan.graph[node].id()].clone());
 
-        // We only need to return the final CompileAstResult
-        if i == plan.compilation_order.len() - 1 {
-            return Ok(ast_res);
+            // We only need to return the final CompileAstResult
+            if is_project {
+                proj_ast_res = Some(ast_res);
+            }
         }
     }
-    bail!("unable to check sway program: build plan contains no packages")
+    proj_ast_res
+        .ok_or_else(|| anyhow!("unable to check sway program: build plan contains no packages"))
+}
+
+/// Compute the ID of the contract with the given bytecode, initial storage slots and salt.
+///
+/// This is the ID assigned to the contract when it is deployed with the same salt and storage
+/// slots, allowing it to be known before deployment.
+pub fn contract_id(bytecode: &[u8], storage_slots: &[StorageSlot], salt: &Salt) -> ContractId {
+    let contract = Contract::from(bytecode.to_vec());
+    let root = contract.root();
+    let state_root = contract_state_root(storage_slots);
+    contract.id(salt, &root, &state_root)
+}
+
+/// Compute the initial state root of a contract with the given storage slots.
+///
+/// The VM requires storage slots to be sorted, so they are sorted before computing the root.
+pub fn contract_state_root(storage_slots: &[StorageSlot]) -> Bytes32 {
+    let mut storage_slots = storage_slots.to_vec();
+    storage_slots.sort();
+    Contract::initial_state_root(storage_slots.iter())
+}
+
+/// Compute the address of the predicate with the given bytecode, i.e. the root of its bytecode.
+pub fn predicate_address(bytecode: &[u8]) -> Address {
+    Address::new(*Contract::root_from_code(bytecode))
 }
 
 /// Attempt to find a `Forc.toml` with the given project name within the given directory.
@@ -1720,6 +2566,443 @@ fn test_source_git_pinned_parsing() {
     }
 }
 
+#[test]
+fn test_contract_id_and_predicate_address() {
+    // The expected values are computed independently of `fuel-tx`, from the merkle root of the
+    // 8-byte words of the bytecode and, for the contract ID, the `"FUEL"` seed and the salt.
+    let bytecode: Vec<u8> = (0..16).collect();
+    let address = predicate_address(&bytecode);
+    assert_eq!(
+        format!("{}", address),
+        "da755e2df013ea7fb7f25d69fdbaa23944aee2693ef562df59196dcb4a1159ea"
+    );
+
+    let id = contract_id(&bytecode, &[], &Salt::zeroed());
+    assert_eq!(
+        format!("{}", id),
+        "8a299d6e166ec341d55fb403a88da96e2b0e5f36242fe30302c249ce18769bfb"
+    );
+    let id = contract_id(&bytecode, &[], &Salt::from([1u8; 32]));
+    assert_eq!(
+        format!("{}", id),
+        "b28207a6a578e3955c38d9e503b42f9956fb332dfeeeb42d7e34f9131894ab24"
+    );
+
+    // Storage slots contribute to the ID regardless of the order in which they are given.
+    let slots = [
+        StorageSlot::new(Bytes32::from([1u8; 32]), Bytes32::from([2u8; 32])),
+        StorageSlot::new(Bytes32::from([3u8; 32]), Bytes32::from([4u8; 32])),
+    ];
+    let reversed = [slots[1].clone(), slots[0].clone()];
+    let id_with_slots = contract_id(&bytecode, &slots, &Salt::zeroed());
+    assert_ne!(id_with_slots, contract_id(&bytecode, &[], &Salt::zeroed()));
+    assert_eq!(
+        id_with_slots,
+        contract_id(&bytecode, &reversed, &Salt::zeroed())
+    );
+}
+
+#[test]
+fn test_source_checksum() {
+    let temp_dir = tempfile::tempdir().unwrap();
+    let dir = temp_dir.path();
+    fs::create_dir_all(dir.join("src")).unwrap();
+    fs::write(dir.join("Forc.toml"), "[project]").unwrap();
+    fs::write(dir.join("src").join("lib.sw"), "library foo;").unwrap();
+    let checksum = source_checksum(dir).unwrap();
+
+    // The checkout checksum file and `.git` directory are ignored.
+    fs::write(dir.join(CHECKOUT_CHECKSUM_FILE_NAME), &checksum).unwrap();
+    fs::create_dir_all(dir.join(".git")).unwrap();
+    fs::write(dir.join(".git").join("HEAD"), "ref").unwrap();
+    assert_eq!(checksum, source_checksum(dir).unwrap());
+
+    // Modifying, adding or renaming files changes the checksum.
+    fs::write(dir.join("src").join("lib.sw"), "library bar;").unwrap();
+    let modified = source_checksum(dir).unwrap();
+    assert_ne!(checksum, modified);
+    fs::write(dir.join("src").join("other.sw"), "").unwrap();
+    let added = source_checksum(dir).unwrap();
+    assert_ne!(modified, added);
+    fs::rename(
+        dir.join("src").join("other.sw"),
+        dir.join("src").join("renamed.sw"),
+    )
+    .unwrap();
+    assert_ne!(added, source_checksum(dir).unwrap());
+}
+
+#[test]
+fn test_verify_git_checkout() {
+    let temp_dir = tempfile::tempdir().unwrap();
+    let dir = temp_dir.path();
+    fs::create_dir_all(dir.join("src")).unwrap();
+    fs::write(
+        dir.join("Forc.toml"),
+        "[project]\nauthors = []\nentry = \"lib.sw\"\nlicense = \"Apache-2.0\"\nname = \"foo\"\n\
+         implicit-std = false\n",
+    )
+    .unwrap();
+    fs::write(dir.join("src").join("lib.sw"), "library foo;").unwrap();
+    let checksum = source_checksum(dir).unwrap();
+
+    // A checkout without a recorded checksum is accepted and has its checksum recorded.
+    assert_eq!(verify_git_checkout(dir, "foo", None, "").unwrap(), dir);
+    let recorded = fs::read_to_string(dir.join(CHECKOUT_CHECKSUM_FILE_NAME)).unwrap();
+    assert_eq!(recorded, checksum);
+    assert!(verify_git_checkout(dir, "foo", Some(&checksum), "").is_ok());
+
+    // An actual mismatch with the lock file or the recorded checksum is an error.
+    assert!(verify_git_checkout(dir, "foo", Some("0000"), "").is_err());
+    fs::write(dir.join("src").join("lib.sw"), "library bar;").unwrap();
+    assert!(verify_git_checkout(dir, "foo", None, "").is_err());
+
+    // Without a recorded checksum, only a mismatch with the lock file is an error.
+    fs::remove_file(dir.join(CHECKOUT_CHECKSUM_FILE_NAME)).unwrap();
+    assert!(verify_git_checkout(dir, "foo", Some(&checksum), "").is_err());
+    assert!(!dir.join(CHECKOUT_CHECKSUM_FILE_NAME).exists());
+}
+
+/// The manifest of a package without an implicit `std`, with the given `[dependencies]` entries.
+#[cfg(test)]
+fn test_manifest(name: &str, entry: &str, deps: &str) -> String {
+    format!(
+        "[project]\nauthors = []\nentry = \"{}\"\nlicense = \"Apache-2.0\"\n\
+         name = \"{}\"\nimplicit-std = false\n\n[dependencies]\n{}",
+        entry, name, deps
+    )
+}
+
+/// Write a library named `name` exposing `fn one() -> u64` to `repo_dir`, and commit it to the
+/// `main` branch of the git repository there, returning the repository's URL.
+#[cfg(test)]
+fn test_git_library(repo_dir: &Path, name: &str, deps: &str) -> Url {
+    fs::create_dir_all(repo_dir.join("src")).unwrap();
+    fs::write(
+        repo_dir.join("Forc.toml"),
+        test_manifest(name, "lib.sw", deps),
+    )
+    .unwrap();
+    fs::write(
+        repo_dir.join("src").join("lib.sw"),
+        format!("library {};\n\npub fn one() -> u64 {{\n    1\n}}\n", name),
+    )
+    .unwrap();
+    let repo = git2::Repository::init(repo_dir).unwrap();
+    test_git_commit(&repo);
+    Url::from_file_path(repo_dir).unwrap()
+}
+
+/// Commit all files within the repository to the `main` branch.
+#[cfg(test)]
+fn test_git_commit(repo: &git2::Repository) {
+    let mut index = repo.index().unwrap();
+    index
+        .add_all(["*"], git2::IndexAddOption::DEFAULT, None)
+        .unwrap();
+    index.write().unwrap();
+    let tree = repo.find_tree(index.write_tree().unwrap()).unwrap();
+    let sig = git2::Signature::now("forc", "forc@fuel.sh").unwrap();
+    let parent = repo
+        .find_reference("refs/heads/main")
+        .ok()
+        .map(|head| head.peel_to_commit().unwrap());
+    let parents: Vec<_> = parent.iter().collect();
+    repo.commit(
+        Some("refs/heads/main"),
+        &sig,
+        &sig,
+        "commit",
+        &tree,
+        &parents,
+    )
+    .unwrap();
+}
+
+#[test]
+fn test_vendor_and_offline_build() {
+    let temp_dir = tempfile::tempdir().unwrap();
+    let dir = temp_dir.path();
+
+    // A git repository containing the `utils` library, committed to the `main` branch.
+    let repo_dir = dir.join("utils");
+    let repo_url = test_git_library(&repo_dir, "utils", "");
+
+    // A script depending upon `utils` via git.
+    let app_dir = dir.join("app");
+    fs::create_dir_all(app_dir.join("src")).unwrap();
+    let dep = format!("utils = {{ git = \"{}\", branch = \"main\" }}\n", repo_url);
+    fs::write(
+        app_dir.join("Forc.toml"),
+        test_manifest("app", "main.sw", &dep),
+    )
+    .unwrap();
+    fs::write(
+        app_dir.join("src").join("main.sw"),
+        "script;\n\nuse utils::one;\n\nfn main() -> u64 {\n    one()\n}\n",
+    )
+    .unwrap();
+    let app_manifest = ManifestFile::from_dir(&app_dir, "").unwrap();
+
+    // Vendoring copies the fetched source into the project.
+    let plan = BuildPlan::load_from_manifest(&app_manifest, false, false, "").unwrap();
+    let vendored = vendor(&plan).unwrap();
+    assert_eq!(vendored.len(), 1);
+    let (pinned, vendored_path) = &vendored[0];
+    let commit_hash = match &pinned.source {
+        SourcePinned::Git(git) => git.commit_hash.clone(),
+        source => panic!("unexpected source {}", source),
+    };
+    let app_dir = app_dir.canonicalize().unwrap();
+    let expected_path = vendored_git_commit_path(&app_dir, "utils", &repo_url, &commit_hash);
+    assert_eq!(vendored_path, &expected_path);
+    assert!(vendored_path.join("src").join("lib.sw").exists());
+    assert!(!vendored_path.join(".git").exists());
+
+    // Without the repository or the checkout, the locked project is built offline from its
+    // vendored sources.
+    fs::remove_dir_all(&repo_dir).unwrap();
+    let checkout_dir = git_commit_path("utils", &repo_url, &commit_hash);
+    fs::remove_dir_all(checkout_dir.parent().unwrap()).unwrap();
+    let utils_path = |plan: &BuildPlan| {
+        plan.graph()
+            .node_weights()
+            .find(|pinned| pinned.name == "utils")
+            .map(|pinned| plan.path_map()[&pinned.id()].clone())
+            .unwrap()
+    };
+    let plan = BuildPlan::load_from_manifest(&app_manifest, true, true, "").unwrap();
+    assert_eq!(utils_path(&plan), expected_path);
+    let profile = BuildProfile {
+        silent: true,
+        ..BuildProfile::debug()
+    };
+    let (compiled, _) = build(&plan, &profile, "").unwrap();
+    assert!(!compiled.bytecode.is_empty());
+
+    // Without the lock file, the commit that a branch refers to cannot be known offline, even
+    // though a commit has been vendored.
+    let lock_path = app_dir.join(constants::LOCK_FILE_NAME);
+    fs::remove_file(&lock_path).unwrap();
+    assert!(BuildPlan::load_from_manifest(&app_manifest, false, true, "").is_err());
+
+    // A `rev` is pinned to the vendored commit that it refers to.
+    let dep = format!(
+        "utils = {{ git = \"{}\", rev = \"{}\" }}\n",
+        repo_url,
+        &commit_hash[..8]
+    );
+    fs::write(
+        app_dir.join("Forc.toml"),
+        test_manifest("app", "main.sw", &dep),
+    )
+    .unwrap();
+    let app_manifest = ManifestFile::from_dir(&app_dir, "").unwrap();
+    let plan = BuildPlan::load_from_manifest(&app_manifest, false, true, "").unwrap();
+    assert_eq!(utils_path(&plan), expected_path);
+
+    // Sources that are neither vendored nor locked cannot be pinned offline.
+    fs::remove_dir_all(app_dir.join(VENDOR_DIR_NAME)).unwrap();
+    fs::remove_file(&lock_path).unwrap();
+    assert!(BuildPlan::load_from_manifest(&app_manifest, false, true, "").is_err());
+}
+
+#[test]
+fn test_update_package() {
+    let temp_dir = tempfile::tempdir().unwrap();
+    let dir = temp_dir.path();
+    let utils_url = test_git_library(&dir.join("utils"), "utils", "");
+    let other_url = test_git_library(&dir.join("other"), "other", "");
+    let extra_url = test_git_library(&dir.join("extra"), "extra", "");
+    let git_dep =
+        |name: &str, url: &Url| format!("{} = {{ git = \"{}\", branch = \"main\" }}\n", name, url);
+
+    // A script depending upon both `other` and `utils` via git.
+    let app_dir = dir.join("app");
+    fs::create_dir_all(app_dir.join("src")).unwrap();
+    let deps = git_dep("other", &other_url) + &git_dep("utils", &utils_url);
+    fs::write(
+        app_dir.join("Forc.toml"),
+        test_manifest("app", "main.sw", &deps),
+    )
+    .unwrap();
+    fs::write(
+        app_dir.join("src").join("main.sw"),
+        "script;\n\nfn main() {}\n",
+    )
+    .unwrap();
+    let manifest = ManifestFile::from_dir(&app_dir, "").unwrap();
+    let plan = BuildPlan::load_from_manifest(&manifest, false, false, "").unwrap();
+
+    // New commits to both repositories, with `utils` now also depending upon `extra`.
+    for (name, deps) in [
+        ("utils", git_dep("extra", &extra_url)),
+        ("other", String::new()),
+    ] {
+        let repo_dir = dir.join(name);
+        fs::write(
+            repo_dir.join("Forc.toml"),
+            test_manifest(name, "lib.sw", &deps),
+        )
+        .unwrap();
+        fs::write(
+            repo_dir.join("src").join("lib.sw"),
+            format!("library {};\n\npub fn two() -> u64 {{\n    2\n}}\n", name),
+        )
+        .unwrap();
+        test_git_commit(&git2::Repository::open(&repo_dir).unwrap());
+    }
+    let new_plan = plan.update_package(&manifest, "utils", "", false).unwrap();
+
+    // Only the entry of `utils` changes, and `extra` is added as its new dependency. All other
+    // entries of the lock file are left untouched.
+    let lock_entries = |plan: &BuildPlan| -> BTreeSet<String> {
+        let lock = toml::ser::to_string_pretty(&Lock::from_plan(plan).unwrap()).unwrap();
+        lock.split("[[package]]")
+            .filter(|entry| !entry.trim().is_empty())
+            .map(str::to_string)
+            .collect()
+    };
+    let (old_entries, new_entries) = (lock_entries(&plan), lock_entries(&new_plan));
+    let removed: Vec<_> = old_entries.difference(&new_entries).collect();
+    let added: Vec<_> = new_entries.difference(&old_entries).collect();
+    assert_eq!(removed.len(), 1);
+    assert!(removed[0].contains("name = 'utils'"));
+    assert_eq!(added.len(), 2);
+    let utils_head = git2::Repository::open(dir.join("utils"))
+        .unwrap()
+        .refname_to_id("refs/heads/main")
+        .unwrap()
+        .to_string();
+    assert!(added
+        .iter()
+        .any(|entry| entry.contains("name = 'utils'") && entry.contains(&utils_head)));
+    assert!(added.iter().any(|entry| entry.contains("name = 'extra'")));
+
+    assert!(plan
+        .update_package(&manifest, "missing", "", false)
+        .is_err());
+
+    // Remove the checkouts of the repositories, which are only valid for the duration of the test.
+    for pinned in plan
+        .graph()
+        .node_weights()
+        .chain(new_plan.graph().node_weights())
+    {
+        if let SourcePinned::Git(git) = &pinned.source {
+            let path = git_commit_path(&pinned.name, &git.source.repo, &git.commit_hash);
+            let _ = fs::remove_dir_all(path.parent().unwrap());
+        }
+    }
+}
+
+#[test]
+fn test_build_is_deterministic() {
+    let temp_dir = tempfile::tempdir().unwrap();
+    let dir = temp_dir.path();
+
+    // A contract depending upon two libraries that are compiled concurrently.
+    for name in ["a", "b"] {
+        let lib_dir = dir.join(name);
+        fs::create_dir_all(lib_dir.join("src")).unwrap();
+        fs::write(lib_dir.join("Forc.toml"), test_manifest(name, "lib.sw", "")).unwrap();
+        fs::write(
+            lib_dir.join("src").join("lib.sw"),
+            format!(
+                "library {};\n\npub fn first(x: (u64, u64)) -> u64 {{\n    \
+                 let (first, _) = x;\n    match first {{\n        y => y,\n    }}\n}}\n",
+                name
+            ),
+        )
+        .unwrap();
+    }
+    let app_dir = dir.join("app");
+    fs::create_dir_all(app_dir.join("src")).unwrap();
+    let deps = "a = { path = \"../a\" }\nb = { path = \"../b\" }\n";
+    fs::write(
+        app_dir.join("Forc.toml"),
+        test_manifest("app", "main.sw", deps),
+    )
+    .unwrap();
+    fs::write(
+        app_dir.join("src").join("main.sw"),
+        "contract;\n\nuse a::first;\n\nstorage {\n    value: u64 = 42,\n}\n\n\
+         abi Test {\n    #[storage(read)]\n    fn get(x: (u64, u64)) -> u64;\n}\n\n\
+         impl Test for Contract {\n    #[storage(read)]\n    fn get(x: (u64, u64)) -> u64 {\n        \
+         let (_, second) = x;\n        match second {\n            y => first((y, storage.value)),\n        \
+         }\n    }\n}\n",
+    )
+    .unwrap();
+
+    let manifest = ManifestFile::from_dir(&app_dir, "").unwrap();
+    let plan = BuildPlan::load_from_manifest(&manifest, false, true, "").unwrap();
+    let profile = BuildProfile {
+        silent: true,
+        ..BuildProfile::debug()
+    };
+    let output = || {
+        let (compiled, _) = build(&plan, &profile, "").unwrap();
+        (
+            serde_json::to_vec(&compiled.json_abi).unwrap(),
+            serde_json::to_vec(&compiled.storage_slots).unwrap(),
+            compiled.bytecode,
+        )
+    };
+    let (json_abi, storage_slots, bytecode) = output();
+    assert!(json_abi.len() > 2 && storage_slots.len() > 2);
+    assert_eq!(output(), (json_abi, storage_slots, bytecode));
+}
+
+#[test]
+fn test_check_with_failing_contract_dependency() {
+    let temp_dir = tempfile::tempdir().unwrap();
+    let dir = temp_dir.path();
+
+    // A contract that fails to type check.
+    let contract_dir = dir.join("token");
+    fs::create_dir_all(contract_dir.join("src")).unwrap();
+    fs::write(
+        contract_dir.join("Forc.toml"),
+        test_manifest("token", "main.sw", ""),
+    )
+    .unwrap();
+    let contract_entry = contract_dir.join("src").join("main.sw");
+    fs::write(
+        &contract_entry,
+        "contract;\n\nabi Token {\n    fn supply() -> u64;\n}\n\n\
+         impl Token for Contract {\n    fn supply() -> u64 {\n        true\n    }\n}\n",
+    )
+    .unwrap();
+
+    // A script declaring it as a contract dependency.
+    let app_dir = dir.join("app");
+    fs::create_dir_all(app_dir.join("src")).unwrap();
+    let deps = "\n[contract-dependencies]\ntoken = { path = \"../token\" }\n";
+    fs::write(
+        app_dir.join("Forc.toml"),
+        test_manifest("app", "main.sw", deps),
+    )
+    .unwrap();
+    fs::write(
+        app_dir.join("src").join("main.sw"),
+        "script;\n\nfn main() -> b256 {\n    token::CONTRACT_ID\n}\n",
+    )
+    .unwrap();
+
+    // The errors of the contract are reported rather than those of the script.
+    let manifest = ManifestFile::from_dir(&app_dir, "").unwrap();
+    let plan = BuildPlan::load_from_manifest(&manifest, false, true, "").unwrap();
+    match check(&plan, true, "").unwrap() {
+        CompileAstResult::Failure { errors, .. } => {
+            assert!(!errors.is_empty());
+            assert!(errors
+                .iter()
+                .all(|e| e.path().as_deref() == Some(&contract_entry)));
+        }
+        CompileAstResult::Success { .. } => panic!("the contract dependency type checked"),
+    }
+}
+
 /// Format an error message for an absent `Forc.toml`.
 pub fn manifest_file_missing(dir: &Path) -> anyhow::Error {
     let message = format!(
@@ -1766,7 +3049,7 @@ fn create_new_lock(
     manifest: &ManifestFile,
     lock_path: &Path,
 ) -> Result<()> {
-    let lock = Lock::from_graph(plan.graph());
+    let lock = Lock::from_plan(plan)?;
     let diff = lock.diff(old_lock);
     super::lock::print_diff(&manifest.project.name, &diff);
     let string = toml::ser::to_string_pretty(&lock)
@@ -8,7 +8,7 @@ use std::default::Default;
 use std::path::PathBuf;
 use std::{fs, path::Path, sync::Arc};
 use sway_core::BuildConfig;
-use sway_fmt::{get_formatted_data, FormattingOptions};
+use sway_fmt_v2::Formatter;
 use sway_utils::{constants, get_sway_files};
 use taplo::formatter as taplo_fmt;
 use tracing::{error, info};
@@ -45,11 +45,12 @@ fn run() -> Result<()> {
         Some(p) => PathBuf::from(p),
         None => std::env::current_dir()?,
     };
-    format_pkg_at_dir(app, &dir)
+    let mut formatter = Formatter::from_dir(&dir)?;
+    format_pkg_at_dir(app, &dir, &mut formatter)
 }
 
 /// Format the package at the given directory.
-fn format_pkg_at_dir(app: App, dir: &Path) -> Result<()> {
+fn format_pkg_at_dir(app: App, dir: &Path, formatter: &mut Formatter) -> Result<()> {
     match find_manifest_dir(dir) {
         Some(path) => {
             let manifest_path = path.clone();
@@ -59,21 +60,15 @@ fn format_pkg_at_dir(app: App, dir: &Path) -> Result<()> {
 
             for file in files {
                 if let Ok(file_content) = fs::read_to_string(&file) {
-                    // todo read options from manifest file
-                    let formatting_options = FormattingOptions::default();
                     let file_content: Arc<str> = Arc::from(file_content);
                     let build_config = BuildConfig::root_from_file_name_and_manifest_path(
                         file.clone(),
                         manifest_path.clone(),
                     );
-                    match get_formatted_data(
-                        file_content.clone(),
-                        formatting_options,
-                        Some(&build_config),
-                    ) {
-                        Ok((_, formatted_content)) => {
+                    match formatter.format(file_content.clone(), Some(&build_config)) {
+                        Ok(formatted_content) => {
                             if app.check {
-                                if *file_content != *formatted_content {
+                                if *file_content != formatted_content {
                                     contains_edits = true;
                                     info!("\n{:?}\n", file);
                                     display_file_diff(&file_content, &formatted_content)?;
@@ -85,7 +80,7 @@ fn format_pkg_at_dir(app: App, dir: &Path) -> Result<()> {
                         Err(err) => {
                             // there could still be Sway files that are not part of the build
                             error!("\nThis file: {:?} is not part of the build", file);
-                            error!("{}", err.join("\n"));
+                            error!("{}", err);
                         }
                     }
                 }
@@ -0,0 +1,50 @@
+use crate::ops::forc_add;
+use anyhow::Result;
+use clap::Parser;
+
+/// Add a dependency to the project's `Forc.toml` and update the `Forc.lock` file.
+///
+/// The manifest is edited in place, preserving existing formatting and comments. The new
+/// dependency source is pinned before the change is kept, so an invalid source leaves the
+/// manifest untouched.
+#[derive(Debug, Parser)]
+pub struct Command {
+    /// The name of the dependency, used to refer to it within the project.
+    pub name: String,
+
+    /// Path to the project, if not specified, current working directory will be used.
+    #[clap(long)]
+    pub manifest_path: Option<String>,
+
+    /// The URL of the git repository to fetch the dependency from.
+    #[clap(long, conflicts_with = "path")]
+    pub git: Option<String>,
+
+    /// The git branch to use for the dependency.
+    #[clap(long, requires = "git", conflicts_with_all = &["tag", "rev"])]
+    pub branch: Option<String>,
+
+    /// The git tag to use for the dependency.
+    #[clap(long, requires = "git", conflicts_with = "rev")]
+    pub tag: Option<String>,
+
+    /// The git revision to use for the dependency.
+    #[clap(long, requires = "git")]
+    pub rev: Option<String>,
+
+    /// The local path to the dependency.
+    #[clap(long)]
+    pub path: Option<String>,
+
+    /// The name of the package within the source, if it differs from the dependency name.
+    #[clap(long)]
+    pub package: Option<String>,
+
+    /// Offline mode, prevents Forc from using the network when managing dependencies.
+    #[clap(long)]
+    pub offline: bool,
+}
+
+pub(crate) fn exec(command: Command) -> Result<()> {
+    forc_add::add(command)
+}
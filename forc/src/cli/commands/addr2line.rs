@@ -140,3 +140,36 @@ fn read_range<P: AsRef<Path>>(
         length,
     })
 }
+
+#[test]
+fn vendored_source_resolves_from_written_source_map() {
+    use std::sync::Arc;
+    use sway_types::Span;
+
+    let temp_dir = tempfile::tempdir().unwrap();
+    let project_dir = temp_dir.path().join("app");
+    let dep_dir = project_dir.join("vendor").join("utils");
+    let src_path = dep_dir.join("src").join("lib.sw");
+    let src = "library utils;\n\npub fn one() -> u64 {\n    1\n}\n";
+    fs::create_dir_all(src_path.parent().unwrap()).unwrap();
+    fs::write(&src_path, src).unwrap();
+
+    // Vendored sources are recorded relative to the project directory.
+    let mut source_map = SourceMap::new();
+    source_map.set_project_dir(&project_dir);
+    source_map.insert_dependency(&dep_dir);
+    let start = src.find('1').unwrap();
+    let span = Span::new(Arc::from(src), start, start + 1, Some(Arc::new(src_path))).unwrap();
+    source_map.insert(0, &span);
+    let sourcemap_path = temp_dir.path().join("source_map.json");
+    fs::write(&sourcemap_path, serde_json::to_vec(&source_map).unwrap()).unwrap();
+
+    // The written source map resolves them without searching from the project directory.
+    let command = Command {
+        search_dir: temp_dir.path().join("elsewhere"),
+        sourcemap_path,
+        context: 2,
+        opcode_index: 0,
+    };
+    assert!(exec(command).is_ok());
+}
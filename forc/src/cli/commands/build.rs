@@ -1,6 +1,7 @@
 use crate::ops::forc_build;
 use anyhow::Result;
 use clap::Parser;
+use fuel_tx::Salt;
 
 /// Compile the current or target project.
 ///
@@ -14,8 +15,15 @@ use clap::Parser;
 /// - `predicate` projects will also produce a file containing the **root** hash of the bytecode binary
 /// `<project-name>-bin-root` (using `fuel_tx::Contract::root_from_code`).
 ///
+/// - `contract` projects will also produce their contract ID `<project-name>-contract-id`.
+///
 /// - `contract` and `library` projects will also produce the public ABI in JSON format
 /// `<project-name>-abi.json`.
+///
+/// - all projects will also produce `build-info.json`, recording how the artifacts were produced.
+///
+/// - when `--size-report` is specified, `script`, `predicate` and `contract` projects will also
+///   produce a breakdown of the bytecode size in JSON format `<project-name>-size-report.json`.
 #[derive(Debug, Default, Parser)]
 pub struct Command {
     /// Path to the project, if not specified, current working directory will be used.
@@ -77,6 +85,21 @@ pub struct Command {
     /// Output the time elapsed over each part of the compilation process.
     #[clap(long)]
     pub time_phases: bool,
+    /// Relate the bytecode back to the source it was compiled from, even if the build profile
+    /// sets `include-debug-info = false`.
+    #[clap(long)]
+    pub include_debug_info: bool,
+    /// Print a breakdown of the bytecode size by entry point and by source function.
+    ///
+    /// Data section values are attributed to the function that first loads them. Functions that
+    /// have been inlined are reported separately from their callers.
+    #[clap(long)]
+    pub size_report: bool,
+    /// The salt used to compute the ID of a contract, as a 32-byte hex string. Defaults to zero.
+    ///
+    /// Deploy with the same salt for the deployed contract to have the computed ID.
+    #[clap(long)]
+    pub salt: Option<Salt>,
 }
 
 pub(crate) fn exec(command: Command) -> Result<()> {
@@ -10,6 +10,11 @@ pub struct Command {
     /// Path to the project, if not specified, current working directory will be used.
     #[clap(short, long)]
     pub path: Option<String>,
+
+    /// Instead of cleaning a project, prune the git dependency cache within the user's forc
+    /// directory, removing any checkouts that fail verification.
+    #[clap(long, conflicts_with = "path")]
+    pub cache: bool,
 }
 
 pub fn exec(command: Command) -> Result<()> {
@@ -0,0 +1,65 @@
+use crate::ops::forc_debug;
+use anyhow::Result;
+use clap::Parser;
+
+/// Debug a script project at the level of its Sway source.
+///
+/// The script is built and executed within an in-process VM. By default, an interactive prompt
+/// allows for setting breakpoints on lines of source, stepping through the script and inspecting
+/// the VM's registers and memory. Type `help` at the prompt for a list of commands.
+///
+/// Alternatively, `--dap-port` serves the Debug Adapter Protocol to a single editor connection.
+#[derive(Debug, Default, Parser)]
+pub struct Command {
+    /// Path to the script project, if not specified, current working directory will be used.
+    #[clap(short, long)]
+    pub path: Option<String>,
+
+    /// Path to a contract project to build and deploy before running the script.
+    ///
+    /// May be specified multiple times. Contracts are deployed with a zero salt, matching
+    /// `forc deploy`, so that IDs hardcoded within the script resolve to these contracts.
+    #[clap(long = "contract")]
+    pub contracts: Vec<String>,
+
+    /// Hex string of data to input to script.
+    #[clap(short, long)]
+    pub data: Option<String>,
+
+    /// Set the transaction gas limit. Defaults to the maximum gas limit.
+    #[clap(long)]
+    pub gas_limit: Option<u64>,
+
+    /// Set a breakpoint before starting, given as `<file>:<line>`, e.g. `src/main.sw:12`.
+    ///
+    /// May be specified multiple times.
+    #[clap(short, long = "break")]
+    pub breakpoints: Vec<String>,
+
+    /// Serve the Debug Adapter Protocol on this port of localhost rather than starting the
+    /// interactive prompt.
+    #[clap(long)]
+    pub dap_port: Option<u16>,
+
+    /// Build using the release profile.
+    #[clap(long)]
+    pub release: bool,
+
+    /// Offline mode, prevents Forc from using the network when managing dependencies.
+    /// Meaning it will only try to use previously downloaded dependencies.
+    #[clap(long = "offline")]
+    pub offline_mode: bool,
+
+    /// Silent mode. Don't output any warnings or errors to the command line.
+    #[clap(long = "silent", short = 's')]
+    pub silent_mode: bool,
+
+    /// Requires that the Forc.lock file is up-to-date. If the lock file is missing, or it
+    /// needs to be updated, Forc will exit with an error
+    #[clap(long)]
+    pub locked: bool,
+}
+
+pub(crate) fn exec(command: Command) -> Result<()> {
+    forc_debug::debug(command)
+}
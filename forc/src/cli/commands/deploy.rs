@@ -1,6 +1,7 @@
 use crate::ops::forc_deploy;
 use anyhow::{bail, Result};
 use clap::Parser;
+use fuel_tx::Salt;
 
 /// Deploy contract project.
 /// Crafts a contract deployment transaction then sends it to a running node.
@@ -69,6 +70,11 @@ pub struct Command {
     /// Output the time elapsed over each part of the compilation process.
     #[clap(long)]
     pub time_phases: bool,
+    /// The salt used when creating the contract, as a 32-byte hex string. Defaults to zero.
+    ///
+    /// The contract ID printed by `forc build` matches the deployed contract for the same salt.
+    #[clap(long)]
+    pub salt: Option<Salt>,
 }
 
 pub(crate) async fn exec(command: Command) -> Result<()> {
@@ -0,0 +1,33 @@
+use crate::ops::forc_doc;
+use anyhow::Result;
+use clap::Parser;
+
+/// Generate HTML documentation for a project from the doc comments of its items.
+///
+/// The project is type-checked, after which its root module and each of its `dep` modules are
+/// documented. Every public function, struct, enum, trait and constant is given a page, as are
+/// ABIs and storage. Pages include the declaration of each item, its `///` doc comments, the
+/// methods of ABIs and traits, and the `impl`s of each type. The documentation is written to
+/// `out/doc` within the project directory, where `index.html` documents the root module.
+#[derive(Debug, Default, Parser)]
+pub struct Command {
+    /// Path to the project, if not specified, current working directory will be used.
+    #[clap(short, long)]
+    pub path: Option<String>,
+    /// Offline mode, prevents Forc from using the network when managing dependencies.
+    /// Meaning it will only try to use previously downloaded dependencies.
+    #[clap(long = "offline")]
+    pub offline_mode: bool,
+    /// Silent mode. Don't output any warnings or errors to the command line.
+    #[clap(long = "silent", short = 's')]
+    pub silent_mode: bool,
+    /// Requires that the Forc.lock file is up-to-date. If the lock file is missing, or it
+    /// needs to be updated, Forc will exit with an error
+    #[clap(long)]
+    pub locked: bool,
+}
+
+pub(crate) fn exec(command: Command) -> Result<()> {
+    forc_doc::doc(command)?;
+    Ok(())
+}
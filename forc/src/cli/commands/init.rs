@@ -3,6 +3,12 @@ use anyhow::Result;
 use clap::Parser;
 
 /// Create a new Forc project.
+///
+/// With `--template`, the project is created from a built-in template (`token`, `multisig` or
+/// `library`), a local directory or a git repository. The placeholders `{{project-name}}`,
+/// `{{project_name}}`, `{{authors}}` and `{{license}}` are substituted within every file of the
+/// template, and each package of the new project is built once to check that it compiles. If the
+/// build fails, the new project is removed.
 #[derive(Debug, Parser)]
 pub struct Command {
     /// The default program type, excluding all flags or adding this flag creates a basic contract program.
@@ -17,6 +23,26 @@ pub struct Command {
     /// Adding this flag creates an empty library program.
     #[clap(long)]
     pub library: bool,
+    /// Create the project from a template: the name of a built-in template, the path to a local
+    /// directory or the URL of a git repository.
+    #[clap(
+        long,
+        conflicts_with_all = &["contract", "script", "predicate", "library", "workspace"]
+    )]
+    pub template: Option<String>,
+    /// The name of the template to use from the git repository given with `--template`.
+    #[clap(long, requires = "template")]
+    pub template_name: Option<String>,
+    /// Create a workspace of an ABI library, a contract implementing it and a script calling the
+    /// contract, each within its own directory.
+    #[clap(long, conflicts_with_all = &["contract", "script", "predicate", "library"])]
+    pub workspace: bool,
+    /// The license of the project.
+    #[clap(long, default_value = "Apache-2.0")]
+    pub license: String,
+    /// Build the new project without fetching its dependencies over the network.
+    #[clap(long)]
+    pub offline: bool,
     /// The name of your project
     pub project_name: String,
 }
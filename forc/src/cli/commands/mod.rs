@@ -1,14 +1,22 @@
+pub mod add;
 pub mod addr2line;
 pub mod build;
 pub mod check;
 pub mod clean;
 pub mod completions;
+pub mod debug;
 pub mod deploy;
+pub mod doc;
 pub mod init;
 pub mod json_abi;
 pub mod parse_bytecode;
 pub mod plugins;
+pub mod profile;
+pub mod remove;
 pub mod run;
 pub mod template;
 pub mod test;
+pub mod tree;
 pub mod update;
+pub mod vendor;
+pub mod verify;
@@ -0,0 +1,66 @@
+use crate::ops::forc_profile;
+use anyhow::Result;
+use clap::Parser;
+
+/// Profile the gas used by a script project.
+///
+/// The script is built and executed within an in-process VM, and the gas used by each instruction
+/// is attributed to its source function and line through the source map. Contracts called by the
+/// script may be deployed to the VM beforehand using `--contract`, in which case the gas used
+/// within them is attributed in the same way.
+///
+/// A report of the most expensive functions and lines is printed, and all gas use is written to
+/// `<project-name>-gas-profile.folded` in the output directory. This file uses the folded stack
+/// format that is accepted by flamegraph tools, e.g. `inferno-flamegraph`.
+#[derive(Debug, Default, Parser)]
+pub struct Command {
+    /// Path to the script project, if not specified, current working directory will be used.
+    #[clap(short, long)]
+    pub path: Option<String>,
+
+    /// Path to a contract project to build and deploy before running the script.
+    ///
+    /// May be specified multiple times. Contracts are deployed with a zero salt, matching
+    /// `forc deploy`, so that IDs hardcoded within the script resolve to these contracts.
+    #[clap(long = "contract")]
+    pub contracts: Vec<String>,
+
+    /// Hex string of data to input to script.
+    #[clap(short, long)]
+    pub data: Option<String>,
+
+    /// Set the transaction gas limit. Defaults to the maximum gas limit.
+    #[clap(long)]
+    pub gas_limit: Option<u64>,
+
+    /// The number of functions and lines to show in the report.
+    #[clap(long, default_value = "20")]
+    pub top: usize,
+
+    /// If set, the folded stacks are written to this file rather than the output directory.
+    #[clap(long)]
+    pub folded_outfile: Option<String>,
+
+    /// Build using the release profile.
+    #[clap(long)]
+    pub release: bool,
+
+    /// Offline mode, prevents Forc from using the network when managing dependencies.
+    /// Meaning it will only try to use previously downloaded dependencies.
+    #[clap(long = "offline")]
+    pub offline_mode: bool,
+
+    /// Silent mode. Don't output any warnings or errors to the command line.
+    #[clap(long = "silent", short = 's')]
+    pub silent_mode: bool,
+
+    /// Requires that the Forc.lock file is up-to-date. If the lock file is missing, or it
+    /// needs to be updated, Forc will exit with an error
+    #[clap(long)]
+    pub locked: bool,
+}
+
+pub(crate) fn exec(command: Command) -> Result<()> {
+    forc_profile::profile(command)?;
+    Ok(())
+}
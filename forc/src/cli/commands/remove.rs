@@ -0,0 +1,24 @@
+use crate::ops::forc_remove;
+use anyhow::Result;
+use clap::Parser;
+
+/// Remove a dependency from the project's `Forc.toml` and update the `Forc.lock` file.
+///
+/// The manifest is edited in place, preserving existing formatting and comments.
+#[derive(Debug, Parser)]
+pub struct Command {
+    /// The name of the dependency to remove.
+    pub name: String,
+
+    /// Path to the project, if not specified, current working directory will be used.
+    #[clap(long)]
+    pub manifest_path: Option<String>,
+
+    /// Offline mode, prevents Forc from using the network when managing dependencies.
+    #[clap(long)]
+    pub offline: bool,
+}
+
+pub(crate) fn exec(command: Command) -> Result<()> {
+    forc_remove::remove(command)
+}
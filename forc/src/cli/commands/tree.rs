@@ -0,0 +1,36 @@
+use crate::ops::forc_tree;
+use anyhow::Result;
+use clap::Parser;
+
+/// Display the project's resolved dependency graph as a tree.
+///
+/// Each package is shown along with its pinned source. Packages that have already been displayed
+/// elsewhere in the tree are marked with `(*)` and their dependencies are not repeated.
+#[derive(Debug, Parser)]
+pub struct Command {
+    /// Path to the project, if not specified, current working directory will be used.
+    #[clap(short, long)]
+    pub path: Option<String>,
+
+    /// Invert the tree, showing the packages that depend on the given package.
+    #[clap(short, long, conflicts_with = "duplicates")]
+    pub invert: Option<String>,
+
+    /// Show only the packages that appear in the graph more than once with differing sources,
+    /// along with the packages that depend on them.
+    #[clap(short, long)]
+    pub duplicates: bool,
+
+    /// Requires that the Forc.lock file is up-to-date. If the lock file is missing, or it
+    /// needs to be updated, Forc will exit with an error
+    #[clap(long)]
+    pub locked: bool,
+
+    /// Offline mode, prevents Forc from using the network when managing dependencies.
+    #[clap(long)]
+    pub offline: bool,
+}
+
+pub(crate) fn exec(command: Command) -> Result<()> {
+    forc_tree::tree(command)
+}
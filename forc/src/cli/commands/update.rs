@@ -9,10 +9,15 @@ pub struct Command {
     #[clap(short, long)]
     pub path: Option<String>,
 
-    /// Dependency to be updated.
-    /// If not set, all dependencies will be updated.
-    #[clap(short = 'd')]
-    pub target_dependency: Option<String>,
+    /// Package to be updated.
+    /// If not set, all dependencies will be updated. Otherwise, only the given package is
+    /// re-pinned and all other packages remain at their locked versions.
+    #[clap(short = 'd', long = "package")]
+    pub package: Option<String>,
+
+    /// Offline mode, prevents Forc from using the network when managing dependencies.
+    #[clap(long)]
+    pub offline: bool,
 
     /// Checks if the dependencies have newer versions.
     /// Won't actually perform the update, will output which
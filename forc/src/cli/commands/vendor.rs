@@ -0,0 +1,28 @@
+use crate::ops::forc_vendor;
+use anyhow::Result;
+use clap::Parser;
+
+/// Copy all git dependencies of the project into its `vendor` directory.
+///
+/// Once vendored, the dependencies pinned in `Forc.lock` are resolved to their vendored copies,
+/// allowing the project to be built with `--offline` without access to the user's forc directory.
+#[derive(Debug, Parser)]
+pub struct Command {
+    /// Path to the project, if not specified, current working directory will be used.
+    #[clap(short, long)]
+    pub path: Option<String>,
+
+    /// Requires that the Forc.lock file is up-to-date. If the lock file is missing, or it
+    /// needs to be updated, Forc will exit with an error
+    #[clap(long)]
+    pub locked: bool,
+
+    /// Offline mode, prevents Forc from using the network when managing dependencies.
+    /// Meaning it will only try to use previously downloaded dependencies.
+    #[clap(long)]
+    pub offline: bool,
+}
+
+pub(crate) fn exec(command: Command) -> Result<()> {
+    forc_vendor::vendor(command)
+}
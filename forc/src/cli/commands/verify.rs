@@ -0,0 +1,48 @@
+use crate::ops::forc_verify;
+use anyhow::Result;
+use clap::Parser;
+use fuel_tx::Salt;
+
+/// Rebuild the project from source and check that it reproduces a deployed contract or bytecode.
+///
+/// The target is either a `0x`-prefixed contract ID, which is compared against the ID of the
+/// rebuilt contract, or the path to a bytecode file, which must match the rebuilt bytecode byte
+/// for byte.
+#[derive(Debug, Parser)]
+pub struct Command {
+    /// The contract ID or the path to the bytecode file to verify.
+    pub target: String,
+
+    /// Path to the project, if not specified, current working directory will be used.
+    #[clap(short, long)]
+    pub path: Option<String>,
+
+    /// Name of the build profile to use.
+    /// If it is not specified, forc will use debug build profile.
+    #[clap(long)]
+    pub build_profile: Option<String>,
+
+    /// Use release build plan. If a custom release plan is not specified, it is implicitly added to the manifest file.
+    ///
+    ///  If --build-profile is also provided, forc omits this flag and uses provided build-profile.
+    #[clap(long)]
+    pub release: bool,
+
+    /// Requires that the Forc.lock file is up-to-date. If the lock file is missing, or it
+    /// needs to be updated, Forc will exit with an error
+    #[clap(long)]
+    pub locked: bool,
+
+    /// Offline mode, prevents Forc from using the network when managing dependencies.
+    /// Meaning it will only try to use previously downloaded dependencies.
+    #[clap(long)]
+    pub offline: bool,
+
+    /// The salt the contract was deployed with, as a 32-byte hex string. Defaults to zero.
+    #[clap(long)]
+    pub salt: Option<Salt>,
+}
+
+pub(crate) fn exec(command: Command) -> Result<()> {
+    forc_verify::verify(command)
+}
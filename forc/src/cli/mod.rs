@@ -1,7 +1,8 @@
 use self::commands::{
-    addr2line, build, check, clean, completions, deploy, init, json_abi, parse_bytecode, plugins,
-    run, template, test, update,
+    add, addr2line, build, check, clean, completions, debug, deploy, doc, init, json_abi,
+    parse_bytecode, plugins, profile, remove, run, template, test, tree, update, vendor, verify,
 };
+pub use add::Command as AddCommand;
 use addr2line::Command as Addr2LineCommand;
 use anyhow::{anyhow, Result};
 pub use build::Command as BuildCommand;
@@ -9,15 +10,22 @@ pub use check::Command as CheckCommand;
 use clap::Parser;
 pub use clean::Command as CleanCommand;
 pub use completions::Command as CompletionsCommand;
+pub use debug::Command as DebugCommand;
 pub use deploy::Command as DeployCommand;
+pub use doc::Command as DocCommand;
 pub use init::Command as InitCommand;
 pub use json_abi::Command as JsonAbiCommand;
 use parse_bytecode::Command as ParseBytecodeCommand;
 pub use plugins::Command as PluginsCommand;
+pub use profile::Command as ProfileCommand;
+pub use remove::Command as RemoveCommand;
 pub use run::Command as RunCommand;
 pub use template::Command as TemplateCommand;
 use test::Command as TestCommand;
+pub use tree::Command as TreeCommand;
 pub use update::Command as UpdateCommand;
+pub use vendor::Command as VendorCommand;
+pub use verify::Command as VerifyCommand;
 
 mod commands;
 mod plugin;
@@ -32,6 +40,7 @@ struct Opt {
 
 #[derive(Debug, Parser)]
 enum Forc {
+    Add(AddCommand),
     #[clap(name = "addr2line")]
     Addr2Line(Addr2LineCommand),
     #[clap(visible_alias = "b")]
@@ -39,13 +48,20 @@ enum Forc {
     Check(CheckCommand),
     Clean(CleanCommand),
     Completions(CompletionsCommand),
+    Debug(DebugCommand),
     Deploy(DeployCommand),
+    Doc(DocCommand),
     Init(InitCommand),
     ParseBytecode(ParseBytecodeCommand),
+    Profile(ProfileCommand),
+    Remove(RemoveCommand),
     Run(RunCommand),
     #[clap(visible_alias = "t")]
     Test(TestCommand),
+    Tree(TreeCommand),
     Update(UpdateCommand),
+    Vendor(VendorCommand),
+    Verify(VerifyCommand),
     JsonAbi(JsonAbiCommand),
     Plugins(PluginsCommand),
     Template(TemplateCommand),
@@ -64,18 +80,26 @@ enum Forc {
 pub async fn run_cli() -> Result<()> {
     let opt = Opt::parse();
     match opt.command {
+        Forc::Add(command) => add::exec(command),
         Forc::Addr2Line(command) => addr2line::exec(command),
         Forc::Build(command) => build::exec(command),
         Forc::Check(command) => check::exec(command),
         Forc::Clean(command) => clean::exec(command),
         Forc::Completions(command) => completions::exec(command),
+        Forc::Debug(command) => debug::exec(command),
         Forc::Deploy(command) => deploy::exec(command).await,
+        Forc::Doc(command) => doc::exec(command),
         Forc::Init(command) => init::exec(command),
         Forc::ParseBytecode(command) => parse_bytecode::exec(command),
         Forc::Plugins(command) => plugins::exec(command),
+        Forc::Profile(command) => profile::exec(command),
+        Forc::Remove(command) => remove::exec(command),
         Forc::Run(command) => run::exec(command).await,
         Forc::Test(command) => test::exec(command),
+        Forc::Tree(command) => tree::exec(command),
         Forc::Update(command) => update::exec(command).await,
+        Forc::Vendor(command) => vendor::exec(command),
+        Forc::Verify(command) => verify::exec(command),
         Forc::JsonAbi(command) => json_abi::exec(command),
         Forc::Template(command) => template::exec(command),
         Forc::Plugin(args) => {
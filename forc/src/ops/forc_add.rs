@@ -0,0 +1,110 @@
+use crate::{
+    cli::AddCommand,
+    utils::manifest_edit::{edit_manifest, DEPENDENCIES_TABLE},
+};
+use anyhow::{anyhow, bail, Result};
+use forc_util::validate_name;
+use toml_edit::{InlineTable, Item};
+use tracing::info;
+
+/// Add the dependency described by `command` to the project's `[dependencies]` table, replacing
+/// any existing entry of the same name, and update the lock file accordingly.
+pub fn add(command: AddCommand) -> Result<()> {
+    let AddCommand {
+        name,
+        manifest_path,
+        git,
+        branch,
+        tag,
+        rev,
+        path,
+        package,
+        offline,
+    } = command;
+
+    validate_name(&name, "dependency name")?;
+
+    let mut details = InlineTable::new();
+    match (git, path) {
+        (Some(git), None) => {
+            details.insert("git", git.into());
+            let reference = [("branch", branch), ("tag", tag), ("rev", rev)];
+            for (key, value) in reference {
+                if let Some(value) = value {
+                    details.insert(key, value.into());
+                }
+            }
+        }
+        (None, Some(path)) => {
+            details.insert("path", path.into());
+        }
+        (Some(_), Some(_)) => bail!("`--git` and `--path` cannot be used together"),
+        (None, None) => bail!("a dependency source must be specified with `--git` or `--path`"),
+    }
+    if let Some(package) = package {
+        details.insert("package", package.into());
+    }
+    details.fmt();
+
+    edit_manifest(manifest_path, offline, |doc| {
+        let deps = doc
+            .entry(DEPENDENCIES_TABLE)
+            .or_insert_with(toml_edit::table)
+            .as_table_like_mut()
+            .ok_or_else(|| anyhow!("`{}` in the manifest is not a table", DEPENDENCIES_TABLE))?;
+        deps.insert(&name, Item::Value(details.into()));
+        Ok(())
+    })?;
+
+    info!("      Added dependency `{}`", name);
+    Ok(())
+}
+
+#[test]
+fn add_path_dependency() {
+    use crate::utils::manifest_edit::{test_project, TEST_MANIFEST};
+    let dir = tempfile::tempdir().unwrap();
+    let app_dir = test_project(dir.path());
+    add(AddCommand {
+        name: "lib".to_string(),
+        manifest_path: Some(app_dir.to_string_lossy().to_string()),
+        git: None,
+        branch: None,
+        tag: None,
+        rev: None,
+        path: Some("../lib".to_string()),
+        package: None,
+        offline: true,
+    })
+    .unwrap();
+
+    // The existing formatting and comments are left untouched.
+    let manifest = std::fs::read_to_string(app_dir.join("Forc.toml")).unwrap();
+    assert_eq!(
+        manifest,
+        format!("{}lib = {{ path = \"../lib\" }}\n", TEST_MANIFEST)
+    );
+    let lock = std::fs::read_to_string(app_dir.join("Forc.lock")).unwrap();
+    assert!(lock.contains("name = 'lib'"));
+}
+
+#[test]
+fn add_without_source_fails() {
+    use crate::utils::manifest_edit::{test_project, TEST_MANIFEST};
+    let dir = tempfile::tempdir().unwrap();
+    let app_dir = test_project(dir.path());
+    let res = add(AddCommand {
+        name: "lib".to_string(),
+        manifest_path: Some(app_dir.to_string_lossy().to_string()),
+        git: None,
+        branch: None,
+        tag: None,
+        rev: None,
+        path: None,
+        package: None,
+        offline: true,
+    });
+    assert!(res.is_err());
+    let manifest = std::fs::read_to_string(app_dir.join("Forc.toml")).unwrap();
+    assert_eq!(manifest, TEST_MANIFEST);
+}
@@ -1,19 +1,29 @@
 use crate::{
     cli::BuildCommand,
-    utils::{SWAY_BIN_HASH_SUFFIX, SWAY_BIN_ROOT_SUFFIX, SWAY_GIT_TAG},
+    utils::{
+        build_info::{BuildInfo, BUILD_INFO_FILE_NAME},
+        size_report::SizeReport,
+        SWAY_BIN_HASH_SUFFIX, SWAY_BIN_ROOT_SUFFIX, SWAY_BUILD_PROFILE_SUFFIX,
+        SWAY_CONTRACT_ID_SUFFIX, SWAY_GIT_TAG, SWAY_SIZE_REPORT_SUFFIX,
+    },
 };
 use anyhow::Result;
 use forc_pkg::{self as pkg, ManifestFile};
 use forc_util::default_output_directory;
-use fuel_tx::Contract;
+use fuel_tx::Salt;
 use std::{
     fs::{self, File},
     path::PathBuf,
 };
-use sway_core::TreeType;
+use sway_core::{source_map::SourceMap, TreeType};
 use tracing::{info, warn};
 
 pub fn build(command: BuildCommand) -> Result<pkg::Compiled> {
+    build_with_source_map(command).map(|(compiled, _)| compiled)
+}
+
+/// Build the project, also returning the source map that relates its bytecode back to the source.
+pub fn build_with_source_map(command: BuildCommand) -> Result<(pkg::Compiled, SourceMap)> {
     let BuildCommand {
         path,
         binary_outfile,
@@ -30,7 +40,11 @@ pub fn build(command: BuildCommand) -> Result<pkg::Compiled> {
         build_profile,
         release,
         time_phases,
+        include_debug_info,
+        size_report,
+        salt,
     } = command;
+    let salt = salt.unwrap_or_else(Salt::zeroed);
 
     let key_debug: String = "debug".to_string();
     let key_release: String = "release".to_string();
@@ -77,6 +91,8 @@ pub fn build(command: BuildCommand) -> Result<pkg::Compiled> {
     profile.print_intermediate_asm |= print_intermediate_asm;
     profile.silent |= silent_mode;
     profile.time_phases |= time_phases;
+    // The source map is needed to write the debug outfile or to attribute bytecode in the report.
+    profile.include_debug_info |= include_debug_info || debug_outfile.is_some() || size_report;
 
     // Build it!
     let (compiled, source_map) = pkg::build(&plan, &profile, SWAY_GIT_TAG)?;
@@ -93,7 +109,7 @@ pub fn build(command: BuildCommand) -> Result<pkg::Compiled> {
     // Create the output directory for build artifacts.
     let output_dir = output_directory
         .map(PathBuf::from)
-        .unwrap_or_else(|| default_output_directory(manifest.dir()).join(selected_build_profile));
+        .unwrap_or_else(|| default_output_directory(manifest.dir()).join(&selected_build_profile));
     if !output_dir.exists() {
         fs::create_dir_all(&output_dir)?;
     }
@@ -115,6 +131,24 @@ pub fn build(command: BuildCommand) -> Result<pkg::Compiled> {
         res?;
     }
 
+    // Record the profile settings so that the artifacts can be reproduced.
+    let build_profile_stem = format!("{}{}", manifest.project.name, SWAY_BUILD_PROFILE_SUFFIX);
+    let build_profile_path = output_dir.join(build_profile_stem).with_extension("json");
+    let file = File::create(build_profile_path)?;
+    serde_json::to_writer_pretty(&file, &profile)?;
+
+    // Record the compiler, lock file and sources that the artifacts were produced from.
+    let build_info = BuildInfo::new(
+        &manifest,
+        &plan,
+        &selected_build_profile,
+        &profile,
+        &compiled,
+        &salt,
+    )?;
+    let file = File::create(output_dir.join(BUILD_INFO_FILE_NAME))?;
+    serde_json::to_writer_pretty(&file, &build_info)?;
+
     info!("  Bytecode size is {} bytes.", compiled.bytecode.len());
 
     // Additional ops required depending on the program type
@@ -132,14 +166,23 @@ pub fn build(command: BuildCommand) -> Result<pkg::Compiled> {
                 serde_json::to_writer_pretty(&file, &compiled.storage_slots)
             };
             res?;
+
+            // Compute the ID the contract will have once deployed with the same salt.
+            let contract_id = pkg::contract_id(&compiled.bytecode, &compiled.storage_slots, &salt);
+            let contract_id = format!("0x{}", hex::encode(contract_id));
+            let id_file_name = format!("{}{}", &manifest.project.name, SWAY_CONTRACT_ID_SUFFIX);
+            let id_path = output_dir.join(id_file_name);
+            fs::write(id_path, &contract_id)?;
+            info!("  Contract id: {}", contract_id);
         }
         TreeType::Predicate => {
-            // get the root hash of the bytecode for predicates and store the result in a file in the output directory
-            let root = format!("0x{}", Contract::root_from_code(&compiled.bytecode));
+            // get the root hash of the bytecode for predicates, which is also the predicate's
+            // address, and store the result in a file in the output directory
+            let root = format!("0x{}", pkg::predicate_address(&compiled.bytecode));
             let root_file_name = format!("{}{}", &manifest.project.name, SWAY_BIN_ROOT_SUFFIX);
             let root_path = output_dir.join(root_file_name);
             fs::write(root_path, &root)?;
-            info!("  Predicate root: {}", root);
+            info!("  Predicate address: {}", root);
         }
         TreeType::Script => {
             // hash the bytecode for scripts and store the result in a file in the output directory
@@ -152,5 +195,61 @@ pub fn build(command: BuildCommand) -> Result<pkg::Compiled> {
         _ => (),
     }
 
-    Ok(compiled)
+    if size_report {
+        if let TreeType::Library { .. } = compiled.tree_type {
+            warn!("  Libraries produce no bytecode. Skipping size report.");
+        } else {
+            let report = SizeReport::new(
+                &compiled.bytecode,
+                &compiled.bytecode_layout,
+                &source_map,
+                manifest.dir(),
+            );
+            info!("{}", report.render_table());
+            let report_stem = format!("{}{}", manifest.project.name, SWAY_SIZE_REPORT_SUFFIX);
+            let report_path = output_dir.join(report_stem).with_extension("json");
+            let file = File::create(report_path)?;
+            serde_json::to_writer_pretty(&file, &report)?;
+        }
+    }
+
+    Ok((compiled, source_map))
+}
+
+#[test]
+fn build_contract_id_with_salt() {
+    use crate::utils::build_info::test_contract;
+    let dir = tempfile::tempdir().unwrap();
+    let proj_dir = test_contract(dir.path());
+    // The ID of the contract built with the given salt, as written to the output directory.
+    let contract_id = |salt: Option<Salt>| {
+        let output_dir = dir.path().join("out");
+        let compiled = build(BuildCommand {
+            path: Some(proj_dir.display().to_string()),
+            output_directory: Some(output_dir.display().to_string()),
+            silent_mode: true,
+            salt,
+            ..Default::default()
+        })
+        .unwrap();
+        let id_file_name = format!("counter{}", SWAY_CONTRACT_ID_SUFFIX);
+        let id = fs::read_to_string(output_dir.join(id_file_name)).unwrap();
+        let salt = salt.unwrap_or_else(Salt::zeroed);
+        let expected = pkg::contract_id(&compiled.bytecode, &compiled.storage_slots, &salt);
+        assert_eq!(id, format!("0x{}", hex::encode(expected)));
+        id
+    };
+
+    assert_eq!(
+        contract_id(None),
+        "0xad8c9b07f8c2044bccca109ccd567cf96bb117505b67dba11f37aa95a2a8a851"
+    );
+    assert_eq!(
+        contract_id(Some(Salt::zeroed())),
+        "0xad8c9b07f8c2044bccca109ccd567cf96bb117505b67dba11f37aa95a2a8a851"
+    );
+    assert_eq!(
+        contract_id(Some(Salt::from([1u8; 32]))),
+        "0x3ba8755ad3d8cc07be1dfc0a91ed6a8fe74248632aabe163450fe3130b53863c"
+    );
 }
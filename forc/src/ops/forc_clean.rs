@@ -1,11 +1,16 @@
-use crate::cli::CleanCommand;
+use crate::{cli::CleanCommand, utils::SWAY_GIT_TAG};
 use anyhow::{anyhow, bail, Result};
 use forc_util::{default_output_directory, find_cargo_manifest_dir, find_manifest_dir};
 use std::{path::PathBuf, process};
 use sway_utils::MANIFEST_FILE_NAME;
+use tracing::info;
 
 pub fn clean(command: CleanCommand) -> Result<()> {
-    let CleanCommand { path } = command;
+    let CleanCommand { path, cache } = command;
+
+    if cache {
+        return clean_cache();
+    }
 
     // find manifest directory, even if in subdirectory
     let this_dir = if let Some(ref path) = path {
@@ -42,3 +47,16 @@ pub fn clean(command: CleanCommand) -> Result<()> {
 
     Ok(())
 }
+
+/// Prune the git dependency cache, removing checkouts that fail verification.
+fn clean_cache() -> Result<()> {
+    let removed = forc_pkg::prune_git_checkouts(SWAY_GIT_TAG)?;
+    for path in &removed {
+        info!("    Removed {}", path.display());
+    }
+    info!(
+        "  Pruned {} invalid or temporary cache entries",
+        removed.len()
+    );
+    Ok(())
+}
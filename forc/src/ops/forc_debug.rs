@@ -0,0 +1,258 @@
+use crate::{
+    cli::DebugCommand,
+    utils::{
+        dap,
+        debugger::{render_snippet, Debugger, Stop, REGISTER_NAMES},
+        local_vm::{self, BuildOptions, Program},
+    },
+};
+use anyhow::{anyhow, bail, Result};
+use fuel_vm::prelude::*;
+use std::{
+    io::{self, BufRead, Write},
+    net::TcpListener,
+    path::PathBuf,
+};
+use sway_core::TreeType;
+use tracing::{info, warn};
+
+/// The number of lines shown either side of the current line.
+const SNIPPET_CONTEXT_LINES: usize = 2;
+
+const HELP: &str = "\
+Commands:
+  break, b [<file>:<line>]   Set a breakpoint, or list breakpoints when no location is given
+  delete, d <id>             Remove a breakpoint
+  run, r                     Start the script
+  continue, c                Continue until the next breakpoint or the end of the script
+  step, s                    Step to the next line of source
+  stepi, si                  Step a single instruction
+  where, w                   Show the current location and source
+  registers, reg [<reg>..]   Show registers by name (e.g. `sp`) or index (e.g. `16`)
+  memory, x <addr> [<len>]   Show `len` bytes of memory from `addr` (an address or register)
+  receipts                   Show the receipts produced so far
+  help, h                    Show this message
+  quit, q                    Exit the debugger";
+
+pub fn debug(command: DebugCommand) -> Result<()> {
+    let DebugCommand {
+        path,
+        contracts,
+        data,
+        gas_limit,
+        breakpoints,
+        dap_port,
+        release,
+        offline_mode,
+        silent_mode,
+        locked,
+    } = command;
+
+    let this_dir = if let Some(ref path) = path {
+        PathBuf::from(path)
+    } else {
+        std::env::current_dir()?
+    };
+    let options = BuildOptions {
+        release,
+        offline_mode,
+        silent_mode,
+        locked,
+    };
+
+    let mut vm = Interpreter::with_memory_storage();
+    let mut programs = local_vm::deploy_contracts(&mut vm, &contracts, options)?;
+    let script = Program::build(&this_dir.display().to_string(), TreeType::Script, options)?;
+    let tx = local_vm::script_tx(&script, &data.unwrap_or_default(), &programs, gas_limit)?;
+    programs.insert(None, script);
+
+    let mut debugger = Debugger::new(vm, programs, tx);
+    for breakpoint in breakpoints {
+        let (file, line) = parse_file_line(&breakpoint)?;
+        debugger.add_breakpoint(file, line)?;
+    }
+
+    match dap_port {
+        Some(port) => {
+            let listener = TcpListener::bind(("127.0.0.1", port))?;
+            info!("  Waiting for a DAP client on port {}", port);
+            let (stream, addr) = listener.accept()?;
+            info!("  Accepted DAP client {}", addr);
+            dap::serve(debugger, io::BufReader::new(stream.try_clone()?), stream)
+        }
+        None => repl(debugger),
+    }
+}
+
+/// Read and execute commands from stdin until `quit` or the end of input.
+fn repl(mut debugger: Debugger) -> Result<()> {
+    info!("{}", HELP);
+    let stdin = io::stdin();
+    let mut lines = stdin.lock().lines();
+    loop {
+        print!("(forc-debug) ");
+        io::stdout().flush()?;
+        let line = match lines.next() {
+            Some(line) => line?,
+            None => return Ok(()),
+        };
+        let args: Vec<&str> = line.split_whitespace().collect();
+        let (cmd, args) = match args.split_first() {
+            Some((cmd, args)) => (*cmd, args),
+            None => continue,
+        };
+        if matches!(cmd, "quit" | "q" | "exit") {
+            return Ok(());
+        }
+        if let Err(err) = exec_repl_command(&mut debugger, cmd, args) {
+            warn!("{}", err);
+        }
+    }
+}
+
+fn exec_repl_command(debugger: &mut Debugger, cmd: &str, args: &[&str]) -> Result<()> {
+    match cmd {
+        "help" | "h" => info!("{}", HELP),
+        "break" | "b" => match args.first() {
+            Some(arg) => {
+                let (file, line) = parse_file_line(arg)?;
+                let breakpoint = debugger.add_breakpoint(file, line)?;
+                info!(
+                    "Breakpoint {} at {}:{} ({} location(s))",
+                    breakpoint.id,
+                    file,
+                    line,
+                    breakpoint.locations.len()
+                );
+            }
+            None if debugger.breakpoints().is_empty() => info!("No breakpoints"),
+            None => {
+                for breakpoint in debugger.breakpoints() {
+                    info!("{}: {}:{}", breakpoint.id, breakpoint.file, breakpoint.line);
+                }
+            }
+        },
+        "delete" | "d" => {
+            let id = args
+                .first()
+                .ok_or_else(|| anyhow!("Expected the ID of a breakpoint"))?;
+            debugger.remove_breakpoint(id.parse()?)?;
+        }
+        "run" | "r" => {
+            let stop = debugger.start(false)?;
+            show_stop(debugger, stop);
+        }
+        "continue" | "c" => {
+            let stop = debugger.resume()?;
+            show_stop(debugger, stop);
+        }
+        "step" | "s" | "stepi" | "si" => {
+            let stop = match (debugger.is_started(), cmd) {
+                (false, _) => debugger.start(true)?,
+                (true, "step" | "s") => debugger.step_line()?,
+                (true, _) => debugger.step_instruction()?,
+            };
+            show_stop(debugger, stop);
+        }
+        "where" | "w" => match debugger.location() {
+            Some(location) => show_stop(debugger, Stop::Paused(location)),
+            None => info!("The script is not paused"),
+        },
+        "registers" | "reg" => {
+            let registers = debugger.registers();
+            if args.is_empty() {
+                for (ix, name) in REGISTER_NAMES.iter().enumerate() {
+                    info!("{:>6} = {:#x}", name, registers[ix]);
+                }
+                for (ix, value) in registers.iter().enumerate().skip(REGISTER_NAMES.len()) {
+                    if *value != 0 {
+                        info!("{:>6} = {:#x}", ix, value);
+                    }
+                }
+            }
+            for arg in args {
+                let ix = parse_register(arg)?;
+                info!("{:>6} = {:#x}", arg, registers[ix]);
+            }
+        }
+        "memory" | "x" => {
+            let addr = match args.first() {
+                Some(arg) => match parse_register(arg) {
+                    Ok(ix) => debugger.registers()[ix] as usize,
+                    Err(_) => parse_number(arg)?,
+                },
+                None => bail!("Expected an address or register"),
+            };
+            let len = args.get(1).map(|arg| parse_number(arg)).transpose()?;
+            let bytes = debugger.memory(addr, len.unwrap_or(32))?;
+            for (ix, chunk) in bytes.chunks(16).enumerate() {
+                info!("{:#010x}: {}", addr + ix * 16, hex::encode(chunk));
+            }
+        }
+        "receipts" => {
+            for receipt in debugger.receipts() {
+                info!("{:?}", receipt);
+            }
+        }
+        _ => bail!(
+            "Unknown command `{}`. Type `help` for a list of commands.",
+            cmd
+        ),
+    }
+    Ok(())
+}
+
+/// Show where execution stopped, along with the surrounding source.
+fn show_stop(debugger: &mut Debugger, stop: Stop) {
+    match stop {
+        Stop::Paused(location) => {
+            info!("Stopped in {}", debugger.describe(&location));
+            let snippet = debugger
+                .source_location(&location)
+                .map(|source| render_snippet(&source.path, source.line, SNIPPET_CONTEXT_LINES));
+            match snippet {
+                Some(Ok(snippet)) => info!("{}", snippet.trim_end()),
+                Some(Err(err)) => warn!("{}", err),
+                None => (),
+            }
+        }
+        Stop::Finished(state) => {
+            info!("Script finished: {:?}", state);
+            for receipt in debugger.receipts() {
+                info!("{:?}", receipt);
+            }
+        }
+    }
+}
+
+/// Parse a `<file>:<line>` location.
+fn parse_file_line(location: &str) -> Result<(&str, usize)> {
+    let (file, line) = location
+        .rsplit_once(':')
+        .ok_or_else(|| anyhow!("Expected a location of the form `<file>:<line>`"))?;
+    let line = line
+        .parse()
+        .map_err(|_| anyhow!("Invalid line number `{}`", line))?;
+    Ok((file, line))
+}
+
+/// Parse a register given by its name, with an optional `$` prefix, or by its index.
+fn parse_register(reg: &str) -> Result<usize> {
+    let reg = reg.strip_prefix('$').unwrap_or(reg);
+    if let Some(ix) = REGISTER_NAMES.iter().position(|name| *name == reg) {
+        return Ok(ix);
+    }
+    match reg.parse::<usize>() {
+        Ok(ix) if ix < fuel_vm::consts::VM_REGISTER_COUNT => Ok(ix),
+        _ => bail!("Unknown register `{}`", reg),
+    }
+}
+
+/// Parse a decimal number, or a hexadecimal number prefixed with `0x`.
+fn parse_number(num: &str) -> Result<usize> {
+    let res = match num.strip_prefix("0x") {
+        Some(hex) => usize::from_str_radix(hex, 16),
+        None => num.parse(),
+    };
+    res.map_err(|_| anyhow!("Invalid number `{}`", num))
+}
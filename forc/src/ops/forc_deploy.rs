@@ -4,7 +4,7 @@ use crate::{
     utils::SWAY_GIT_TAG,
 };
 use anyhow::{bail, Result};
-use forc_pkg::ManifestFile;
+use forc_pkg::{self as pkg, ManifestFile};
 use fuel_gql_client::client::FuelClient;
 use fuel_tx::{Output, Salt, StorageSlot, Transaction};
 use fuel_vm::prelude::*;
@@ -39,7 +39,9 @@ pub async fn deploy(command: DeployCommand) -> Result<fuel_tx::ContractId> {
         build_profile,
         release,
         time_phases,
+        salt,
     } = command;
+    let salt = salt.unwrap_or_else(Salt::zeroed);
 
     let build_command = BuildCommand {
         path,
@@ -57,6 +59,9 @@ pub async fn deploy(command: DeployCommand) -> Result<fuel_tx::ContractId> {
         build_profile,
         release,
         time_phases,
+        include_debug_info: false,
+        size_report: false,
+        salt: Some(salt),
     };
 
     let compiled = forc_build::build(build_command)?;
@@ -65,6 +70,7 @@ pub async fn deploy(command: DeployCommand) -> Result<fuel_tx::ContractId> {
         Vec::<fuel_tx::Input>::new(),
         Vec::<fuel_tx::Output>::new(),
         compiled.storage_slots,
+        salt,
     );
 
     let node_url = match &manifest.network {
@@ -88,11 +94,12 @@ pub async fn deploy(command: DeployCommand) -> Result<fuel_tx::ContractId> {
     }
 }
 
-fn create_contract_tx(
+pub(crate) fn create_contract_tx(
     compiled_contract: Vec<u8>,
     inputs: Vec<Input>,
     outputs: Vec<Output>,
     storage_slots: Vec<StorageSlot>,
+    salt: Salt,
 ) -> (Transaction, fuel_tx::ContractId) {
     let gas_price = 0;
     let gas_limit = fuel_tx::default_parameters::MAX_GAS_PER_TX;
@@ -101,19 +108,15 @@ fn create_contract_tx(
     let bytecode_witness_index = 0;
     let witnesses = vec![compiled_contract.clone().into()];
 
-    let salt = Salt::new([0; 32]);
     let static_contracts = vec![];
 
-    let contract = Contract::from(compiled_contract);
-    let root = contract.root();
-
     // The VM currently requires that storage slots are sorted but this shouldn't be neessary.
     // Downstream tooling should do the sorting themselves.
     // Ref: https://github.com/FuelLabs/fuel-tx/issues/153
     let mut storage_slots = storage_slots;
     storage_slots.sort();
-    let state_root = Contract::initial_state_root(storage_slots.iter());
-    let id = contract.id(&salt, &root, &state_root);
+    let state_root = pkg::contract_state_root(&storage_slots);
+    let id = pkg::contract_id(&compiled_contract, &storage_slots, &salt);
     info!("Contract id: 0x{}", hex::encode(id));
     let outputs = [
         &[Output::ContractCreated {
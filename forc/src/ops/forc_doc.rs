@@ -0,0 +1,84 @@
+use crate::{cli::DocCommand, utils::SWAY_GIT_TAG};
+use anyhow::{anyhow, bail, Result};
+use docstrings::{Documenter, SourceCodeModule};
+use forc_pkg::{self as pkg, ManifestFile};
+use forc_util::{default_output_directory, print_on_failure};
+use std::{fs, path::PathBuf};
+use sway_core::{CompileAstResult, TypedModule};
+use sway_types::Spanned;
+use tracing::info;
+
+/// The directory within the output directory to which documentation is written.
+const DOC_DIRECTORY: &str = "doc";
+
+pub fn doc(command: DocCommand) -> Result<PathBuf> {
+    let DocCommand {
+        path,
+        offline_mode: offline,
+        silent_mode,
+        locked,
+    } = command;
+
+    let this_dir = if let Some(ref path) = path {
+        PathBuf::from(path)
+    } else {
+        std::env::current_dir()?
+    };
+    let manifest = ManifestFile::from_dir(&this_dir, SWAY_GIT_TAG)?;
+    let plan = pkg::BuildPlan::load_from_manifest(&manifest, locked, offline, SWAY_GIT_TAG)?;
+    let typed_program = match pkg::check(&plan, silent_mode, SWAY_GIT_TAG)? {
+        CompileAstResult::Success { typed_program, .. } => typed_program,
+        CompileAstResult::Failure { warnings, errors } => {
+            print_on_failure(silent_mode, &warnings, &errors);
+            bail!("Failed to compile {}", manifest.project.name);
+        }
+    };
+
+    // Document the root module, followed by the submodules in the order they're declared.
+    let project_name = manifest.project.name.clone();
+    let root_name = vec![project_name.clone()];
+    let mut modules = vec![source_code_module(
+        root_name.clone(),
+        manifest.entry_path(),
+    )?];
+    collect_submodules(&typed_program.root, &root_name, &mut modules)?;
+    let documentation = Documenter::new(modules).generate_documentation()?;
+
+    let doc_dir = default_output_directory(manifest.dir()).join(DOC_DIRECTORY);
+    fs::create_dir_all(&doc_dir)?;
+    for file in documentation.to_html(&project_name) {
+        fs::write(doc_dir.join(&file.file_name), file.contents)?;
+    }
+    info!(
+        "  Documentation written to {}",
+        doc_dir.join("index.html").display()
+    );
+    Ok(doc_dir)
+}
+
+/// Collect the `dep` modules of the given module, each followed by its own submodules.
+fn collect_submodules(
+    module: &TypedModule,
+    name: &[String],
+    modules: &mut Vec<SourceCodeModule>,
+) -> Result<()> {
+    for (dep_name, submodule) in &module.submodules {
+        let mut submodule_name = name.to_vec();
+        submodule_name.push(dep_name.as_str().to_string());
+        let path = submodule
+            .library_name
+            .span()
+            .path()
+            .map(|path| path.to_path_buf())
+            .ok_or_else(|| anyhow!("unable to find the source of module `{}`", dep_name))?;
+        modules.push(source_code_module(submodule_name.clone(), path)?);
+        collect_submodules(&submodule.module, &submodule_name, modules)?;
+    }
+    Ok(())
+}
+
+fn source_code_module(name: Vec<String>, path: PathBuf) -> Result<SourceCodeModule> {
+    let source = fs::read_to_string(&path)
+        .map_err(|e| anyhow!("failed to read {}: {}", path.display(), e))?;
+    Ok(SourceCodeModule::new(name, source).with_path(path))
+}
@@ -1,15 +1,21 @@
-use crate::cli::InitCommand;
+use crate::cli::{BuildCommand, InitCommand};
+use crate::ops::{forc_build, forc_template};
 use crate::utils::{
     defaults,
     program_type::{ProgramType, ProgramType::*},
+    templates::{self, Placeholders, Template},
+    SWAY_GIT_TAG,
 };
-use anyhow::Result;
+use anyhow::{bail, Context, Result};
+use forc_pkg::find_dir_within;
 use forc_util::{println_green, validate_name};
 use serde::Deserialize;
 use std::fs;
-use std::path::Path;
+use std::path::{Path, PathBuf};
 use sway_utils::constants;
 use tracing::info;
+use url::Url;
+use walkdir::WalkDir;
 
 #[derive(Debug, Deserialize)]
 #[serde(rename_all = "lowercase")]
@@ -96,10 +102,25 @@ fn print_welcome_message() {
     );
 }
 
+/// Where the files of a template are found.
+enum TemplateSource {
+    Builtin(&'static Template),
+    Dir(PathBuf),
+}
+
 pub fn init(command: InitCommand) -> Result<()> {
     let project_name = command.project_name;
     validate_name(&project_name, "project name")?;
 
+    if command.workspace {
+        let source = TemplateSource::Builtin(&templates::WORKSPACE_TEMPLATE);
+        return init_from_template(&project_name, source, &command.license, command.offline);
+    }
+    if let Some(template) = command.template {
+        let source = resolve_template(&template, command.template_name.as_deref(), &project_name)?;
+        return init_from_template(&project_name, source, &command.license, command.offline);
+    }
+
     let program_type = match (
         command.contract,
         command.script,
@@ -116,10 +137,193 @@ pub fn init(command: InitCommand) -> Result<()> {
         ),
     };
 
-    init_new_project(project_name, program_type)
+    init_new_project(project_name, program_type, &command.license)
+}
+
+/// Find the template given with `--template`, fetching it first if it is a git repository.
+fn resolve_template(
+    template: &str,
+    template_name: Option<&str>,
+    project_name: &str,
+) -> Result<TemplateSource> {
+    if let Some(builtin) = templates::builtin_template(template) {
+        if template_name.is_some() {
+            bail!(
+                "`--template-name` cannot be used with the built-in template `{}`",
+                template
+            );
+        }
+        return Ok(TemplateSource::Builtin(builtin));
+    }
+    let path = Path::new(template);
+    if path.is_dir() {
+        let dir = match template_name {
+            Some(name) => find_dir_within(path, name, SWAY_GIT_TAG).with_context(|| {
+                format!("failed to find a template `{}` in {}", name, path.display())
+            })?,
+            None => path.to_path_buf(),
+        };
+        return Ok(TemplateSource::Dir(dir));
+    }
+    if Url::parse(template).is_ok() {
+        let dir = forc_template::fetch_template(template, template_name, project_name)?;
+        return Ok(TemplateSource::Dir(dir));
+    }
+    let builtin_names: Vec<_> = templates::BUILTIN_TEMPLATES
+        .iter()
+        .map(|builtin| format!("\n - {}: {}", builtin.name, builtin.description))
+        .collect();
+    bail!(
+        "`{}` is neither a built-in template, a directory nor a git repository URL. \
+        The built-in templates are:{}",
+        template,
+        builtin_names.concat()
+    )
+}
+
+/// Create a new project from the given template and check that each of its packages builds.
+fn init_from_template(
+    project_name: &str,
+    source: TemplateSource,
+    license: &str,
+    offline: bool,
+) -> Result<()> {
+    let project_dir = Path::new(project_name);
+    if project_dir.exists() {
+        bail!("`{}` already exists", project_dir.display());
+    }
+    let neat_name = project_name.split('/').next_back().unwrap().to_string();
+    let placeholders = Placeholders {
+        project_name: neat_name.clone(),
+        authors: defaults::get_author(),
+        license: license.to_string(),
+    };
+
+    // Don't leave a partially created project behind if the template fails to build.
+    let template_desc =
+        match create_from_template(project_dir, &neat_name, source, &placeholders, offline) {
+            Ok(template_desc) => template_desc,
+            Err(err) => {
+                let _ = fs::remove_dir_all(project_dir);
+                return Err(err);
+            }
+        };
+
+    println_green(&format!(
+        "Successfully created {project_name} from template `{template_desc}`",
+    ));
+
+    print_welcome_message();
+
+    Ok(())
 }
 
-pub(crate) fn init_new_project(project_name: String, program_type: ProgramType) -> Result<()> {
+/// Populate `project_dir` from the given template and build each of its packages, returning a
+/// description of the template.
+fn create_from_template(
+    project_dir: &Path,
+    neat_name: &str,
+    source: TemplateSource,
+    placeholders: &Placeholders,
+    offline: bool,
+) -> Result<String> {
+    let template_desc = match source {
+        TemplateSource::Builtin(template) => {
+            for file in template.files {
+                let path = project_dir.join(file.path);
+                if let Some(parent) = path.parent() {
+                    fs::create_dir_all(parent)?;
+                }
+                fs::write(path, placeholders.substitute(file.contents))?;
+            }
+            template.name.to_string()
+        }
+        TemplateSource::Dir(dir) => {
+            copy_template_dir(&dir, project_dir, placeholders)?;
+            dir.display().to_string()
+        }
+    };
+
+    // Like `forc init`, give a package at the root of the project a Rust test harness.
+    let has_root_package = project_dir.join(constants::MANIFEST_FILE_NAME).exists();
+    let has_tests_manifest = project_dir
+        .join(constants::TEST_MANIFEST_FILE_NAME)
+        .exists();
+    if has_root_package && !has_tests_manifest {
+        if !project_dir.join("tests").exists() {
+            fs::create_dir_all(project_dir.join("tests"))?;
+            fs::write(
+                project_dir.join("tests").join("harness.rs"),
+                defaults::default_test_program(neat_name),
+            )?;
+        }
+        fs::write(
+            project_dir.join(constants::TEST_MANIFEST_FILE_NAME),
+            defaults::default_tests_manifest(neat_name),
+        )?;
+    }
+
+    build_packages(project_dir, offline).with_context(|| {
+        format!(
+            "the project created from template `{}` failed to build",
+            template_desc
+        )
+    })?;
+
+    Ok(template_desc)
+}
+
+/// Copy the template within `from` to `to`, substituting placeholders within each text file.
+///
+/// Build output and git metadata are not copied.
+fn copy_template_dir(from: &Path, to: &Path, placeholders: &Placeholders) -> Result<()> {
+    let entries = WalkDir::new(from).into_iter().filter_entry(|entry| {
+        entry.depth() == 0 || !matches!(entry.file_name().to_str(), Some("out" | "target" | ".git"))
+    });
+    for entry in entries {
+        let entry = entry?;
+        let path = to.join(entry.path().strip_prefix(from)?);
+        if entry.file_type().is_dir() {
+            fs::create_dir_all(&path)?;
+            continue;
+        }
+        match String::from_utf8(fs::read(entry.path())?) {
+            Ok(contents) => fs::write(&path, placeholders.substitute(&contents))?,
+            Err(err) => fs::write(&path, err.into_bytes())?,
+        }
+    }
+    Ok(())
+}
+
+/// Build each package within `project_dir`, writing the artifacts to a temporary directory.
+fn build_packages(project_dir: &Path, offline: bool) -> Result<()> {
+    let mut pkg_dirs: Vec<_> = WalkDir::new(project_dir)
+        .into_iter()
+        .filter_map(|entry| entry.ok())
+        .filter(|entry| entry.file_name() == constants::MANIFEST_FILE_NAME)
+        .filter_map(|entry| entry.path().parent().map(Path::to_path_buf))
+        .collect();
+    pkg_dirs.sort();
+
+    let output_dir = tempfile::Builder::new().prefix("forc-init").tempdir()?;
+    for pkg_dir in pkg_dirs {
+        info!("  Building {}", pkg_dir.display());
+        let build_command = BuildCommand {
+            path: Some(pkg_dir.display().to_string()),
+            output_directory: Some(output_dir.path().display().to_string()),
+            offline_mode: offline,
+            ..Default::default()
+        };
+        forc_build::build(build_command)?;
+    }
+    Ok(())
+}
+
+pub(crate) fn init_new_project(
+    project_name: String,
+    program_type: ProgramType,
+    license: &str,
+) -> Result<()> {
     let neat_name: String = project_name.split('/').last().unwrap().to_string();
 
     // Make a new directory for the project
@@ -132,11 +336,11 @@ pub(crate) fn init_new_project(project_name: String, program_type: ProgramType)
     match program_type {
         Library => fs::write(
             Path::new(&project_name).join(constants::MANIFEST_FILE_NAME),
-            defaults::default_manifest(&neat_name, constants::LIB_ENTRY),
+            defaults::default_manifest(&neat_name, constants::LIB_ENTRY, license),
         )?,
         _ => fs::write(
             Path::new(&project_name).join(constants::MANIFEST_FILE_NAME),
-            defaults::default_manifest(&neat_name, constants::MAIN_ENTRY),
+            defaults::default_manifest(&neat_name, constants::MAIN_ENTRY, license),
         )?,
     }
 
@@ -194,3 +398,33 @@ pub(crate) fn init_new_project(project_name: String, program_type: ProgramType)
 
     Ok(())
 }
+
+#[test]
+fn init_from_failing_template() {
+    let dir = tempfile::tempdir().unwrap();
+    let template_dir = dir.path().join("template");
+    fs::create_dir_all(template_dir.join("src")).unwrap();
+    fs::write(
+        template_dir.join(constants::MANIFEST_FILE_NAME),
+        "[project]\n\
+        authors = [\"{{authors}}\"]\n\
+        entry = \"main.sw\"\n\
+        license = \"{{license}}\"\n\
+        name = \"{{project-name}}\"\n\
+        implicit-std = false\n",
+    )
+    .unwrap();
+    let main_path = template_dir.join("src").join("main.sw");
+    fs::write(&main_path, "script;\n\nfn main() -> u64 {\n    true\n}\n").unwrap();
+
+    // The project is removed when it fails to build.
+    let project_dir = dir.path().join("project");
+    let project_name = project_dir.display().to_string();
+    let source = || TemplateSource::Dir(template_dir.clone());
+    assert!(init_from_template(&project_name, source(), "Apache-2.0", true).is_err());
+    assert!(!project_dir.exists());
+
+    fs::write(&main_path, "script;\n\nfn main() -> u64 {\n    0\n}\n").unwrap();
+    init_from_template(&project_name, source(), "Apache-2.0", true).unwrap();
+    assert!(project_dir.join("src").join("main.sw").exists());
+}
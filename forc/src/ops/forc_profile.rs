@@ -0,0 +1,120 @@
+use crate::{
+    cli::ProfileCommand,
+    utils::{
+        gas_profile::GasProfile,
+        local_vm::{self, BuildOptions, Program},
+        SWAY_GAS_PROFILE_SUFFIX,
+    },
+};
+use anyhow::{anyhow, bail, Result};
+use forc_pkg::BuildProfile;
+use forc_util::default_output_directory;
+use fuel_tx::Receipt;
+use fuel_vm::{
+    prelude::*,
+    profiler::{ProfileReceiver, ProfilingData},
+};
+use std::{
+    fs,
+    path::PathBuf,
+    sync::{Arc, Mutex},
+};
+use sway_core::TreeType;
+use tracing::{info, warn};
+
+/// Collects the profiling data reported by the VM at the end of each transaction.
+#[derive(Clone, Default)]
+struct ProfilingOutput {
+    data: Arc<Mutex<Option<ProfilingData>>>,
+}
+
+impl ProfileReceiver for ProfilingOutput {
+    fn on_transaction(
+        &mut self,
+        _state: &Result<ProgramState, InterpreterError>,
+        data: &ProfilingData,
+    ) {
+        *self.data.lock().unwrap() = Some(data.clone());
+    }
+}
+
+pub fn profile(command: ProfileCommand) -> Result<GasProfile> {
+    let ProfileCommand {
+        path,
+        contracts,
+        data,
+        gas_limit,
+        top,
+        folded_outfile,
+        release,
+        offline_mode,
+        silent_mode,
+        locked,
+    } = command;
+
+    let this_dir = if let Some(ref path) = path {
+        PathBuf::from(path)
+    } else {
+        std::env::current_dir()?
+    };
+    let options = BuildOptions {
+        release,
+        offline_mode,
+        silent_mode,
+        locked,
+    };
+
+    let output = ProfilingOutput::default();
+    let mut vm = Interpreter::with_memory_storage().with_profiling(Box::new(output.clone()));
+    let mut programs = local_vm::deploy_contracts(&mut vm, &contracts, options)?;
+    let script = Program::build(&this_dir.display().to_string(), TreeType::Script, options)?;
+    let tx = local_vm::script_tx(&script, &data.unwrap_or_default(), &programs, gas_limit)?;
+
+    let receipts = vm
+        .transact(tx)
+        .map_err(|e| anyhow!("Failed to run script: {}", e))?
+        .receipts()
+        .to_vec();
+    for receipt in &receipts {
+        match receipt {
+            Receipt::ScriptResult { result, gas_used } => {
+                info!("  Script result: {:?}, gas used: {}", result, gas_used)
+            }
+            Receipt::Panic { reason, .. } => warn!("  Script panicked: {:?}", reason.reason()),
+            Receipt::Revert { ra, .. } => warn!("  Script reverted with code {}", ra),
+            _ => (),
+        }
+    }
+    let script_name = script.name.clone();
+    let script_dir = script.project_dir.clone();
+    programs.insert(None, script);
+
+    let data = match output.data.lock().unwrap().take() {
+        Some(data) => data,
+        None => bail!("The VM did not produce any profiling data"),
+    };
+    let gas_use = data.gas().iter().map(|(location, gas)| {
+        let ix = location.offset() as usize / Opcode::LEN;
+        (location.context(), ix, *gas)
+    });
+    let profile = GasProfile::new(gas_use, &programs);
+    info!("{}", profile.render_table(top));
+
+    let folded_path = match folded_outfile {
+        Some(outfile) => PathBuf::from(outfile),
+        None => {
+            let profile_name = if release {
+                BuildProfile::RELEASE
+            } else {
+                BuildProfile::DEBUG
+            };
+            let output_dir = default_output_directory(&script_dir).join(profile_name);
+            let stem = format!("{}{}", script_name, SWAY_GAS_PROFILE_SUFFIX);
+            output_dir.join(stem).with_extension("folded")
+        }
+    };
+    fs::write(&folded_path, profile.folded_stacks())?;
+    info!("  Folded stacks written to {}", folded_path.display());
+
+    Ok(profile)
+}
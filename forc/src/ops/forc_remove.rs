@@ -0,0 +1,84 @@
+use crate::{
+    cli::RemoveCommand,
+    utils::manifest_edit::{edit_manifest, DEPENDENCIES_TABLE},
+};
+use anyhow::{bail, Result};
+use tracing::info;
+
+/// Remove the named dependency from the project's `[dependencies]` table and update the lock file
+/// accordingly.
+pub fn remove(command: RemoveCommand) -> Result<()> {
+    let RemoveCommand {
+        name,
+        manifest_path,
+        offline,
+    } = command;
+
+    edit_manifest(manifest_path, offline, |doc| {
+        let removed = doc
+            .get_mut(DEPENDENCIES_TABLE)
+            .and_then(|deps| deps.as_table_like_mut())
+            .and_then(|deps| deps.remove(&name));
+        if removed.is_none() {
+            bail!(
+                "the dependency `{}` could not be found in the manifest",
+                name
+            );
+        }
+        Ok(())
+    })?;
+
+    info!("    Removed dependency `{}`", name);
+    Ok(())
+}
+
+#[test]
+fn remove_dependency() {
+    use crate::utils::manifest_edit::{test_project, TEST_MANIFEST};
+    let dir = tempfile::tempdir().unwrap();
+    let app_dir = test_project(dir.path());
+    let manifest_path = app_dir.join("Forc.toml");
+    std::fs::write(
+        &manifest_path,
+        format!("{}lib = {{ path = \"../lib\" }}\n", TEST_MANIFEST),
+    )
+    .unwrap();
+    let lock_path = app_dir.join("Forc.lock");
+    edit_manifest(
+        Some(app_dir.to_string_lossy().to_string()),
+        true,
+        |_| Ok(()),
+    )
+    .unwrap();
+    let lock = std::fs::read_to_string(&lock_path).unwrap();
+    assert!(lock.contains("name = 'lib'"));
+
+    remove(RemoveCommand {
+        name: "lib".to_string(),
+        manifest_path: Some(app_dir.to_string_lossy().to_string()),
+        offline: true,
+    })
+    .unwrap();
+
+    // Only the dependency's entry is removed, leaving the rest of the manifest as it was.
+    let manifest = std::fs::read_to_string(&manifest_path).unwrap();
+    assert_eq!(manifest, TEST_MANIFEST);
+    let lock = std::fs::read_to_string(&lock_path).unwrap();
+    assert!(!lock.contains("name = 'lib'"));
+}
+
+#[test]
+fn remove_missing_dependency() {
+    use crate::utils::manifest_edit::{test_project, TEST_MANIFEST};
+    let dir = tempfile::tempdir().unwrap();
+    let app_dir = test_project(dir.path());
+    let err = remove(RemoveCommand {
+        name: "lib".to_string(),
+        manifest_path: Some(app_dir.to_string_lossy().to_string()),
+        offline: true,
+    })
+    .unwrap_err();
+    assert!(err.to_string().contains("`lib` could not be found"));
+    let manifest = std::fs::read_to_string(app_dir.join("Forc.toml")).unwrap();
+    assert_eq!(manifest, TEST_MANIFEST);
+}
@@ -42,6 +42,9 @@ pub async fn run(command: RunCommand) -> Result<Vec<fuel_tx::Receipt>> {
         build_profile: None,
         release: false,
         time_phases: command.time_phases,
+        include_debug_info: false,
+        size_report: false,
+        salt: None,
     };
 
     let compiled = forc_build::build(build_command)?;
@@ -113,7 +116,7 @@ async fn send_tx(
     }
 }
 
-fn create_tx_with_script_and_data(
+pub(crate) fn create_tx_with_script_and_data(
     script: Vec<u8>,
     script_data: Vec<u8>,
     inputs: Vec<fuel_tx::Input>,
@@ -140,7 +143,7 @@ fn create_tx_with_script_and_data(
 }
 
 // cut '0x' from the start
-fn format_hex_data(data: &str) -> &str {
+pub(crate) fn format_hex_data(data: &str) -> &str {
     data.strip_prefix("0x").unwrap_or(data)
 }
 
@@ -162,7 +165,7 @@ fn construct_output_from_contract((idx, _contract): (usize, &String)) -> fuel_tx
 }
 
 /// Given some contracts, constructs the most basic input and output set that satisfies validation.
-fn get_tx_inputs_and_outputs(
+pub(crate) fn get_tx_inputs_and_outputs(
     contracts: Vec<String>,
 ) -> (Vec<fuel_tx::Input>, Vec<fuel_tx::Output>) {
     let inputs = contracts
@@ -16,14 +16,59 @@ use url::Url;
 
 pub fn init(command: TemplateCommand) -> Result<()> {
     validate_name(&command.project_name, "project name")?;
+    let from_path = fetch_template(
+        &command.url,
+        command.template_name.as_deref(),
+        &command.project_name,
+    )?;
+
+    let current_dir = &env::current_dir()?;
+
+    // Create the target dir
+    let target_dir = current_dir.join(&command.project_name);
+
+    info!("Creating {} from template", &command.project_name);
+    // Copy contents from template to target dir
+    copy_template_to_target(&from_path, &target_dir)?;
+
+    // Edit forc.toml
+    edit_forc_toml(&target_dir, &command.project_name, &whoami::realname())?;
+    if target_dir.join("test").exists() {
+        edit_cargo_toml(&target_dir, &command.project_name, &whoami::realname())?;
+    } else {
+        // Create the tests directory, harness.rs and Cargo.toml file
+        fs::create_dir_all(target_dir.join("tests"))?;
+
+        fs::write(
+            target_dir.join("tests").join("harness.rs"),
+            defaults::default_test_program(&command.project_name),
+        )?;
+
+        fs::write(
+            target_dir.join("Cargo.toml"),
+            defaults::default_tests_manifest(&command.project_name),
+        )?;
+    }
+    Ok(())
+}
+
+/// Fetch the git repository at `url` and return the path to the template within it.
+///
+/// With a `template_name`, the template is the package of that name within the repository.
+/// Otherwise, the root of the repository must be a package.
+pub(crate) fn fetch_template(
+    url: &str,
+    template_name: Option<&str>,
+    project_name: &str,
+) -> Result<PathBuf> {
     // The name used for the temporary local repo directory used for fetching the template.
-    let local_repo_name = match command.template_name.clone() {
-        Some(temp_name) => temp_name,
-        None => format!("{}-template-source", command.project_name),
+    let local_repo_name = match template_name {
+        Some(temp_name) => temp_name.to_string(),
+        None => format!("{}-template-source", project_name),
     };
 
     let source = SourceGit {
-        repo: Url::parse(&command.url)?,
+        repo: Url::parse(url)?,
         reference: forc_pkg::GitReference::DefaultBranch,
     };
 
@@ -45,50 +90,17 @@ pub fn init(command: TemplateCommand) -> Result<()> {
         fetch_git(fetch_id, &local_repo_name, &git_source)?;
     }
 
-    let from_path = match command.template_name {
-        Some(ref template_name) => find_dir_within(&repo_path, template_name, SWAY_GIT_TAG)
-            .ok_or_else(|| {
-                anyhow!(
-                    "failed to find a template `{}` in {}",
-                    template_name,
-                    command.url
-                )
-            })?,
+    match template_name {
+        Some(template_name) => find_dir_within(&repo_path, template_name, SWAY_GIT_TAG)
+            .ok_or_else(|| anyhow!("failed to find a template `{}` in {}", template_name, url)),
         None => {
             let manifest_path = repo_path.join(constants::MANIFEST_FILE_NAME);
             if Manifest::from_file(&manifest_path, SWAY_GIT_TAG).is_err() {
-                anyhow::bail!("failed to find a template in {}", command.url);
+                anyhow::bail!("failed to find a template in {}", url);
             }
-            repo_path
+            Ok(repo_path)
         }
-    };
-
-    // Create the target dir
-    let target_dir = current_dir.join(&command.project_name);
-
-    info!("Creating {} from template", &command.project_name);
-    // Copy contents from template to target dir
-    copy_template_to_target(&from_path, &target_dir)?;
-
-    // Edit forc.toml
-    edit_forc_toml(&target_dir, &command.project_name, &whoami::realname())?;
-    if target_dir.join("test").exists() {
-        edit_cargo_toml(&target_dir, &command.project_name, &whoami::realname())?;
-    } else {
-        // Create the tests directory, harness.rs and Cargo.toml file
-        fs::create_dir_all(target_dir.join("tests"))?;
-
-        fs::write(
-            target_dir.join("tests").join("harness.rs"),
-            defaults::default_test_program(&command.project_name),
-        )?;
-
-        fs::write(
-            target_dir.join("Cargo.toml"),
-            defaults::default_tests_manifest(&command.project_name),
-        )?;
     }
-    Ok(())
 }
 
 fn edit_forc_toml(out_dir: &Path, project_name: &str, real_name: &str) -> Result<()> {
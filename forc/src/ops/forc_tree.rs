@@ -0,0 +1,243 @@
+use crate::{cli::TreeCommand, utils::SWAY_GIT_TAG};
+use anyhow::{anyhow, bail, Result};
+use forc_pkg::{self as pkg, ManifestFile, NodeIx, PathMap, SourcePinned};
+use petgraph::{visit::EdgeRef, Direction};
+use std::{
+    collections::{BTreeMap, HashSet},
+    path::PathBuf,
+};
+use tracing::info;
+
+/// Print the project's resolved dependency graph.
+///
+/// By default the tree is printed from the project root downwards. Use `--invert <package>` to
+/// print the packages that depend on the given package, or `--duplicates` to print the inverted
+/// tree for each package that is pinned to more than one source.
+pub fn tree(command: TreeCommand) -> Result<()> {
+    let TreeCommand {
+        path,
+        invert,
+        duplicates,
+        locked,
+        offline,
+    } = command;
+
+    let this_dir = match path {
+        Some(path) => PathBuf::from(path),
+        None => std::env::current_dir()?,
+    };
+    let manifest = ManifestFile::from_dir(&this_dir, SWAY_GIT_TAG)?;
+    let plan = pkg::BuildPlan::load_from_manifest(&manifest, locked, offline, SWAY_GIT_TAG)?;
+    let proj_node = *plan
+        .compilation_order()
+        .last()
+        .ok_or_else(|| anyhow!("Invalid Graph"))?;
+    let lines = tree_lines(plan.graph(), plan.path_map(), proj_node, invert, duplicates)?;
+    if duplicates && lines.is_empty() {
+        info!("No duplicate packages found");
+    }
+    for line in lines {
+        info!("{}", line);
+    }
+
+    Ok(())
+}
+
+/// The lines of the trees selected by `invert` and `duplicates`, or of the tree of dependencies of
+/// `proj_node` if neither is given.
+fn tree_lines(
+    graph: &pkg::Graph,
+    path_map: &PathMap,
+    proj_node: NodeIx,
+    invert: Option<String>,
+    duplicates: bool,
+) -> Result<Vec<String>> {
+    let lines = if duplicates {
+        let mut by_name: BTreeMap<&str, Vec<NodeIx>> = BTreeMap::new();
+        for node in graph.node_indices() {
+            by_name.entry(&graph[node].name).or_default().push(node);
+        }
+        let dups: Vec<_> = by_name
+            .into_values()
+            .filter(|nodes| nodes.len() > 1)
+            .flatten()
+            .collect();
+        trees(graph, path_map, &dups, Direction::Incoming)
+    } else if let Some(name) = invert {
+        let nodes: Vec<_> = graph
+            .node_indices()
+            .filter(|&node| graph[node].name == name)
+            .collect();
+        if nodes.is_empty() {
+            bail!(
+                "package `{}` could not be found in the dependency graph",
+                name
+            );
+        }
+        trees(graph, path_map, &nodes, Direction::Incoming)
+    } else {
+        trees(graph, path_map, &[proj_node], Direction::Outgoing)
+    };
+    Ok(lines)
+}
+
+/// The lines of a tree for each of the given root nodes, separated by an empty line.
+fn trees(
+    graph: &pkg::Graph,
+    path_map: &PathMap,
+    roots: &[NodeIx],
+    direction: Direction,
+) -> Vec<String> {
+    let mut lines = vec![];
+    for (i, &root) in roots.iter().enumerate() {
+        if i > 0 {
+            lines.push(String::new());
+        }
+        lines.push(node_label(graph, path_map, root));
+        let mut visited = HashSet::new();
+        visited.insert(root);
+        push_children(
+            graph,
+            path_map,
+            root,
+            direction,
+            "",
+            &mut visited,
+            &mut lines,
+        );
+    }
+    lines
+}
+
+/// Recursively push the lines of the neighbours of `node` in the given `direction`.
+fn push_children(
+    graph: &pkg::Graph,
+    path_map: &PathMap,
+    node: NodeIx,
+    direction: Direction,
+    prefix: &str,
+    visited: &mut HashSet<NodeIx>,
+    lines: &mut Vec<String>,
+) {
+    let mut children: Vec<_> = graph
+        .edges_directed(node, direction)
+        .map(|edge| match direction {
+            Direction::Outgoing => edge.target(),
+            Direction::Incoming => edge.source(),
+        })
+        .collect();
+    children.sort_by(|&a, &b| graph[a].name.cmp(&graph[b].name));
+    children.dedup();
+
+    let last = children.len().saturating_sub(1);
+    for (i, child) in children.into_iter().enumerate() {
+        let (branch, indent) = if i == last {
+            ("└── ", "    ")
+        } else {
+            ("├── ", "│   ")
+        };
+        let label = node_label(graph, path_map, child);
+        if visited.insert(child) {
+            lines.push(format!("{}{}{}", prefix, branch, label));
+            let prefix = format!("{}{}", prefix, indent);
+            push_children(graph, path_map, child, direction, &prefix, visited, lines);
+        } else {
+            lines.push(format!("{}{}{} (*)", prefix, branch, label));
+        }
+    }
+}
+
+/// The package name along with a description of its pinned source.
+///
+/// Git packages show their repository, reference and pinned commit, while root and path packages
+/// show the local directory in which they are found.
+fn node_label(graph: &pkg::Graph, path_map: &PathMap, node: NodeIx) -> String {
+    let pinned = &graph[node];
+    let source = match &pinned.source {
+        SourcePinned::Git(git) => git.to_string(),
+        SourcePinned::Root | SourcePinned::Path(_) => path_map[&pinned.id()].display().to_string(),
+        SourcePinned::Registry(reg) => format!("registry+{}", reg.version),
+    };
+    format!("{} ({})", pinned.name, source)
+}
+
+#[test]
+fn dependency_trees() {
+    use pkg::{Pinned, SourceGitPinned, SourcePathPinned};
+    use std::str::FromStr;
+
+    // `app` depends on `a` and `b`, which depend on different tags of `utils`.
+    let mut graph = pkg::Graph::new();
+    let mut path_map = PathMap::new();
+    let mut add = |name: &str, source: SourcePinned| {
+        let pinned = Pinned {
+            name: name.to_string(),
+            source,
+        };
+        if !matches!(pinned.source, SourcePinned::Git(_)) {
+            path_map.insert(pinned.id(), PathBuf::from(format!("/{}", name)));
+        }
+        graph.add_node(pinned)
+    };
+    let path_root = pkg::PinnedId::new("app", &SourcePinned::Root);
+    let path = || SourcePinned::Path(SourcePathPinned { path_root });
+    let utils_v1 =
+        "git+https://github.com/org/utils?tag=v1#0101010101010101010101010101010101010101";
+    let utils_v2 =
+        "git+https://github.com/org/utils?tag=v2#0202020202020202020202020202020202020202";
+    let git = |s| SourcePinned::Git(SourceGitPinned::from_str(s).unwrap());
+    let app = add("app", SourcePinned::Root);
+    let a = add("a", path());
+    let b = add("b", path());
+    let utils_1 = add("utils", git(utils_v1));
+    let utils_2 = add("utils", git(utils_v2));
+    for (from, to, name) in [
+        (app, a, "a"),
+        (app, b, "b"),
+        (a, utils_1, "utils"),
+        (b, utils_2, "utils"),
+    ] {
+        graph.add_edge(from, to, name.to_string());
+    }
+
+    let lines = |invert: Option<&str>, duplicates| {
+        tree_lines(
+            &graph,
+            &path_map,
+            app,
+            invert.map(str::to_string),
+            duplicates,
+        )
+        .map(|lines| lines.join("\n"))
+    };
+    assert_eq!(
+        lines(None, false).unwrap(),
+        format!(
+            "app (/app)\n\
+             ├── a (/a)\n\
+             │   └── utils ({})\n\
+             └── b (/b)\n    \
+                 └── utils ({})",
+            utils_v1, utils_v2
+        )
+    );
+    let inverted = format!(
+        "utils ({})\n\
+         └── a (/a)\n    \
+             └── app (/app)\n\
+         \n\
+         utils ({})\n\
+         └── b (/b)\n    \
+             └── app (/app)",
+        utils_v1, utils_v2
+    );
+    assert_eq!(lines(Some("utils"), false).unwrap(), inverted);
+    assert_eq!(lines(None, true).unwrap(), inverted);
+    assert!(lines(Some("missing"), false).is_err());
+
+    // Without duplicates, there is nothing to print.
+    graph.remove_node(utils_2);
+    assert!(tree_lines(&graph, &path_map, app, None, true)
+        .unwrap()
+        .is_empty());
+}
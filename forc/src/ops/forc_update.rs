@@ -17,14 +17,14 @@ use tracing::info;
 /// performed across all dependencies without actually committing them to the lock file.
 ///
 /// Use the `--package <package-name>` flag to update only a specific package throughout the
-/// dependency graph.
+/// dependency graph. All other packages remain pinned to the versions recorded in the existing
+/// `Forc.lock`.
 pub async fn update(command: UpdateCommand) -> Result<()> {
     let UpdateCommand {
         path,
         check,
-        // TODO: Use `package` here rather than `target_dependency`
-        target_dependency: _,
-        ..
+        package,
+        offline,
     } = command;
 
     let this_dir = match path {
@@ -35,9 +35,20 @@ pub async fn update(command: UpdateCommand) -> Result<()> {
     let manifest = ManifestFile::from_dir(&this_dir, SWAY_GIT_TAG)?;
     let lock_path = lock_path(manifest.dir());
     let old_lock = Lock::from_path(&lock_path).ok().unwrap_or_default();
-    let offline = false;
-    let new_plan = pkg::BuildPlan::new(&manifest, SWAY_GIT_TAG, offline)?;
-    let new_lock = Lock::from_graph(new_plan.graph());
+    let new_plan = match package {
+        Some(package) => {
+            let old_plan = pkg::BuildPlan::from_lock_file(&lock_path, false, SWAY_GIT_TAG)
+                .map_err(|e| {
+                    anyhow!(
+                        "a valid lock file is required to update a single package: {}",
+                        e
+                    )
+                })?;
+            old_plan.update_package(&manifest, &package, SWAY_GIT_TAG, offline)?
+        }
+        None => pkg::BuildPlan::new(&manifest, SWAY_GIT_TAG, offline)?,
+    };
+    let new_lock = Lock::from_plan(&new_plan)?;
     let diff = new_lock.diff(&old_lock);
     lock::print_diff(&manifest.project.name, &diff);
 
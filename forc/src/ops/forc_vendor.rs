@@ -0,0 +1,33 @@
+use crate::{cli::VendorCommand, utils::SWAY_GIT_TAG};
+use anyhow::Result;
+use forc_pkg::{self as pkg, ManifestFile};
+use std::path::PathBuf;
+use tracing::info;
+
+/// Vendor the project's git dependencies, along with any path dependencies within their
+/// repositories, into the project's `vendor` directory.
+pub fn vendor(command: VendorCommand) -> Result<()> {
+    let VendorCommand {
+        path,
+        locked,
+        offline,
+    } = command;
+
+    let this_dir = match path {
+        Some(path) => PathBuf::from(path),
+        None => std::env::current_dir()?,
+    };
+    let manifest = ManifestFile::from_dir(&this_dir, SWAY_GIT_TAG)?;
+    let plan = pkg::BuildPlan::load_from_manifest(&manifest, locked, offline, SWAY_GIT_TAG)?;
+    let vendored = pkg::vendor(&plan)?;
+    for (pinned, path) in &vendored {
+        let rel_path = path.strip_prefix(manifest.dir()).unwrap_or(path);
+        info!("   Vendored {} to {}", pinned.name, rel_path.display());
+    }
+    info!(
+        "  Vendored {} packages to {}",
+        vendored.len(),
+        manifest.dir().join(pkg::VENDOR_DIR_NAME).display()
+    );
+    Ok(())
+}
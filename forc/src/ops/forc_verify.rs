@@ -0,0 +1,172 @@
+use crate::{
+    cli::{BuildCommand, VerifyCommand},
+    ops::forc_build,
+};
+use anyhow::{bail, Result};
+use forc_pkg as pkg;
+use fuel_tx::{ContractId, Salt};
+use std::{fs, path::Path, str::FromStr};
+use sway_core::TreeType;
+use tracing::info;
+
+/// What the rebuilt project is compared against.
+enum Target {
+    ContractId(ContractId),
+    Bytecode { path: String, bytecode: Vec<u8> },
+}
+
+/// Rebuild the project and check that the result matches the given contract ID or bytecode.
+pub fn verify(command: VerifyCommand) -> Result<()> {
+    let VerifyCommand {
+        target,
+        path,
+        build_profile,
+        release,
+        locked,
+        offline,
+        salt,
+    } = command;
+    let salt = salt.unwrap_or_else(Salt::zeroed);
+
+    let target = parse_target(&target)?;
+
+    // Build into a temporary directory to leave any existing artifacts untouched.
+    let output_dir = tempfile::Builder::new().prefix("forc-verify").tempdir()?;
+    let build_command = BuildCommand {
+        path,
+        output_directory: Some(output_dir.path().display().to_string()),
+        locked,
+        offline_mode: offline,
+        build_profile,
+        release,
+        salt: Some(salt),
+        ..Default::default()
+    };
+    let compiled = forc_build::build(build_command)?;
+
+    match target {
+        Target::ContractId(expected_id) => {
+            if !matches!(compiled.tree_type, TreeType::Contract) {
+                bail!("only contracts have a contract ID; verify the bytecode file instead");
+            }
+            let id = pkg::contract_id(&compiled.bytecode, &compiled.storage_slots, &salt);
+            if id != expected_id {
+                bail!(
+                    "the rebuilt contract ID 0x{} does not match 0x{}",
+                    hex::encode(id),
+                    hex::encode(expected_id),
+                );
+            }
+            info!("  Verified contract 0x{}", hex::encode(id));
+        }
+        Target::Bytecode { path, bytecode } => {
+            if let Some(offset) = first_difference(&compiled.bytecode, &bytecode) {
+                bail!(
+                    "the rebuilt bytecode ({} bytes) does not match `{}` ({} bytes): \
+                    the first difference is at byte {}",
+                    compiled.bytecode.len(),
+                    path,
+                    bytecode.len(),
+                    offset,
+                );
+            }
+            info!("  Verified bytecode `{}`", path);
+        }
+    }
+    Ok(())
+}
+
+/// Parse the target as a path to an existing bytecode file, or otherwise as a contract ID.
+fn parse_target(target: &str) -> Result<Target> {
+    if Path::new(target).is_file() {
+        let bytecode = fs::read(target)?;
+        let path = target.to_string();
+        return Ok(Target::Bytecode { path, bytecode });
+    }
+    match ContractId::from_str(target) {
+        Ok(id) => Ok(Target::ContractId(id)),
+        Err(_) => bail!(
+            "expected a contract ID or the path to a bytecode file, found `{}`",
+            target
+        ),
+    }
+}
+
+/// The offset of the first byte at which `a` and `b` differ, if any.
+fn first_difference(a: &[u8], b: &[u8]) -> Option<usize> {
+    match a.iter().zip(b).position(|(a, b)| a != b) {
+        Some(offset) => Some(offset),
+        None if a.len() != b.len() => Some(a.len().min(b.len())),
+        None => None,
+    }
+}
+
+#[cfg(test)]
+fn verify_command(target: String, proj_dir: &Path, salt: Option<Salt>) -> VerifyCommand {
+    VerifyCommand {
+        target,
+        path: Some(proj_dir.display().to_string()),
+        build_profile: None,
+        release: false,
+        locked: false,
+        offline: false,
+        salt,
+    }
+}
+
+#[test]
+fn verify_bytecode() {
+    use crate::utils::build_info::test_contract;
+    let dir = tempfile::tempdir().unwrap();
+    let proj_dir = test_contract(dir.path());
+    let compiled = forc_build::build(BuildCommand {
+        path: Some(proj_dir.display().to_string()),
+        output_directory: Some(dir.path().join("out").display().to_string()),
+        silent_mode: true,
+        ..Default::default()
+    })
+    .unwrap();
+
+    let bin_path = dir.path().join("counter.bin");
+    fs::write(&bin_path, &compiled.bytecode).unwrap();
+    let target = bin_path.display().to_string();
+    verify(verify_command(target.clone(), &proj_dir, None)).unwrap();
+
+    // A single differing byte is reported.
+    let mut bytecode = compiled.bytecode.clone();
+    let last = bytecode.len() - 1;
+    bytecode[last] ^= 0xff;
+    fs::write(&bin_path, &bytecode).unwrap();
+    let err = verify(verify_command(target.clone(), &proj_dir, None)).unwrap_err();
+    assert!(err
+        .to_string()
+        .contains(&format!("the first difference is at byte {}", last)));
+
+    // As is truncated bytecode.
+    fs::write(&bin_path, &compiled.bytecode[..last]).unwrap();
+    let err = verify(verify_command(target, &proj_dir, None)).unwrap_err();
+    assert!(err.to_string().contains("does not match"));
+}
+
+#[test]
+fn verify_contract_id() {
+    use crate::utils::build_info::test_contract;
+    let dir = tempfile::tempdir().unwrap();
+    let proj_dir = test_contract(dir.path());
+    let compiled = forc_build::build(BuildCommand {
+        path: Some(proj_dir.display().to_string()),
+        output_directory: Some(dir.path().join("out").display().to_string()),
+        silent_mode: true,
+        ..Default::default()
+    })
+    .unwrap();
+
+    let salt = Salt::from([1u8; 32]);
+    let id = pkg::contract_id(&compiled.bytecode, &compiled.storage_slots, &salt);
+    let target = format!("0x{}", hex::encode(id));
+    verify(verify_command(target.clone(), &proj_dir, Some(salt))).unwrap();
+
+    // The contract ID depends on the salt it was deployed with.
+    let err = verify(verify_command(target, &proj_dir, None)).unwrap_err();
+    assert!(err.to_string().contains("does not match"));
+}
@@ -1,9 +1,17 @@
 pub mod forc_abi_json;
+pub mod forc_add;
 pub mod forc_build;
 pub mod forc_check;
 pub mod forc_clean;
+pub mod forc_debug;
 pub mod forc_deploy;
+pub mod forc_doc;
 pub mod forc_init;
+pub mod forc_profile;
+pub mod forc_remove;
 pub mod forc_run;
 pub mod forc_template;
+pub mod forc_tree;
 pub mod forc_update;
+pub mod forc_vendor;
+pub mod forc_verify;
@@ -0,0 +1,205 @@
+use anyhow::Result;
+use forc_pkg::{self as pkg, BuildProfile, ManifestFile};
+use fuel_crypto::Hasher;
+use fuel_tx::Salt;
+use serde::{Deserialize, Serialize};
+use std::{fs, path::Path};
+use sway_core::TreeType;
+use sway_utils::constants::{LOCK_FILE_NAME, MANIFEST_FILE_NAME, SRC_DIR};
+
+/// The name of the file written alongside the build artifacts that records how they were produced.
+pub const BUILD_INFO_FILE_NAME: &str = "build-info.json";
+
+/// Everything that went into producing a set of build artifacts.
+///
+/// The build info contains no timestamps or absolute paths, so that building the same sources with
+/// the same compiler and profile produces the same file on any machine.
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub struct BuildInfo {
+    pub project_name: String,
+    pub program_type: String,
+    pub compiler_version: String,
+    pub build_profile_name: String,
+    pub build_profile: BuildProfile,
+    /// The SHA-256 hash of the `Forc.lock` used to resolve dependencies.
+    pub lock_file_hash: Option<String>,
+    /// Every package that was compiled, in compilation order.
+    pub sources: Vec<SourceInfo>,
+    /// The SHA-256 hash of the bytecode.
+    pub bytecode_hash: String,
+    /// The salt used to compute the contract ID.
+    pub salt: Option<String>,
+    /// The ID of the contract when deployed with the recorded salt.
+    pub contract_id: Option<String>,
+    /// The address of the predicate.
+    pub predicate_address: Option<String>,
+}
+
+/// Identifies the source of a single compiled package.
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub struct SourceInfo {
+    pub name: String,
+    /// The pinned source, as recorded in `Forc.lock`.
+    pub source: String,
+    /// The SHA-256 hash of the package's manifest and `src` directory.
+    pub source_hash: String,
+}
+
+impl BuildInfo {
+    pub fn new(
+        manifest: &ManifestFile,
+        plan: &pkg::BuildPlan,
+        build_profile_name: &str,
+        build_profile: &BuildProfile,
+        compiled: &pkg::Compiled,
+        salt: &Salt,
+    ) -> Result<Self> {
+        let lock_path = manifest.dir().join(LOCK_FILE_NAME);
+        let lock_file_hash = match fs::read(&lock_path) {
+            Ok(lock_bytes) => Some(format!("0x{}", Hasher::hash(lock_bytes))),
+            Err(_) => None,
+        };
+        let sources = plan
+            .compilation_order()
+            .iter()
+            .map(|&node| {
+                let pinned = &plan.graph()[node];
+                let pkg_dir = &plan.path_map()[&pinned.id()];
+                Ok(SourceInfo {
+                    name: pinned.name.clone(),
+                    source: pinned.source.to_string(),
+                    source_hash: package_source_hash(pkg_dir)?,
+                })
+            })
+            .collect::<Result<_>>()?;
+        let (salt, contract_id, predicate_address) = match compiled.tree_type {
+            TreeType::Contract => {
+                let id = pkg::contract_id(&compiled.bytecode, &compiled.storage_slots, salt);
+                let salt = Some(format!("0x{}", salt));
+                (salt, Some(format!("0x{}", hex::encode(id))), None)
+            }
+            TreeType::Predicate => {
+                let address = pkg::predicate_address(&compiled.bytecode);
+                (None, None, Some(format!("0x{}", address)))
+            }
+            _ => (None, None, None),
+        };
+        Ok(Self {
+            project_name: manifest.project.name.clone(),
+            program_type: program_type_str(&compiled.tree_type).to_string(),
+            compiler_version: clap::crate_version!().to_string(),
+            build_profile_name: build_profile_name.to_string(),
+            // Only the settings that can affect the artifacts are recorded.
+            build_profile: BuildProfile {
+                print_ir: false,
+                print_finalized_asm: false,
+                print_intermediate_asm: false,
+                silent: false,
+                time_phases: false,
+                ..build_profile.clone()
+            },
+            lock_file_hash,
+            sources,
+            bytecode_hash: format!("0x{}", Hasher::hash(&compiled.bytecode)),
+            salt,
+            contract_id,
+            predicate_address,
+        })
+    }
+}
+
+/// Hash the manifest and `src` directory of the package within `pkg_dir`.
+///
+/// Build output is excluded so that the hash is the same before and after building.
+fn package_source_hash(pkg_dir: &Path) -> Result<String> {
+    let mut hasher = Hasher::default();
+    hasher.input(fs::read(pkg_dir.join(MANIFEST_FILE_NAME))?);
+    hasher.input(pkg::source_checksum(&pkg_dir.join(SRC_DIR))?);
+    Ok(format!("0x{}", hasher.finalize()))
+}
+
+fn program_type_str(tree_type: &TreeType) -> &'static str {
+    match tree_type {
+        TreeType::Contract => "contract",
+        TreeType::Script => "script",
+        TreeType::Predicate => "predicate",
+        TreeType::Library { .. } => "library",
+    }
+}
+
+/// Create a contract project named `counter` within `dir`, returning the project directory.
+#[cfg(test)]
+pub(crate) fn test_contract(dir: &Path) -> std::path::PathBuf {
+    let proj_dir = dir.join("counter");
+    fs::create_dir_all(proj_dir.join(SRC_DIR)).unwrap();
+    fs::write(
+        proj_dir.join(MANIFEST_FILE_NAME),
+        r#"[project]
+authors = ["Fuel Labs <contact@fuel.sh>"]
+entry = "main.sw"
+license = "Apache-2.0"
+name = "counter"
+implicit-std = false
+"#,
+    )
+    .unwrap();
+    fs::write(
+        proj_dir.join(SRC_DIR).join("main.sw"),
+        r#"contract;
+
+abi Counter {
+    fn count() -> u64;
+}
+
+impl Counter for Contract {
+    fn count() -> u64 {
+        1
+    }
+}
+"#,
+    )
+    .unwrap();
+    proj_dir
+}
+
+#[test]
+fn build_info_is_reproducible() {
+    use crate::{cli::BuildCommand, ops::forc_build};
+    let dir = tempfile::tempdir().unwrap();
+    let build_info = |proj_dir: &Path, output_dir: &Path| {
+        forc_build::build(BuildCommand {
+            path: Some(proj_dir.display().to_string()),
+            output_directory: Some(output_dir.display().to_string()),
+            silent_mode: true,
+            ..Default::default()
+        })
+        .unwrap();
+        fs::read_to_string(output_dir.join(BUILD_INFO_FILE_NAME)).unwrap()
+    };
+
+    // Building the same project twice produces the same build info.
+    let proj_dir = test_contract(dir.path());
+    let first = build_info(&proj_dir, &dir.path().join("out-1"));
+    let second = build_info(&proj_dir, &dir.path().join("out-2"));
+    assert_eq!(first, second);
+
+    // As does building a copy of the project at another location.
+    let other_dir = dir.path().join("other");
+    let other_proj_dir = test_contract(&other_dir);
+    fs::copy(
+        proj_dir.join(LOCK_FILE_NAME),
+        other_proj_dir.join(LOCK_FILE_NAME),
+    )
+    .unwrap();
+    let other = build_info(&other_proj_dir, &dir.path().join("out-3"));
+    assert_eq!(first, other);
+
+    let info: BuildInfo = serde_json::from_str(&first).unwrap();
+    assert_eq!(info.project_name, "counter");
+    assert_eq!(info.program_type, "contract");
+    assert_eq!(info.sources.len(), 1);
+    assert!(info.lock_file_hash.is_some());
+    assert!(info.contract_id.is_some());
+}
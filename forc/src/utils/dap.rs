@@ -0,0 +1,390 @@
+//! A minimal server for the Debug Adapter Protocol, allowing editors to drive a [Debugger].
+//!
+//! Only a single thread and a single stack frame are reported, as all functions are inlined into
+//! the entry points of the program. The VM's registers are reported as variables.
+
+use crate::utils::debugger::{Debugger, Stop, REGISTER_NAMES};
+use anyhow::{anyhow, bail, Result};
+use fuel_vm::prelude::*;
+use serde_json::{json, Value};
+use std::{
+    collections::HashMap,
+    io::{BufRead, Write},
+};
+
+/// The ID of the only thread reported to the client.
+const THREAD_ID: u64 = 1;
+
+/// The variables reference of the registers scope.
+const REGISTERS_REF: u64 = 1;
+
+/// Serve requests from the client until it disconnects.
+pub fn serve(debugger: Debugger, reader: impl BufRead, writer: impl Write) -> Result<()> {
+    Server {
+        debugger,
+        reader,
+        writer,
+        seq: 0,
+        stop_on_entry: false,
+        breakpoints_by_file: HashMap::new(),
+    }
+    .run()
+}
+
+struct Server<R, W> {
+    debugger: Debugger,
+    reader: R,
+    writer: W,
+    /// The sequence number of the last message sent to the client.
+    seq: u64,
+    stop_on_entry: bool,
+    /// The IDs of the breakpoints set within each file, so that they may be replaced.
+    breakpoints_by_file: HashMap<String, Vec<usize>>,
+}
+
+impl<R: BufRead, W: Write> Server<R, W> {
+    fn run(&mut self) -> Result<()> {
+        while let Some(request) = self.read_message()? {
+            let command = request["command"].as_str().unwrap_or_default().to_string();
+            let args = &request["arguments"];
+            let res = match command.as_str() {
+                "initialize" => Ok(json!({ "supportsConfigurationDoneRequest": true })),
+                "launch" | "attach" => {
+                    self.stop_on_entry = args["stopOnEntry"].as_bool().unwrap_or(false);
+                    Ok(Value::Null)
+                }
+                "setBreakpoints" => self.set_breakpoints(args),
+                "setExceptionBreakpoints" => Ok(json!({ "breakpoints": [] })),
+                "threads" => Ok(json!({ "threads": [{ "id": THREAD_ID, "name": "main" }] })),
+                "stackTrace" => Ok(self.stack_trace()),
+                "scopes" => Ok(json!({
+                    "scopes": [{
+                        "name": "Registers",
+                        "variablesReference": REGISTERS_REF,
+                        "expensive": false,
+                    }]
+                })),
+                "variables" => Ok(self.variables(args)),
+                "configurationDone" | "continue" | "next" | "stepIn" | "stepOut" => Ok(Value::Null),
+                "disconnect" | "terminate" => {
+                    self.respond(&request, Ok(Value::Null))?;
+                    return Ok(());
+                }
+                _ => Err(anyhow!("Unsupported request `{}`", command)),
+            };
+            self.respond(&request, res)?;
+            if command == "initialize" {
+                self.send_event("initialized", Value::Null)?;
+            }
+            // Execution is resumed only after responding, as required by the protocol.
+            let stop = match command.as_str() {
+                "configurationDone" => {
+                    let reason = if self.stop_on_entry {
+                        "entry"
+                    } else {
+                        "breakpoint"
+                    };
+                    Some((self.debugger.start(self.stop_on_entry), reason))
+                }
+                "continue" => Some((self.debugger.resume(), "breakpoint")),
+                "next" | "stepIn" | "stepOut" => {
+                    let stop = match args["granularity"].as_str() {
+                        Some("instruction") => self.debugger.step_instruction(),
+                        _ => self.debugger.step_line(),
+                    };
+                    Some((stop, "step"))
+                }
+                _ => None,
+            };
+            if let Some((stop, reason)) = stop {
+                self.report_stop(stop, reason)?;
+            }
+        }
+        Ok(())
+    }
+
+    /// Replace the breakpoints within the given source file.
+    fn set_breakpoints(&mut self, args: &Value) -> Result<Value> {
+        let file = args["source"]["path"]
+            .as_str()
+            .ok_or_else(|| anyhow!("Expected the path of the source"))?
+            .to_string();
+        for id in self.breakpoints_by_file.remove(&file).unwrap_or_default() {
+            self.debugger.remove_breakpoint(id)?;
+        }
+        let mut ids = vec![];
+        let mut breakpoints = vec![];
+        let requested = args["breakpoints"].as_array().cloned().unwrap_or_default();
+        for breakpoint in requested {
+            let line = breakpoint["line"].as_u64().unwrap_or_default() as usize;
+            match self.debugger.add_breakpoint(&file, line) {
+                Ok(breakpoint) => {
+                    ids.push(breakpoint.id);
+                    breakpoints
+                        .push(json!({ "id": breakpoint.id, "verified": true, "line": line }));
+                }
+                Err(err) => breakpoints.push(json!({
+                    "verified": false,
+                    "line": line,
+                    "message": err.to_string(),
+                })),
+            }
+        }
+        self.breakpoints_by_file.insert(file, ids);
+        Ok(json!({ "breakpoints": breakpoints }))
+    }
+
+    fn stack_trace(&mut self) -> Value {
+        let location = match self.debugger.location() {
+            Some(location) => location,
+            None => return json!({ "stackFrames": [], "totalFrames": 0 }),
+        };
+        let name = self.debugger.describe(&location);
+        let frame = match self.debugger.source_location(&location) {
+            Some(source) => json!({
+                "id": 0,
+                "name": name,
+                "source": { "path": source.path },
+                "line": source.line,
+                "column": 1,
+            }),
+            None => json!({ "id": 0, "name": name, "line": 0, "column": 0 }),
+        };
+        json!({ "stackFrames": [frame], "totalFrames": 1 })
+    }
+
+    fn variables(&self, args: &Value) -> Value {
+        if args["variablesReference"].as_u64() != Some(REGISTERS_REF) {
+            return json!({ "variables": [] });
+        }
+        let registers = self.debugger.registers();
+        let named = REGISTER_NAMES
+            .iter()
+            .enumerate()
+            .map(|(ix, name)| (name.to_string(), registers[ix]));
+        let general = registers
+            .iter()
+            .enumerate()
+            .skip(REGISTER_NAMES.len())
+            .filter(|(_, value)| **value != 0)
+            .map(|(ix, value)| (ix.to_string(), *value));
+        let variables: Vec<_> = named
+            .chain(general)
+            .map(|(name, value)| {
+                json!({
+                    "name": name,
+                    "value": format!("{:#x}", value),
+                    "variablesReference": 0,
+                })
+            })
+            .collect();
+        json!({ "variables": variables })
+    }
+
+    fn report_stop(&mut self, stop: Result<Stop>, reason: &str) -> Result<()> {
+        match stop {
+            Ok(Stop::Paused(_)) => self.send_event(
+                "stopped",
+                json!({ "reason": reason, "threadId": THREAD_ID, "allThreadsStopped": true }),
+            ),
+            Ok(Stop::Finished(state)) => {
+                let receipts: Vec<_> = self
+                    .debugger
+                    .receipts()
+                    .map(|receipt| format!("{:?}\n", receipt))
+                    .collect();
+                for output in receipts {
+                    self.send_event("output", json!({ "category": "stdout", "output": output }))?;
+                }
+                let exit_code = match state {
+                    ProgramState::Revert(_) => 1,
+                    _ => 0,
+                };
+                self.send_event("exited", json!({ "exitCode": exit_code }))?;
+                self.send_event("terminated", Value::Null)
+            }
+            Err(err) => {
+                let output = format!("{}\n", err);
+                self.send_event("output", json!({ "category": "stderr", "output": output }))?;
+                self.send_event("terminated", Value::Null)
+            }
+        }
+    }
+
+    fn respond(&mut self, request: &Value, res: Result<Value>) -> Result<()> {
+        let mut response = json!({
+            "type": "response",
+            "request_seq": request["seq"],
+            "command": request["command"],
+        });
+        match res {
+            Ok(body) => {
+                response["success"] = json!(true);
+                if !body.is_null() {
+                    response["body"] = body;
+                }
+            }
+            Err(err) => {
+                response["success"] = json!(false);
+                response["message"] = json!(err.to_string());
+            }
+        }
+        self.send(response)
+    }
+
+    fn send_event(&mut self, event: &str, body: Value) -> Result<()> {
+        let mut message = json!({ "type": "event", "event": event });
+        if !body.is_null() {
+            message["body"] = body;
+        }
+        self.send(message)
+    }
+
+    fn send(&mut self, mut message: Value) -> Result<()> {
+        self.seq += 1;
+        message["seq"] = json!(self.seq);
+        let content = serde_json::to_string(&message)?;
+        write!(
+            self.writer,
+            "Content-Length: {}\r\n\r\n{}",
+            content.len(),
+            content
+        )?;
+        self.writer.flush()?;
+        Ok(())
+    }
+
+    /// Read the next message from the client, or `None` if the connection was closed.
+    fn read_message(&mut self) -> Result<Option<Value>> {
+        let mut content_len = None;
+        loop {
+            let mut header = String::new();
+            if self.reader.read_line(&mut header)? == 0 {
+                return Ok(None);
+            }
+            let header = header.trim_end();
+            if header.is_empty() {
+                break;
+            }
+            if let Some(len) = header.strip_prefix("Content-Length:") {
+                content_len = Some(len.trim().parse::<usize>()?);
+            }
+        }
+        let content_len = match content_len {
+            Some(len) => len,
+            None => bail!("Expected a `Content-Length` header"),
+        };
+        let mut content = vec![0; content_len];
+        self.reader.read_exact(&mut content)?;
+        Ok(Some(serde_json::from_slice(&content)?))
+    }
+}
+
+#[cfg(test)]
+fn frame_messages(messages: &[Value]) -> Vec<u8> {
+    let mut framed = vec![];
+    for message in messages {
+        let content = serde_json::to_string(message).unwrap();
+        write!(
+            framed,
+            "Content-Length: {}\r\n\r\n{}",
+            content.len(),
+            content
+        )
+        .unwrap();
+    }
+    framed
+}
+
+#[cfg(test)]
+fn parse_messages(mut output: &[u8]) -> Vec<Value> {
+    let mut messages = vec![];
+    while !output.is_empty() {
+        let header_end = output.windows(4).position(|w| w == b"\r\n\r\n").unwrap();
+        let header = std::str::from_utf8(&output[..header_end]).unwrap();
+        let len: usize = header
+            .strip_prefix("Content-Length: ")
+            .unwrap()
+            .parse()
+            .unwrap();
+        let content = &output[header_end + 4..header_end + 4 + len];
+        messages.push(serde_json::from_slice(content).unwrap());
+        output = &output[header_end + 4 + len..];
+    }
+    messages
+}
+
+#[test]
+fn serve_requests() {
+    let dir = tempfile::tempdir().unwrap();
+    let debugger = crate::utils::debugger::test_debugger(dir.path());
+    let requests = frame_messages(&[
+        json!({ "seq": 1, "type": "request", "command": "initialize", "arguments": {} }),
+        json!({
+            "seq": 2,
+            "type": "request",
+            "command": "setBreakpoints",
+            "arguments": {
+                "source": { "path": "src/main.sw" },
+                "breakpoints": [{ "line": 4 }, { "line": 5 }],
+            },
+        }),
+        json!({ "seq": 3, "type": "request", "command": "threads" }),
+        json!({ "seq": 4, "type": "request", "command": "stackTrace", "arguments": {} }),
+        json!({ "seq": 5, "type": "request", "command": "evaluate", "arguments": {} }),
+        json!({ "seq": 6, "type": "request", "command": "disconnect" }),
+        // Requests following a disconnect are not served.
+        json!({ "seq": 7, "type": "request", "command": "threads" }),
+    ]);
+    let mut output = vec![];
+    serve(debugger, &requests[..], &mut output).unwrap();
+    let messages = parse_messages(&output);
+
+    let seqs: Vec<_> = messages
+        .iter()
+        .map(|m| m["seq"].as_u64().unwrap())
+        .collect();
+    assert_eq!(seqs, (1..=7).collect::<Vec<_>>());
+    let kinds: Vec<_> = messages
+        .iter()
+        .map(|m| {
+            let name = m["command"].as_str().or_else(|| m["event"].as_str());
+            (m["type"].as_str().unwrap(), name.unwrap())
+        })
+        .collect();
+    assert_eq!(
+        kinds,
+        vec![
+            ("response", "initialize"),
+            ("event", "initialized"),
+            ("response", "setBreakpoints"),
+            ("response", "threads"),
+            ("response", "stackTrace"),
+            ("response", "evaluate"),
+            ("response", "disconnect"),
+        ]
+    );
+
+    assert_eq!(messages[0]["request_seq"], 1);
+    assert_eq!(messages[0]["success"], true);
+    let breakpoints = &messages[2]["body"]["breakpoints"];
+    assert_eq!(breakpoints[0]["verified"], true);
+    assert_eq!(breakpoints[0]["line"], 4);
+    assert_eq!(breakpoints[1]["verified"], false);
+    assert_eq!(breakpoints[1]["line"], 5);
+    assert_eq!(messages[3]["body"]["threads"][0]["id"], THREAD_ID);
+    // The script has not been started, so there are no frames to report.
+    assert_eq!(messages[4]["body"]["totalFrames"], 0);
+    assert_eq!(messages[5]["success"], false);
+    assert_eq!(messages[5]["message"], "Unsupported request `evaluate`");
+}
+
+#[test]
+fn reject_message_without_content_length() {
+    let dir = tempfile::tempdir().unwrap();
+    let debugger = crate::utils::debugger::test_debugger(dir.path());
+    let requests = b"Content-Type: application/json\r\n\r\n{}";
+    let mut output = vec![];
+    assert!(serve(debugger, &requests[..], &mut output).is_err());
+    assert!(output.is_empty());
+}
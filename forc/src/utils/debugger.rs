@@ -0,0 +1,592 @@
+use crate::utils::{
+    local_vm::Programs,
+    size_report::{display_path, NO_SOURCE},
+    source_fns::SourceFns,
+};
+use anyhow::{anyhow, bail, Result};
+use fuel_tx::{ContractId, Receipt, Transaction};
+use fuel_vm::{consts::*, prelude::*};
+use std::{
+    collections::HashMap,
+    fmt,
+    path::{Path, PathBuf},
+};
+use sway_core::source_map::LocationRange;
+
+/// The names of the VM's reserved registers, indexed by register.
+pub const REGISTER_NAMES: [&str; REG_WRITABLE] = [
+    "zero", "one", "of", "pc", "ssp", "sp", "fp", "hp", "err", "ggas", "cgas", "bal", "is", "ret",
+    "retl", "flag",
+];
+
+/// An instruction within one of the programs loaded into the VM.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct Location {
+    /// `None` for the script, otherwise the ID of the contract.
+    pub context: Option<ContractId>,
+    /// The index of the instruction within the program's bytecode.
+    pub instruction_ix: usize,
+}
+
+/// The source from which an instruction was generated.
+#[derive(Clone, Debug)]
+pub struct SourceLocation {
+    pub path: PathBuf,
+    /// The line on which the source begins, starting from 1.
+    pub line: usize,
+    pub range: LocationRange,
+    /// The name of the function containing the source, if any.
+    pub function: Option<String>,
+}
+
+/// A breakpoint requested by the user on a line of source.
+#[derive(Clone, Debug)]
+pub struct SourceBreakpoint {
+    pub id: usize,
+    /// The file as it was given by the user.
+    pub file: String,
+    pub line: usize,
+    /// The first instruction of each sequence of instructions generated from the line.
+    pub locations: Vec<Location>,
+}
+
+/// The state of execution after running the VM.
+#[derive(Debug)]
+pub enum Stop {
+    /// Execution is suspended at the given instruction, which has not yet been executed.
+    Paused(Location),
+    /// The script has finished executing.
+    Finished(ProgramState),
+}
+
+/// How the VM came to stop running.
+enum Run {
+    /// Execution was suspended by a breakpoint or single step.
+    Suspended(Location),
+    /// Execution returned from a contract call in which it had been suspended, and the VM was
+    /// restored to the instruction following the call. See [Debugger::recover].
+    Recovered(Location),
+    Finished(ProgramState),
+}
+
+#[derive(Debug)]
+enum State {
+    NotStarted,
+    Paused(Location),
+    Finished,
+}
+
+/// Runs a script within an in-process VM, suspending execution at breakpoints set on lines of
+/// source or after single steps.
+pub struct Debugger {
+    vm: Interpreter<MemoryStorage>,
+    /// The state of storage before the script was run, from which execution may be replayed.
+    storage: MemoryStorage,
+    programs: Programs,
+    tx: Transaction,
+    state: State,
+    source_fns: SourceFns,
+    /// The source line of each instruction of each program, if known.
+    lines: HashMap<Option<ContractId>, Vec<Option<(PathBuf, usize)>>>,
+    breakpoints: Vec<SourceBreakpoint>,
+    next_breakpoint_id: usize,
+    /// The locations being replayed to, innermost last. While replaying, the VM breaks only at
+    /// the innermost location rather than at the user's breakpoints.
+    replay_targets: Vec<Location>,
+}
+
+impl Debugger {
+    /// Create a debugger that will execute the given script transaction.
+    ///
+    /// The `programs` must include the script itself, along with any contracts that have already
+    /// been deployed to the `vm`.
+    pub fn new(vm: Interpreter<MemoryStorage>, programs: Programs, tx: Transaction) -> Self {
+        let mut source_fns = SourceFns::new();
+        let lines = programs
+            .iter()
+            .map(|(context, program)| {
+                let num_instructions =
+                    program.compiled.bytecode_layout.data_section_offset / Opcode::LEN;
+                let lines = (0..num_instructions)
+                    .map(|ix| {
+                        let (path, range) = program.source_map.addr_to_span(ix)?;
+                        let line = source_fns.line(&path, range.start)?;
+                        Some((path, line))
+                    })
+                    .collect();
+                (*context, lines)
+            })
+            .collect();
+        Self {
+            storage: vm.as_ref().clone(),
+            vm,
+            programs,
+            tx,
+            state: State::NotStarted,
+            source_fns,
+            lines,
+            breakpoints: vec![],
+            next_breakpoint_id: 1,
+            replay_targets: vec![],
+        }
+    }
+
+    pub fn programs(&self) -> &Programs {
+        &self.programs
+    }
+
+    /// The instruction at which execution is suspended, if any.
+    pub fn location(&self) -> Option<Location> {
+        match self.state {
+            State::Paused(location) => Some(location),
+            _ => None,
+        }
+    }
+
+    pub fn is_started(&self) -> bool {
+        !matches!(self.state, State::NotStarted)
+    }
+
+    pub fn is_finished(&self) -> bool {
+        matches!(self.state, State::Finished)
+    }
+
+    pub fn breakpoints(&self) -> &[SourceBreakpoint] {
+        &self.breakpoints
+    }
+
+    /// Set a breakpoint on each sequence of instructions generated from the given line.
+    ///
+    /// The `file` matches any source file whose path ends with it, e.g. `src/main.sw`.
+    pub fn add_breakpoint(&mut self, file: &str, line: usize) -> Result<&SourceBreakpoint> {
+        let mut locations = vec![];
+        for (context, lines) in &self.lines {
+            let mut prev_line = None;
+            for (ix, source_line) in lines.iter().enumerate() {
+                let is_match = matches!(
+                    source_line,
+                    Some((path, l)) if *l == line && path.ends_with(file)
+                );
+                if is_match && prev_line != source_line.as_ref() {
+                    locations.push(Location {
+                        context: *context,
+                        instruction_ix: ix,
+                    });
+                }
+                prev_line = source_line.as_ref();
+            }
+        }
+        if locations.is_empty() {
+            bail!("No instructions were generated from {}:{}", file, line);
+        }
+        for location in &locations {
+            self.vm.set_breakpoint(vm_breakpoint(location));
+        }
+        let breakpoint = SourceBreakpoint {
+            id: self.next_breakpoint_id,
+            file: file.to_string(),
+            line,
+            locations,
+        };
+        self.next_breakpoint_id += 1;
+        self.breakpoints.push(breakpoint);
+        Ok(self.breakpoints.last().unwrap())
+    }
+
+    /// Remove the breakpoint with the given ID.
+    pub fn remove_breakpoint(&mut self, id: usize) -> Result<()> {
+        let ix = self
+            .breakpoints
+            .iter()
+            .position(|breakpoint| breakpoint.id == id)
+            .ok_or_else(|| anyhow!("No breakpoint with ID {}", id))?;
+        let breakpoint = self.breakpoints.remove(ix);
+        for location in &breakpoint.locations {
+            // Other breakpoints may share the same instruction.
+            let is_shared = self
+                .breakpoints
+                .iter()
+                .any(|other| other.locations.contains(location));
+            if !is_shared {
+                self.vm.remove_breakpoint(&vm_breakpoint(location));
+            }
+        }
+        Ok(())
+    }
+
+    /// Start executing the script, pausing at the first instruction if `stop_on_entry` is set.
+    pub fn start(&mut self, stop_on_entry: bool) -> Result<Stop> {
+        if self.is_started() {
+            bail!("The script has already been started");
+        }
+        self.vm.set_single_stepping(stop_on_entry);
+        let run = self.transact();
+        self.vm.set_single_stepping(false);
+        self.update_state(run)
+    }
+
+    /// Continue execution until the next breakpoint or the end of the script.
+    pub fn resume(&mut self) -> Result<Stop> {
+        self.ensure_paused()?;
+        self.vm.set_single_stepping(false);
+        let run = loop {
+            match self.resume_vm() {
+                Ok(Run::Recovered(_)) => continue,
+                run => break run,
+            }
+        };
+        self.update_state(run)
+    }
+
+    /// Execute a single instruction.
+    pub fn step_instruction(&mut self) -> Result<Stop> {
+        self.ensure_paused()?;
+        self.vm.set_single_stepping(true);
+        let run = self.resume_vm();
+        self.vm.set_single_stepping(false);
+        self.update_state(run)
+    }
+
+    /// Execute instructions until reaching one that was generated from a different line of source.
+    ///
+    /// Instructions without a known source are stepped over.
+    pub fn step_line(&mut self) -> Result<Stop> {
+        let start = self.location().and_then(|location| self.line_at(&location));
+        loop {
+            let stop = self.step_instruction()?;
+            let location = match stop {
+                Stop::Paused(location) => location,
+                Stop::Finished(_) => return Ok(stop),
+            };
+            match self.line_at(&location) {
+                Some(line) if Some(&line) != start.as_ref() => return Ok(stop),
+                _ => (),
+            }
+        }
+    }
+
+    /// The source from which the instruction at the given location was generated.
+    pub fn source_location(&mut self, location: &Location) -> Option<SourceLocation> {
+        let program = self.programs.get(&location.context)?;
+        let (path, range) = program.source_map.addr_to_span(location.instruction_ix)?;
+        let line = self.source_fns.line(&path, range.start)?;
+        let function = self
+            .source_fns
+            .enclosing(&path, range)
+            .map(|f| f.name.clone());
+        Some(SourceLocation {
+            path,
+            line,
+            range,
+            function,
+        })
+    }
+
+    /// Describe the given location, e.g. `main at src/main.sw:4 (script, instruction 12)`.
+    pub fn describe(&mut self, location: &Location) -> String {
+        let program = match self.programs.get(&location.context) {
+            Some(program) => program.name.clone(),
+            None => "<unknown program>".to_string(),
+        };
+        let position = format!("{}, instruction {}", program, location.instruction_ix);
+        match self.source_location(location) {
+            Some(source) => {
+                let project_dir = &self.programs[&location.context].project_dir;
+                format!(
+                    "{} at {}:{} ({})",
+                    source.function.as_deref().unwrap_or(NO_SOURCE),
+                    display_path(&source.path, project_dir),
+                    source.line,
+                    position,
+                )
+            }
+            None => format!("{} ({})", NO_SOURCE, position),
+        }
+    }
+
+    pub fn registers(&self) -> &[Word] {
+        self.vm.registers()
+    }
+
+    /// Read `len` bytes of the VM's memory starting from `addr`.
+    pub fn memory(&self, addr: usize, len: usize) -> Result<&[u8]> {
+        let memory = self.vm.memory();
+        match addr.checked_add(len) {
+            Some(end) if end <= memory.len() => Ok(&memory[addr..end]),
+            _ => bail!(
+                "The range {:#x}..{:#x} lies outside of the VM's memory",
+                addr,
+                addr.saturating_add(len)
+            ),
+        }
+    }
+
+    /// The receipts produced so far.
+    ///
+    /// The VM produces a `ScriptResult` receipt whenever execution is first suspended, so these are
+    /// omitted as they do not describe the result of the script.
+    pub fn receipts(&self) -> impl Iterator<Item = &Receipt> {
+        self.vm
+            .receipts()
+            .iter()
+            .filter(|receipt| !matches!(receipt, Receipt::ScriptResult { .. }))
+    }
+
+    fn line_at(&self, location: &Location) -> Option<(PathBuf, usize)> {
+        self.lines
+            .get(&location.context)?
+            .get(location.instruction_ix)?
+            .clone()
+    }
+
+    fn ensure_paused(&self) -> Result<()> {
+        match self.state {
+            State::Paused(_) => Ok(()),
+            State::NotStarted => bail!("The script has not been started"),
+            State::Finished => bail!("The script has finished"),
+        }
+    }
+
+    fn update_state(&mut self, run: Result<Run>) -> Result<Stop> {
+        match run {
+            Ok(Run::Suspended(location) | Run::Recovered(location)) => {
+                self.state = State::Paused(location);
+                Ok(Stop::Paused(location))
+            }
+            Ok(Run::Finished(state)) => {
+                self.state = State::Finished;
+                Ok(Stop::Finished(state))
+            }
+            Err(err) => {
+                // The VM cannot be resumed after an error, e.g. a panic.
+                self.state = State::Finished;
+                Err(err)
+            }
+        }
+    }
+
+    /// Run the script from the start within the VM.
+    fn transact(&mut self) -> Result<Run> {
+        let state = *self
+            .vm
+            .transact(self.tx.clone())
+            .map_err(|e| anyhow!("Failed to run script: {}", e))?
+            .state();
+        Ok(self.run_result(state))
+    }
+
+    /// Resume the suspended VM, recovering from returns out of contract calls.
+    fn resume_vm(&mut self) -> Result<Run> {
+        let state = self.vm.resume().map_err(|e| anyhow!("{}", e))?;
+        let run = self.run_result(state);
+        let returned_from_contract = matches!(
+            self.vm.receipts().last(),
+            Some(Receipt::Return { id, .. } | Receipt::ReturnData { id, .. })
+                if *id != ContractId::default()
+        );
+        match run {
+            Run::Finished(_) if returned_from_contract => self.recover().map(Run::Recovered),
+            run => Ok(run),
+        }
+    }
+
+    /// The VM executes a contract call within a nested loop, which is unwound when execution is
+    /// suspended within the callee. When the callee later returns, the VM treats this as the end
+    /// of the script. While the VM's state is correct, it can no longer be resumed.
+    ///
+    /// Recover by replaying the transaction within a fresh VM until it reaches the same instruction
+    /// with the same gas remaining, as execution is deterministic.
+    fn recover(&mut self) -> Result<Location> {
+        let registers = self.vm.registers();
+        let target = Location {
+            context: current_context(self.vm.receipts()),
+            instruction_ix: (registers[REG_PC] - registers[REG_IS]) as usize / Opcode::LEN,
+        };
+        let gas = registers[REG_GGAS];
+
+        self.replay_targets.push(target);
+        self.vm = Interpreter::with_storage(self.storage.clone(), Default::default());
+        self.set_vm_breakpoints();
+        let mut run = self.transact();
+        loop {
+            match run? {
+                Run::Suspended(location) | Run::Recovered(location)
+                    if location == target && self.vm.registers()[REG_GGAS] == gas =>
+                {
+                    break
+                }
+                Run::Finished(_) => {
+                    bail!("Failed to restore the VM after returning from a contract call")
+                }
+                _ => run = self.resume_vm(),
+            }
+        }
+        self.replay_targets.pop();
+        self.vm.remove_breakpoint(&vm_breakpoint(&target));
+        self.set_vm_breakpoints();
+        Ok(target)
+    }
+
+    /// Set the VM's breakpoints to those of the user, or to the innermost replay target.
+    fn set_vm_breakpoints(&mut self) {
+        match self.replay_targets.last() {
+            Some(target) => self.vm.set_breakpoint(vm_breakpoint(target)),
+            None => {
+                for breakpoint in &self.breakpoints {
+                    for location in &breakpoint.locations {
+                        self.vm.set_breakpoint(vm_breakpoint(location));
+                    }
+                }
+            }
+        }
+    }
+
+    fn run_result(&self, state: ProgramState) -> Run {
+        match state.debug_ref().and_then(DebugEval::breakpoint) {
+            Some(breakpoint) => Run::Suspended(Location {
+                context: Some(*breakpoint.contract()).filter(|id| *id != ContractId::default()),
+                instruction_ix: breakpoint.pc() as usize / Opcode::LEN,
+            }),
+            None => Run::Finished(state),
+        }
+    }
+}
+
+/// The context in which execution is taking place, determined by the calls and returns so far.
+fn current_context(receipts: &[Receipt]) -> Option<ContractId> {
+    let mut contexts = vec![];
+    for receipt in receipts {
+        match receipt {
+            Receipt::Call { to, .. } => contexts.push(*to),
+            Receipt::Return { id, .. } | Receipt::ReturnData { id, .. }
+                if contexts.last() == Some(id) =>
+            {
+                contexts.pop();
+            }
+            _ => (),
+        }
+    }
+    contexts.pop()
+}
+
+/// The VM breakpoint for the given location. Script breakpoints use the zero contract ID.
+fn vm_breakpoint(location: &Location) -> Breakpoint {
+    let pc = location.instruction_ix as Word;
+    match location.context {
+        Some(contract_id) => Breakpoint::new(contract_id, pc),
+        None => Breakpoint::script(pc),
+    }
+}
+
+/// Render the lines of the file surrounding the given line, marking the line itself.
+pub fn render_snippet(path: &Path, line: usize, context_lines: usize) -> Result<String> {
+    let src = std::fs::read_to_string(path)
+        .map_err(|e| anyhow!("{}: could not read: {}", path.display(), e))?;
+    let first = line.saturating_sub(context_lines).max(1);
+    let last = line + context_lines;
+    let width = last.to_string().len();
+    let snippet = src
+        .lines()
+        .enumerate()
+        .map(|(ix, text)| (ix + 1, text))
+        .filter(|(n, _)| first <= *n && *n <= last)
+        .map(|(n, text)| {
+            let marker = if n == line { "=>" } else { "  " };
+            format!("{} {:>width$} | {}\n", marker, n, text, width = width)
+        })
+        .collect();
+    Ok(snippet)
+}
+
+impl fmt::Display for Location {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self.context {
+            Some(contract_id) => write!(f, "0x{}:{}", contract_id, self.instruction_ix),
+            None => write!(f, "script:{}", self.instruction_ix),
+        }
+    }
+}
+
+/// Create a debugger for a script whose instructions map to the lines of a small source file
+/// written within `dir`.
+///
+/// Instruction 0 has no source, instructions 1, 2 and 4 come from line 4 (within `helper`) and
+/// instruction 3 comes from line 8 (within `main`).
+#[cfg(test)]
+pub(crate) fn test_debugger(dir: &Path) -> Debugger {
+    use crate::utils::{
+        local_vm::Program,
+        test_program::{compiled, main_entry_point, TestScript},
+    };
+    use sway_core::{source_map::SourceMap, TreeType};
+
+    let test_script = TestScript::new(dir);
+    let helper_span = test_script.span_of("1\n");
+    let mut source_map = SourceMap::new();
+    source_map.insert(1, &helper_span);
+    source_map.insert(2, &helper_span);
+    source_map.insert(3, &test_script.span_of("helper()\n"));
+    source_map.insert(4, &helper_span);
+    let script = Program {
+        name: "script".to_string(),
+        compiled: compiled(TreeType::Script, 5 * Opcode::LEN, vec![main_entry_point(1)]),
+        source_map,
+        project_dir: dir.to_path_buf(),
+    };
+    let programs: Programs = [(None, script)].into_iter().collect();
+    Debugger::new(
+        Interpreter::with_memory_storage(),
+        programs,
+        Transaction::default(),
+    )
+}
+
+#[test]
+fn map_breakpoints_to_instructions() {
+    let dir = tempfile::tempdir().unwrap();
+    let mut debugger = test_debugger(dir.path());
+    let script = |instruction_ix| Location {
+        context: None,
+        instruction_ix,
+    };
+
+    // A breakpoint is placed on the first instruction of each sequence generated from the line.
+    let breakpoint = debugger.add_breakpoint("src/main.sw", 4).unwrap();
+    assert_eq!(breakpoint.id, 1);
+    assert_eq!(breakpoint.locations, vec![script(1), script(4)]);
+    let breakpoint = debugger.add_breakpoint("main.sw", 8).unwrap();
+    assert_eq!(breakpoint.id, 2);
+    assert_eq!(breakpoint.locations, vec![script(3)]);
+
+    // Lines from which no instructions were generated, and other files, are rejected.
+    assert!(debugger.add_breakpoint("src/main.sw", 3).is_err());
+    assert!(debugger.add_breakpoint("src/lib.sw", 4).is_err());
+
+    debugger.remove_breakpoint(1).unwrap();
+    assert!(debugger.remove_breakpoint(1).is_err());
+    let ids: Vec<_> = debugger.breakpoints().iter().map(|b| b.id).collect();
+    assert_eq!(ids, vec![2]);
+}
+
+#[test]
+fn describe_source_locations() {
+    let dir = tempfile::tempdir().unwrap();
+    let mut debugger = test_debugger(dir.path());
+    let script = |instruction_ix| Location {
+        context: None,
+        instruction_ix,
+    };
+    let main_sw = Path::new("src").join("main.sw").display().to_string();
+
+    let source = debugger.source_location(&script(3)).unwrap();
+    assert_eq!(source.line, 8);
+    assert_eq!(source.function.as_deref(), Some("main"));
+    assert_eq!(
+        debugger.describe(&script(4)),
+        format!("helper at {}:4 (script, instruction 4)", main_sw)
+    );
+    assert_eq!(
+        debugger.describe(&script(0)),
+        format!("{} (script, instruction 0)", NO_SOURCE)
+    );
+}
@@ -1,13 +1,13 @@
 /// We intentionally don't construct this using [serde]'s default deserialization so we get
 /// the chance to insert some helpful comments and nicer formatting.
-pub(crate) fn default_manifest(project_name: &str, entry_type: &str) -> String {
+pub(crate) fn default_manifest(project_name: &str, entry_type: &str, license: &str) -> String {
     let author = get_author();
 
     format!(
         r#"[project]
 authors = ["{author}"]
 entry = "{entry_type}"
-license = "Apache-2.0"
+license = "{license}"
 name = "{project_name}"
 
 [dependencies]
@@ -132,7 +132,7 @@ target
     .into()
 }
 
-fn get_author() -> String {
+pub(crate) fn get_author() -> String {
     std::env::var(sway_utils::FORC_INIT_MANIFEST_AUTHOR).unwrap_or_else(|_| whoami::realname())
 }
 
@@ -141,7 +141,12 @@ fn parse_default_manifest() {
     use sway_utils::constants::MAIN_ENTRY;
     tracing::info!(
         "{:#?}",
-        toml::from_str::<forc_pkg::Manifest>(&default_manifest("test_proj", MAIN_ENTRY)).unwrap()
+        toml::from_str::<forc_pkg::Manifest>(&default_manifest(
+            "test_proj",
+            MAIN_ENTRY,
+            "Apache-2.0"
+        ))
+        .unwrap()
     )
 }
 
@@ -0,0 +1,250 @@
+use crate::utils::{
+    local_vm::Programs,
+    size_report::{display_path, NO_SOURCE, PREAMBLE},
+    source_fns::SourceFns,
+};
+use fuel_tx::ContractId;
+use std::collections::{BTreeMap, HashMap};
+use term_table::{
+    row::Row,
+    table_cell::{Alignment, TableCell},
+};
+
+/// The gas used by an execution, aggregated by source function and by source line.
+#[derive(Debug)]
+pub struct GasProfile {
+    /// The total gas used by all profiled instructions.
+    pub total_gas: u64,
+    /// The gas used by each source function (including those that were inlined), largest first.
+    pub functions: Vec<GasEntry>,
+    /// The gas used by each line of source, largest first.
+    pub lines: Vec<GasEntry>,
+    /// The gas used by each unique stack of program, entry point, function and line.
+    stacks: BTreeMap<Vec<String>, u64>,
+}
+
+/// The gas attributed to a single function or line.
+#[derive(Debug, Default)]
+pub struct GasEntry {
+    pub name: String,
+    /// The location of the function's declaration as `path:line`.
+    pub location: Option<String>,
+    pub gas: u64,
+}
+
+impl GasProfile {
+    /// Attribute the gas used by each instruction to its source.
+    ///
+    /// Each item of `gas_use` is the context in which an instruction was executed (`None` for the
+    /// script, otherwise the ID of the contract), the instruction's index and the gas it used.
+    pub fn new(
+        gas_use: impl IntoIterator<Item = (Option<ContractId>, usize, u64)>,
+        programs: &Programs,
+    ) -> Self {
+        let mut source_fns = SourceFns::new();
+        let mut total_gas = 0;
+        let mut functions: HashMap<(String, Option<String>), u64> = HashMap::new();
+        let mut lines: HashMap<String, u64> = HashMap::new();
+        let mut stacks: BTreeMap<Vec<String>, u64> = BTreeMap::new();
+        // Paths relative to each project are ambiguous when profiling more than one program.
+        let qualify_paths = programs.len() > 1;
+
+        for (context, ix, gas) in gas_use {
+            total_gas += gas;
+            let program = match programs.get(&context) {
+                Some(program) => program,
+                None => {
+                    let name = match context {
+                        Some(contract_id) => format!("<unknown contract 0x{}>", contract_id),
+                        None => "<unknown script>".to_string(),
+                    };
+                    *stacks.entry(vec![name]).or_default() += gas;
+                    continue;
+                }
+            };
+            let entry_name = program
+                .compiled
+                .bytecode_layout
+                .entry_point_at(ix)
+                .map(|entry| entry.name.clone())
+                .unwrap_or_else(|| PREAMBLE.to_string());
+            let mut stack = vec![program.name.clone(), entry_name];
+
+            match program.source_map.addr_to_span(ix) {
+                Some((path, range)) => {
+                    let mut path_str = display_path(&path, &program.project_dir);
+                    if qualify_paths && path.starts_with(&program.project_dir) {
+                        path_str = format!("{}/{}", program.name, path_str);
+                    }
+                    let function = source_fns.enclosing(&path, range).map(|f| {
+                        let location = format!("{}:{}", path_str, f.line);
+                        (f.name.clone(), Some(location))
+                    });
+                    let function = function.unwrap_or_else(|| (NO_SOURCE.to_string(), None));
+                    stack.push(function.0.clone());
+                    *functions.entry(function).or_default() += gas;
+                    let line = match source_fns.line(&path, range.start) {
+                        Some(line) => format!("{}:{}", path_str, line),
+                        None => path_str,
+                    };
+                    stack.push(line.clone());
+                    *lines.entry(line).or_default() += gas;
+                }
+                None => {
+                    stack.push(NO_SOURCE.to_string());
+                    *functions.entry((NO_SOURCE.to_string(), None)).or_default() += gas;
+                }
+            }
+            *stacks.entry(stack).or_default() += gas;
+        }
+
+        let functions = functions
+            .into_iter()
+            .map(|((name, location), gas)| GasEntry {
+                name,
+                location,
+                gas,
+            });
+        let lines = lines.into_iter().map(|(name, gas)| GasEntry {
+            name,
+            location: None,
+            gas,
+        });
+        Self {
+            total_gas,
+            functions: sorted_by_gas(functions),
+            lines: sorted_by_gas(lines),
+            stacks,
+        }
+    }
+
+    /// Render the profile in the folded stack format expected by flamegraph tools, one stack per
+    /// line followed by the gas it used.
+    pub fn folded_stacks(&self) -> String {
+        self.stacks
+            .iter()
+            .map(|(stack, gas)| format!("{} {}\n", stack.join(";"), gas))
+            .collect()
+    }
+
+    /// Render the `top` most expensive functions and lines as a pair of tables.
+    pub fn render_table(&self, top: usize) -> String {
+        let mut out = format!("  Total gas: {}\n", self.total_gas);
+        out.push_str(&self.table("function", &self.functions, top).render());
+        out.push_str(&self.table("line", &self.lines, top).render());
+        out
+    }
+
+    fn table(&self, kind: &str, entries: &[GasEntry], top: usize) -> term_table::Table<'_> {
+        let mut table = term_table::Table::new();
+        table.separate_rows = false;
+        table.style = term_table::TableStyle::thin();
+        let show_location = entries.iter().any(|entry| entry.location.is_some());
+        let mut header = vec![TableCell::new(kind)];
+        if show_location {
+            header.push(TableCell::new("location"));
+        }
+        header.extend(["gas", "%"].map(TableCell::new));
+        table.add_row(Row::new(header));
+        for entry in entries.iter().take(top) {
+            let percentage = match self.total_gas {
+                0 => 0.0,
+                total => entry.gas as f64 * 100.0 / total as f64,
+            };
+            let mut row = vec![TableCell::new(&entry.name)];
+            if show_location {
+                row.push(TableCell::new(entry.location.as_deref().unwrap_or("")));
+            }
+            row.extend([
+                TableCell::new_with_alignment(entry.gas, 1, Alignment::Right),
+                TableCell::new_with_alignment(format!("{:.1}", percentage), 1, Alignment::Right),
+            ]);
+            table.add_row(Row::new(row));
+        }
+        table
+    }
+}
+
+fn sorted_by_gas(entries: impl Iterator<Item = GasEntry>) -> Vec<GasEntry> {
+    let mut entries: Vec<_> = entries.collect();
+    entries.sort_by(|a, b| {
+        b.gas
+            .cmp(&a.gas)
+            .then_with(|| a.name.cmp(&b.name))
+            .then_with(|| a.location.cmp(&b.location))
+    });
+    entries
+}
+
+#[test]
+fn attribute_gas_to_functions_lines_and_stacks() {
+    use crate::utils::{
+        local_vm::Program,
+        test_program::{compiled, main_entry_point, TestScript},
+    };
+    use sway_core::{source_map::SourceMap, TreeType};
+
+    let dir = tempfile::tempdir().unwrap();
+    let test_script = TestScript::new(dir.path());
+    let mut source_map = SourceMap::new();
+    source_map.insert(1, &test_script.span_of("1\n"));
+    source_map.insert(2, &test_script.span_of("helper()\n"));
+    let script = Program {
+        name: "script".to_string(),
+        compiled: compiled(TreeType::Script, 12, vec![main_entry_point(1)]),
+        source_map,
+        project_dir: dir.path().to_path_buf(),
+    };
+    let programs: Programs = [(None, script)].into_iter().collect();
+
+    let unknown_contract = ContractId::from([1; 32]);
+    let gas_use = vec![
+        (None, 0, 1),
+        (None, 1, 5),
+        (None, 2, 2),
+        (None, 1, 5),
+        (Some(unknown_contract), 0, 7),
+    ];
+    let profile = GasProfile::new(gas_use, &programs);
+
+    assert_eq!(profile.total_gas, 20);
+    let main_sw = std::path::Path::new("src")
+        .join("main.sw")
+        .display()
+        .to_string();
+    let functions: Vec<_> = profile
+        .functions
+        .iter()
+        .map(|e| (e.name.as_str(), e.location.clone(), e.gas))
+        .collect();
+    assert_eq!(
+        functions,
+        vec![
+            ("helper", Some(format!("{}:3", main_sw)), 10),
+            ("main", Some(format!("{}:7", main_sw)), 2),
+            (NO_SOURCE, None, 1),
+        ]
+    );
+    let lines: Vec<_> = profile
+        .lines
+        .iter()
+        .map(|e| (e.name.clone(), e.gas))
+        .collect();
+    assert_eq!(
+        lines,
+        vec![
+            (format!("{}:4", main_sw), 10),
+            (format!("{}:8", main_sw), 2),
+        ]
+    );
+    assert_eq!(
+        profile.folded_stacks(),
+        format!(
+            "<unknown contract 0x{}> 7\n\
+             script;{};{} 1\n\
+             script;main;helper;{}:4 10\n\
+             script;main;main;{}:8 2\n",
+            unknown_contract, PREAMBLE, NO_SOURCE, main_sw, main_sw,
+        )
+    );
+}
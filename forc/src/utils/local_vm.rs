@@ -0,0 +1,161 @@
+use crate::{
+    cli::BuildCommand,
+    ops::{forc_build, forc_deploy, forc_run},
+    utils::{parameters::TxParameters, SWAY_GIT_TAG},
+};
+use anyhow::{anyhow, Result};
+use forc_pkg::{Compiled, ManifestFile};
+use fuel_tx::{ContractId, Transaction};
+use fuel_vm::prelude::*;
+use std::{collections::HashMap, path::PathBuf};
+use sway_core::{source_map::SourceMap, TreeType};
+
+/// A program built for execution within an in-process VM, along with what is needed to map its
+/// instructions back to source.
+pub struct Program {
+    /// The name of the package from which the program was built.
+    pub name: String,
+    pub compiled: Compiled,
+    pub source_map: SourceMap,
+    /// Paths within this directory are reported relative to it.
+    pub project_dir: PathBuf,
+}
+
+/// The programs loaded into a VM, keyed by the context in which they execute: `None` for the
+/// script, otherwise the ID of the contract.
+pub type Programs = HashMap<Option<ContractId>, Program>;
+
+/// Options used to build each of the programs loaded into the VM.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct BuildOptions {
+    pub release: bool,
+    pub offline_mode: bool,
+    pub silent_mode: bool,
+    pub locked: bool,
+}
+
+impl Program {
+    /// Build the project at the given path, which must be of the given program type.
+    pub fn build(path: &str, tree_type: TreeType, options: BuildOptions) -> Result<Self> {
+        let manifest = ManifestFile::from_dir(&PathBuf::from(path), SWAY_GIT_TAG)?;
+        manifest.check_program_type(vec![tree_type])?;
+        let (compiled, source_map) = forc_build::build_with_source_map(BuildCommand {
+            path: Some(path.to_string()),
+            offline_mode: options.offline_mode,
+            silent_mode: options.silent_mode,
+            locked: options.locked,
+            release: options.release,
+            // Gas profiles and the debugger relate bytecode to source through the source map.
+            include_debug_info: true,
+            ..Default::default()
+        })?;
+        Ok(Self {
+            name: manifest.project.name.clone(),
+            compiled,
+            source_map,
+            project_dir: manifest.dir().to_path_buf(),
+        })
+    }
+}
+
+/// Build the contract projects at the given paths and deploy them to the VM.
+///
+/// Contracts are deployed with a zero salt, matching `forc deploy`, so that IDs hardcoded within a
+/// script resolve to these contracts.
+pub fn deploy_contracts(
+    vm: &mut Interpreter<MemoryStorage>,
+    contract_paths: &[String],
+    options: BuildOptions,
+) -> Result<Programs> {
+    let mut programs = Programs::new();
+    for contract_path in contract_paths {
+        let program = Program::build(contract_path, TreeType::Contract, options)?;
+        let (tx, contract_id) = forc_deploy::create_contract_tx(
+            program.compiled.bytecode.clone(),
+            vec![],
+            vec![],
+            program.compiled.storage_slots.clone(),
+            Salt::zeroed(),
+        );
+        vm.transact(tx)
+            .map_err(|e| anyhow!("Failed to deploy contract {}: {}", program.name, e))?;
+        programs.insert(Some(contract_id), program);
+    }
+    Ok(programs)
+}
+
+/// Create a transaction that runs the given script with access to the deployed contracts.
+///
+/// The script data is given as a hex string, optionally prefixed with `0x`.
+pub fn script_tx(
+    script: &Program,
+    data: &str,
+    programs: &Programs,
+    gas_limit: Option<u64>,
+) -> Result<Transaction> {
+    let script_data = hex::decode(forc_run::format_hex_data(data))
+        .map_err(|e| anyhow!("Invalid hex script data: {}", e))?;
+    let mut contract_ids: Vec<_> = programs
+        .keys()
+        .flatten()
+        .map(|contract_id| format!("0x{}", contract_id))
+        .collect();
+    contract_ids.sort();
+    let (inputs, outputs) = forc_run::get_tx_inputs_and_outputs(contract_ids);
+    Ok(forc_run::create_tx_with_script_and_data(
+        script.compiled.bytecode.clone(),
+        script_data,
+        inputs,
+        outputs,
+        TxParameters::new(None, gas_limit, None),
+    ))
+}
+
+#[test]
+fn script_tx_inputs_and_data() {
+    use crate::utils::test_program::compiled;
+
+    let script = Program {
+        name: "script".to_string(),
+        compiled: compiled(TreeType::Script, 8, vec![]),
+        source_map: SourceMap::new(),
+        project_dir: PathBuf::from("/script"),
+    };
+    let contract = |id: u8| Program {
+        name: format!("contract_{}", id),
+        compiled: compiled(TreeType::Contract, 0, vec![]),
+        source_map: SourceMap::new(),
+        project_dir: PathBuf::from(format!("/contract_{}", id)),
+    };
+    let (a, b) = (ContractId::from([2; 32]), ContractId::from([1; 32]));
+    let programs: Programs = [(Some(a), contract(2)), (Some(b), contract(1))]
+        .into_iter()
+        .collect();
+
+    let tx = script_tx(&script, "0x0102", &programs, Some(1000)).unwrap();
+    match tx {
+        Transaction::Script {
+            gas_limit,
+            script,
+            script_data,
+            inputs,
+            ..
+        } => {
+            assert_eq!(gas_limit, 1000);
+            assert_eq!(script, vec![0; 8]);
+            assert_eq!(script_data, vec![1, 2]);
+            // Contract inputs are ordered by ID, independently of the order of the programs.
+            let input_ids: Vec<_> = inputs
+                .iter()
+                .filter_map(|input| match input {
+                    Input::Contract { contract_id, .. } => Some(*contract_id),
+                    _ => None,
+                })
+                .collect();
+            assert_eq!(input_ids, vec![b, a]);
+        }
+        _ => panic!("expected a script transaction"),
+    }
+
+    assert!(script_tx(&script, "0xzz", &programs, None).is_err());
+}
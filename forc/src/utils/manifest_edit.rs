@@ -0,0 +1,97 @@
+//! Utilities for editing a project's `Forc.toml` in place while preserving its formatting.
+
+use crate::utils::SWAY_GIT_TAG;
+use anyhow::{anyhow, Context, Result};
+use forc_pkg::{self as pkg, ManifestFile};
+use std::{fs, path::PathBuf};
+use toml_edit::Document;
+
+/// The name of the manifest table in which dependencies are declared.
+pub const DEPENDENCIES_TABLE: &str = "dependencies";
+
+/// Apply `edit` to the `Forc.toml` of the project at `manifest_path` (or the current directory)
+/// and update the project's `Forc.lock` to match.
+///
+/// The edited manifest is written and the build plan is reloaded from it, pinning any new
+/// dependency sources. If either step fails, the original manifest is restored so that an invalid
+/// edit never leaves the project in a broken state.
+pub fn edit_manifest<F>(manifest_path: Option<String>, offline: bool, edit: F) -> Result<()>
+where
+    F: FnOnce(&mut Document) -> Result<()>,
+{
+    let this_dir = match manifest_path {
+        Some(path) => PathBuf::from(path),
+        None => std::env::current_dir()?,
+    };
+    let manifest = ManifestFile::from_dir(&this_dir, SWAY_GIT_TAG)?;
+    let manifest_path = manifest.path().to_path_buf();
+
+    let original = fs::read_to_string(&manifest_path)
+        .with_context(|| format!("failed to read {}", manifest_path.display()))?;
+    let mut doc = original
+        .parse::<Document>()
+        .map_err(|e| anyhow!("failed to parse {}: {}", manifest_path.display(), e))?;
+    edit(&mut doc)?;
+    fs::write(&manifest_path, doc.to_string())
+        .with_context(|| format!("failed to write {}", manifest_path.display()))?;
+
+    let res = ManifestFile::from_dir(manifest.dir(), SWAY_GIT_TAG).and_then(|manifest| {
+        pkg::BuildPlan::load_from_manifest(&manifest, false, offline, SWAY_GIT_TAG)
+    });
+    if let Err(e) = res {
+        fs::write(&manifest_path, original)
+            .with_context(|| format!("failed to restore {}", manifest_path.display()))?;
+        return Err(e);
+    }
+    Ok(())
+}
+
+/// The manifest of the project created by [test_project], prior to any edits.
+#[cfg(test)]
+pub(crate) const TEST_MANIFEST: &str = r#"[project]
+authors = ["Fuel Labs <contact@fuel.sh>"]
+entry = "main.sw"
+license = "Apache-2.0"
+name = "app"
+implicit-std = false
+
+# Libraries used by the app.
+[dependencies]
+"#;
+
+/// Create a script project named `app` within `dir` alongside a library named `lib` that it may
+/// depend upon via `path = "../lib"`, returning the directory of `app`.
+#[cfg(test)]
+pub(crate) fn test_project(dir: &std::path::Path) -> PathBuf {
+    let lib_dir = dir.join("lib");
+    fs::create_dir_all(lib_dir.join("src")).unwrap();
+    let lib_manifest = TEST_MANIFEST
+        .replace("name = \"app\"", "name = \"lib\"")
+        .replace("main.sw", "lib.sw");
+    fs::write(lib_dir.join("Forc.toml"), lib_manifest).unwrap();
+    fs::write(lib_dir.join("src").join("lib.sw"), "library lib;\n").unwrap();
+
+    let app_dir = dir.join("app");
+    fs::create_dir_all(app_dir.join("src")).unwrap();
+    fs::write(app_dir.join("Forc.toml"), TEST_MANIFEST).unwrap();
+    fs::write(
+        app_dir.join("src").join("main.sw"),
+        "script;\n\nfn main() {}\n",
+    )
+    .unwrap();
+    app_dir
+}
+
+#[test]
+fn invalid_edit_restores_manifest() {
+    let dir = tempfile::tempdir().unwrap();
+    let app_dir = test_project(dir.path());
+    let manifest_path = Some(app_dir.to_string_lossy().to_string());
+    let res = edit_manifest(manifest_path, true, |doc| {
+        doc[DEPENDENCIES_TABLE]["missing"]["path"] = toml_edit::value("../missing");
+        Ok(())
+    });
+    assert!(res.is_err());
+    let manifest = fs::read_to_string(app_dir.join("Forc.toml")).unwrap();
+    assert_eq!(manifest, TEST_MANIFEST);
+}
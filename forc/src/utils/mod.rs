@@ -1,6 +1,17 @@
+pub mod build_info;
+pub mod dap;
+pub mod debugger;
 pub mod defaults;
+pub mod gas_profile;
+pub mod local_vm;
+pub mod manifest_edit;
 pub mod parameters;
 pub mod program_type;
+pub mod size_report;
+pub mod source_fns;
+pub mod templates;
+#[cfg(test)]
+pub(crate) mod test_program;
 
 /// The `forc` crate version formatted with the `v` prefix. E.g. "v1.2.3".
 ///
@@ -15,3 +26,18 @@ pub const SWAY_BIN_HASH_SUFFIX: &str = "-bin-hash";
 /// The suffix that helps identify the file which contains the root hash of the binary file created
 /// when predicates are built.
 pub const SWAY_BIN_ROOT_SUFFIX: &str = "-bin-root";
+
+/// The suffix that helps identify the file which contains the ID of the contract created when
+/// contracts are built.
+pub const SWAY_CONTRACT_ID_SUFFIX: &str = "-contract-id";
+
+/// The suffix that helps identify the file which contains the bytecode size report created when
+/// building with `--size-report`.
+pub const SWAY_SIZE_REPORT_SUFFIX: &str = "-size-report";
+
+/// The suffix that helps identify the file which records the build profile settings used to
+/// produce the other build artifacts.
+pub const SWAY_BUILD_PROFILE_SUFFIX: &str = "-build-profile";
+
+/// The suffix that helps identify the folded stack file created by `forc profile`.
+pub const SWAY_GAS_PROFILE_SUFFIX: &str = "-gas-profile";
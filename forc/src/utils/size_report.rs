@@ -0,0 +1,260 @@
+use crate::utils::source_fns::SourceFns;
+use forc_util::git_checkouts_directory;
+use serde::Serialize;
+use std::{
+    collections::HashMap,
+    path::{Path, PathBuf},
+};
+use sway_core::{bytecode_layout::BytecodeLayout, source_map::SourceMap};
+use term_table::{
+    row::Row,
+    table_cell::{Alignment, TableCell},
+};
+
+/// The name under which instructions preceding the first entry point are reported.
+///
+/// This includes the preamble and, for contracts, the ABI selector switch.
+pub(crate) const PREAMBLE: &str = "<preamble>";
+
+/// The name under which instructions and data without a source location are reported.
+pub(crate) const NO_SOURCE: &str = "<compiler generated>";
+
+/// The size of the compiled bytecode, broken down by entry point and by source function.
+#[derive(Debug, Serialize)]
+pub struct SizeReport {
+    /// The total size of the bytecode in bytes.
+    pub bytecode_size: usize,
+    /// The size of the instructions in bytes.
+    pub instructions_size: usize,
+    /// The size of the data section in bytes.
+    pub data_section_size: usize,
+    /// The size attributed to each entry point, largest first.
+    pub entry_points: Vec<SizeEntry>,
+    /// The size attributed to each source function (including those that were inlined), largest
+    /// first.
+    pub functions: Vec<SizeEntry>,
+}
+
+/// The number of bytes attributed to a single entry point or function.
+#[derive(Debug, Default, Serialize)]
+pub struct SizeEntry {
+    pub name: String,
+    /// The location of the function's declaration as `path:line`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub location: Option<String>,
+    /// Bytes of instructions.
+    pub instructions: usize,
+    /// Bytes of data section values loaded by those instructions.
+    pub data: usize,
+}
+
+impl SizeEntry {
+    pub fn total(&self) -> usize {
+        self.instructions + self.data
+    }
+}
+
+impl SizeReport {
+    /// Attribute every instruction and data section value within the `bytecode` to its entry point
+    /// and the source function from which it was generated.
+    ///
+    /// Data section values are attributed to the first instruction that loads them. Paths within
+    /// `project_dir` are reported relative to it.
+    pub fn new(
+        bytecode: &[u8],
+        layout: &BytecodeLayout,
+        source_map: &SourceMap,
+        project_dir: &Path,
+    ) -> Self {
+        const INSTRUCTION_SIZE: usize = 4;
+        let mut source_fns = SourceFns::new();
+        let mut entry_points: HashMap<String, SizeEntry> = HashMap::new();
+        let mut functions: HashMap<(String, Option<String>), SizeEntry> = HashMap::new();
+
+        // Find the entry point and the function to which the instruction at `ix` belongs.
+        let mut attribute = |ix: usize| {
+            let entry_name = layout
+                .entry_point_at(ix)
+                .map(|entry| entry.name.clone())
+                .unwrap_or_else(|| PREAMBLE.to_string());
+            let function = source_map
+                .addr_to_span(ix)
+                .and_then(|(path, range)| source_fns.enclosing(&path, range).cloned());
+            let function_key = match function {
+                Some(f) => {
+                    let location = format!("{}:{}", display_path(&f.path, project_dir), f.line);
+                    (f.name, Some(location))
+                }
+                None => (NO_SOURCE.to_string(), None),
+            };
+            (entry_name, function_key)
+        };
+
+        let num_instructions = layout.data_section_offset / INSTRUCTION_SIZE;
+        let mut add = |ix: usize, instructions: usize, data: usize| {
+            let (entry_name, (fn_name, location)) = attribute(ix);
+            let entry = entry_points
+                .entry(entry_name.clone())
+                .or_insert_with(|| SizeEntry {
+                    name: entry_name,
+                    ..Default::default()
+                });
+            entry.instructions += instructions;
+            entry.data += data;
+            let function = functions
+                .entry((fn_name.clone(), location.clone()))
+                .or_insert_with(|| SizeEntry {
+                    name: fn_name,
+                    location,
+                    ..Default::default()
+                });
+            function.instructions += instructions;
+            function.data += data;
+        };
+        for ix in 0..num_instructions {
+            add(ix, INSTRUCTION_SIZE, 0);
+        }
+        for value in &layout.data {
+            match value.loaded_by.first() {
+                Some(&ix) => add(ix, 0, value.len),
+                // Values that are never loaded are attributed to the preamble.
+                None => add(0, 0, value.len),
+            }
+        }
+
+        Self {
+            bytecode_size: bytecode.len(),
+            instructions_size: layout.data_section_offset,
+            data_section_size: bytecode.len() - layout.data_section_offset,
+            entry_points: sorted_by_size(entry_points.into_values()),
+            functions: sorted_by_size(functions.into_values()),
+        }
+    }
+
+    /// Render the report as a pair of tables, one for entry points and one for functions.
+    pub fn render_table(&self) -> String {
+        let mut out = format!(
+            "  Bytecode size: {} bytes ({} bytes of instructions, {} bytes of data)\n",
+            self.bytecode_size, self.instructions_size, self.data_section_size,
+        );
+        out.push_str(&self.table("entry point", &self.entry_points).render());
+        out.push_str(&self.table("function", &self.functions).render());
+        out
+    }
+
+    fn table(&self, kind: &str, entries: &[SizeEntry]) -> term_table::Table<'_> {
+        let mut table = term_table::Table::new();
+        table.separate_rows = false;
+        table.style = term_table::TableStyle::thin();
+        // Entry points have no location of their own, so only show the column when it's needed.
+        let show_location = entries.iter().any(|entry| entry.location.is_some());
+        let mut header = vec![TableCell::new(kind)];
+        if show_location {
+            header.push(TableCell::new("location"));
+        }
+        header.extend(["instructions", "data", "total", "%"].map(TableCell::new));
+        table.add_row(Row::new(header));
+        for entry in entries {
+            let percentage = match self.bytecode_size {
+                0 => 0.0,
+                size => entry.total() as f64 * 100.0 / size as f64,
+            };
+            let mut row = vec![TableCell::new(&entry.name)];
+            if show_location {
+                row.push(TableCell::new(entry.location.as_deref().unwrap_or("")));
+            }
+            row.extend([
+                TableCell::new_with_alignment(entry.instructions, 1, Alignment::Right),
+                TableCell::new_with_alignment(entry.data, 1, Alignment::Right),
+                TableCell::new_with_alignment(entry.total(), 1, Alignment::Right),
+                TableCell::new_with_alignment(format!("{:.1}", percentage), 1, Alignment::Right),
+            ]);
+            table.add_row(Row::new(row));
+        }
+        table
+    }
+}
+
+fn sorted_by_size(entries: impl Iterator<Item = SizeEntry>) -> Vec<SizeEntry> {
+    let mut entries: Vec<_> = entries.collect();
+    entries.sort_by(|a, b| {
+        b.total()
+            .cmp(&a.total())
+            .then_with(|| a.name.cmp(&b.name))
+            .then_with(|| a.location.cmp(&b.location))
+    });
+    entries
+}
+
+/// Shorten paths within the project or the git checkouts directory for display.
+pub(crate) fn display_path(path: &Path, project_dir: &Path) -> String {
+    let checkouts = git_checkouts_directory();
+    let path: PathBuf = match path.strip_prefix(project_dir) {
+        Ok(relative) => relative.to_path_buf(),
+        Err(_) => path.strip_prefix(&checkouts).unwrap_or(path).to_path_buf(),
+    };
+    path.display().to_string()
+}
+
+#[test]
+fn attribute_size_to_entry_points_and_functions() {
+    use crate::utils::test_program::{main_entry_point, TestScript};
+    use sway_core::bytecode_layout::DataSectionEntry;
+
+    let dir = tempfile::tempdir().unwrap();
+    let script = TestScript::new(dir.path());
+    let span_of = |pattern| script.span_of(pattern);
+    let mut source_map = SourceMap::new();
+    // Instruction 0 has no source, 1 and 3 come from `helper` (the latter inlined into `main`).
+    source_map.insert(1, &span_of("1\n"));
+    source_map.insert(2, &span_of("helper()\n"));
+    source_map.insert(3, &span_of("1\n"));
+    source_map.insert(4, &span_of("helper()\n"));
+
+    let layout = BytecodeLayout {
+        entry_points: vec![main_entry_point(2)],
+        data_section_offset: 20,
+        data: vec![
+            DataSectionEntry {
+                offset: 0,
+                len: 8,
+                loaded_by: vec![3],
+            },
+            // Never loaded, so attributed to the preamble.
+            DataSectionEntry {
+                offset: 8,
+                len: 8,
+                loaded_by: vec![],
+            },
+        ],
+    };
+    let bytecode = vec![0; 36];
+    let report = SizeReport::new(&bytecode, &layout, &source_map, dir.path());
+
+    assert_eq!(report.bytecode_size, 36);
+    assert_eq!(report.instructions_size, 20);
+    assert_eq!(report.data_section_size, 16);
+
+    let sizes = |entries: &[SizeEntry]| {
+        entries
+            .iter()
+            .map(|e| (e.name.clone(), e.location.clone(), e.instructions, e.data))
+            .collect::<Vec<_>>()
+    };
+    let main_sw = Path::new("src").join("main.sw").display().to_string();
+    assert_eq!(
+        sizes(&report.entry_points),
+        vec![
+            ("main".to_string(), None, 12, 8),
+            (PREAMBLE.to_string(), None, 8, 8),
+        ]
+    );
+    assert_eq!(
+        sizes(&report.functions),
+        vec![
+            ("helper".to_string(), Some(format!("{}:3", main_sw)), 8, 8),
+            (NO_SOURCE.to_string(), None, 4, 8),
+            ("main".to_string(), Some(format!("{}:7", main_sw)), 8, 0),
+        ]
+    );
+}
@@ -0,0 +1,204 @@
+use std::{
+    collections::HashMap,
+    ops::Range,
+    path::{Path, PathBuf},
+    sync::Arc,
+};
+use sway_core::source_map::LocationRange;
+use sway_parse::{ItemFn, ItemImplItem, ItemKind};
+use sway_types::Spanned;
+
+/// A function declared within a Sway source file.
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct SourceFn {
+    /// The name of the function, qualified by the `impl`, `trait` or `abi` in which it is declared.
+    /// E.g. `Foo::bar` or `<MyAbi for Contract>::baz`.
+    pub name: String,
+    /// The file in which the function is declared.
+    pub path: PathBuf,
+    /// The line on which the declaration begins, starting from 1.
+    pub line: usize,
+    /// The byte range of the declaration within the file.
+    pub range: Range<usize>,
+}
+
+/// Maps ranges of source code to the functions and lines that contain them.
+///
+/// Files are parsed the first time a location within them is requested.
+#[derive(Default)]
+pub struct SourceFns {
+    files: HashMap<PathBuf, SourceFile>,
+}
+
+/// The functions and the offsets of the lines within a single source file.
+#[derive(Default)]
+struct SourceFile {
+    fns: Vec<SourceFn>,
+    /// The byte offset at which each line begins.
+    line_starts: Vec<usize>,
+}
+
+impl SourceFns {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Find the innermost function whose declaration contains the given range of the file at
+    /// `path`.
+    ///
+    /// Returns `None` if the range lies outside of all functions, or if the file can no longer be
+    /// read or parsed.
+    pub fn enclosing(&mut self, path: &Path, range: LocationRange) -> Option<&SourceFn> {
+        self.file(path)
+            .fns
+            .iter()
+            .filter(|f| f.range.start <= range.start && range.end <= f.range.end)
+            .min_by_key(|f| f.range.len())
+    }
+
+    /// The line containing the given byte offset of the file at `path`, starting from 1.
+    ///
+    /// Returns `None` if the file can no longer be read.
+    pub fn line(&mut self, path: &Path, offset: usize) -> Option<usize> {
+        match self.file(path).line_starts.binary_search(&offset) {
+            Ok(ix) => Some(ix + 1),
+            Err(0) => None,
+            Err(ix) => Some(ix),
+        }
+    }
+
+    fn file(&mut self, path: &Path) -> &SourceFile {
+        self.files
+            .entry(path.to_path_buf())
+            .or_insert_with(|| read_source_file(path).unwrap_or_default())
+    }
+}
+
+/// Read the file at the given path and collect the functions declared within it.
+///
+/// If the file cannot be parsed, only its lines are collected.
+fn read_source_file(path: &Path) -> Option<SourceFile> {
+    let src = std::fs::read_to_string(path).ok()?;
+    let line_starts = std::iter::once(0)
+        .chain(src.match_indices('\n').map(|(ix, _)| ix + 1))
+        .collect();
+    let fns = parse_fns(path, Arc::from(src)).unwrap_or_default();
+    Some(SourceFile { fns, line_starts })
+}
+
+/// Collect all functions declared within the given source of the file at `path`.
+fn parse_fns(path: &Path, src: Arc<str>) -> Option<Vec<SourceFn>> {
+    let module = sway_parse::parse_file(src, Some(Arc::new(path.to_path_buf()))).ok()?;
+    let mut fns = vec![];
+    let mut push_fn = |qualifier: Option<&str>, item_fn: &ItemFn| {
+        let name = item_fn.fn_signature.name.as_str();
+        let name = match qualifier {
+            Some(qualifier) => format!("{}::{}", qualifier, name),
+            None => name.to_string(),
+        };
+        let span = item_fn.span();
+        fns.push(SourceFn {
+            name,
+            path: path.to_path_buf(),
+            line: span.start_pos().line_col().0,
+            range: span.start()..span.end(),
+        });
+    };
+    for item in &module.items {
+        match &item.value {
+            ItemKind::Fn(item_fn) => push_fn(None, item_fn),
+            ItemKind::Impl(item_impl) => {
+                let ty = item_impl.ty.span();
+                let qualifier = match &item_impl.trait_opt {
+                    Some((trait_path, _)) => {
+                        format!("<{} for {}>", trait_path.span().as_str(), ty.as_str())
+                    }
+                    None => ty.as_str().to_string(),
+                };
+                for item in item_impl.contents.get() {
+                    if let ItemImplItem::Fn(item_fn) = &item.value {
+                        push_fn(Some(&qualifier), item_fn);
+                    }
+                }
+            }
+            ItemKind::Trait(item_trait) => {
+                for item_fn in item_trait.trait_defs_opt.iter().flat_map(|defs| defs.get()) {
+                    push_fn(Some(item_trait.name.as_str()), &item_fn.value);
+                }
+            }
+            ItemKind::Abi(item_abi) => {
+                for item_fn in item_abi.abi_defs_opt.iter().flat_map(|defs| defs.get()) {
+                    push_fn(Some(item_abi.name.as_str()), &item_fn.value);
+                }
+            }
+            _ => (),
+        }
+    }
+    Some(fns)
+}
+
+#[cfg(test)]
+const TEST_SRC: &str = r#"script;
+
+fn helper() -> u64 {
+    1
+}
+
+impl Foo {
+    fn bar() {}
+}
+
+impl MyTrait for Foo {
+    fn baz() {}
+}
+
+fn main() -> u64 {
+    helper()
+}
+"#;
+
+#[test]
+fn parse_qualified_fns() {
+    let path = Path::new("/project/src/main.sw");
+    let fns = parse_fns(path, Arc::from(TEST_SRC)).unwrap();
+    let names_and_lines: Vec<_> = fns.iter().map(|f| (f.name.as_str(), f.line)).collect();
+    assert_eq!(
+        names_and_lines,
+        vec![
+            ("helper", 3),
+            ("Foo::bar", 8),
+            ("<MyTrait for Foo>::baz", 12),
+            ("main", 15),
+        ]
+    );
+    assert!(fns.iter().all(|f| f.path == path));
+}
+
+#[test]
+fn map_ranges_to_fns_and_lines() {
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("main.sw");
+    std::fs::write(&path, TEST_SRC).unwrap();
+    let mut source_fns = SourceFns::new();
+
+    let call = TEST_SRC.find("helper()\n").unwrap();
+    let range = LocationRange {
+        start: call,
+        end: call + "helper()".len(),
+    };
+    assert_eq!(source_fns.enclosing(&path, range).unwrap().name, "main");
+    assert_eq!(source_fns.line(&path, call), Some(16));
+
+    let literal = TEST_SRC.find("    1\n").unwrap() + 4;
+    let range = LocationRange {
+        start: literal,
+        end: literal + 1,
+    };
+    assert_eq!(source_fns.enclosing(&path, range).unwrap().name, "helper");
+    assert_eq!(source_fns.line(&path, literal), Some(4));
+
+    // The `script;` declaration lies outside of every function.
+    let range = LocationRange { start: 0, end: 7 };
+    assert!(source_fns.enclosing(&path, range).is_none());
+    assert_eq!(source_fns.line(&path, 0), Some(1));
+}
@@ -0,0 +1,141 @@
+//! The templates that `forc init` can create projects from.
+//!
+//! The built-in templates live in the `templates` directory of the `forc` crate and are embedded
+//! within the binary. Any file of a template may contain the placeholders described by
+//! [Placeholders], which are substituted when a project is created from it.
+
+/// A single file of a built-in template.
+pub(crate) struct TemplateFile {
+    /// The path of the file relative to the root of the template.
+    pub path: &'static str,
+    pub contents: &'static str,
+}
+
+/// A template embedded within `forc`.
+pub(crate) struct Template {
+    pub name: &'static str,
+    pub description: &'static str,
+    pub files: &'static [TemplateFile],
+}
+
+macro_rules! template_files {
+    ($template:literal: $($path:literal),* $(,)?) => {
+        &[$(TemplateFile {
+            path: $path,
+            contents: include_str!(concat!("../../templates/", $template, "/", $path)),
+        }),*]
+    };
+}
+
+/// The templates that may be selected by name with `forc init --template <name>`.
+pub(crate) const BUILTIN_TEMPLATES: &[Template] = &[
+    Template {
+        name: "token",
+        description: "a contract minting and burning a native token",
+        files: template_files!("token": ".gitignore", "Forc.toml", "src/main.sw"),
+    },
+    Template {
+        name: "multisig",
+        description: "a contract wallet requiring the signatures of two of its three owners",
+        files: template_files!("multisig": ".gitignore", "Forc.toml", "src/main.sw"),
+    },
+    Template {
+        name: "library",
+        description: "a library, tested through a contract exposing it",
+        files: template_files!(
+            "library":
+            ".gitignore",
+            "Forc.toml",
+            "src/lib.sw",
+            "test_contract/Forc.toml",
+            "test_contract/src/main.sw",
+            "tests/harness.rs",
+        ),
+    },
+];
+
+/// The template used by `forc init --workspace`.
+pub(crate) const WORKSPACE_TEMPLATE: Template = Template {
+    name: "workspace",
+    description: "an ABI library, a contract implementing it and a script calling the contract",
+    files: template_files!(
+        "workspace":
+        ".gitignore",
+        "abi/Forc.toml",
+        "abi/src/lib.sw",
+        "contract/Forc.toml",
+        "contract/src/main.sw",
+        "script/Forc.toml",
+        "script/src/main.sw",
+    ),
+};
+
+/// Find the built-in template with the given name.
+pub(crate) fn builtin_template(name: &str) -> Option<&'static Template> {
+    BUILTIN_TEMPLATES
+        .iter()
+        .find(|template| template.name == name)
+}
+
+/// The values substituted for the placeholders within template files.
+///
+/// - `{{project-name}}` is replaced with the name of the project.
+/// - `{{project_name}}` is replaced with the name of the project in snake case, for use in Sway.
+/// - `{{authors}}` is replaced with the author of the project.
+/// - `{{license}}` is replaced with the license of the project.
+pub(crate) struct Placeholders {
+    pub project_name: String,
+    pub authors: String,
+    pub license: String,
+}
+
+impl Placeholders {
+    /// Replace all placeholders within `contents`.
+    pub(crate) fn substitute(&self, contents: &str) -> String {
+        contents
+            .replace("{{project-name}}", &self.project_name)
+            .replace(
+                "{{project_name}}",
+                &forc_util::kebab_to_snake_case(&self.project_name),
+            )
+            .replace("{{authors}}", &self.authors)
+            .replace("{{license}}", &self.license)
+    }
+}
+
+#[test]
+fn substitute_placeholders() {
+    let placeholders = Placeholders {
+        project_name: "my-token".to_string(),
+        authors: "Alice".to_string(),
+        license: "MIT".to_string(),
+    };
+    assert_eq!(
+        placeholders.substitute("name = \"{{project-name}}\"\nlibrary {{project_name}};"),
+        "name = \"my-token\"\nlibrary my_token;"
+    );
+    assert_eq!(
+        placeholders.substitute("authors = [\"{{authors}}\"]\nlicense = \"{{license}}\""),
+        "authors = [\"Alice\"]\nlicense = \"MIT\""
+    );
+}
+
+#[test]
+fn parse_builtin_template_manifests() {
+    let placeholders = Placeholders {
+        project_name: "test-proj".to_string(),
+        authors: "Alice".to_string(),
+        license: "Apache-2.0".to_string(),
+    };
+    let templates = BUILTIN_TEMPLATES.iter().chain(Some(&WORKSPACE_TEMPLATE));
+    for file in templates.flat_map(|template| template.files) {
+        if file
+            .path
+            .ends_with(sway_utils::constants::MANIFEST_FILE_NAME)
+        {
+            let manifest: forc_pkg::Manifest =
+                toml::from_str(&placeholders.substitute(file.contents)).unwrap();
+            manifest.validate().unwrap();
+        }
+    }
+}
@@ -0,0 +1,65 @@
+//! Fixtures shared by the tests of the size report, gas profiler, debugger and local VM.
+
+use forc_pkg::Compiled;
+use std::{
+    path::{Path, PathBuf},
+    sync::Arc,
+};
+use sway_core::{
+    bytecode_layout::{BytecodeLayout, EntryPoint},
+    TreeType,
+};
+use sway_types::Span;
+
+/// A script in which `main` (at line 7) calls `helper` (at line 3), returning `1` at line 4.
+pub(crate) const SCRIPT_SRC: &str =
+    "script;\n\nfn helper() -> u64 {\n    1\n}\n\nfn main() -> u64 {\n    helper()\n}\n";
+
+/// The `SCRIPT_SRC` script, written to `src/main.sw` within a project directory.
+pub(crate) struct TestScript {
+    path: PathBuf,
+}
+
+impl TestScript {
+    /// Write the script to `src/main.sw` within `project_dir`.
+    pub(crate) fn new(project_dir: &Path) -> Self {
+        let path = project_dir.join("src").join("main.sw");
+        std::fs::create_dir_all(path.parent().unwrap()).unwrap();
+        std::fs::write(&path, SCRIPT_SRC).unwrap();
+        Self { path }
+    }
+
+    /// The span of the first occurrence of `pattern` within the script.
+    pub(crate) fn span_of(&self, pattern: &str) -> Span {
+        let start = SCRIPT_SRC.find(pattern).unwrap();
+        let path = Some(Arc::new(self.path.clone()));
+        Span::new(Arc::from(SCRIPT_SRC), start, start + pattern.len(), path).unwrap()
+    }
+}
+
+/// A compiled program of `bytecode_len` bytes of instructions, without a data section.
+pub(crate) fn compiled(
+    tree_type: TreeType,
+    bytecode_len: usize,
+    entry_points: Vec<EntryPoint>,
+) -> Compiled {
+    Compiled {
+        json_abi: Default::default(),
+        storage_slots: vec![],
+        bytecode: vec![0; bytecode_len],
+        bytecode_layout: BytecodeLayout {
+            entry_points,
+            data_section_offset: bytecode_len,
+            data: vec![],
+        },
+        tree_type,
+    }
+}
+
+/// The entry point of `main` at the given instruction.
+pub(crate) fn main_entry_point(start: usize) -> EntryPoint {
+    EntryPoint {
+        name: "main".to_string(),
+        start,
+    }
+}
@@ -0,0 +1,6 @@
+library {{project_name}};
+
+/// Returns the larger of `a` and `b`.
+pub fn max(a: u64, b: u64) -> u64 {
+    if a > b { a } else { b }
+}
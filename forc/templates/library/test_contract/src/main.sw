@@ -0,0 +1,14 @@
+contract;
+
+use {{project_name}}::max;
+
+/// Exposes the library to the tests in `tests/harness.rs`.
+abi TestContract {
+    fn test_max(a: u64, b: u64) -> u64;
+}
+
+impl TestContract for Contract {
+    fn test_max(a: u64, b: u64) -> u64 {
+        max(a, b)
+    }
+}
@@ -0,0 +1,35 @@
+use fuels::prelude::*;
+use fuels_abigen_macro::abigen;
+
+// Libraries cannot be deployed, so the library is tested through `test_contract`. Build it with
+// `forc build -p test_contract` before running the tests.
+abigen!(
+    TestContract,
+    "test_contract/out/debug/test_contract-abi.json"
+);
+
+async fn get_test_contract_instance() -> TestContract {
+    // Launch a local network and deploy the test contract
+    let wallet = launch_provider_and_get_single_wallet().await;
+
+    let id = Contract::deploy(
+        "./test_contract/out/debug/test_contract.bin",
+        &wallet,
+        TxParameters::default(),
+    )
+    .await
+    .unwrap();
+
+    TestContract::new(id.to_string(), wallet)
+}
+
+#[tokio::test]
+async fn max_returns_the_larger_value() {
+    let instance = get_test_contract_instance().await;
+
+    let result = instance.test_max(1, 2).call().await.unwrap();
+    assert_eq!(result.value, 2);
+
+    let result = instance.test_max(2, 1).call().await.unwrap();
+    assert_eq!(result.value, 2);
+}
@@ -0,0 +1,67 @@
+contract;
+
+use std::{
+    address::Address,
+    assert::require,
+    b512::B512,
+    contract_id::ContractId,
+    ecr::ec_recover_address,
+    hash::sha256,
+    result::*,
+    token::transfer_to_output,
+};
+
+/// The owners of the wallet. Replace them with your own addresses.
+const OWNER_1: b256 = 0x0000000000000000000000000000000000000000000000000000000000000001;
+const OWNER_2: b256 = 0x0000000000000000000000000000000000000000000000000000000000000002;
+const OWNER_3: b256 = 0x0000000000000000000000000000000000000000000000000000000000000003;
+
+storage {
+    nonce: u64,
+}
+
+abi MultiSig {
+    /// Transfer `amount` of `asset_id` to `recipient`.
+    ///
+    /// The transfer must be signed by two different owners, each signing the hash returned by
+    /// `transfer_hash` for the same arguments.
+    #[storage(read, write)]
+    fn transfer(recipient: Address, asset_id: ContractId, amount: u64, signatures: [B512; 2]);
+
+    /// The hash that owners sign to approve a transfer. It changes after every transfer, so that
+    /// signatures cannot be replayed.
+    #[storage(read)]
+    fn transfer_hash(recipient: Address, asset_id: ContractId, amount: u64) -> b256;
+}
+
+impl MultiSig for Contract {
+    #[storage(read, write)]
+    fn transfer(recipient: Address, asset_id: ContractId, amount: u64, signatures: [B512; 2]) {
+        let hash = sha256((storage.nonce, recipient.value, asset_id.value, amount));
+        let first = owner_index(ec_recover_address(signatures[0], hash).unwrap());
+        let second = owner_index(ec_recover_address(signatures[1], hash).unwrap());
+        require(first != 0 && second != 0, "signer is not an owner");
+        require(first != second, "owners must be different");
+
+        storage.nonce = storage.nonce + 1;
+        transfer_to_output(amount, asset_id, recipient);
+    }
+
+    #[storage(read)]
+    fn transfer_hash(recipient: Address, asset_id: ContractId, amount: u64) -> b256 {
+        sha256((storage.nonce, recipient.value, asset_id.value, amount))
+    }
+}
+
+/// The position of `address` among the owners, starting from 1, or 0 if it is not an owner.
+fn owner_index(address: Address) -> u64 {
+    if address.value == OWNER_1 {
+        1
+    } else if address.value == OWNER_2 {
+        2
+    } else if address.value == OWNER_3 {
+        3
+    } else {
+        0
+    }
+}
@@ -0,0 +1,57 @@
+contract;
+
+use std::{
+    address::Address,
+    assert::require,
+    chain::auth::msg_sender,
+    context::{call_frames::{contract_id, msg_asset_id}, msg_amount},
+    identity::Identity,
+    result::*,
+    token::{burn, mint_to},
+};
+
+/// The address allowed to mint new tokens. Replace it with your own address.
+const MINTER: b256 = 0x0000000000000000000000000000000000000000000000000000000000000000;
+
+storage {
+    total_supply: u64,
+}
+
+abi Token {
+    /// Mint `amount` tokens to `recipient`. Only the minter may mint tokens.
+    #[storage(read, write)]
+    fn mint(amount: u64, recipient: Identity);
+
+    /// Burn the tokens forwarded with the call.
+    #[storage(read, write)]
+    fn burn();
+
+    /// The number of tokens in circulation.
+    #[storage(read)]
+    fn total_supply() -> u64;
+}
+
+impl Token for Contract {
+    #[storage(read, write)]
+    fn mint(amount: u64, recipient: Identity) {
+        let sender = msg_sender().unwrap();
+        require(sender == Identity::Address(~Address::from(MINTER)), "sender is not the minter");
+
+        storage.total_supply = storage.total_supply + amount;
+        mint_to(amount, recipient);
+    }
+
+    #[storage(read, write)]
+    fn burn() {
+        require(msg_asset_id().value == contract_id().value, "only this token can be burned");
+
+        let amount = msg_amount();
+        storage.total_supply = storage.total_supply - amount;
+        burn(amount);
+    }
+
+    #[storage(read)]
+    fn total_supply() -> u64 {
+        storage.total_supply
+    }
+}
@@ -0,0 +1,7 @@
+library {{project_name}}_abi;
+
+abi Counter {
+    /// Increment the counter and return its new value.
+    #[storage(read, write)]
+    fn increment() -> u64;
+}
@@ -0,0 +1,15 @@
+contract;
+
+use {{project_name}}_abi::Counter;
+
+storage {
+    count: u64,
+}
+
+impl Counter for Contract {
+    #[storage(read, write)]
+    fn increment() -> u64 {
+        storage.count = storage.count + 1;
+        storage.count
+    }
+}
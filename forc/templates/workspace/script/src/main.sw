@@ -0,0 +1,11 @@
+script;
+
+use {{project_name}}_abi::Counter;
+
+// The ID of the contract is generated from the `[contract-dependencies]` in `Forc.toml`.
+use {{project_name}}_contract::CONTRACT_ID;
+
+fn main() -> u64 {
+    let counter = abi(Counter, CONTRACT_ID);
+    counter.increment()
+}
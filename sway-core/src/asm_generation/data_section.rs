@@ -80,6 +80,13 @@ impl fmt::Display for DataSection {
                 Literal::U16(num) => format!(".u16 {:#04x}", num),
                 Literal::U32(num) => format!(".u32 {:#04x}", num),
                 Literal::U64(num) => format!(".u64 {:#04x}", num),
+                Literal::U256(b) => format!(
+                    ".u256 0x{}",
+                    b.iter()
+                        .map(|x| format!("{:02x}", x))
+                        .collect::<Vec<_>>()
+                        .join("")
+                ),
                 Literal::Numeric(num) => format!(".u64 {:#04x}", num),
                 Literal::Boolean(b) => format!(".bool {}", if *b { "0x01" } else { "0x00" }),
                 Literal::String(st) => format!(".str \"{}\"", st.as_str()),
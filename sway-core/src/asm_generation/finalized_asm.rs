@@ -1,5 +1,7 @@
-use super::{DataSection, InstructionSet};
+use super::{DataId, DataSection, InstructionSet, PREAMBLE_LEN};
 use crate::asm_lang::allocated_ops::AllocatedOpcode;
+use crate::bytecode_layout::{BytecodeLayout, DataSectionEntry, EntryPoint};
+use crate::constants::DEFAULT_ENTRY_POINT_FN_NAME;
 use crate::error::*;
 use crate::source_map::SourceMap;
 
@@ -16,6 +18,8 @@ pub enum FinalizedAsm {
     ContractAbi {
         data_section: DataSection,
         program_section: InstructionSet,
+        /// The names of the ABI methods, in the order in which they appear in the selector switch.
+        entry_fn_names: Vec<String>,
     },
     ScriptMain {
         data_section: DataSection,
@@ -30,27 +34,100 @@ pub enum FinalizedAsm {
 }
 
 impl FinalizedAsm {
-    pub(crate) fn to_bytecode_mut(&mut self, source_map: &mut SourceMap) -> CompileResult<Vec<u8>> {
+    /// Detaches every op from the source span it was generated from.
+    pub(crate) fn clear_spans(&mut self) {
+        use FinalizedAsm::*;
+        match self {
+            ContractAbi {
+                program_section, ..
+            }
+            | ScriptMain {
+                program_section, ..
+            }
+            | PredicateMain {
+                program_section, ..
+            } => {
+                for op in program_section.ops.iter_mut() {
+                    op.owning_span = None;
+                }
+            }
+            Library => (),
+        }
+    }
+
+    pub(crate) fn to_bytecode_mut(
+        &mut self,
+        source_map: &mut SourceMap,
+    ) -> CompileResult<(Vec<u8>, BytecodeLayout)> {
         use FinalizedAsm::*;
         match self {
             ContractAbi {
                 program_section,
                 ref mut data_section,
-            } => to_bytecode_mut(program_section, data_section, source_map),
+                entry_fn_names,
+            } => {
+                let entry_points = contract_entry_points(program_section, entry_fn_names);
+                to_bytecode_mut(program_section, data_section, entry_points, source_map)
+            }
             // libraries are not compiled to asm
-            Library => ok(vec![], vec![], vec![]),
+            Library => ok((vec![], BytecodeLayout::default()), vec![], vec![]),
             ScriptMain {
                 program_section,
                 ref mut data_section,
-            } => to_bytecode_mut(program_section, data_section, source_map),
+            } => to_bytecode_mut(
+                program_section,
+                data_section,
+                main_entry_point(),
+                source_map,
+            ),
             PredicateMain {
                 program_section,
                 ref mut data_section,
-            } => to_bytecode_mut(program_section, data_section, source_map),
+            } => to_bytecode_mut(
+                program_section,
+                data_section,
+                main_entry_point(),
+                source_map,
+            ),
         }
     }
 }
 
+/// Scripts and predicates begin executing `main` immediately after the preamble.
+fn main_entry_point() -> Vec<EntryPoint> {
+    vec![EntryPoint {
+        name: DEFAULT_ENTRY_POINT_FN_NAME.to_string(),
+        start: PREAMBLE_LEN,
+    }]
+}
+
+/// Each contract ABI method begins at the target of its jump within the selector switch.
+///
+/// The selector switch directly follows the preamble and contains one `JNZI` per ABI method in the
+/// order given by `entry_fn_names`, so the first of these jumps are those of the switch.
+fn contract_entry_points(
+    program_section: &InstructionSet,
+    entry_fn_names: &[String],
+) -> Vec<EntryPoint> {
+    let switch_targets = program_section
+        .ops
+        .iter()
+        .filter_map(|op| match &op.opcode {
+            AllocatedOpcode::JNZI(_, imm) => Some(imm.value as usize),
+            _ => None,
+        });
+    let mut entry_points: Vec<_> = entry_fn_names
+        .iter()
+        .zip(switch_targets)
+        .map(|(name, start)| EntryPoint {
+            name: name.clone(),
+            start,
+        })
+        .collect();
+    entry_points.sort_by_key(|entry| entry.start);
+    entry_points
+}
+
 impl fmt::Display for FinalizedAsm {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
@@ -65,6 +142,7 @@ impl fmt::Display for FinalizedAsm {
             FinalizedAsm::ContractAbi {
                 program_section,
                 data_section,
+                ..
             } => write!(f, "{}\n{}", program_section, data_section),
             // Libraries do not directly generate any asm.
             FinalizedAsm::Library => write!(f, ""),
@@ -75,8 +153,9 @@ impl fmt::Display for FinalizedAsm {
 fn to_bytecode_mut(
     program_section: &InstructionSet,
     data_section: &mut DataSection,
+    entry_points: Vec<EntryPoint>,
     source_map: &mut SourceMap,
-) -> CompileResult<Vec<u8>> {
+) -> CompileResult<(Vec<u8>, BytecodeLayout)> {
     let mut errors = vec![];
     if program_section.ops.len() & 1 != 0 {
         tracing::info!("ops len: {}", program_section.ops.len());
@@ -112,10 +191,26 @@ fn to_bytecode_mut(
     // each op is four bytes, so the length of the buf is the number of ops times four.
     let mut buf = vec![0; (program_section.ops.len() * 4) + 4];
 
+    // The indices of the instructions loading each value from the data section.
+    let mut data_loaded_by: Vec<Vec<usize>> = vec![vec![]; data_section.value_pairs.len()];
+
     let mut half_word_ix = 0;
     for op in program_section.ops.iter() {
         let span = op.owning_span.clone();
+        let data_len_before = data_section.value_pairs.len();
+        let loaded_data_id = match &op.opcode {
+            AllocatedOpcode::LWDataId(_, DataId(id)) => Some(*id as usize),
+            _ => None,
+        };
         let op = op.to_fuel_asm(offset_to_data_section_in_bytes, data_section);
+        // Loading a value larger than a word may append a pointer to it to the data section.
+        data_loaded_by.resize(data_section.value_pairs.len(), vec![]);
+        for id in loaded_data_id
+            .into_iter()
+            .chain(data_len_before..data_section.value_pairs.len())
+        {
+            data_loaded_by[id].push(half_word_ix);
+        }
         match op {
             Either::Right(data) => {
                 for i in 0..data.len() {
@@ -139,9 +234,26 @@ fn to_bytecode_mut(
         }
     }
 
+    let mut data = Vec::with_capacity(data_section.value_pairs.len());
+    let mut data_offset = 0;
+    for (value, loaded_by) in data_section.value_pairs.iter().zip(data_loaded_by) {
+        let len = value.to_bytes().len();
+        data.push(DataSectionEntry {
+            offset: data_offset,
+            len,
+            loaded_by,
+        });
+        data_offset += len;
+    }
+    let layout = BytecodeLayout {
+        entry_points,
+        data_section_offset: buf.len(),
+        data,
+    };
+
     let mut data_section = data_section.serialize_to_bytes();
 
     buf.append(&mut data_section);
 
-    ok(buf, vec![], errors)
+    ok((buf, layout), vec![], errors)
 }
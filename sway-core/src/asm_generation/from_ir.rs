@@ -22,7 +22,7 @@ use crate::{
     },
     error::*,
     parse_tree::Literal,
-    BuildConfig,
+    BuildConfig, OptLevel,
 };
 
 use sway_ir::*;
@@ -42,8 +42,8 @@ pub fn compile_ir_to_asm(ir: &Context, build_config: &BuildConfig) -> CompileRes
     // of libraries and link against them, rather than recompile everything each time.
     assert!(ir.module_iter().count() == 1);
     let module = ir.module_iter().next().unwrap();
-    let (data_section, mut ops, mut reg_seqr) = check!(
-        compile_module_to_asm(reg_seqr, ir, module),
+    let (data_section, mut ops, mut reg_seqr, entry_fn_names) = check!(
+        compile_module_to_asm(reg_seqr, ir, module, build_config.optimization_level),
         return err(warnings, errors),
         warnings,
         errors
@@ -58,6 +58,7 @@ pub fn compile_ir_to_asm(ir: &Context, build_config: &BuildConfig) -> CompileRes
         Kind::Contract => SwayAsmSet::ContractAbi {
             program_section: AbstractInstructionSet { ops: bytecode },
             data_section,
+            entry_fn_names,
         },
         Kind::Library | Kind::Predicate => todo!("libraries and predicates coming soon!"),
     };
@@ -66,11 +67,17 @@ pub fn compile_ir_to_asm(ir: &Context, build_config: &BuildConfig) -> CompileRes
         tracing::info!("{}", asm);
     }
 
-    let finalized_asm = asm
-        .remove_unnecessary_jumps()
+    let mut finalized_asm = asm
+        .remove_unnecessary_jumps(build_config.optimization_level)
         .allocate_registers(&mut reg_seqr)
         .optimize();
 
+    // Without debug info no ops are attributed to the source they were generated from, so
+    // nothing is recorded in the source map when the bytecode is produced.
+    if !build_config.include_debug_info {
+        finalized_asm.clear_spans();
+    }
+
     if build_config.print_finalized_asm {
         tracing::info!("{}", finalized_asm);
     }
@@ -85,12 +92,20 @@ pub fn compile_ir_to_asm(ir: &Context, build_config: &BuildConfig) -> CompileRes
     ok(finalized_asm, warnings, errors)
 }
 
+/// Compiles the module's entry functions. For contracts, the names of the ABI methods are also
+/// returned in the order in which they appear in the selector switch.
 fn compile_module_to_asm(
     reg_seqr: RegisterSequencer,
     context: &Context,
     module: Module,
-) -> CompileResult<(DataSection, Vec<Op>, RegisterSequencer)> {
-    let mut builder = AsmBuilder::new(DataSection::default(), reg_seqr, context);
+    optimization_level: OptLevel,
+) -> CompileResult<(DataSection, Vec<Op>, RegisterSequencer, Vec<String>)> {
+    let mut builder = AsmBuilder::new(
+        DataSection::default(),
+        reg_seqr,
+        context,
+        optimization_level,
+    );
     match module.get_kind(context) {
         Kind::Script => {
             // We can't do function calls yet, so we expect everything to be inlined into `main`.
@@ -101,12 +116,14 @@ fn compile_module_to_asm(
             builder
                 .compile_function(function)
                 .flat_map(|_| builder.finalize())
+                .map(|(data_section, ops, reg_seqr)| (data_section, ops, reg_seqr, vec![]))
         }
         Kind::Contract => {
             let mut warnings = Vec::new();
             let mut errors = Vec::new();
 
             let mut selectors_and_labels: Vec<([u8; 4], Label)> = Vec::new();
+            let mut entry_fn_names = Vec::new();
 
             // Compile only the functions which have selectors and gather the selectors and labels.
             for function in module.function_iter(context) {
@@ -120,6 +137,7 @@ fn compile_module_to_asm(
                         errors
                     );
                     selectors_and_labels.push((selector, label));
+                    entry_fn_names.push(function.get_name(context).to_string());
                 }
             }
             let (mut data_section, mut funcs_bytecode, mut reg_seqr) = check!(
@@ -133,7 +151,7 @@ fn compile_module_to_asm(
                 build_contract_abi_switch(&mut reg_seqr, &mut data_section, selectors_and_labels);
             bytecode_with_switch.append(&mut funcs_bytecode);
             ok(
-                (data_section, bytecode_with_switch, reg_seqr),
+                (data_section, bytecode_with_switch, reg_seqr, entry_fn_names),
                 warnings,
                 errors,
             )
@@ -180,6 +198,9 @@ struct AsmBuilder<'ir> {
 
     // Final resulting VM bytecode ops.
     bytecode: Vec<Op>,
+
+    // The optimizations to apply while selecting instructions.
+    optimization_level: OptLevel,
 }
 
 // NOTE: For stack storage we need to be aware:
@@ -198,7 +219,12 @@ pub enum StateAccessType {
 }
 
 impl<'ir> AsmBuilder<'ir> {
-    fn new(data_section: DataSection, reg_seqr: RegisterSequencer, context: &'ir Context) -> Self {
+    fn new(
+        data_section: DataSection,
+        reg_seqr: RegisterSequencer,
+        context: &'ir Context,
+        optimization_level: OptLevel,
+    ) -> Self {
         AsmBuilder {
             data_section,
             reg_seqr,
@@ -208,6 +234,7 @@ impl<'ir> AsmBuilder<'ir> {
             stack_base_reg: None,
             context,
             bytecode: Vec::new(),
+            optimization_level,
         }
     }
 
@@ -347,15 +374,20 @@ impl<'ir> AsmBuilder<'ir> {
                 self.ptr_map.insert(*ptr, Storage::Data(data_id));
             } else {
                 match ptr_content.ty {
-                    Type::Unit | Type::Bool | Type::Uint(_) => {
+                    Type::Unit | Type::Bool | Type::Uint(0..=64) => {
                         self.ptr_map.insert(*ptr, Storage::Stack(stack_base));
                         stack_base += 1;
                     }
-                    Type::B256 => {
+                    Type::B256 | Type::Uint(_) => {
                         // XXX Like strings, should we just reserve space for a pointer?
                         self.ptr_map.insert(*ptr, Storage::Stack(stack_base));
                         stack_base += 4;
                     }
+                    Type::Slice => {
+                        // A pointer and a length.
+                        self.ptr_map.insert(*ptr, Storage::Stack(stack_base));
+                        stack_base += 2;
+                    }
                     Type::String(n) => {
                         // Strings are always constant and used by reference, so we only store the
                         // pointer on the stack.
@@ -856,10 +888,68 @@ impl<'ir> AsmBuilder<'ir> {
         // now, move the return value of the contract call to the return register.
         // TODO validate RETL matches the expected type (this is a comment from the old codegen)
         let instr_reg = self.reg_seqr.next();
-        self.bytecode.push(Op::unowned_register_move(
-            instr_reg.clone(),
-            VirtualRegister::Constant(ConstantRegister::ReturnValue),
-        ));
+        if matches!(instr_val.get_type(self.context), Some(Type::Slice)) {
+            // A returned slice is just the data, which is left in the callee's call frame.  Copy
+            // it to the heap and put the pointer to it and its length together there too.
+            let span = instr_val.get_span(self.context);
+            let ret_len_reg = VirtualRegister::Constant(ConstantRegister::ReturnLength);
+            let data_reg = self.reg_seqr.next();
+            self.bytecode.push(Op {
+                opcode: Either::Left(VirtualOp::ALOC(ret_len_reg.clone())),
+                comment: "allocate returned slice data".into(),
+                owning_span: span.clone(),
+            });
+            self.bytecode.push(Op {
+                opcode: Either::Left(VirtualOp::ADDI(
+                    data_reg.clone(),
+                    VirtualRegister::Constant(ConstantRegister::HeapPointer),
+                    VirtualImmediate12 { value: 1 },
+                )),
+                comment: "get returned slice data pointer".into(),
+                owning_span: span.clone(),
+            });
+            self.bytecode.push(Op {
+                opcode: Either::Left(VirtualOp::MCP(
+                    data_reg.clone(),
+                    VirtualRegister::Constant(ConstantRegister::ReturnValue),
+                    ret_len_reg.clone(),
+                )),
+                comment: "copy returned slice data".into(),
+                owning_span: span.clone(),
+            });
+            let size_reg = self.reg_seqr.next();
+            self.number_to_reg(16, &size_reg, span.clone());
+            self.bytecode.push(Op {
+                opcode: Either::Left(VirtualOp::ALOC(size_reg)),
+                comment: "allocate returned slice".into(),
+                owning_span: span.clone(),
+            });
+            self.bytecode.push(Op {
+                opcode: Either::Left(VirtualOp::ADDI(
+                    instr_reg.clone(),
+                    VirtualRegister::Constant(ConstantRegister::HeapPointer),
+                    VirtualImmediate12 { value: 1 },
+                )),
+                comment: "get returned slice pointer".into(),
+                owning_span: span.clone(),
+            });
+            for (reg, offset) in [(data_reg, 0), (ret_len_reg, 1)] {
+                self.bytecode.push(Op {
+                    opcode: Either::Left(VirtualOp::SW(
+                        instr_reg.clone(),
+                        reg,
+                        VirtualImmediate12 { value: offset },
+                    )),
+                    comment: "store returned slice".into(),
+                    owning_span: span.clone(),
+                });
+            }
+        } else {
+            self.bytecode.push(Op::unowned_register_move(
+                instr_reg.clone(),
+                VirtualRegister::Constant(ConstantRegister::ReturnValue),
+            ));
+        }
         self.reg_map.insert(*instr_val, instr_reg);
     }
 
@@ -1521,6 +1611,26 @@ impl<'ir> AsmBuilder<'ir> {
                     opcode: Either::Left(VirtualOp::RET(ret_reg)),
                     comment: "".into(),
                 });
+            } else if let Type::Slice = ret_type {
+                // Slices return the data they point to, rather than the pointer and length.
+                let ptr_reg = self.reg_seqr.next();
+                let len_reg = self.reg_seqr.next();
+                for (reg, offset) in [(&ptr_reg, 0), (&len_reg, 1)] {
+                    self.bytecode.push(Op {
+                        opcode: Either::Left(VirtualOp::LW(
+                            reg.clone(),
+                            ret_reg.clone(),
+                            VirtualImmediate12 { value: offset },
+                        )),
+                        comment: "load slice for RETD".into(),
+                        owning_span: instr_val.get_span(self.context),
+                    });
+                }
+                self.bytecode.push(Op {
+                    owning_span: instr_val.get_span(self.context),
+                    opcode: Either::Left(VirtualOp::RETD(ptr_reg, len_reg)),
+                    comment: "".into(),
+                });
             } else {
                 // If the type not a reference type then we use RETD to return data.  First put the
                 // size into the data section, then add a LW to get it, then add a RETD which uses
@@ -1588,8 +1698,11 @@ impl<'ir> AsmBuilder<'ir> {
         key: &Value,
         access_type: StateAccessType,
     ) -> CompileResult<()> {
-        // Make sure that both val and key are pointers to B256.
-        assert!(matches!(val.get_type(self.context), Some(Type::B256)));
+        // Make sure that both val and key are pointers to 32 byte values.
+        assert!(matches!(
+            val.get_type(self.context),
+            Some(Type::B256 | Type::Uint(256))
+        ));
         assert!(matches!(key.get_type(self.context), Some(Type::B256)));
 
         let key_ptr = self.resolve_ptr(key);
@@ -1609,8 +1722,8 @@ impl<'ir> AsmBuilder<'ir> {
         }
         let (val_ptr, ptr_ty, offset) = val_ptr.value.unwrap();
 
-        // Expect the ptr_ty for val to also be B256
-        assert!(ptr_ty.eq(self.context, &Type::B256));
+        // Expect the ptr_ty for val to also be B256, or the equally sized U256
+        assert!(matches!(ptr_ty, Type::B256 | Type::Uint(256)));
 
         match (self.ptr_map.get(&val_ptr), self.ptr_map.get(&key_ptr)) {
             (Some(Storage::Stack(val_offset)), Some(Storage::Stack(key_offset))) => {
@@ -1915,8 +2028,31 @@ impl<'ir> AsmBuilder<'ir> {
         constant: &Constant,
         span: Option<Span>,
     ) -> VirtualRegister {
-        // Get the constant into the namespace.
         let lit = ir_constant_to_ast_literal(constant);
+
+        // When optimizing for speed, values which fit in an immediate are moved into a register
+        // directly, which is cheaper than loading them from the data section.
+        if self.optimization_level == OptLevel::Speed {
+            let small_value = match &lit {
+                Literal::Boolean(b) => Some(*b as u64),
+                Literal::U64(n) if *n <= compiler_constants::EIGHTEEN_BITS => Some(*n),
+                _ => None,
+            };
+            if let Some(value) = small_value {
+                let reg = self.reg_seqr.next();
+                self.bytecode.push(Op {
+                    opcode: either::Either::Left(VirtualOp::MOVI(
+                        reg.clone(),
+                        VirtualImmediate18::new_unchecked(value, "value fits in 18 bits"),
+                    )),
+                    comment: "literal instantiation".into(),
+                    owning_span: span,
+                });
+                return reg;
+            }
+        }
+
+        // Get the constant into the namespace.
         let data_id = self.data_section.insert_data_value(&lit);
 
         // Allocate a register for it, and a load instruction.
@@ -2016,6 +2152,7 @@ impl<'ir> AsmBuilder<'ir> {
                                 value.get_span(self.context),
                             ))
                         }
+                        Type::Slice => unreachable!("Slices cannot be constants."),
                         Type::Array(_) | Type::Struct(_) | Type::Union(_) => {
                             Some(self.initialise_aggregate_type(
                                 constant,
@@ -2107,7 +2244,7 @@ impl<'ir> AsmBuilder<'ir> {
         }
 
         match &value_type {
-            Type::Unit | Type::Bool | Type::Uint(_) => {
+            Type::Unit | Type::Bool | Type::Uint(0..=64) => {
                 // Get the constant into the namespace.
                 let lit = ir_constant_to_ast_literal(constant);
                 let data_id = self.data_section.insert_data_value(&lit);
@@ -2157,7 +2294,8 @@ impl<'ir> AsmBuilder<'ir> {
 
                 1
             }
-            Type::B256 | Type::String(_) => {
+            Type::Slice => unreachable!("Slices cannot be constants."),
+            Type::B256 | Type::Uint(_) | Type::String(_) => {
                 // Get the constant into the namespace.
                 let lit = ir_constant_to_ast_literal(constant);
                 let data_id = self.data_section.insert_data_value(&lit);
@@ -2170,8 +2308,8 @@ impl<'ir> AsmBuilder<'ir> {
                     owning_span: span.clone(),
                 });
 
-                // Write the initialiser to memory.  Most Literals are 1 word, B256 is 32 bytes and
-                // needs to use a MCP instruction.
+                // Write the initialiser to memory.  Most Literals are 1 word, B256 and U256 are 32
+                // bytes and need to use a MCP instruction.
                 let offs_reg = self.reg_seqr.next();
                 if offs_in_words * 8 > compiler_constants::TWELVE_BITS {
                     self.number_to_reg(offs_in_words * 8, &offs_reg, span.clone());
@@ -2332,6 +2470,7 @@ fn ir_constant_to_ast_literal(constant: &Constant) -> Literal {
         ConstantValue::Bool(b) => Literal::Boolean(*b),
         ConstantValue::Uint(n) => Literal::U64(*n),
         ConstantValue::B256(bs) => Literal::B256(*bs),
+        ConstantValue::U256(bs) => Literal::U256(*bs),
         ConstantValue::String(bs) => {
             // ConstantValue::String bytes are guaranteed to be valid UTF8.
             let s = std::str::from_utf8(bs).unwrap();
@@ -2347,8 +2486,9 @@ fn ir_constant_to_ast_literal(constant: &Constant) -> Literal {
 
 pub fn ir_type_size_in_bytes(context: &Context, ty: &Type) -> u64 {
     match ty {
-        Type::Unit | Type::Bool | Type::Uint(_) => 8,
-        Type::B256 => 32,
+        Type::Unit | Type::Bool | Type::Uint(0..=64) => 8,
+        Type::B256 | Type::Uint(_) => 32,
+        Type::Slice => 16,
         Type::String(n) => size_bytes_round_up_to_word_alignment!(n),
         Type::Array(aggregate) => {
             if let AggregateContent::ArrayType(el_ty, cnt) = &context.aggregates[aggregate.0] {
@@ -2464,6 +2604,42 @@ mod tests {
         }
     }
 
+    #[test]
+    fn optimization_levels_select_different_pipelines() {
+        let manifest_dir = env!("CARGO_MANIFEST_DIR");
+        let input = std::fs::read_to_string(format!("{}/tests/ir_to_asm/if_expr.ir", manifest_dir))
+            .unwrap();
+        let ir = parse(&input).expect("parsed ir");
+        let compile = |optimization_level| {
+            let build_config = BuildConfig {
+                canonical_root_module: std::sync::Arc::new("".into()),
+                print_intermediate_asm: false,
+                print_finalized_asm: false,
+                print_ir: false,
+                optimization_level,
+                include_debug_info: false,
+            };
+            let mut warnings = Vec::new();
+            let mut errors = Vec::new();
+            let mut asm = compile_ir_to_asm(&ir, &build_config).unwrap(&mut warnings, &mut errors);
+            let (bytecode, _) = asm
+                .to_bytecode_mut(&mut crate::source_map::SourceMap::new())
+                .unwrap(&mut warnings, &mut errors);
+            assert!(warnings.is_empty() && errors.is_empty());
+            bytecode
+        };
+        let none = compile(OptLevel::None);
+        let size = compile(OptLevel::Size);
+        let speed = compile(OptLevel::Speed);
+
+        // `size` removes the jump to the next instruction left in by `none`, along with the
+        // padding which aligned the data section after it.
+        assert_eq!(none.len(), size.len() + 8);
+        // `speed` moves `false` and `42` into registers rather than loading them from the data
+        // section, while `1000000` does not fit in an immediate and is still loaded.
+        assert_eq!(size.len(), speed.len() + 16);
+    }
+
     fn test_ir_to_asm(mut path: PathBuf) {
         let input_bytes = std::fs::read(&path).unwrap();
         let input = String::from_utf8_lossy(&input_bytes);
@@ -2481,6 +2657,8 @@ mod tests {
                 print_intermediate_asm: false,
                 print_finalized_asm: false,
                 print_ir: true,
+                optimization_level: crate::OptLevel::Size,
+                include_debug_info: true,
             },
         );
 
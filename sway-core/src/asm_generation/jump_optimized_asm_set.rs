@@ -8,6 +8,7 @@ pub enum JumpOptimizedAsmSet {
     ContractAbi {
         data_section: DataSection,
         program_section: AbstractInstructionSet,
+        entry_fn_names: Vec<String>,
     },
     ScriptMain {
         data_section: DataSection,
@@ -55,11 +56,13 @@ impl JumpOptimizedAsmSet {
             JumpOptimizedAsmSet::ContractAbi {
                 program_section,
                 data_section,
+                entry_fn_names,
             } => RegisterAllocatedAsmSet::ContractAbi {
                 program_section: program_section
                     .realize_labels(&data_section)
                     .allocate_registers(register_sequencer),
                 data_section,
+                entry_fn_names,
             },
         }
     }
@@ -79,6 +82,7 @@ impl fmt::Display for JumpOptimizedAsmSet {
             JumpOptimizedAsmSet::ContractAbi {
                 data_section,
                 program_section,
+                ..
             } => write!(f, "{}\n{}", program_section, data_section),
             // Libraries do not directly generate any asm.
             JumpOptimizedAsmSet::Library => write!(f, ""),
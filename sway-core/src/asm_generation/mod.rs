@@ -4,6 +4,7 @@ use crate::{
         VirtualImmediate12, VirtualOp,
     },
     parse_tree::Literal,
+    OptLevel,
 };
 use std::{collections::BTreeSet, fmt};
 
@@ -67,6 +68,8 @@ pub enum SwayAsmSet {
     ContractAbi {
         data_section: DataSection,
         program_section: AbstractInstructionSet,
+        /// The names of the ABI methods, in the order in which they appear in the selector switch.
+        entry_fn_names: Vec<String>,
     },
     ScriptMain {
         data_section: DataSection,
@@ -137,6 +140,7 @@ impl fmt::Display for SwayAsmSet {
             SwayAsmSet::ContractAbi {
                 data_section,
                 program_section,
+                ..
             } => write!(f, "{}\n{}", program_section, data_section),
             // Libraries do not directly generate any asm.
             SwayAsmSet::Library => write!(f, ""),
@@ -145,34 +149,51 @@ impl fmt::Display for SwayAsmSet {
 }
 
 impl SwayAsmSet {
-    pub(crate) fn remove_unnecessary_jumps(self) -> JumpOptimizedAsmSet {
+    pub(crate) fn remove_unnecessary_jumps(
+        self,
+        optimization_level: OptLevel,
+    ) -> JumpOptimizedAsmSet {
+        // Jumps to the next instruction are only removed when optimizing.
+        let remove_jumps = |program_section: AbstractInstructionSet| {
+            if optimization_level.optimizes() {
+                program_section.remove_sequential_jumps()
+            } else {
+                program_section
+            }
+        };
         match self {
             SwayAsmSet::ScriptMain {
                 data_section,
                 program_section,
             } => JumpOptimizedAsmSet::ScriptMain {
                 data_section,
-                program_section: program_section.remove_sequential_jumps(),
+                program_section: remove_jumps(program_section),
             },
             SwayAsmSet::PredicateMain {
                 data_section,
                 program_section,
             } => JumpOptimizedAsmSet::PredicateMain {
                 data_section,
-                program_section: program_section.remove_sequential_jumps(),
+                program_section: remove_jumps(program_section),
             },
             SwayAsmSet::Library {} => JumpOptimizedAsmSet::Library,
             SwayAsmSet::ContractAbi {
                 data_section,
                 program_section,
+                entry_fn_names,
             } => JumpOptimizedAsmSet::ContractAbi {
                 data_section,
-                program_section: program_section.remove_sequential_jumps(),
+                program_section: remove_jumps(program_section),
+                entry_fn_names,
             },
         }
     }
 }
 
+/// The number of instructions occupied by the preamble once realized, i.e. the index of the first
+/// instruction following it.
+pub(crate) const PREAMBLE_LEN: usize = 6;
+
 /// Builds the asm preamble, which includes metadata and a jump past the metadata.
 /// Right now, it looks like this:
 ///
@@ -9,6 +9,7 @@ pub enum RegisterAllocatedAsmSet {
     ContractAbi {
         data_section: DataSection,
         program_section: InstructionSet,
+        entry_fn_names: Vec<String>,
     },
     ScriptMain {
         data_section: DataSection,
@@ -66,6 +67,7 @@ impl RegisterAllocatedAsmSet {
             RegisterAllocatedAsmSet::ContractAbi {
                 mut program_section,
                 data_section,
+                entry_fn_names,
             } => {
                 // ensure there's an even number of ops so the
                 // data section offset is valid
@@ -79,6 +81,7 @@ impl RegisterAllocatedAsmSet {
                 FinalizedAsm::ContractAbi {
                     program_section,
                     data_section,
+                    entry_fn_names,
                 }
             }
         }
@@ -103,6 +106,7 @@ impl fmt::Display for RegisterAllocatedAsmSet {
             RegisterAllocatedAsmSet::ContractAbi {
                 program_section,
                 data_section,
+                ..
             } => {
                 write!(f, "{}\n{}", program_section, data_section)
             }
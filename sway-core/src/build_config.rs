@@ -1,4 +1,52 @@
-use std::{path::PathBuf, sync::Arc};
+use serde::{Deserialize, Serialize};
+use std::{fmt, path::PathBuf, str::FromStr, sync::Arc};
+
+/// Selects which optimizations are applied to the IR and the generated ASM.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum OptLevel {
+    /// Only the transformations that code generation depends upon, i.e. inlining into the entry
+    /// points, are performed.
+    None,
+    /// Optimize for the smallest bytecode.
+    Size,
+    /// Optimize for the lowest execution cost. In addition to the `Size` optimizations, values
+    /// which fit in an immediate are moved into registers rather than loaded from the data section.
+    Speed,
+}
+
+impl OptLevel {
+    /// Whether the optimization passes beyond those required for code generation are run.
+    pub(crate) fn optimizes(&self) -> bool {
+        !matches!(self, OptLevel::None)
+    }
+}
+
+impl fmt::Display for OptLevel {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let s = match self {
+            OptLevel::None => "none",
+            OptLevel::Size => "size",
+            OptLevel::Speed => "speed",
+        };
+        write!(f, "{}", s)
+    }
+}
+
+impl FromStr for OptLevel {
+    type Err = String;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "none" => Ok(OptLevel::None),
+            "size" => Ok(OptLevel::Size),
+            "speed" => Ok(OptLevel::Speed),
+            _ => Err(format!(
+                "unknown optimization level \"{}\", expected one of \"none\", \"size\" or \"speed\"",
+                s
+            )),
+        }
+    }
+}
 
 /// Configuration for the overall build and compilation process.
 #[derive(Clone)]
@@ -9,6 +57,8 @@ pub struct BuildConfig {
     pub(crate) print_intermediate_asm: bool,
     pub(crate) print_finalized_asm: bool,
     pub(crate) print_ir: bool,
+    pub(crate) optimization_level: OptLevel,
+    pub(crate) include_debug_info: bool,
 }
 
 impl BuildConfig {
@@ -46,6 +96,8 @@ impl BuildConfig {
             print_intermediate_asm: false,
             print_finalized_asm: false,
             print_ir: false,
+            optimization_level: OptLevel::Size,
+            include_debug_info: true,
         }
     }
 
@@ -70,6 +122,22 @@ impl BuildConfig {
         }
     }
 
+    pub fn optimization_level(self, optimization_level: OptLevel) -> Self {
+        Self {
+            optimization_level,
+            ..self
+        }
+    }
+
+    /// Whether the compiled bytecode is mapped back to the source spans it was generated from.
+    /// The mapping is recorded in the `SourceMap` passed to `asm_to_bytecode`.
+    pub fn include_debug_info(self, a: bool) -> Self {
+        Self {
+            include_debug_info: a,
+            ..self
+        }
+    }
+
     pub fn canonical_root_module(&self) -> Arc<PathBuf> {
         self.canonical_root_module.clone()
     }
@@ -0,0 +1,47 @@
+use serde::{Deserialize, Serialize};
+
+/// Describes where each part of a program lives within its compiled bytecode.
+///
+/// Combined with the [SourceMap](crate::source_map::SourceMap), this allows for attributing the
+/// size of the bytecode back to the source that produced it.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct BytecodeLayout {
+    /// The entry points of the program ordered by the instruction index at which they begin.
+    ///
+    /// This is `main` for scripts and predicates, or each of the ABI methods for contracts.
+    /// Instructions preceding the first entry point belong to the preamble and, for contracts,
+    /// the ABI selector switch.
+    pub entry_points: Vec<EntryPoint>,
+    /// The offset in bytes from the start of the bytecode to the data section.
+    pub data_section_offset: usize,
+    /// Every value within the data section, in order.
+    pub data: Vec<DataSectionEntry>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct EntryPoint {
+    /// The name of the entry function.
+    pub name: String,
+    /// The index of the first instruction of the entry function.
+    pub start: usize,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DataSectionEntry {
+    /// The offset in bytes from the start of the data section.
+    pub offset: usize,
+    /// The size of the value in bytes.
+    pub len: usize,
+    /// The indices of the instructions that load this value.
+    pub loaded_by: Vec<usize>,
+}
+
+impl BytecodeLayout {
+    /// The entry point to which the instruction at the given index belongs, if any.
+    pub fn entry_point_at(&self, instruction_ix: usize) -> Option<&EntryPoint> {
+        self.entry_points
+            .iter()
+            .rev()
+            .find(|entry| entry.start <= instruction_ix)
+    }
+}
@@ -27,6 +27,9 @@ pub const TUPLE_NAME_PREFIX: &str = "__tuple_";
 /// The default prefix for the compiler generated names of match
 pub const MATCH_RETURN_VAR_NAME_PREFIX: &str = "__match_return_var_name_";
 
+/// The default prefix for the compiler generated names of the variables of desugared for loops
+pub const FOR_LOOP_VAR_NAME_PREFIX: &str = "__for_loop_";
+
 /// The valid attribute strings related to storage and purity.
 pub const STORAGE_PURITY_ATTRIBUTE_NAME: &str = "storage";
 pub const STORAGE_PURITY_READ_NAME: &str = "read";
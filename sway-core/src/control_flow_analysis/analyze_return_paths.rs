@@ -172,6 +172,7 @@ fn connect_declaration(
         | AbiDeclaration(_)
         | StructDeclaration(_)
         | EnumDeclaration(_)
+        | TypeAliasDeclaration(_)
         | StorageDeclaration(_)
         | GenericTypeForFunctionScope { .. } => leaves.to_vec(),
         VariableDeclaration(_) | ConstantDeclaration(_) => {
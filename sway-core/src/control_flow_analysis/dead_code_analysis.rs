@@ -414,7 +414,10 @@ fn connect_declaration(
             connect_storage_declaration(storage, graph, entry_node, tree_type);
             Ok(leaves.to_vec())
         }
-        ErrorRecovery | GenericTypeForFunctionScope { .. } => Ok(leaves.to_vec()),
+        // uses of a type alias resolve to the aliased type, so there is nothing to connect
+        TypeAliasDeclaration(_) | ErrorRecovery | GenericTypeForFunctionScope { .. } => {
+            Ok(leaves.to_vec())
+        }
     }
 }
 
@@ -1025,6 +1028,9 @@ fn connect_expression(
             tree_type,
             exp.span.clone(),
         ),
+        // Nothing follows a `break` or `continue`, so any code after one is dead. They needn't be
+        // connected to the loop, as its beginning and exit are always reachable.
+        Break | Continue => Ok(vec![]),
     }
 }
 
@@ -1045,6 +1051,15 @@ fn connect_intrinsic_function(
             tree_type,
             exp.span.clone(),
         )?,
+        TypedIntrinsicFunctionKind::AddrOf { exp } => connect_expression(
+            &exp.expression,
+            graph,
+            leaves,
+            exit_node,
+            "addr_of",
+            tree_type,
+            exp.span.clone(),
+        )?,
         TypedIntrinsicFunctionKind::SizeOfType { .. } => {
             let node = graph.add_node("size of type".into());
             for leaf in leaves {
@@ -1199,6 +1214,11 @@ fn construct_dead_code_warning_from_node(node: &TypedAstNode) -> Option<CompileW
             content: TypedAstNodeContent::Declaration(TypedDeclaration::AbiDeclaration { .. }),
             ..
         } => return None,
+        // uses of a type alias aren't tracked, since they resolve to the aliased type
+        TypedAstNode {
+            content: TypedAstNodeContent::Declaration(TypedDeclaration::TypeAliasDeclaration(..)),
+            ..
+        } => return None,
         TypedAstNode {
             content: TypedAstNodeContent::Declaration(..),
             span,
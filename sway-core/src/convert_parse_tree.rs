@@ -7,34 +7,29 @@ use {
         },
         error::{err, ok, CompileError, CompileResult, CompileWarning},
         type_engine::{insert_type, AbiName, IntegerBits},
-        AbiDeclaration, AsmExpression, AsmOp, AsmRegister, AsmRegisterDeclaration, AstNode,
-        AstNodeContent, CallPath, CodeBlock, ConstantDeclaration, Declaration, EnumDeclaration,
-        EnumVariant, Expression, FunctionDeclaration, FunctionParameter, ImplSelf, ImplTrait,
-        ImportType, IncludeStatement, IntrinsicFunctionKind, LazyOp, Literal, MatchBranch,
-        MethodName, ParseTree, Purity, Reassignment, ReassignmentTarget, ReturnStatement,
-        Scrutinee, StorageDeclaration, StorageField, StructDeclaration, StructExpressionField,
-        StructField, StructScrutineeField, Supertrait, TraitDeclaration, TraitFn, TreeType,
-        TypeInfo, UseStatement, VariableDeclaration, Visibility, WhileLoop,
-    },
-    std::{
-        collections::HashMap,
-        convert::TryFrom,
-        iter,
-        mem::MaybeUninit,
-        ops::ControlFlow,
-        sync::atomic::{AtomicUsize, Ordering},
+        AbiDeclaration, AsmExpression, AsmOp, AsmRegister, AsmRegisterDeclaration, AssociatedType,
+        AstNode, AstNodeContent, CallPath, CodeBlock, ConstantDeclaration, Declaration,
+        EnumDeclaration, EnumVariant, Expression, FunctionDeclaration, FunctionParameter, ImplSelf,
+        ImplTrait, ImportType, IncludeStatement, IntrinsicFunctionKind, LazyOp, Literal,
+        MatchBranch, MethodName, ParseTree, Purity, Reassignment, ReassignmentTarget,
+        ReturnStatement, Scrutinee, StorageDeclaration, StorageField, StructDeclaration,
+        StructExpressionField, StructField, StructScrutineeField, Supertrait, TraitConstant,
+        TraitDeclaration, TraitFn, TreeType, TypeAliasDeclaration, TypeInfo, UseStatement,
+        VariableDeclaration, Visibility, WhileLoop,
     },
+    std::{collections::HashMap, convert::TryFrom, iter, mem::MaybeUninit, ops::ControlFlow},
     sway_parse::{
         expr::{ReassignmentOp, ReassignmentOpVariant},
         ty::TyTupleDescriptor,
         AbiCastArgs, AngleBrackets, AsmBlock, Assignable, AttributeDecl, Braces, CodeBlockContents,
         Dependency, DoubleColonToken, Expr, ExprArrayDescriptor, ExprStructField,
-        ExprTupleDescriptor, FnArg, FnArgs, FnSignature, GenericArgs, GenericParams, IfCondition,
-        IfExpr, Instruction, Intrinsic, Item, ItemAbi, ItemConst, ItemEnum, ItemFn, ItemImpl,
-        ItemKind, ItemStorage, ItemStruct, ItemTrait, ItemUse, LitInt, LitIntType, MatchBranchKind,
-        Module, ModuleKind, PathExpr, PathExprSegment, PathType, PathTypeSegment, Pattern,
-        PatternStructField, PubToken, QualifiedPathRoot, Statement, StatementLet, Traits, Ty,
-        TypeField, UseTree, WhereClause,
+        ExprTupleDescriptor, FnArg, FnArgs, FnSignature, ForIterable, GenericArgs, GenericParams,
+        IfCondition, IfExpr, Instruction, Intrinsic, Item, ItemAbi, ItemConst, ItemEnum, ItemFn,
+        ItemImpl, ItemImplItem, ItemKind, ItemStorage, ItemStruct, ItemTrait, ItemTraitItem,
+        ItemTypeAlias, ItemUse, LitInt, LitIntType, MatchBranchKind, Module, ModuleKind, PathExpr,
+        PathExprSegment, PathType, PathTypeSegment, Pattern, PatternStructField, PubToken,
+        QualifiedPathRoot, Statement, StatementLet, TraitConst, TraitType, Traits, Ty, TypeField,
+        UseTree, WhereClause,
     },
     sway_types::{Ident, Span, Spanned},
     thiserror::Error,
@@ -47,6 +42,12 @@ use {
 pub struct ErrorContext {
     warnings: Vec<CompileWarning>,
     errors: Vec<CompileError>,
+    /// Counters used to generate names for the variables introduced while desugaring. They are
+    /// local to each conversion so that the generated names only depend on the module being
+    /// converted, even when several modules are converted concurrently.
+    for_loop_counter: usize,
+    match_return_var_counter: usize,
+    tuple_counter: usize,
 }
 
 #[derive(Debug)]
@@ -90,12 +91,12 @@ impl ErrorContext {
 
 #[derive(Error, Debug, Clone, PartialEq, Hash)]
 pub enum ConvertParseTreeError {
-    #[error("pub use imports are not supported")]
-    PubUseNotSupported { span: Span },
     #[error("return expressions are not allowed outside of blocks")]
     ReturnOutsideOfBlock { span: Span },
     #[error("while expressions are not allowed outside of blocks")]
     WhileOutsideOfBlock { span: Span },
+    #[error("for expressions are not allowed outside of blocks")]
+    ForOutsideOfBlock { span: Span },
     #[error("functions used in applications may not be arbitrary expressions")]
     FunctionArbitraryExpression { span: Span },
     #[error("generics are not supported here")]
@@ -112,6 +113,8 @@ pub enum ConvertParseTreeError {
     IsReferenceTypeOneGenericArg { span: Span },
     #[error("__size_of_val requires exactly one argument")]
     SizeOfValOneArg { span: Span },
+    #[error("__addr_of requires exactly one argument")]
+    AddrOfOneArg { span: Span },
     #[error("tuple index out of range")]
     TupleIndexOutOfRange { span: Span },
     #[error("shift-left expressions are not implemented")]
@@ -146,6 +149,8 @@ pub enum ConvertParseTreeError {
     U32LiteralOutOfRange { span: Span },
     #[error("u64 literal out of range")]
     U64LiteralOutOfRange { span: Span },
+    #[error("u256 literal out of range")]
+    U256LiteralOutOfRange { span: Span },
     #[error("signed integers are not supported")]
     SignedIntegersNotSupported { span: Span },
     #[error("literal patterns not supported in this position")]
@@ -160,6 +165,10 @@ pub enum ConvertParseTreeError {
     WildcardPatternsNotSupportedHere { span: Span },
     #[error("tuple patterns not supported in this position")]
     TuplePatternsNotSupportedHere { span: Span },
+    #[error("range patterns not supported in this position")]
+    RangePatternsNotSupportedHere { span: Span },
+    #[error("or-patterns not supported in this position")]
+    OrPatternsNotSupportedHere { span: Span },
     #[error("constructor patterns require a single argument")]
     ConstructorPatternOneArg { span: Span },
     #[error("mutable bindings are not supported in this position")]
@@ -188,14 +197,18 @@ pub enum ConvertParseTreeError {
     DuplicateStorageField { name: Ident, span: Span },
     #[error("struct field \"{name}\" already declared")]
     DuplicateStructField { name: Ident, span: Span },
+    #[error("associated types are only allowed in trait implementations")]
+    AssociatedTypeOutsideOfTraitImpl { span: Span },
+    #[error("enum variants are always public and cannot be marked `pub`")]
+    PubEnumVariant { span: Span },
 }
 
 impl Spanned for ConvertParseTreeError {
     fn span(&self) -> Span {
         match self {
-            ConvertParseTreeError::PubUseNotSupported { span } => span.clone(),
             ConvertParseTreeError::ReturnOutsideOfBlock { span } => span.clone(),
             ConvertParseTreeError::WhileOutsideOfBlock { span } => span.clone(),
+            ConvertParseTreeError::ForOutsideOfBlock { span } => span.clone(),
             ConvertParseTreeError::FunctionArbitraryExpression { span } => span.clone(),
             ConvertParseTreeError::GenericsNotSupportedHere { span } => span.clone(),
             ConvertParseTreeError::FullyQualifiedPathsNotSupportedHere { span } => span.clone(),
@@ -204,6 +217,7 @@ impl Spanned for ConvertParseTreeError {
             ConvertParseTreeError::IsReferenceTypeTooManyArgs { span } => span.clone(),
             ConvertParseTreeError::IsReferenceTypeOneGenericArg { span } => span.clone(),
             ConvertParseTreeError::SizeOfValOneArg { span } => span.clone(),
+            ConvertParseTreeError::AddrOfOneArg { span } => span.clone(),
             ConvertParseTreeError::TupleIndexOutOfRange { span } => span.clone(),
             ConvertParseTreeError::ShlNotImplemented { span } => span.clone(),
             ConvertParseTreeError::ShrNotImplemented { span } => span.clone(),
@@ -221,6 +235,7 @@ impl Spanned for ConvertParseTreeError {
             ConvertParseTreeError::U16LiteralOutOfRange { span } => span.clone(),
             ConvertParseTreeError::U32LiteralOutOfRange { span } => span.clone(),
             ConvertParseTreeError::U64LiteralOutOfRange { span } => span.clone(),
+            ConvertParseTreeError::U256LiteralOutOfRange { span } => span.clone(),
             ConvertParseTreeError::SignedIntegersNotSupported { span } => span.clone(),
             ConvertParseTreeError::LiteralPatternsNotSupportedHere { span } => span.clone(),
             ConvertParseTreeError::ConstantPatternsNotSupportedHere { span } => span.clone(),
@@ -228,6 +243,8 @@ impl Spanned for ConvertParseTreeError {
             ConvertParseTreeError::StructPatternsNotSupportedHere { span } => span.clone(),
             ConvertParseTreeError::WildcardPatternsNotSupportedHere { span } => span.clone(),
             ConvertParseTreeError::TuplePatternsNotSupportedHere { span } => span.clone(),
+            ConvertParseTreeError::RangePatternsNotSupportedHere { span } => span.clone(),
+            ConvertParseTreeError::OrPatternsNotSupportedHere { span } => span.clone(),
             ConvertParseTreeError::ConstructorPatternOneArg { span } => span.clone(),
             ConvertParseTreeError::MutableBindingsNotSupportedHere { span } => span.clone(),
             ConvertParseTreeError::ConstructorPatternSubPatterns { span } => span.clone(),
@@ -242,6 +259,8 @@ impl Spanned for ConvertParseTreeError {
             ConvertParseTreeError::DuplicateEnumVariant { span, .. } => span.clone(),
             ConvertParseTreeError::DuplicateStorageField { span, .. } => span.clone(),
             ConvertParseTreeError::DuplicateStructField { span, .. } => span.clone(),
+            ConvertParseTreeError::AssociatedTypeOutsideOfTraitImpl { span } => span.clone(),
+            ConvertParseTreeError::PubEnumVariant { span } => span.clone(),
         }
     }
 }
@@ -250,6 +269,9 @@ pub fn convert_parse_tree(module: Module) -> CompileResult<(TreeType, ParseTree)
     let mut ec = ErrorContext {
         warnings: Vec::new(),
         errors: Vec::new(),
+        for_loop_counter: 0,
+        match_return_var_counter: 0,
+        tuple_counter: 0,
     };
     let tree_type = match module.kind {
         ModuleKind::Script { .. } => TreeType::Script,
@@ -258,7 +280,9 @@ pub fn convert_parse_tree(module: Module) -> CompileResult<(TreeType, ParseTree)
         ModuleKind::Library { ref name, .. } => TreeType::Library { name: name.clone() },
     };
     let res = module_to_sway_parse_tree(&mut ec, module);
-    let ErrorContext { warnings, errors } = ec;
+    let ErrorContext {
+        warnings, errors, ..
+    } = ec;
     match res {
         Ok(parse_tree) => ok((tree_type, parse_tree), warnings, errors),
         Err(_error_emitted) => err(warnings, errors),
@@ -298,7 +322,7 @@ fn item_to_ast_nodes(ec: &mut ErrorContext, item: Item) -> Result<Vec<AstNode>,
     let span = item.span();
     let contents = match item.value {
         ItemKind::Use(item_use) => {
-            let use_statements = item_use_to_use_statements(ec, item_use)?;
+            let use_statements = item_use_to_use_statements(item_use);
             use_statements
                 .into_iter()
                 .map(AstNodeContent::UseStatement)
@@ -350,6 +374,13 @@ fn item_to_ast_nodes(ec: &mut ErrorContext, item: Item) -> Result<Vec<AstNode>,
                 Declaration::StorageDeclaration(storage_declaration),
             )]
         }
+        ItemKind::TypeAlias(item_type_alias) => {
+            let type_alias_declaration =
+                item_type_alias_to_type_alias_declaration(ec, item_type_alias)?;
+            vec![AstNodeContent::Declaration(
+                Declaration::TypeAliasDeclaration(type_alias_declaration),
+            )]
+        }
     };
     Ok(contents
         .into_iter()
@@ -406,38 +437,31 @@ fn item_attrs_to_map(attribute_list: &[AttributeDecl]) -> Result<AttributesMap,
     Ok(attrs_map)
 }
 
-fn item_use_to_use_statements(
-    ec: &mut ErrorContext,
-    item_use: ItemUse,
-) -> Result<Vec<UseStatement>, ErrorEmitted> {
-    if let Some(pub_token) = item_use.visibility {
-        let error = ConvertParseTreeError::PubUseNotSupported {
-            span: pub_token.span(),
-        };
-        return Err(ec.error(error));
-    }
+fn item_use_to_use_statements(item_use: ItemUse) -> Vec<UseStatement> {
     let mut ret = Vec::new();
     let mut prefix = Vec::new();
     use_tree_to_use_statements(
         item_use.tree,
         item_use.root_import.is_some(),
+        pub_token_opt_to_visibility(item_use.visibility),
         &mut prefix,
         &mut ret,
     );
     debug_assert!(prefix.is_empty());
-    Ok(ret)
+    ret
 }
 
 fn use_tree_to_use_statements(
     use_tree: UseTree,
     is_absolute: bool,
+    visibility: Visibility,
     path: &mut Vec<Ident>,
     ret: &mut Vec<UseStatement>,
 ) {
     match use_tree {
         UseTree::Group { imports } => {
             for use_tree in imports.into_inner() {
-                use_tree_to_use_statements(use_tree, is_absolute, path, ret);
+                use_tree_to_use_statements(use_tree, is_absolute, visibility, path, ret);
             }
         }
         UseTree::Name { name } => {
@@ -451,6 +475,7 @@ fn use_tree_to_use_statements(
                 import_type,
                 is_absolute,
                 alias: None,
+                visibility,
             });
         }
         UseTree::Rename { name, alias, .. } => {
@@ -464,6 +489,7 @@ fn use_tree_to_use_statements(
                 import_type,
                 is_absolute,
                 alias: Some(alias),
+                visibility,
             });
         }
         UseTree::Glob { .. } => {
@@ -472,16 +498,39 @@ fn use_tree_to_use_statements(
                 import_type: ImportType::Star,
                 is_absolute,
                 alias: None,
+                visibility,
             });
         }
         UseTree::Path { prefix, suffix, .. } => {
             path.push(prefix);
-            use_tree_to_use_statements(*suffix, is_absolute, path, ret);
+            use_tree_to_use_statements(*suffix, is_absolute, visibility, path, ret);
             path.pop().unwrap();
         }
     }
 }
 
+fn item_type_alias_to_type_alias_declaration(
+    ec: &mut ErrorContext,
+    item_type_alias: ItemTypeAlias,
+) -> Result<TypeAliasDeclaration, ErrorEmitted> {
+    let span = item_type_alias.span();
+    let type_span = item_type_alias.ty.span();
+    let type_info = ty_to_type_info(ec, item_type_alias.ty)?;
+    if matches!(&type_info, TypeInfo::Custom { name, .. } if name == &item_type_alias.name) {
+        let error = ConvertParseTreeError::RecursiveType { span };
+        return Err(ec.error(error));
+    }
+    let type_alias_declaration = TypeAliasDeclaration {
+        name: item_type_alias.name,
+        type_parameters: generic_params_opt_to_type_parameters(ec, item_type_alias.generics, None)?,
+        type_info,
+        type_span,
+        visibility: pub_token_opt_to_visibility(item_type_alias.visibility),
+        span,
+    };
+    Ok(type_alias_declaration)
+}
+
 fn item_struct_to_struct_declaration(
     ec: &mut ErrorContext,
     item_struct: ItemStruct,
@@ -536,6 +585,13 @@ fn item_enum_to_enum_declaration(
 ) -> Result<EnumDeclaration, ErrorEmitted> {
     let mut errors = Vec::new();
     let span = item_enum.span();
+    for type_field in item_enum.fields.get() {
+        if let Some(pub_token) = &type_field.visibility {
+            errors.push(ConvertParseTreeError::PubEnumVariant {
+                span: pub_token.span(),
+            });
+        }
+    }
     let variants = item_enum
         .fields
         .into_inner()
@@ -649,17 +705,24 @@ fn item_trait_to_trait_declaration(
     item_trait: ItemTrait,
 ) -> Result<TraitDeclaration, ErrorEmitted> {
     let name = item_trait.name;
-    let interface_surface = {
-        item_trait
-            .trait_items
-            .into_inner()
-            .into_iter()
-            .map(|(fn_signature, _semicolon_token)| {
-                let attributes = item_attrs_to_map(&fn_signature.attribute_list)?;
-                fn_signature_to_trait_fn(ec, fn_signature.value, &attributes)
-            })
-            .collect::<Result<_, _>>()?
-    };
+    let type_parameters = generic_params_opt_to_type_parameters(ec, item_trait.generics, None)?;
+    let mut associated_types = Vec::new();
+    let mut constants = Vec::new();
+    let mut interface_surface = Vec::new();
+    for (trait_item, _semicolon_token) in item_trait.trait_items.into_inner() {
+        match trait_item.value {
+            ItemTraitItem::Fn(fn_signature) => {
+                let attributes = item_attrs_to_map(&trait_item.attribute_list)?;
+                interface_surface.push(fn_signature_to_trait_fn(ec, fn_signature, &attributes)?);
+            }
+            ItemTraitItem::Const(trait_const) => {
+                constants.push(trait_const_to_trait_constant(ec, trait_const)?);
+            }
+            ItemTraitItem::Type(trait_type) => {
+                associated_types.push(trait_type_to_type_parameter(trait_type));
+            }
+        }
+    }
     let methods = match item_trait.trait_defs_opt {
         None => Vec::new(),
         Some(trait_defs) => trait_defs
@@ -678,6 +741,9 @@ fn item_trait_to_trait_declaration(
     let visibility = pub_token_opt_to_visibility(item_trait.visibility);
     Ok(TraitDeclaration {
         name,
+        type_parameters,
+        associated_types,
+        constants,
         interface_surface,
         methods,
         supertraits,
@@ -692,17 +758,32 @@ fn item_impl_to_declaration(
     let block_span = item_impl.span();
     let type_implementing_for_span = item_impl.ty.span();
     let type_implementing_for = ty_to_type_info(ec, item_impl.ty)?;
-    let functions = {
-        item_impl
-            .contents
-            .into_inner()
-            .into_iter()
-            .map(|item| {
+    let mut associated_types = Vec::new();
+    let mut constants = Vec::new();
+    let mut functions = Vec::new();
+    for item in item_impl.contents.into_inner() {
+        match item.value {
+            ItemImplItem::Fn(item_fn) => {
                 let attributes = item_attrs_to_map(&item.attribute_list)?;
-                item_fn_to_function_declaration(ec, item.value, &attributes)
-            })
-            .collect::<Result<_, _>>()?
-    };
+                functions.push(item_fn_to_function_declaration(ec, item_fn, &attributes)?);
+            }
+            ItemImplItem::Const(item_const) => {
+                constants.push(item_const_to_constant_declaration(ec, item_const)?);
+            }
+            ItemImplItem::Type(impl_type) => {
+                let span = impl_type.span();
+                let name = associated_type_ident(impl_type.name, impl_type.type_token.span());
+                let type_argument = ty_to_type_argument(ec, impl_type.ty)?;
+                associated_types.push((
+                    span,
+                    AssociatedType {
+                        name,
+                        type_argument,
+                    },
+                ));
+            }
+        }
+    }
 
     let type_parameters = generic_params_opt_to_type_parameters(
         ec,
@@ -712,21 +793,34 @@ fn item_impl_to_declaration(
 
     match item_impl.trait_opt {
         Some((path_type, _for_token)) => {
+            let (trait_name, trait_type_arguments) =
+                path_type_to_call_path_and_type_arguments(ec, path_type)?;
             let impl_trait = ImplTrait {
-                trait_name: path_type_to_call_path(ec, path_type)?,
+                trait_name,
+                trait_type_arguments,
                 type_implementing_for,
                 type_implementing_for_span,
                 type_parameters,
+                associated_types: associated_types
+                    .into_iter()
+                    .map(|(_span, associated_type)| associated_type)
+                    .collect(),
+                constants,
                 functions,
                 block_span,
             };
             Ok(Declaration::ImplTrait(impl_trait))
         }
         None => {
+            if let Some((span, _associated_type)) = associated_types.into_iter().next() {
+                let error = ConvertParseTreeError::AssociatedTypeOutsideOfTraitImpl { span };
+                return Err(ec.error(error));
+            }
             let impl_self = ImplSelf {
                 type_implementing_for,
                 type_implementing_for_span,
                 type_parameters,
+                constants,
                 functions,
                 block_span,
             };
@@ -740,6 +834,10 @@ fn item_abi_to_abi_declaration(
     item_abi: ItemAbi,
 ) -> Result<AbiDeclaration, ErrorEmitted> {
     let span = item_abi.span();
+    let supertraits = match item_abi.super_traits {
+        None => Vec::new(),
+        Some((_colon_token, traits)) => traits_to_supertraits(ec, traits)?,
+    };
     Ok(AbiDeclaration {
         name: item_abi.name,
         interface_surface: {
@@ -764,6 +862,7 @@ fn item_abi_to_abi_declaration(
                 })
                 .collect::<Result<_, _>>()?,
         },
+        supertraits,
         span,
     })
 }
@@ -824,6 +923,7 @@ fn type_field_to_struct_field(
     let span = type_field.span();
     let type_span = type_field.ty.span();
     let struct_field = StructField {
+        visibility: pub_token_opt_to_visibility(type_field.visibility),
         name: type_field.name,
         type_info: ty_to_type_info(ec, type_field.ty)?,
         span,
@@ -980,10 +1080,13 @@ fn type_name_to_type_info_opt(name: &Ident) -> Option<TypeInfo> {
         "u16" => Some(TypeInfo::UnsignedInteger(IntegerBits::Sixteen)),
         "u32" => Some(TypeInfo::UnsignedInteger(IntegerBits::ThirtyTwo)),
         "u64" => Some(TypeInfo::UnsignedInteger(IntegerBits::SixtyFour)),
+        "u256" => Some(TypeInfo::UnsignedInteger(IntegerBits::TwoFiftySix)),
         "bool" => Some(TypeInfo::Boolean),
         "unit" => Some(TypeInfo::Tuple(Vec::new())),
         "byte" => Some(TypeInfo::Byte),
         "b256" => Some(TypeInfo::B256),
+        "raw_ptr" => Some(TypeInfo::RawUntypedPtr),
+        "raw_slice" => Some(TypeInfo::RawUntypedSlice),
         "Self" | "self" => Some(TypeInfo::SelfType),
         "Contract" => Some(TypeInfo::Contract),
         _other => None,
@@ -1043,6 +1146,43 @@ fn fn_signature_to_trait_fn(
     Ok(trait_fn)
 }
 
+fn trait_const_to_trait_constant(
+    ec: &mut ErrorContext,
+    trait_const: TraitConst,
+) -> Result<TraitConstant, ErrorEmitted> {
+    let type_span = trait_const.ty.span();
+    let trait_constant = TraitConstant {
+        name: trait_const.name,
+        type_ascription: ty_to_type_info(ec, trait_const.ty)?,
+        type_span,
+        value: match trait_const.default_opt {
+            Some((_eq_token, expr)) => Some(expr_to_expression(ec, expr)?),
+            None => None,
+        },
+    };
+    Ok(trait_constant)
+}
+
+fn trait_type_to_type_parameter(trait_type: TraitType) -> TypeParameter {
+    let name_ident = associated_type_ident(trait_type.name, trait_type.type_token.span());
+    TypeParameter {
+        type_id: insert_type(TypeInfo::Custom {
+            name: name_ident.clone(),
+            type_arguments: Vec::new(),
+        }),
+        name_ident,
+        trait_constraints: Vec::new(),
+    }
+}
+
+/// The name of an associated type as it is referred to within a trait and its implementations,
+/// i.e. `Self::<name>`.
+fn associated_type_ident(name: Ident, self_span: Span) -> Ident {
+    let qualified_name = format!("Self::{}", name.as_str());
+    let span = Span::join(self_span, name.span());
+    Ident::new_with_override(Box::leak(qualified_name.into_boxed_str()), span)
+}
+
 fn traits_to_call_paths(
     ec: &mut ErrorContext,
     traits: Traits,
@@ -1099,6 +1239,26 @@ fn path_type_to_call_path(
     Ok(call_path)
 }
 
+/// Like [path_type_to_call_path], but also returns the type arguments of the final segment, as in
+/// `From<u64>`.
+fn path_type_to_call_path_and_type_arguments(
+    ec: &mut ErrorContext,
+    mut path_type: PathType,
+) -> Result<(CallPath, Vec<TypeArgument>), ErrorEmitted> {
+    let last_segment = match path_type.suffix.last_mut() {
+        Some((_double_colon_token, segment)) => segment,
+        None => &mut path_type.prefix,
+    };
+    let type_arguments = match last_segment.generics_opt.take() {
+        Some((_double_colon_token, generic_args)) => {
+            generic_args_to_type_arguments(ec, generic_args)?
+        }
+        None => Vec::new(),
+    };
+    let call_path = path_type_to_call_path(ec, path_type)?;
+    Ok((call_path, type_arguments))
+}
+
 fn expr_to_ast_node(
     ec: &mut ErrorContext,
     expr: Expr,
@@ -1128,6 +1288,17 @@ fn expr_to_ast_node(
             }),
             span,
         },
+        Expr::For {
+            pattern,
+            iterable,
+            block,
+            ..
+        } => AstNode {
+            content: AstNodeContent::Expression(for_to_expression(
+                ec, *pattern, iterable, block, &span,
+            )?),
+            span,
+        },
         Expr::Reassignment {
             assignable,
             expr,
@@ -1181,6 +1352,146 @@ fn expr_to_ast_node(
     Ok(ast_node)
 }
 
+/// Desugar a `for` loop into a `while` loop within a block, which holds the index of the loop and
+/// either the end of the range or the array being iterated over:
+///
+/// ```ignore
+/// {
+///     let __for_loop_array_0 = array;
+///     let mut __for_loop_index_0 = 0;
+///     while __for_loop_index_0 < /* the length of the array */ {
+///         let pattern = __for_loop_array_0[__for_loop_index_0];
+///         __for_loop_index_0 = __for_loop_index_0 + 1;
+///         /* the body of the loop */
+///     }
+/// }
+/// ```
+///
+/// The index is incremented before the body so that a `continue` within it moves on to the next
+/// element.
+fn for_to_expression(
+    ec: &mut ErrorContext,
+    pattern: Pattern,
+    iterable: ForIterable,
+    block: Braces<CodeBlockContents>,
+    span: &Span,
+) -> Result<Expression, ErrorEmitted> {
+    // Generate deterministic names for the variables of the loop. The counter is local to the
+    // module being converted, so the names generated below will be stable.
+    let counter = ec.for_loop_counter;
+    ec.for_loop_counter += 1;
+    let var_name = |name: &str, span: Span| {
+        let var_name = format!(
+            "{}{}_{}",
+            crate::constants::FOR_LOOP_VAR_NAME_PREFIX,
+            name,
+            counter
+        );
+        Ident::new_with_override(Box::leak(var_name.into_boxed_str()), span)
+    };
+    let var_decl = |name: Ident, body: Expression, is_mutable: bool| AstNode {
+        content: AstNodeContent::Declaration(Declaration::VariableDeclaration(
+            VariableDeclaration {
+                name,
+                type_ascription: TypeInfo::Unknown,
+                type_ascription_span: None,
+                body,
+                is_mutable,
+            },
+        )),
+        span: span.clone(),
+    };
+    let var_exp = |name: &Ident| Expression::VariableExpression {
+        name: name.clone(),
+        span: name.span(),
+    };
+
+    let iterable_span = iterable.span();
+    let index_name = var_name("index", iterable_span.clone());
+    let (mut contents, end, element) = match iterable {
+        ForIterable::Range { start, end, .. } => {
+            let end_name = var_name("end", end.span());
+            let contents = vec![
+                var_decl(index_name.clone(), expr_to_expression(ec, *start)?, true),
+                var_decl(end_name.clone(), expr_to_expression(ec, *end)?, false),
+            ];
+            (contents, var_exp(&end_name), var_exp(&index_name))
+        }
+        ForIterable::Expr(array) => {
+            let array_name = var_name("array", array.span());
+            let contents = vec![
+                var_decl(array_name.clone(), expr_to_expression(ec, *array)?, false),
+                var_decl(
+                    index_name.clone(),
+                    Expression::Literal {
+                        value: Literal::U64(0),
+                        span: iterable_span.clone(),
+                    },
+                    true,
+                ),
+            ];
+            let end = Expression::ArrayLength {
+                array: Box::new(var_exp(&array_name)),
+                span: iterable_span.clone(),
+            };
+            let element = Expression::ArrayIndex {
+                prefix: Box::new(var_exp(&array_name)),
+                index: Box::new(var_exp(&index_name)),
+                span: iterable_span.clone(),
+            };
+            (contents, end, element)
+        }
+    };
+
+    let condition = binary_op_call(
+        "lt",
+        iterable_span.clone(),
+        iterable_span.clone(),
+        var_exp(&index_name),
+        end,
+    )?;
+    let increment = AstNode {
+        content: AstNodeContent::Declaration(Declaration::Reassignment(Reassignment {
+            lhs: ReassignmentTarget::VariableExpression(Box::new(var_exp(&index_name))),
+            rhs: binary_op_call(
+                "add",
+                iterable_span.clone(),
+                iterable_span.clone(),
+                var_exp(&index_name),
+                Expression::Literal {
+                    value: Literal::Numeric(1),
+                    span: iterable_span.clone(),
+                },
+            )?,
+            span: iterable_span.clone(),
+        })),
+        span: iterable_span,
+    };
+    let pattern_span = pattern.span();
+    let mut body = pattern_to_ast_nodes(ec, pattern, None, element, pattern_span)?;
+    body.push(increment);
+    let user_body = braced_code_block_contents_to_code_block(ec, block)?;
+    body.extend(user_body.contents);
+    contents.push(AstNode {
+        content: AstNodeContent::WhileLoop(WhileLoop {
+            condition,
+            body: CodeBlock {
+                contents: body,
+                whole_block_span: user_body.whole_block_span,
+            },
+        }),
+        span: span.clone(),
+    });
+
+    Ok(Expression::CodeBlock {
+        contents: CodeBlock {
+            contents,
+            whole_block_span: span.clone(),
+        },
+        span: span.clone(),
+    })
+}
+
 fn expr_to_expression(ec: &mut ErrorContext, expr: Expr) -> Result<Expression, ErrorEmitted> {
     let span = expr.span();
     let expression = match expr {
@@ -1268,14 +1579,14 @@ fn expr_to_expression(ec: &mut ErrorContext, expr: Expr) -> Result<Expression, E
             let var_decl_span = value.span();
 
             // Generate a deterministic name for the variable returned by the match expression.
-            // Because the parser is single threaded, the name generated below will be stable.
-            static COUNTER: AtomicUsize = AtomicUsize::new(0);
+            // The counter is local to the module being converted, so the name generated below
+            // will be stable.
             let match_return_var_name = format!(
                 "{}{}",
                 crate::constants::MATCH_RETURN_VAR_NAME_PREFIX,
-                COUNTER.load(Ordering::SeqCst)
+                ec.match_return_var_counter
             );
-            COUNTER.fetch_add(1, Ordering::SeqCst);
+            ec.match_return_var_counter += 1;
             let var_decl_name = Ident::new_with_override(
                 Box::leak(match_return_var_name.into_boxed_str()),
                 var_decl_span.clone(),
@@ -1329,6 +1640,14 @@ fn expr_to_expression(ec: &mut ErrorContext, expr: Expr) -> Result<Expression, E
             };
             return Err(ec.error(error));
         }
+        Expr::For { for_token, .. } => {
+            let error = ConvertParseTreeError::ForOutsideOfBlock {
+                span: for_token.span(),
+            };
+            return Err(ec.error(error));
+        }
+        Expr::Break { .. } => Expression::Break { span },
+        Expr::Continue { .. } => Expression::Continue { span },
         Expr::FuncApp { func, args } => {
             let path_expr = match *func {
                 Expr::Path(path_expr) => path_expr,
@@ -1534,6 +1853,22 @@ fn expr_to_expression(ec: &mut ErrorContext, expr: Expr) -> Result<Expression, E
                             kind: IntrinsicFunctionKind::SizeOfVal { exp },
                             span,
                         }
+                    } else if call_path.prefixes.is_empty()
+                        && !call_path.is_absolute
+                        && Intrinsic::try_from_str(call_path.suffix.as_str())
+                            == Some(Intrinsic::AddrOf)
+                    {
+                        let exp = match <[_; 1]>::try_from(arguments) {
+                            Ok([exp]) => Box::new(exp),
+                            Err(..) => {
+                                let error = ConvertParseTreeError::AddrOfOneArg { span };
+                                return Err(ec.error(error));
+                            }
+                        };
+                        Expression::IntrinsicFunction {
+                            kind: IntrinsicFunctionKind::AddrOf { exp },
+                            span,
+                        }
                     } else {
                         let type_arguments = match generics_opt {
                             Some((_double_colon_token, generic_args)) => {
@@ -1928,6 +2263,14 @@ fn fn_arg_to_function_parameter(
             let error = ConvertParseTreeError::TuplePatternsNotSupportedHere { span: pat_span };
             return Err(ec.error(error));
         }
+        Pattern::Range { .. } => {
+            let error = ConvertParseTreeError::RangePatternsNotSupportedHere { span: pat_span };
+            return Err(ec.error(error));
+        }
+        Pattern::Or { .. } => {
+            let error = ConvertParseTreeError::OrPatternsNotSupportedHere { span: pat_span };
+            return Err(ec.error(error));
+        }
     };
     let function_parameter = FunctionParameter {
         name,
@@ -2188,6 +2531,7 @@ fn if_expr_to_expression(
             let scrutinee_span = scrutinee.span();
             let mut branches = vec![MatchBranch {
                 scrutinee,
+                guard: None,
                 result: then_block.clone(),
                 span: Span::join(scrutinee_span, then_block_span),
             }];
@@ -2198,6 +2542,7 @@ fn if_expr_to_expression(
                         scrutinee: Scrutinee::CatchAll {
                             span: else_block_span.clone(),
                         },
+                        guard: None,
                         result: else_block,
                         span: else_block_span,
                     }
@@ -2208,6 +2553,7 @@ fn if_expr_to_expression(
                         scrutinee: Scrutinee::CatchAll {
                             span: else_block_span.clone(),
                         },
+                        guard: None,
                         result: then_block,
                         span: else_block_span,
                     }
@@ -2353,6 +2699,16 @@ fn literal_to_literal(
                         };
                         Literal::U64(value)
                     }
+                    LitIntType::U256 => {
+                        let bytes = parsed.to_bytes_be();
+                        if bytes.len() > 32 {
+                            let error = ConvertParseTreeError::U256LiteralOutOfRange { span };
+                            return Err(ec.error(error));
+                        }
+                        let mut full_bytes = [0u8; 32];
+                        full_bytes[(32 - bytes.len())..].copy_from_slice(&bytes);
+                        Literal::U256(full_bytes)
+                    }
                     LitIntType::I8 | LitIntType::I16 | LitIntType::I32 | LitIntType::I64 => {
                         let error = ConvertParseTreeError::SignedIntegersNotSupported { span };
                         return Err(ec.error(error));
@@ -2533,6 +2889,10 @@ fn match_branch_to_match_branch(
     let span = match_branch.span();
     Ok(MatchBranch {
         scrutinee: pattern_to_scrutinee(ec, match_branch.pattern)?,
+        guard: match match_branch.guard_opt {
+            Some((_if_token, guard)) => Some(expr_to_expression(ec, *guard)?),
+            None => None,
+        },
         result: match match_branch.kind {
             MatchBranchKind::Block { block, .. } => {
                 let span = block.span();
@@ -2551,128 +2911,9 @@ fn statement_let_to_ast_nodes(
     ec: &mut ErrorContext,
     statement_let: StatementLet,
 ) -> Result<Vec<AstNode>, ErrorEmitted> {
-    fn unfold(
-        ec: &mut ErrorContext,
-        pattern: Pattern,
-        ty_opt: Option<Ty>,
-        expression: Expression,
-        span: Span,
-    ) -> Result<Vec<AstNode>, ErrorEmitted> {
-        let ast_nodes = match pattern {
-            Pattern::Wildcard { .. } | Pattern::Var { .. } => {
-                let (mutable, name) = match pattern {
-                    Pattern::Var { mutable, name } => (mutable, name),
-                    Pattern::Wildcard { .. } => (None, Ident::new_no_span("_")),
-                    _ => unreachable!(),
-                };
-                let (type_ascription, type_ascription_span) = match ty_opt {
-                    Some(ty) => {
-                        let type_ascription_span = ty.span();
-                        let type_ascription = ty_to_type_info(ec, ty)?;
-                        (type_ascription, Some(type_ascription_span))
-                    }
-                    None => (TypeInfo::Unknown, None),
-                };
-                let ast_node = AstNode {
-                    content: AstNodeContent::Declaration(Declaration::VariableDeclaration(
-                        VariableDeclaration {
-                            name,
-                            type_ascription,
-                            type_ascription_span,
-                            body: expression,
-                            is_mutable: mutable.is_some(),
-                        },
-                    )),
-                    span,
-                };
-                vec![ast_node]
-            }
-            Pattern::Literal(..) => {
-                let error = ConvertParseTreeError::LiteralPatternsNotSupportedHere { span };
-                return Err(ec.error(error));
-            }
-            Pattern::Constant(..) => {
-                let error = ConvertParseTreeError::ConstantPatternsNotSupportedHere { span };
-                return Err(ec.error(error));
-            }
-            Pattern::Constructor { .. } => {
-                let error = ConvertParseTreeError::ConstructorPatternsNotSupportedHere { span };
-                return Err(ec.error(error));
-            }
-            Pattern::Struct { .. } => {
-                let error = ConvertParseTreeError::StructPatternsNotSupportedHere { span };
-                return Err(ec.error(error));
-            }
-            Pattern::Tuple(pat_tuple) => {
-                let mut ast_nodes = Vec::new();
-
-                // Generate a deterministic name for the tuple. Because the parser is single
-                // threaded, the name generated below will be stable.
-                static COUNTER: AtomicUsize = AtomicUsize::new(0);
-                let tuple_name = format!(
-                    "{}{}",
-                    crate::constants::TUPLE_NAME_PREFIX,
-                    COUNTER.load(Ordering::SeqCst)
-                );
-                COUNTER.fetch_add(1, Ordering::SeqCst);
-                let name =
-                    Ident::new_with_override(Box::leak(tuple_name.into_boxed_str()), span.clone());
-
-                let (type_ascription, type_ascription_span) = match &ty_opt {
-                    Some(ty) => {
-                        let type_ascription_span = ty.span();
-                        let type_ascription = ty_to_type_info(ec, ty.clone())?;
-                        (type_ascription, Some(type_ascription_span))
-                    }
-                    None => (TypeInfo::Unknown, None),
-                };
-                let save_body_first = VariableDeclaration {
-                    name: name.clone(),
-                    type_ascription,
-                    type_ascription_span,
-                    body: expression,
-                    is_mutable: false,
-                };
-                ast_nodes.push(AstNode {
-                    content: AstNodeContent::Declaration(Declaration::VariableDeclaration(
-                        save_body_first,
-                    )),
-                    span: span.clone(),
-                });
-                let new_expr = Expression::VariableExpression {
-                    name,
-                    span: span.clone(),
-                };
-                let tuple_tys_opt = match ty_opt {
-                    Some(Ty::Tuple(tys)) => Some(tys.into_inner().to_tys()),
-                    _ => None,
-                };
-                for (index, pattern) in pat_tuple.into_inner().into_iter().enumerate() {
-                    let ty_opt = match &tuple_tys_opt {
-                        Some(tys) => tys.get(index).cloned(),
-                        None => None,
-                    };
-                    ast_nodes.extend(unfold(
-                        ec,
-                        pattern,
-                        ty_opt,
-                        Expression::TupleIndex {
-                            prefix: Box::new(new_expr.clone()),
-                            index,
-                            index_span: span.clone(),
-                            span: span.clone(),
-                        },
-                        span.clone(),
-                    )?);
-                }
-                ast_nodes
-            }
-        };
-        Ok(ast_nodes)
-    }
     let span = statement_let.span();
     let initial_expression = expr_to_expression(ec, statement_let.expr)?;
-    unfold(
+    pattern_to_ast_nodes(
         ec,
         statement_let.pattern,
         statement_let.ty_opt.map(|(_colon_token, ty)| ty),
@@ -2681,6 +2922,134 @@ fn statement_let_to_ast_nodes(
     )
 }
 
+/// Bind the variables of a `let` pattern to the given expression.
+fn pattern_to_ast_nodes(
+    ec: &mut ErrorContext,
+    pattern: Pattern,
+    ty_opt: Option<Ty>,
+    expression: Expression,
+    span: Span,
+) -> Result<Vec<AstNode>, ErrorEmitted> {
+    let ast_nodes = match pattern {
+        Pattern::Wildcard { .. } | Pattern::Var { .. } => {
+            let (mutable, name) = match pattern {
+                Pattern::Var { mutable, name } => (mutable, name),
+                Pattern::Wildcard { .. } => (None, Ident::new_no_span("_")),
+                _ => unreachable!(),
+            };
+            let (type_ascription, type_ascription_span) = match ty_opt {
+                Some(ty) => {
+                    let type_ascription_span = ty.span();
+                    let type_ascription = ty_to_type_info(ec, ty)?;
+                    (type_ascription, Some(type_ascription_span))
+                }
+                None => (TypeInfo::Unknown, None),
+            };
+            let ast_node = AstNode {
+                content: AstNodeContent::Declaration(Declaration::VariableDeclaration(
+                    VariableDeclaration {
+                        name,
+                        type_ascription,
+                        type_ascription_span,
+                        body: expression,
+                        is_mutable: mutable.is_some(),
+                    },
+                )),
+                span,
+            };
+            vec![ast_node]
+        }
+        Pattern::Literal(..) => {
+            let error = ConvertParseTreeError::LiteralPatternsNotSupportedHere { span };
+            return Err(ec.error(error));
+        }
+        Pattern::Constant(..) => {
+            let error = ConvertParseTreeError::ConstantPatternsNotSupportedHere { span };
+            return Err(ec.error(error));
+        }
+        Pattern::Constructor { .. } => {
+            let error = ConvertParseTreeError::ConstructorPatternsNotSupportedHere { span };
+            return Err(ec.error(error));
+        }
+        Pattern::Struct { .. } => {
+            let error = ConvertParseTreeError::StructPatternsNotSupportedHere { span };
+            return Err(ec.error(error));
+        }
+        Pattern::Range { .. } => {
+            let error = ConvertParseTreeError::RangePatternsNotSupportedHere { span };
+            return Err(ec.error(error));
+        }
+        Pattern::Or { .. } => {
+            let error = ConvertParseTreeError::OrPatternsNotSupportedHere { span };
+            return Err(ec.error(error));
+        }
+        Pattern::Tuple(pat_tuple) => {
+            let mut ast_nodes = Vec::new();
+
+            // Generate a deterministic name for the tuple. The counter is local to the module
+            // being converted, so the name generated below will be stable.
+            let tuple_name = format!(
+                "{}{}",
+                crate::constants::TUPLE_NAME_PREFIX,
+                ec.tuple_counter
+            );
+            ec.tuple_counter += 1;
+            let name =
+                Ident::new_with_override(Box::leak(tuple_name.into_boxed_str()), span.clone());
+
+            let (type_ascription, type_ascription_span) = match &ty_opt {
+                Some(ty) => {
+                    let type_ascription_span = ty.span();
+                    let type_ascription = ty_to_type_info(ec, ty.clone())?;
+                    (type_ascription, Some(type_ascription_span))
+                }
+                None => (TypeInfo::Unknown, None),
+            };
+            let save_body_first = VariableDeclaration {
+                name: name.clone(),
+                type_ascription,
+                type_ascription_span,
+                body: expression,
+                is_mutable: false,
+            };
+            ast_nodes.push(AstNode {
+                content: AstNodeContent::Declaration(Declaration::VariableDeclaration(
+                    save_body_first,
+                )),
+                span: span.clone(),
+            });
+            let new_expr = Expression::VariableExpression {
+                name,
+                span: span.clone(),
+            };
+            let tuple_tys_opt = match ty_opt {
+                Some(Ty::Tuple(tys)) => Some(tys.into_inner().to_tys()),
+                _ => None,
+            };
+            for (index, pattern) in pat_tuple.into_inner().into_iter().enumerate() {
+                let ty_opt = match &tuple_tys_opt {
+                    Some(tys) => tys.get(index).cloned(),
+                    None => None,
+                };
+                ast_nodes.extend(pattern_to_ast_nodes(
+                    ec,
+                    pattern,
+                    ty_opt,
+                    Expression::TupleIndex {
+                        prefix: Box::new(new_expr.clone()),
+                        index,
+                        index_span: span.clone(),
+                        span: span.clone(),
+                    },
+                    span.clone(),
+                )?);
+            }
+            ast_nodes
+        }
+    };
+    Ok(ast_nodes)
+}
+
 fn dependency_to_include_statement(dependency: &Dependency) -> IncludeStatement {
     IncludeStatement {
         _alias: None,
@@ -2809,6 +3178,20 @@ fn pattern_to_scrutinee(
             },
             span,
         },
+        Pattern::Range { start, end, .. } => Scrutinee::Range {
+            start: literal_to_literal(ec, start)?,
+            end: literal_to_literal(ec, end)?,
+            span,
+        },
+        Pattern::Or { lhs, rhs, .. } => {
+            // Flatten nested alternatives so that `A | B | C` becomes a single or-pattern.
+            let mut elems = match pattern_to_scrutinee(ec, *lhs)? {
+                Scrutinee::Or { elems, .. } => elems,
+                lhs => vec![lhs],
+            };
+            elems.push(pattern_to_scrutinee(ec, *rhs)?);
+            Scrutinee::Or { elems, span }
+        }
     };
     Ok(scrutinee)
 }
@@ -3027,6 +3410,20 @@ fn path_type_to_type_info(
         prefix,
         suffix,
     } = path_type;
+    // `Self::Item` refers to an associated type of the trait being declared or implemented.
+    if root_opt.is_none()
+        && suffix.len() == 1
+        && prefix.name.as_str() == "Self"
+        && prefix.fully_qualified.is_none()
+        && prefix.generics_opt.is_none()
+    {
+        let (_double_colon_token, segment) = suffix.into_iter().next().unwrap();
+        let name = path_type_segment_to_ident(ec, segment)?;
+        return Ok(TypeInfo::Custom {
+            name: associated_type_ident(name, prefix.name.span()),
+            type_arguments: Vec::new(),
+        });
+    }
     if root_opt.is_some() || !suffix.is_empty() {
         let error = ConvertParseTreeError::FullySpecifiedTypesNotSupported { span };
         return Err(ec.error(error));
@@ -555,6 +555,27 @@ pub enum CompileError {
         missing_functions: String,
         span: Span,
     },
+    #[error("Constant \"{name}\" is not a part of trait \"{trait_name}\"'s interface surface.")]
+    ConstantNotAPartOfInterfaceSurface {
+        name: Ident,
+        trait_name: Ident,
+        span: Span,
+    },
+    #[error("Constants are missing from this trait implementation: {missing_constants}")]
+    MissingInterfaceSurfaceConstants {
+        missing_constants: String,
+        span: Span,
+    },
+    #[error(
+        "Associated type \"{name}\" is not a part of trait \"{trait_name}\"'s interface surface."
+    )]
+    TypeNotAPartOfInterfaceSurface {
+        name: Ident,
+        trait_name: Ident,
+        span: Span,
+    },
+    #[error("Associated types are missing from this trait implementation: {missing_types}")]
+    MissingInterfaceSurfaceTypes { missing_types: String, span: Span },
     #[error("Expected {} type {}, but instead found {}.", expected, if *expected == 1usize { "argument" } else { "arguments" }, given)]
     IncorrectNumberOfTypeArguments {
         given: usize,
@@ -615,6 +636,11 @@ pub enum CompileError {
         method_name: Ident,
         type_name: String,
     },
+    #[error("No associated constant named \"{constant_name}\" found for type \"{type_name}\".")]
+    ConstantNotFound {
+        constant_name: Ident,
+        type_name: String,
+    },
     #[error("Module \"{name}\" could not be found.")]
     ModuleNotFound { span: Span, name: String },
     #[error("\"{name}\" is a {actually}, not a struct. Fields can only be accessed on structs.")]
@@ -656,10 +682,30 @@ pub enum CompileError {
         available_fields: String,
         struct_name: Ident,
     },
+    #[error(
+        "Field \"{field_name}\" of struct \"{struct_name}\" is private. Private fields can only \
+         be used in the module that declares the struct."
+    )]
+    StructFieldIsPrivate {
+        field_name: Ident,
+        struct_name: String,
+        span: Span,
+    },
+    #[error(
+        "Struct \"{struct_name}\" cannot be instantiated here because its fields {private_fields} \
+         are private. Private fields can only be used in the module that declares the struct."
+    )]
+    StructHasPrivateFields {
+        struct_name: Ident,
+        private_fields: String,
+        span: Span,
+    },
     #[error("Could not find symbol \"{name}\" in this scope.")]
     SymbolNotFound { name: Ident },
     #[error("Symbol \"{name}\" is private.")]
     ImportPrivateSymbol { name: Ident },
+    #[error("Symbol \"{name}\" is re-exported in a cycle.")]
+    ReexportCycle { name: Ident },
     #[error(
         "Because this if expression's value is used, an \"else\" branch is required and it must \
          return type \"{r#type}\""
@@ -860,6 +906,10 @@ pub enum CompileError {
     ContractStorageFromExternalContext { span: Span },
     #[error("Array index out of bounds; the length is {count} but the index is {index}.")]
     ArrayOutOfBounds { index: u64, count: u64, span: Span },
+    #[error(
+        "This is a {actually}, not an array. Only ranges and arrays can be iterated over by a \"for\" loop."
+    )]
+    NotIterable { actually: String, span: Span },
     #[error("Tuple index out of bounds; the arity is {count} but the index is {index}.")]
     TupleIndexOutOfBounds {
         index: usize,
@@ -890,6 +940,16 @@ pub enum CompileError {
         missing_fields: Vec<String>,
         span: Span,
     },
+    #[error("Range patterns are only supported for integer types.")]
+    MatchRangePatternNotNumeric { span: Span },
+    #[error("Lower range bound {start} must be less than or equal to upper range bound {end}.")]
+    MatchRangePatternEmpty {
+        start: String,
+        end: String,
+        span: Span,
+    },
+    #[error("Variable \"{var}\" is not bound in all alternatives of this or-pattern.")]
+    MatchOrPatternVariableNotBound { var: Ident, span: Span },
     #[error(
         "Storage attribute access mismatch. Try giving the surrounding function more access by \
         adding \"#[{STORAGE_PURITY_ATTRIBUTE_NAME}({attrs})]\" to the function declaration."
@@ -939,6 +999,8 @@ pub enum CompileError {
     AsteriskWithAlias { span: Span },
     #[error("A trait cannot be a subtrait of an ABI.")]
     AbiAsSupertrait { span: Span },
+    #[error("An ABI can only have other ABIs as supertraits.")]
+    TraitAsAbiSupertrait { span: Span },
     #[error("The trait \"{supertrait_name}\" is not implemented for type \"{type_name}\"")]
     SupertraitImplMissing {
         supertrait_name: crate::parse_tree::CallPath,
@@ -995,6 +1057,10 @@ pub enum CompileError {
     NonConstantDeclValue { span: Span },
     #[error("Declaring storage in a {program_kind} is not allowed.")]
     StorageDeclarationInNonContract { program_kind: String, span: Span },
+    #[error("\"break\" used outside of a loop.")]
+    BreakOutsideLoop { span: Span },
+    #[error("\"continue\" used outside of a loop.")]
+    ContinueOutsideLoop { span: Span },
 }
 
 impl std::convert::From<TypeError> for CompileError {
@@ -1037,6 +1103,10 @@ impl Spanned for CompileError {
             UnknownTrait { span, .. } => span.clone(),
             FunctionNotAPartOfInterfaceSurface { span, .. } => span.clone(),
             MissingInterfaceSurfaceMethods { span, .. } => span.clone(),
+            ConstantNotAPartOfInterfaceSurface { span, .. } => span.clone(),
+            MissingInterfaceSurfaceConstants { span, .. } => span.clone(),
+            TypeNotAPartOfInterfaceSurface { span, .. } => span.clone(),
+            MissingInterfaceSurfaceTypes { span, .. } => span.clone(),
             IncorrectNumberOfTypeArguments { span, .. } => span.clone(),
             DoesNotTakeTypeArguments { span, .. } => span.clone(),
             NeedsTypeArguments { span, .. } => span.clone(),
@@ -1047,13 +1117,17 @@ impl Spanned for CompileError {
             StructMissingField { span, .. } => span.clone(),
             StructDoesNotHaveField { span, .. } => span.clone(),
             MethodNotFound { method_name, .. } => method_name.span(),
+            ConstantNotFound { constant_name, .. } => constant_name.span(),
             ModuleNotFound { span, .. } => span.clone(),
             NotATuple { span, .. } => span.clone(),
             NotAStruct { span, .. } => span.clone(),
             FieldAccessOnNonStruct { span, .. } => span.clone(),
             FieldNotFound { field_name, .. } => field_name.span(),
+            StructFieldIsPrivate { span, .. } => span.clone(),
+            StructHasPrivateFields { span, .. } => span.clone(),
             SymbolNotFound { name, .. } => name.span(),
             ImportPrivateSymbol { name } => name.span(),
+            ReexportCycle { name } => name.span(),
             NoElseBranch { span, .. } => span.clone(),
             UnqualifiedSelfType { span, .. } => span.clone(),
             NotAType { span, .. } => span.clone(),
@@ -1109,12 +1183,16 @@ impl Spanned for CompileError {
             BurnFromExternalContext { span, .. } => span.clone(),
             ContractStorageFromExternalContext { span, .. } => span.clone(),
             ArrayOutOfBounds { span, .. } => span.clone(),
+            NotIterable { span, .. } => span.clone(),
             ShadowsOtherSymbol { name } => name.span(),
             GenericShadowsGeneric { name } => name.span(),
             StarImportShadowsOtherSymbol { name } => name.span(),
             MatchWrongType { span, .. } => span.clone(),
             MatchExpressionNonExhaustive { span, .. } => span.clone(),
             MatchStructPatternMissingFields { span, .. } => span.clone(),
+            MatchRangePatternNotNumeric { span } => span.clone(),
+            MatchRangePatternEmpty { span, .. } => span.clone(),
+            MatchOrPatternVariableNotBound { span, .. } => span.clone(),
             NotAnEnum { span, .. } => span.clone(),
             StorageAccessMismatch { span, .. } => span.clone(),
             TraitDeclPureImplImpure { span, .. } => span.clone(),
@@ -1131,6 +1209,7 @@ impl Spanned for CompileError {
             IntegerContainsInvalidDigit { span, .. } => span.clone(),
             AsteriskWithAlias { span, .. } => span.clone(),
             AbiAsSupertrait { span, .. } => span.clone(),
+            TraitAsAbiSupertrait { span, .. } => span.clone(),
             SupertraitImplMissing { span, .. } => span.clone(),
             SupertraitImplRequired { span, .. } => span.clone(),
             IfLetNonEnum { span, .. } => span.clone(),
@@ -1151,6 +1230,8 @@ impl Spanned for CompileError {
             TupleIndexOutOfBounds { span, .. } => span.clone(),
             NonConstantDeclValue { span } => span.clone(),
             StorageDeclarationInNonContract { span, .. } => span.clone(),
+            BreakOutsideLoop { span } => span.clone(),
+            ContinueOutsideLoop { span } => span.clone(),
         }
     }
 }
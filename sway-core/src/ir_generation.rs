@@ -157,6 +157,8 @@ mod tests {
             print_intermediate_asm: false,
             print_finalized_asm: false,
             print_ir: false,
+            optimization_level: crate::OptLevel::Size,
+            include_debug_info: true,
         };
         let mut warnings = vec![];
         let mut errors = vec![];
@@ -130,6 +130,7 @@ fn compile_declarations(
 
             TypedDeclaration::StructDeclaration(_)
             | TypedDeclaration::EnumDeclaration(_)
+            | TypedDeclaration::TypeAliasDeclaration(_)
             | TypedDeclaration::TraitDeclaration(_)
             | TypedDeclaration::VariableDeclaration(_)
             | TypedDeclaration::Reassignment(_)
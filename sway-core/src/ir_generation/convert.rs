@@ -1,7 +1,7 @@
 use crate::{
     error::CompileError,
     parse_tree::Literal,
-    type_engine::{resolve_type, TypeId, TypeInfo},
+    type_engine::{resolve_type, IntegerBits, TypeId, TypeInfo},
 };
 
 use super::types::{create_enum_aggregate, create_tuple_aggregate};
@@ -35,6 +35,8 @@ pub(super) fn convert_literal_to_value(
         }
         Literal::Boolean(b) => Constant::get_bool(context, *b, span_id_idx),
         Literal::B256(bs) => Constant::get_b256(context, *bs, span_id_idx),
+        // `u256` is the exception to the above, as it doesn't fit in a register.
+        Literal::U256(bs) => Constant::get_u256(context, *bs, span_id_idx),
     }
 }
 
@@ -49,6 +51,7 @@ pub(super) fn convert_literal_to_constant(ast_literal: &Literal) -> Constant {
         Literal::String(s) => Constant::new_string(s.as_str().as_bytes().to_vec()),
         Literal::Boolean(b) => Constant::new_bool(*b),
         Literal::B256(bs) => Constant::new_b256(*bs),
+        Literal::U256(bs) => Constant::new_u256(*bs),
     }
 }
 
@@ -93,12 +96,16 @@ fn convert_resolved_type(
 
     Ok(match ast_type {
         // All integers are `u64`, see comment in convert_literal_to_value() above.
+        TypeInfo::UnsignedInteger(IntegerBits::TwoFiftySix) => Type::Uint(256),
         TypeInfo::UnsignedInteger(_) => Type::Uint(64),
         TypeInfo::Numeric => Type::Uint(64),
         TypeInfo::Boolean => Type::Bool,
         TypeInfo::Byte => Type::Uint(64),
         TypeInfo::B256 => Type::B256,
         TypeInfo::Str(n) => Type::String(*n),
+        // Pointers are just a word, much like integers.
+        TypeInfo::RawUntypedPtr => Type::Uint(64),
+        TypeInfo::RawUntypedSlice => Type::Slice,
         TypeInfo::Struct { fields, .. } => super::types::get_aggregate_for_types(
             context,
             fields
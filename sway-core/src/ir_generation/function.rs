@@ -27,6 +27,17 @@ pub(super) struct FnCompiler {
     pub(super) function: Function,
     pub(super) current_block: Block,
     lexical_map: LexicalMap,
+    /// The blocks of the loops enclosing the code being compiled, innermost last.
+    loop_stack: Vec<LoopBlocks>,
+}
+
+/// The blocks to which a `continue` or `break` within the body of a loop branch.
+struct LoopBlocks {
+    /// The block which evaluates the loop condition, for `continue`.
+    cond_block: Block,
+    /// The block which follows the loop, for `break`.  It is created by the first `break` so that
+    /// loops without one have their blocks in the usual order.
+    end_block: Option<Block>,
 }
 
 pub(super) enum StateAccessType {
@@ -46,6 +57,7 @@ impl FnCompiler {
             function,
             current_block: function.get_entry_block(context),
             lexical_map,
+            loop_stack: Vec::new(),
         }
     }
 
@@ -79,6 +91,10 @@ impl FnCompiler {
             .contents
             .into_iter()
             .map(|ast_node| {
+                // Anything following a `return`, `break` or `continue` is unreachable.
+                if self.current_block.is_terminated(context) {
+                    return Ok(Constant::get_unit(context, None));
+                }
                 let span_md_idx = MetadataIndex::from_span(context, &ast_node.span);
                 match ast_node.content {
                     TypedAstNodeContent::ReturnStatement(trs) => {
@@ -134,6 +150,14 @@ impl FnCompiler {
                             let span_md_idx = MetadataIndex::from_span(context, &span);
                             Ok(Constant::get_unit(context, span_md_idx))
                         }
+                        TypedDeclaration::TypeAliasDeclaration(TypedTypeAliasDeclaration {
+                            span,
+                            ..
+                        }) => {
+                            // A type alias has been resolved away during type checking.
+                            let span_md_idx = MetadataIndex::from_span(context, &span);
+                            Ok(Constant::get_unit(context, span_md_idx))
+                        }
                         TypedDeclaration::AbiDeclaration(_) => {
                             Err(CompileError::UnexpectedDeclaration {
                                 decl_type: "abi",
@@ -312,6 +336,19 @@ impl FnCompiler {
                 self.compile_unsafe_downcast(context, exp, variant)
             }
             TypedExpressionVariant::EnumTag { exp } => self.compile_enum_tag(context, exp),
+            TypedExpressionVariant::Break => {
+                self.compile_break(context, &ast_expr.span)
+                    .ok_or(CompileError::Internal(
+                        "Unexpected 'break' outside of a loop.",
+                        ast_expr.span,
+                    ))
+            }
+            TypedExpressionVariant::Continue => self
+                .compile_continue(context, &ast_expr.span)
+                .ok_or(CompileError::Internal(
+                    "Unexpected 'continue' outside of a loop.",
+                    ast_expr.span,
+                )),
         }
     }
 
@@ -353,6 +390,49 @@ impl FnCompiler {
                     .ins(context)
                     .get_storage_key(span_md_idx, None))
             }
+            TypedIntrinsicFunctionKind::AddrOf { exp } => {
+                let span_md_idx = MetadataIndex::from_span(context, &span);
+                let value = self.compile_expression(context, *exp)?;
+                let value_type = value.get_type(context).unwrap();
+                if value_type.is_copy_type() {
+                    // Copy types live in registers, so copy the value to the stack and use the
+                    // address of that, as is done for by-reference contract call args.
+                    let addr_of_name = self.lexical_map.insert("addr_of_value".to_owned());
+                    let addr_of_local = self
+                        .function
+                        .new_local_ptr(context, addr_of_name, value_type, false, None)
+                        .map_err(|ir_error| {
+                            CompileError::InternalOwned(ir_error.to_string(), Span::dummy())
+                        })?;
+                    let local_ptr =
+                        self.current_block
+                            .ins(context)
+                            .get_ptr(addr_of_local, value_type, 0, None);
+                    self.current_block
+                        .ins(context)
+                        .store(local_ptr, value, None);
+                    Ok(self.current_block.ins(context).get_ptr(
+                        addr_of_local,
+                        Type::Uint(64),
+                        0,
+                        span_md_idx,
+                    ))
+                } else {
+                    // Reference types are already pointers, so an empty ASM block is enough to
+                    // reinterpret the value as its address.
+                    let ptr_name = Ident::new_no_span("ptr");
+                    Ok(self.current_block.ins(context).asm_block(
+                        vec![AsmArg {
+                            name: ptr_name.clone(),
+                            initializer: Some(value),
+                        }],
+                        Vec::new(),
+                        Type::Uint(64),
+                        Some(ptr_name),
+                        span_md_idx,
+                    ))
+                }
+            }
         }
     }
 
@@ -766,7 +846,7 @@ impl FnCompiler {
         self.current_block = true_block_begin;
         let true_value = self.compile_expression(context, ast_then)?;
         let true_block_end = self.current_block;
-        let then_returns = true_block_end.is_terminated_by_ret(context);
+        let then_returns = true_block_end.is_terminated(context);
 
         let false_block_begin = self.function.create_block(context, None);
         self.current_block = false_block_begin;
@@ -775,7 +855,7 @@ impl FnCompiler {
             Some(expr) => self.compile_expression(context, *expr)?,
         };
         let false_block_end = self.current_block;
-        let else_returns = false_block_end.is_terminated_by_ret(context);
+        let else_returns = false_block_end.is_terminated(context);
 
         entry_block.ins(context).conditional_branch(
             cond_value,
@@ -873,20 +953,32 @@ impl FnCompiler {
             .ins(context)
             .branch(cond_block, None, None);
 
-        // Fill in the body block now, jump unconditionally to the cond block at its end.
+        // Fill in the body block now, jump unconditionally to the cond block at its end unless
+        // the body has already jumped away.
         let body_block = self
             .function
             .create_block(context, Some("while_body".into()));
         self.current_block = body_block;
-        self.compile_code_block(context, ast_while_loop.body)?;
-        self.current_block
-            .ins(context)
-            .branch(cond_block, None, None);
+        self.loop_stack.push(LoopBlocks {
+            cond_block,
+            end_block: None,
+        });
+        let body_result = self.compile_code_block(context, ast_while_loop.body);
+        let loop_blocks = self.loop_stack.pop().unwrap();
+        body_result?;
+        if !self.current_block.is_terminated(context) {
+            self.current_block
+                .ins(context)
+                .branch(cond_block, None, None);
+        }
 
-        // Create the final block after we're finished with the body.
-        let final_block = self
-            .function
-            .create_block(context, Some("end_while".into()));
+        // Create the final block after we're finished with the body, if a `break` hasn't already.
+        let final_block = match loop_blocks.end_block {
+            Some(end_block) => end_block,
+            None => self
+                .function
+                .create_block(context, Some("end_while".into())),
+        };
 
         // Add the conditional which jumps into the body or out to the final block.
         self.current_block = cond_block;
@@ -903,6 +995,31 @@ impl FnCompiler {
         Ok(Constant::get_unit(context, span_md_idx))
     }
 
+    /// Branch to the end of the innermost loop, or return `None` if there is no enclosing loop.
+    fn compile_break(&mut self, context: &mut Context, span: &Span) -> Option<Value> {
+        let span_md_idx = MetadataIndex::from_span(context, span);
+        let function = self.function;
+        let loop_blocks = self.loop_stack.last_mut()?;
+        let end_block = *loop_blocks
+            .end_block
+            .get_or_insert_with(|| function.create_block(context, Some("end_while".into())));
+        self.current_block
+            .ins(context)
+            .branch(end_block, None, span_md_idx);
+        Some(Constant::get_unit(context, span_md_idx))
+    }
+
+    /// Branch to the condition of the innermost loop, or return `None` if there is no enclosing
+    /// loop.
+    fn compile_continue(&mut self, context: &mut Context, span: &Span) -> Option<Value> {
+        let span_md_idx = MetadataIndex::from_span(context, span);
+        let cond_block = self.loop_stack.last()?.cond_block;
+        self.current_block
+            .ins(context)
+            .branch(cond_block, None, span_md_idx);
+        Some(Constant::get_unit(context, span_md_idx))
+    }
+
     fn compile_var_expr(
         &mut self,
         context: &mut Context,
@@ -1653,7 +1770,11 @@ impl FnCompiler {
                         "Arrays in storage have not been implemented yet.",
                         Span::dummy(),
                     )),
-                    Type::B256 => self.compile_b256_storage(
+                    Type::Slice => Err(CompileError::Internal(
+                        "Raw slices point into memory and cannot be kept in storage.",
+                        Span::dummy(),
+                    )),
+                    Type::B256 | Type::Uint(256) => self.compile_b256_storage(
                         context,
                         access_type,
                         ix,
@@ -94,7 +94,7 @@ pub fn serialize_to_storage_slots(
                 ),
             )]
         }
-        (Type::B256, ConstantValue::B256(b)) => {
+        (Type::B256, ConstantValue::B256(b)) | (Type::Uint(256), ConstantValue::U256(b)) => {
             vec![StorageSlot::new(
                 get_storage_key(ix, indices),
                 Bytes32::new(*b),
@@ -177,11 +177,9 @@ pub fn serialize_to_words(constant: &Constant, context: &Context, ty: &Type) ->
         (Type::Uint(_), ConstantValue::Uint(n)) => {
             vec![Bytes8::new(n.to_be_bytes())]
         }
-        (Type::B256, ConstantValue::B256(b)) => Vec::from_iter(
-            (0..4)
-                .into_iter()
-                .map(|i| Bytes8::new(b[8 * i..8 * i + 8].try_into().unwrap())),
-        ),
+        (Type::B256, ConstantValue::B256(b)) | (Type::Uint(256), ConstantValue::U256(b)) => {
+            Vec::from_iter((0..4).map(|i| Bytes8::new(b[8 * i..8 * i + 8].try_into().unwrap())))
+        }
         (Type::String(_), ConstantValue::String(s)) => {
             // Turn the serialized words (Bytes8) into seriliazed storage slots (Bytes32)
             // Pad to word alignment
@@ -4,6 +4,7 @@ pub mod error;
 mod asm_generation;
 mod asm_lang;
 mod build_config;
+pub mod bytecode_layout;
 mod concurrent_slab;
 pub mod constants;
 mod control_flow_analysis;
@@ -15,9 +16,9 @@ pub mod source_map;
 mod style;
 pub mod type_engine;
 
-use crate::{error::*, source_map::SourceMap};
+use crate::{bytecode_layout::BytecodeLayout, error::*, source_map::SourceMap};
 use asm_generation::FinalizedAsm;
-pub use build_config::BuildConfig;
+pub use build_config::{BuildConfig, OptLevel};
 use control_flow_analysis::ControlFlowGraph;
 use std::collections::HashMap;
 use std::path::{Path, PathBuf};
@@ -194,6 +195,7 @@ pub enum CompileAstResult {
 pub enum BytecodeCompilationResult {
     Success {
         bytes: Vec<u8>,
+        layout: BytecodeLayout,
         warnings: Vec<CompileWarning>,
     },
     Library {
@@ -400,12 +402,14 @@ pub(crate) fn compile_ast_to_ir_to_asm(
 
     // The only other optimisation we have at the moment is constant combining.  In lieu of a
     // forthcoming pass manager we can just call it here now.
-    check!(
-        combine_constants(&mut ir, &entry_point_functions),
-        return err(warnings, errors),
-        warnings,
-        errors
-    );
+    if build_config.optimization_level.optimizes() {
+        check!(
+            combine_constants(&mut ir, &entry_point_functions),
+            return err(warnings, errors),
+            warnings,
+            errors
+        );
+    }
 
     if build_config.print_ir {
         tracing::info!("{}", ir);
@@ -469,17 +473,18 @@ pub fn asm_to_bytecode(
         } => {
             let mut asm_res = asm.to_bytecode_mut(source_map);
             warnings.append(&mut asm_res.warnings);
-            if asm_res.value.is_none() || !asm_res.errors.is_empty() {
-                BytecodeCompilationResult::Failure {
-                    warnings,
-                    errors: asm_res.errors,
+            match asm_res.value {
+                Some((bytes, layout)) if asm_res.errors.is_empty() => {
+                    BytecodeCompilationResult::Success {
+                        bytes,
+                        layout,
+                        warnings,
+                    }
                 }
-            } else {
-                // asm_res is confirmed to be Some(bytes).
-                BytecodeCompilationResult::Success {
-                    bytes: asm_res.value.unwrap(),
+                _ => BytecodeCompilationResult::Failure {
                     warnings,
-                }
+                    errors: asm_res.errors,
+                },
             }
         }
         CompilationResult::Failure { warnings, errors } => {
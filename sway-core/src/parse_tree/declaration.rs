@@ -7,6 +7,7 @@ mod reassignment;
 mod storage;
 mod r#struct;
 mod r#trait;
+mod type_alias;
 mod variable;
 
 pub(crate) use abi::*;
@@ -18,6 +19,7 @@ pub use r#struct::*;
 pub use r#trait::*;
 pub(crate) use reassignment::*;
 pub use storage::*;
+pub use type_alias::*;
 pub use variable::*;
 
 #[derive(Debug, Clone)]
@@ -33,4 +35,5 @@ pub enum Declaration {
     AbiDeclaration(AbiDeclaration),
     ConstantDeclaration(ConstantDeclaration),
     StorageDeclaration(StorageDeclaration),
+    TypeAliasDeclaration(TypeAliasDeclaration),
 }
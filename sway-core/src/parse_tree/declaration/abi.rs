@@ -1,4 +1,4 @@
-use super::{FunctionDeclaration, TraitFn};
+use super::{FunctionDeclaration, Supertrait, TraitFn};
 
 use sway_types::{ident::Ident, span::Span};
 
@@ -12,5 +12,7 @@ pub struct AbiDeclaration {
     pub interface_surface: Vec<TraitFn>,
     /// The methods provided to a contract "for free" upon opting in to this interface
     pub methods: Vec<FunctionDeclaration>,
+    /// The abis whose methods a contract must also implement in order to opt in to this interface
+    pub(crate) supertraits: Vec<Supertrait>,
     pub(crate) span: Span,
 }
@@ -1,17 +1,20 @@
-use super::FunctionDeclaration;
+use super::{ConstantDeclaration, FunctionDeclaration};
 use crate::{
     parse_tree::CallPath,
-    type_engine::{TypeInfo, TypeParameter},
+    type_engine::{TypeArgument, TypeInfo, TypeParameter},
 };
 
-use sway_types::span::Span;
+use sway_types::{ident::Ident, span::Span};
 
 #[derive(Debug, Clone)]
 pub struct ImplTrait {
     pub trait_name: CallPath,
+    pub(crate) trait_type_arguments: Vec<TypeArgument>,
     pub(crate) type_implementing_for: TypeInfo,
     pub(crate) type_implementing_for_span: Span,
     pub(crate) type_parameters: Vec<TypeParameter>,
+    pub(crate) associated_types: Vec<AssociatedType>,
+    pub(crate) constants: Vec<ConstantDeclaration>,
     pub functions: Vec<FunctionDeclaration>,
     // the span of the whole impl trait and block
     pub(crate) block_span: Span,
@@ -24,7 +27,16 @@ pub struct ImplSelf {
     pub type_implementing_for: TypeInfo,
     pub(crate) type_implementing_for_span: Span,
     pub(crate) type_parameters: Vec<TypeParameter>,
+    pub(crate) constants: Vec<ConstantDeclaration>,
     pub functions: Vec<FunctionDeclaration>,
     // the span of the whole impl trait and block
     pub(crate) block_span: Span,
 }
+
+/// The definition of one of a trait's associated types within an implementation of the trait,
+/// like `type Item = u64;`. The `name` is `Self::<name>`, as in the trait declaration.
+#[derive(Debug, Clone)]
+pub struct AssociatedType {
+    pub name: Ident,
+    pub type_argument: TypeArgument,
+}
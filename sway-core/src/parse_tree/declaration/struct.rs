@@ -16,6 +16,7 @@ pub struct StructDeclaration {
 
 #[derive(Debug, Clone)]
 pub struct StructField {
+    pub visibility: Visibility,
     pub name: Ident,
     pub(crate) type_info: TypeInfo,
     pub(crate) span: Span,
@@ -2,8 +2,8 @@ use super::{FunctionDeclaration, FunctionParameter};
 
 use crate::{
     function::Purity,
-    parse_tree::{CallPath, Visibility},
-    type_engine::TypeInfo,
+    parse_tree::{CallPath, Expression, Visibility},
+    type_engine::{TypeInfo, TypeParameter},
 };
 
 use sway_types::{ident::Ident, span::Span};
@@ -11,6 +11,11 @@ use sway_types::{ident::Ident, span::Span};
 #[derive(Debug, Clone)]
 pub struct TraitDeclaration {
     pub name: Ident,
+    pub(crate) type_parameters: Vec<TypeParameter>,
+    /// The associated types of the trait, named `Self::<name>` so that they can be referred to
+    /// as such within the trait and its implementations.
+    pub(crate) associated_types: Vec<TypeParameter>,
+    pub(crate) constants: Vec<TraitConstant>,
     pub(crate) interface_surface: Vec<TraitFn>,
     pub methods: Vec<FunctionDeclaration>,
     pub(crate) supertraits: Vec<Supertrait>,
@@ -30,3 +35,13 @@ pub struct TraitFn {
     pub return_type: TypeInfo,
     pub(crate) return_type_span: Span,
 }
+
+/// An associated constant that implementations of a trait must provide, unless it has a default
+/// `value`.
+#[derive(Debug, Clone)]
+pub struct TraitConstant {
+    pub name: Ident,
+    pub type_ascription: TypeInfo,
+    pub(crate) type_span: Span,
+    pub value: Option<Expression>,
+}
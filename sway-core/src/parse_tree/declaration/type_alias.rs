@@ -0,0 +1,16 @@
+use crate::{
+    parse_tree::Visibility,
+    type_engine::{TypeInfo, TypeParameter},
+};
+
+use sway_types::{ident::Ident, span::Span};
+
+#[derive(Debug, Clone)]
+pub struct TypeAliasDeclaration {
+    pub name: Ident,
+    pub(crate) type_parameters: Vec<TypeParameter>,
+    pub(crate) type_info: TypeInfo,
+    pub type_span: Span,
+    pub visibility: Visibility,
+    pub(crate) span: Span,
+}
@@ -15,4 +15,7 @@ pub enum IntrinsicFunctionKind {
         type_span: Span,
     },
     GetStorageKey,
+    AddrOf {
+        exp: Box<Expression>,
+    },
 }
@@ -134,6 +134,12 @@ pub enum Expression {
         index: Box<Expression>,
         span: Span,
     },
+    /// The length of an array, which is only known once the array has been type checked. Used
+    /// by the desugaring of `for` loops over arrays.
+    ArrayLength {
+        array: Box<Expression>,
+        span: Span,
+    },
     StorageAccess {
         field_names: Vec<Ident>,
         span: Span,
@@ -142,6 +148,14 @@ pub enum Expression {
         kind: IntrinsicFunctionKind,
         span: Span,
     },
+    /// A `break` out of the innermost enclosing loop.
+    Break {
+        span: Span,
+    },
+    /// A `continue` to the next iteration of the innermost enclosing loop.
+    Continue {
+        span: Span,
+    },
 }
 
 #[derive(Clone, Debug, PartialEq, Hash)]
@@ -178,8 +192,11 @@ impl Spanned for Expression {
             DelineatedPath { span, .. } => span,
             AbiCast { span, .. } => span,
             ArrayIndex { span, .. } => span,
+            ArrayLength { span, .. } => span,
             StorageAccess { span, .. } => span,
             IntrinsicFunction { span, .. } => span,
+            Break { span } => span,
+            Continue { span } => span,
         })
         .clone()
     }
@@ -33,6 +33,17 @@ pub enum Scrutinee {
         elems: Vec<Scrutinee>,
         span: Span,
     },
+    /// An inclusive range of literals, e.g. `0..=9`.
+    Range {
+        start: Literal,
+        end: Literal,
+        span: Span,
+    },
+    /// A list of alternatives, of which at least one must match, e.g. `A | B`.
+    Or {
+        elems: Vec<Scrutinee>,
+        span: Span,
+    },
 }
 
 #[derive(Debug, Clone)]
@@ -56,6 +67,8 @@ impl Spanned for Scrutinee {
             Scrutinee::StructScrutinee { span, .. } => span.clone(),
             Scrutinee::EnumScrutinee { span, .. } => span.clone(),
             Scrutinee::Tuple { span, .. } => span.clone(),
+            Scrutinee::Range { span, .. } => span.clone(),
+            Scrutinee::Or { span, .. } => span.clone(),
         }
     }
 }
@@ -148,13 +161,14 @@ impl Scrutinee {
                 let value = value.gather_approximate_typeinfo_dependencies();
                 vec![name, value].concat()
             }
-            Scrutinee::Tuple { elems, .. } => elems
+            Scrutinee::Tuple { elems, .. } | Scrutinee::Or { elems, .. } => elems
                 .iter()
                 .flat_map(|scrutinee| scrutinee.gather_approximate_typeinfo_dependencies())
                 .collect::<Vec<TypeInfo>>(),
-            Scrutinee::Literal { .. } | Scrutinee::CatchAll { .. } | Scrutinee::Variable { .. } => {
-                vec![]
-            }
+            Scrutinee::Literal { .. }
+            | Scrutinee::CatchAll { .. }
+            | Scrutinee::Variable { .. }
+            | Scrutinee::Range { .. } => vec![],
         }
     }
 }
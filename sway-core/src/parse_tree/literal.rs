@@ -14,6 +14,7 @@ pub enum Literal {
     U16(u16),
     U32(u32),
     U64(u64),
+    U256([u8; 32]),
     String(span::Span),
     Numeric(u64),
     Boolean(bool),
@@ -64,6 +65,10 @@ impl Hash for Literal {
                 state.write_u8(9);
                 x.hash(state);
             }
+            U256(x) => {
+                state.write_u8(10);
+                x.hash(state);
+            }
         }
     }
 }
@@ -83,6 +88,7 @@ impl PartialEq for Literal {
             (Self::Boolean(l0), Self::Boolean(r0)) => l0 == r0,
             (Self::Byte(l0), Self::Byte(r0)) => l0 == r0,
             (Self::B256(l0), Self::B256(r0)) => l0 == r0,
+            (Self::U256(l0), Self::U256(r0)) => l0 == r0,
             _ => false,
         }
     }
@@ -95,6 +101,13 @@ impl fmt::Display for Literal {
             Literal::U16(content) => content.to_string(),
             Literal::U32(content) => content.to_string(),
             Literal::U64(content) => content.to_string(),
+            Literal::U256(content) => format!(
+                "0x{}",
+                content
+                    .iter()
+                    .map(|x| format!("{:02x}", x))
+                    .collect::<String>()
+            ),
             Literal::Numeric(content) => content.to_string(),
             Literal::String(content) => content.as_str().to_string(),
             Literal::Boolean(content) => content.to_string(),
@@ -118,6 +131,7 @@ impl Literal {
             U16(_) => ResolvedType::UnsignedInteger(IntegerBits::Sixteen),
             U32(_) => ResolvedType::UnsignedInteger(IntegerBits::ThirtyTwo),
             U64(_) => ResolvedType::UnsignedInteger(IntegerBits::SixtyFour),
+            U256(_) => ResolvedType::UnsignedInteger(IntegerBits::TwoFiftySix),
             Numeric(_) => ResolvedType::UnsignedInteger(IntegerBits::SixtyFour),
             String(inner) => ResolvedType::Str(inner.as_str().len() as u64),
             Boolean(_) => ResolvedType::Boolean,
@@ -140,6 +154,7 @@ impl Literal {
                 vec![0, 0, 0, 0, bytes[0], bytes[1], bytes[2], bytes[3]]
             }
             U64(val) => val.to_be_bytes().to_vec(),
+            U256(val) => val.to_vec(),
             Numeric(val) => val.to_be_bytes().to_vec(),
             Boolean(b) => {
                 vec![
@@ -198,6 +213,21 @@ impl Literal {
         }
     }
 
+    /// Returns the value of an unsigned integer literal, or `None` for any other kind of literal.
+    pub(crate) fn as_integer(&self) -> Option<u64> {
+        match self {
+            Literal::U8(x) => Some(*x as u64),
+            Literal::U16(x) => Some(*x as u64),
+            Literal::U32(x) => Some(*x as u64),
+            Literal::U64(x) | Literal::Numeric(x) => Some(*x),
+            Literal::U256(_)
+            | Literal::String(_)
+            | Literal::Boolean(_)
+            | Literal::Byte(_)
+            | Literal::B256(_) => None,
+        }
+    }
+
     pub(crate) fn to_typeinfo(&self) -> TypeInfo {
         match self {
             Literal::String(s) => TypeInfo::Str(s.as_str().len() as u64),
@@ -206,6 +236,7 @@ impl Literal {
             Literal::U16(_) => TypeInfo::UnsignedInteger(IntegerBits::Sixteen),
             Literal::U32(_) => TypeInfo::UnsignedInteger(IntegerBits::ThirtyTwo),
             Literal::U64(_) => TypeInfo::UnsignedInteger(IntegerBits::SixtyFour),
+            Literal::U256(_) => TypeInfo::UnsignedInteger(IntegerBits::TwoFiftySix),
             Literal::Boolean(_) => TypeInfo::Boolean,
             Literal::Byte(_) => TypeInfo::Byte,
             Literal::B256(_) => TypeInfo::B256,
@@ -1,3 +1,5 @@
+use crate::parse_tree::Visibility;
+
 use sway_types::ident::Ident;
 
 #[derive(Debug, Clone)]
@@ -16,4 +18,7 @@ pub struct UseStatement {
     // the project root namespace. If not, then it is relative to the current namespace.
     pub(crate) is_absolute: bool,
     pub(crate) alias: Option<Ident>,
+    // A `pub use` statement re-exports the imported symbols, so that other modules may in turn
+    // import them from this module.
+    pub(crate) visibility: Visibility,
 }
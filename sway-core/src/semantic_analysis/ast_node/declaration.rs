@@ -6,6 +6,7 @@ mod monomorphize;
 mod storage;
 mod r#struct;
 mod r#trait;
+mod type_alias;
 mod variable;
 
 pub use abi::*;
@@ -16,6 +17,7 @@ pub use r#enum::*;
 pub use r#struct::*;
 pub use r#trait::*;
 pub use storage::*;
+pub use type_alias::*;
 pub use variable::*;
 
 use crate::{error::*, parse_tree::*, semantic_analysis::*, type_engine::*};
@@ -34,6 +36,7 @@ pub enum TypedDeclaration {
     Reassignment(TypedReassignment),
     ImplTrait(TypedImplTrait),
     AbiDeclaration(TypedAbiDeclaration),
+    TypeAliasDeclaration(TypedTypeAliasDeclaration),
     // If type parameters are defined for a function, they are put in the namespace just for
     // the body of that function.
     GenericTypeForFunctionScope { name: Ident, type_id: TypeId },
@@ -56,6 +59,7 @@ impl CopyTypes for TypedDeclaration {
             EnumDeclaration(ref mut enum_decl) => enum_decl.copy_types(type_mapping),
             Reassignment(ref mut reassignment) => reassignment.copy_types(type_mapping),
             ImplTrait(impl_trait) => impl_trait.copy_types(type_mapping),
+            TypeAliasDeclaration(ref mut alias_decl) => alias_decl.copy_types(type_mapping),
             // generics in an ABI is unsupported by design
             AbiDeclaration(..) => (),
             StorageDeclaration(..) => (),
@@ -84,6 +88,7 @@ impl Spanned for TypedDeclaration {
             }),
             AbiDeclaration(TypedAbiDeclaration { span, .. }) => span.clone(),
             ImplTrait(TypedImplTrait { span, .. }) => span.clone(),
+            TypeAliasDeclaration(TypedTypeAliasDeclaration { name, .. }) => name.span(),
             StorageDeclaration(decl) => decl.span(),
             StorageReassignment(decl) => decl.span(),
             ErrorRecovery | GenericTypeForFunctionScope { .. } => {
@@ -133,6 +138,11 @@ impl fmt::Display for TypedDeclaration {
                     name.as_str().into(),
                 TypedDeclaration::EnumDeclaration(TypedEnumDeclaration { name, .. }) =>
                     name.as_str().into(),
+                TypedDeclaration::TypeAliasDeclaration(TypedTypeAliasDeclaration {
+                    name,
+                    type_id,
+                    ..
+                }) => format!("{} = {}", name, look_up_type_id(*type_id)),
                 TypedDeclaration::Reassignment(TypedReassignment {
                     lhs_base_name,
                     lhs_indices,
@@ -202,6 +212,7 @@ impl UnresolvedTypeCheck for TypedDeclaration {
             | TraitDeclaration(_)
             | StructDeclaration(_)
             | EnumDeclaration(_)
+            | TypeAliasDeclaration(_)
             | ImplTrait { .. }
             | AbiDeclaration(_)
             | GenericTypeForFunctionScope { .. } => vec![],
@@ -367,6 +378,7 @@ impl TypedDeclaration {
             Reassignment(_) => "reassignment",
             ImplTrait { .. } => "impl trait",
             AbiDeclaration(..) => "abi",
+            TypeAliasDeclaration(_) => "type alias",
             GenericTypeForFunctionScope { .. } => "generic type parameter",
             ErrorRecovery => "error",
             StorageDeclaration(_) => "contract storage declaration",
@@ -390,6 +402,7 @@ impl TypedDeclaration {
             }
             TypedDeclaration::StructDeclaration(decl) => decl.create_type_id(),
             TypedDeclaration::EnumDeclaration(decl) => decl.create_type_id(),
+            TypedDeclaration::TypeAliasDeclaration(decl) => decl.create_type_id(),
             TypedDeclaration::Reassignment(TypedReassignment { rhs, .. }) => rhs.return_type,
             TypedDeclaration::StorageDeclaration(decl) => insert_type(TypeInfo::Storage {
                 fields: decl.fields_as_typed_struct_fields(),
@@ -428,7 +441,8 @@ impl TypedDeclaration {
             | ConstantDeclaration(TypedConstantDeclaration { visibility, .. })
             | FunctionDeclaration(TypedFunctionDeclaration { visibility, .. })
             | TraitDeclaration(TypedTraitDeclaration { visibility, .. })
-            | StructDeclaration(TypedStructDeclaration { visibility, .. }) => *visibility,
+            | StructDeclaration(TypedStructDeclaration { visibility, .. })
+            | TypeAliasDeclaration(TypedTypeAliasDeclaration { visibility, .. }) => *visibility,
         }
     }
 }
@@ -460,6 +474,9 @@ pub struct TypedTraitFn {
 
 impl CopyTypes for TypedTraitFn {
     fn copy_types(&mut self, type_mapping: &TypeMapping) {
+        self.parameters
+            .iter_mut()
+            .for_each(|x| x.copy_types(type_mapping));
         self.return_type
             .update_type(type_mapping, &self.return_type_span);
     }
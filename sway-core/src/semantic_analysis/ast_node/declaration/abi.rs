@@ -1,5 +1,5 @@
 use derivative::Derivative;
-use sway_types::{Ident, Span};
+use sway_types::{Ident, Span, Spanned};
 
 use crate::{
     error::{err, ok},
@@ -8,7 +8,8 @@ use crate::{
         TypeCheckContext,
     },
     type_engine::{insert_type, AbiName, TypeId},
-    AbiDeclaration, CompileResult, FunctionDeclaration, TypeInfo,
+    AbiDeclaration, CompileError, CompileResult, FunctionDeclaration, Namespace, Supertrait,
+    TypeInfo, TypedDeclaration,
 };
 
 use super::{CreateTypeId, TypedTraitFn};
@@ -26,6 +27,7 @@ pub struct TypedAbiDeclaration {
     #[derivative(PartialEq = "ignore")]
     #[derivative(Eq(bound = ""))]
     pub(crate) methods: Vec<FunctionDeclaration>,
+    pub(crate) supertraits: Vec<Supertrait>,
     #[derivative(PartialEq = "ignore")]
     #[derivative(Eq(bound = ""))]
     pub(crate) span: Span,
@@ -53,9 +55,26 @@ impl TypedAbiDeclaration {
             name,
             interface_surface,
             methods,
+            supertraits,
             span,
         } = abi_decl;
 
+        // Only abis can be inherited from by an abi, as their methods have to be implemented for
+        // the contract and callable through the inheriting abi.
+        for supertrait in &supertraits {
+            match ctx
+                .namespace
+                .resolve_call_path(&supertrait.name)
+                .ok(&mut warnings, &mut errors)
+            {
+                Some(TypedDeclaration::AbiDeclaration(_)) => (),
+                Some(_) => errors.push(CompileError::TraitAsAbiSupertrait {
+                    span: supertrait.name.span(),
+                }),
+                None => return err(warnings, errors),
+            }
+        }
+
         // type check the interface surface and methods
         // We don't want the user to waste resources by contract calling
         // themselves, and we don't want to do more work in the compiler,
@@ -78,9 +97,28 @@ impl TypedAbiDeclaration {
         let abi_decl = TypedAbiDeclaration {
             interface_surface,
             methods,
+            supertraits,
             name,
             span,
         };
         ok(abi_decl, warnings, errors)
     }
+
+    /// The abis this abi inherits from, directly or through its supertraits, each listed once.
+    pub(crate) fn super_abis(&self, namespace: &Namespace) -> Vec<TypedAbiDeclaration> {
+        let mut super_abis: Vec<TypedAbiDeclaration> = vec![];
+        let mut to_visit = self.supertraits.clone();
+        while let Some(supertrait) = to_visit.pop() {
+            // Unresolvable supertraits were already reported when the abi was type checked.
+            if let Some(TypedDeclaration::AbiDeclaration(abi)) =
+                namespace.resolve_call_path(&supertrait.name).value
+            {
+                if abi.name != self.name && !super_abis.iter().any(|seen| seen.name == abi.name) {
+                    to_visit.extend(abi.supertraits.iter().cloned());
+                    super_abis.push(abi.clone());
+                }
+            }
+        }
+        super_abis
+    }
 }
@@ -142,7 +142,10 @@ impl TypedFunctionDeclaration {
         // create a namespace for the function
         let mut fn_namespace = ctx.namespace.clone();
 
-        let mut ctx = ctx.scoped(&mut fn_namespace).with_purity(purity);
+        let mut ctx = ctx
+            .scoped(&mut fn_namespace)
+            .with_purity(purity)
+            .with_within_loop(false);
 
         // type check the type parameters
         // insert them into the namespace
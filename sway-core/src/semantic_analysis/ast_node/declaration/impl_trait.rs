@@ -4,22 +4,25 @@ use sway_types::{Ident, Span, Spanned};
 
 use crate::{
     error::{err, ok},
-    semantic_analysis::{Mode, TypeCheckContext},
+    semantic_analysis::{error_recovery_expr, Mode, TypeCheckContext, TypedExpression},
+    style::is_screaming_snake_case,
     type_engine::{
         insert_type, look_up_type_id, resolve_type, unify_with_self, CopyTypes, TypeId,
         TypeMapping, TypeParameter,
     },
-    CallPath, CompileError, CompileResult, FunctionDeclaration, ImplSelf, ImplTrait, Purity,
-    TypeInfo, TypedDeclaration, TypedFunctionDeclaration,
+    AssociatedType, CallPath, CompileError, CompileResult, ConstantDeclaration,
+    FunctionDeclaration, ImplSelf, ImplTrait, Purity, TypeInfo, TypedDeclaration,
+    TypedFunctionDeclaration, Visibility,
 };
 
-use super::TypedTraitFn;
+use super::{EnforceTypeArguments, TypedConstantDeclaration, TypedTraitConstant, TypedTraitFn};
 
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub struct TypedImplTrait {
     pub trait_name: CallPath,
     pub(crate) span: Span,
     pub methods: Vec<TypedFunctionDeclaration>,
+    pub(crate) constants: Vec<TypedConstantDeclaration>,
     pub(crate) implementing_for_type_id: TypeId,
 }
 
@@ -28,6 +31,9 @@ impl CopyTypes for TypedImplTrait {
         self.methods
             .iter_mut()
             .for_each(|x| x.copy_types(type_mapping));
+        self.constants
+            .iter_mut()
+            .for_each(|x| x.copy_types(type_mapping));
     }
 }
 
@@ -41,7 +47,10 @@ impl TypedImplTrait {
 
         let ImplTrait {
             trait_name,
+            trait_type_arguments,
             type_parameters,
+            associated_types,
+            constants,
             functions,
             type_implementing_for,
             type_implementing_for_span,
@@ -54,7 +63,6 @@ impl TypedImplTrait {
 
         // type check the type parameters
         // insert them into the namespace
-        // TODO: eventually when we support generic traits, we will want to use this
         let mut new_type_parameters = vec![];
         for type_parameter in type_parameters.into_iter() {
             new_type_parameters.push(check!(
@@ -89,7 +97,7 @@ impl TypedImplTrait {
         );
 
         // Update the context with the new `self` type.
-        let ctx = ctx.with_self_type(implementing_for_type_id);
+        let mut ctx = ctx.with_self_type(implementing_for_type_id);
 
         let impl_trait = match ctx
             .namespace
@@ -98,12 +106,44 @@ impl TypedImplTrait {
             .cloned()
         {
             Some(TypedDeclaration::TraitDeclaration(tr)) => {
-                let functions_buf = check!(
+                // monomorphize the trait with the type arguments given to it by the impl
+                let mut tr = check!(
+                    ctx.monomorphize(
+                        tr,
+                        trait_type_arguments,
+                        EnforceTypeArguments::Yes,
+                        Some(&trait_name.span())
+                    ),
+                    return err(warnings, errors),
+                    warnings,
+                    errors
+                );
+
+                // substitute the associated types of the trait with the ones that the
+                // impl defines
+                let type_mapping = check!(
+                    type_check_associated_types(
+                        ctx.by_ref(),
+                        &tr.associated_types,
+                        associated_types,
+                        &trait_name,
+                        &block_span,
+                    ),
+                    return err(warnings, errors),
+                    warnings,
+                    errors
+                );
+                tr.copy_types(&type_mapping);
+
+                let (functions_buf, constants_buf) = check!(
                     type_check_trait_implementation(
                         ctx,
+                        &tr.type_parameters,
                         &tr.interface_surface,
+                        &tr.constants,
                         &tr.methods,
                         &functions,
+                        &constants,
                         &trait_name,
                         &type_implementing_for_span,
                         &block_span,
@@ -116,6 +156,7 @@ impl TypedImplTrait {
                     trait_name,
                     span: block_span,
                     methods: functions_buf,
+                    constants: constants_buf,
                     implementing_for_type_id,
                 };
                 let implementing_for_type_id = insert_type(
@@ -141,14 +182,31 @@ impl TypedImplTrait {
                     });
                 }
 
+                if let Some(type_argument) = trait_type_arguments.first() {
+                    errors.push(CompileError::DoesNotTakeTypeArguments {
+                        name: trait_name.suffix.clone(),
+                        span: type_argument.span.clone(),
+                    });
+                }
+                for associated_type in associated_types.iter() {
+                    errors.push(CompileError::TypeNotAPartOfInterfaceSurface {
+                        name: associated_type.name.clone(),
+                        trait_name: trait_name.suffix.clone(),
+                        span: associated_type.name.span(),
+                    });
+                }
+
                 let ctx = ctx.with_mode(Mode::ImplAbiFn);
 
-                let functions_buf = check!(
+                let (functions_buf, constants_buf) = check!(
                     type_check_trait_implementation(
                         ctx,
+                        &[],
                         &abi.interface_surface,
+                        &[],
                         &abi.methods,
                         &functions,
+                        &constants,
                         &trait_name,
                         &type_implementing_for_span,
                         &block_span,
@@ -161,6 +219,7 @@ impl TypedImplTrait {
                     trait_name,
                     span: block_span,
                     methods: functions_buf,
+                    constants: constants_buf,
                     implementing_for_type_id,
                 };
                 (impl_trait, implementing_for_type_id)
@@ -187,6 +246,7 @@ impl TypedImplTrait {
             type_implementing_for,
             type_implementing_for_span,
             type_parameters,
+            constants,
             functions,
             block_span,
         } = impl_self;
@@ -245,6 +305,24 @@ impl TypedImplTrait {
             .with_help_text("")
             .with_type_annotation(insert_type(TypeInfo::Unknown));
 
+        // type check the constants inside of the impl block and make them available to the
+        // methods
+        let mut typed_constants = vec![];
+        for const_decl in constants.into_iter() {
+            typed_constants.push(check!(
+                type_check_associated_constant(ctx.by_ref(), const_decl),
+                continue,
+                warnings,
+                errors
+            ));
+        }
+        ctx.namespace.insert_trait_implementation(
+            trait_name.clone(),
+            implementing_for_type_id,
+            vec![],
+            typed_constants.clone(),
+        );
+
         // type check the methods inside of the impl block
         let mut methods = vec![];
         for fn_decl in functions.into_iter() {
@@ -260,25 +338,246 @@ impl TypedImplTrait {
             trait_name,
             span: block_span,
             methods,
+            constants: typed_constants,
             implementing_for_type_id,
         };
         ok(impl_trait, warnings, errors)
     }
 }
 
+/// Type checks the associated types defined by an implementation of a trait against the ones
+/// that the trait declares, and inserts them into the namespace of the implementation.
+///
+/// Returns the mapping from the associated types of the trait to the types defined by the
+/// implementation.
+fn type_check_associated_types(
+    mut ctx: TypeCheckContext,
+    trait_associated_types: &[TypeParameter],
+    associated_types: Vec<AssociatedType>,
+    trait_name: &CallPath,
+    block_span: &Span,
+) -> CompileResult<TypeMapping> {
+    let mut warnings = vec![];
+    let mut errors = vec![];
+
+    let mut type_mapping = vec![];
+    let mut type_checklist: std::collections::BTreeMap<&Ident, _> = trait_associated_types
+        .iter()
+        .map(|type_parameter| (&type_parameter.name_ident, type_parameter))
+        .collect();
+    for AssociatedType {
+        name,
+        type_argument,
+    } in associated_types
+    {
+        let trait_type = match type_checklist.remove(&name) {
+            Some(trait_type) => trait_type,
+            None => {
+                errors.push(CompileError::TypeNotAPartOfInterfaceSurface {
+                    name: name.clone(),
+                    trait_name: trait_name.suffix.clone(),
+                    span: name.span(),
+                });
+                continue;
+            }
+        };
+        let type_id = check!(
+            ctx.resolve_type_with_self(
+                type_argument.type_id,
+                &type_argument.span,
+                EnforceTypeArguments::Yes
+            ),
+            insert_type(TypeInfo::ErrorRecovery),
+            warnings,
+            errors
+        );
+        ctx.namespace
+            .insert_symbol(
+                name.clone(),
+                TypedDeclaration::GenericTypeForFunctionScope { name, type_id },
+            )
+            .ok(&mut warnings, &mut errors);
+        type_mapping.push((trait_type.type_id, type_id));
+    }
+
+    // check that all of the associated types have been defined
+    if !type_checklist.is_empty() {
+        errors.push(CompileError::MissingInterfaceSurfaceTypes {
+            span: block_span.clone(),
+            missing_types: type_checklist
+                .into_keys()
+                .map(|ident| ident.as_str().to_string())
+                .collect::<Vec<_>>()
+                .join("\n"),
+        });
+    }
+    ok(type_mapping, warnings, errors)
+}
+
+/// Type checks an associated constant of an impl block, whose value must be of the constant's
+/// ascribed type.
+fn type_check_associated_constant(
+    mut ctx: TypeCheckContext,
+    const_decl: ConstantDeclaration,
+) -> CompileResult<TypedConstantDeclaration> {
+    let mut warnings = vec![];
+    let mut errors = vec![];
+
+    let ConstantDeclaration {
+        name,
+        type_ascription,
+        value,
+        visibility,
+    } = const_decl;
+    is_screaming_snake_case(&name).ok(&mut warnings, &mut errors);
+    let type_id = check!(
+        ctx.resolve_type_with_self(
+            insert_type(type_ascription),
+            &name.span(),
+            EnforceTypeArguments::No
+        ),
+        insert_type(TypeInfo::ErrorRecovery),
+        warnings,
+        errors
+    );
+    let ctx = ctx.with_type_annotation(type_id).with_help_text(
+        "This declaration's type annotation does not match up with the assigned \
+            expression's type.",
+    );
+    let value = check!(
+        TypedExpression::type_check(ctx, value),
+        error_recovery_expr(name.span()),
+        warnings,
+        errors
+    );
+    let const_decl = TypedConstantDeclaration {
+        name,
+        value,
+        visibility,
+    };
+    ok(const_decl, warnings, errors)
+}
+
 #[allow(clippy::too_many_arguments)]
 fn type_check_trait_implementation(
     mut ctx: TypeCheckContext,
+    trait_type_parameters: &[TypeParameter],
     trait_interface_surface: &[TypedTraitFn],
+    trait_constants: &[TypedTraitConstant],
     trait_methods: &[FunctionDeclaration],
     functions: &[FunctionDeclaration],
+    constants: &[ConstantDeclaration],
     trait_name: &CallPath,
     self_type_span: &Span,
     block_span: &Span,
-) -> CompileResult<Vec<TypedFunctionDeclaration>> {
+) -> CompileResult<(Vec<TypedFunctionDeclaration>, Vec<TypedConstantDeclaration>)> {
     let mut errors = vec![];
     let mut warnings = vec![];
 
+    let self_type_id = insert_type(match resolve_type(ctx.self_type(), self_type_span) {
+        Ok(o) => o,
+        Err(e) => {
+            errors.push(e.into());
+            return err(warnings, errors);
+        }
+    });
+
+    let mut constants_buf: Vec<TypedConstantDeclaration> = vec![];
+
+    // this map keeps track of the remaining constants in the
+    // interface surface that still need to be defined
+    let mut constant_checklist: std::collections::BTreeMap<&Ident, _> = trait_constants
+        .iter()
+        .map(|constant| (&constant.name, constant))
+        .collect();
+    for const_decl in constants {
+        let trait_constant = match constant_checklist.remove(&const_decl.name) {
+            Some(trait_constant) => trait_constant,
+            None => {
+                errors.push(CompileError::ConstantNotAPartOfInterfaceSurface {
+                    name: const_decl.name.clone(),
+                    trait_name: trait_name.suffix.clone(),
+                    span: const_decl.name.span(),
+                });
+                continue;
+            }
+        };
+
+        let const_decl = check!(
+            type_check_associated_constant(ctx.by_ref(), const_decl.clone()),
+            continue,
+            warnings,
+            errors
+        );
+
+        // unify the type of the constant with the type declared in the trait
+        let (mut new_warnings, new_errors) = unify_with_self(
+            const_decl.value.return_type,
+            trait_constant.type_id,
+            ctx.self_type(),
+            &const_decl.name.span(),
+            ctx.help_text(),
+        );
+        warnings.append(&mut new_warnings);
+        if !new_errors.is_empty() {
+            errors.push(CompileError::MismatchedTypeInTrait {
+                span: const_decl.name.span(),
+                given: const_decl.value.return_type.to_string(),
+                expected: trait_constant.type_id.to_string(),
+            });
+            continue;
+        }
+
+        constants_buf.push(const_decl);
+    }
+
+    // fall back to the default values of the constants that have not been defined
+    let mut missing_constants = vec![];
+    for (name, trait_constant) in constant_checklist.into_iter() {
+        let value = match &trait_constant.value {
+            Some(value) => value.clone(),
+            None => {
+                missing_constants.push(name.as_str().to_string());
+                continue;
+            }
+        };
+        let ctx = ctx
+            .by_ref()
+            .with_type_annotation(trait_constant.type_id)
+            .with_help_text(
+                "This constant's default value does not match up with its type in the trait.",
+            );
+        let value = check!(
+            TypedExpression::type_check(ctx, value),
+            continue,
+            warnings,
+            errors
+        );
+        constants_buf.push(TypedConstantDeclaration {
+            name: name.clone(),
+            value,
+            visibility: crate::Visibility::Public,
+        });
+    }
+    if !missing_constants.is_empty() {
+        errors.push(CompileError::MissingInterfaceSurfaceConstants {
+            span: block_span.clone(),
+            missing_constants: missing_constants.join("\n"),
+        });
+    }
+
+    // make the constants available to the functions of the implementation
+    ctx.namespace.insert_trait_implementation(
+        CallPath {
+            prefixes: vec![],
+            suffix: trait_name.suffix.clone(),
+            is_absolute: false,
+        },
+        self_type_id,
+        vec![],
+        constants_buf.clone(),
+    );
+
     let mut functions_buf: Vec<TypedFunctionDeclaration> = vec![];
     let mut processed_fns = std::collections::HashSet::<Ident>::new();
 
@@ -422,15 +721,8 @@ fn type_check_trait_implementation(
         ctx.namespace.get_canonical_path(&trait_name.suffix),
     ]
     .concat();
-    ctx.namespace.star_import(&trait_path);
+    ctx.namespace.star_import(&trait_path, Visibility::Private);
 
-    let self_type_id = insert_type(match resolve_type(ctx.self_type(), self_type_span) {
-        Ok(o) => o,
-        Err(e) => {
-            errors.push(e.into());
-            return err(warnings, errors);
-        }
-    });
     ctx.namespace.insert_trait_implementation(
         CallPath {
             prefixes: vec![],
@@ -439,8 +731,23 @@ fn type_check_trait_implementation(
         },
         self_type_id,
         functions_buf.clone(),
+        vec![],
     );
 
+    // the methods of a generic trait refer to its type parameters, which are bound to the type
+    // arguments of the implementation. Shadowing a generic of the impl block is fine here.
+    for type_parameter in trait_type_parameters.iter() {
+        ctx.namespace
+            .insert_symbol(
+                type_parameter.name_ident.clone(),
+                TypedDeclaration::GenericTypeForFunctionScope {
+                    name: type_parameter.name_ident.clone(),
+                    type_id: type_parameter.type_id,
+                },
+            )
+            .ok(&mut vec![], &mut vec![]);
+    }
+
     let mut ctx = ctx
         .with_help_text("")
         .with_type_annotation(insert_type(TypeInfo::Unknown));
@@ -470,7 +777,7 @@ fn type_check_trait_implementation(
                 .join("\n"),
         });
     }
-    ok(functions_buf, warnings, errors)
+    ok((functions_buf, constants_buf), warnings, errors)
 }
 
 fn check_for_unconstrained_type_parameters(
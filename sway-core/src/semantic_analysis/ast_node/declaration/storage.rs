@@ -8,7 +8,7 @@ use crate::{
         TypedStructField,
     },
     type_engine::{look_up_type_id, TypeId, TypeInfo},
-    Ident,
+    Ident, Visibility,
 };
 use derivative::Derivative;
 use fuel_tx::StorageSlot;
@@ -92,6 +92,13 @@ impl TypedStorageDeclaration {
                 .find(|x| x.name.as_str() == field.as_str())
             {
                 Some(struct_field) => {
+                    if !struct_field.is_accessible_from(&field.span()) {
+                        errors.push(CompileError::StructFieldIsPrivate {
+                            field_name: field.clone(),
+                            struct_name: type_checked_buf.last().unwrap().type_id.to_string(),
+                            span: field.span(),
+                        });
+                    }
                     type_checked_buf.push(TypeCheckedStorageAccessDescriptor {
                         name: field.clone(),
                         type_id: struct_field.type_id,
@@ -139,6 +146,7 @@ impl TypedStorageDeclaration {
                      ref span,
                      ..
                  }| TypedStructField {
+                    visibility: Visibility::Public,
                     name: name.clone(),
                     type_id: *r#type,
                     span: span.clone(),
@@ -132,7 +132,16 @@ impl TypedStructDeclaration {
             .iter()
             .find(|TypedStructField { name, .. }| name.as_str() == field_to_access.as_str())
         {
-            Some(field) => ok(field, warnings, errors),
+            Some(field) => {
+                if !field.is_accessible_from(&field_to_access.span()) {
+                    errors.push(CompileError::StructFieldIsPrivate {
+                        field_name: field_to_access.clone(),
+                        struct_name: self.name.to_string(),
+                        span: field_to_access.span(),
+                    });
+                }
+                ok(field, warnings, errors)
+            }
             None => {
                 errors.push(CompileError::FieldNotFound {
                     available_fields: self
@@ -152,6 +161,7 @@ impl TypedStructDeclaration {
 
 #[derive(Debug, Clone, Eq)]
 pub struct TypedStructField {
+    pub visibility: Visibility,
     pub name: Ident,
     pub type_id: TypeId,
     pub(crate) span: Span,
@@ -215,10 +225,24 @@ impl TypedStructField {
             errors,
         );
         let field = TypedStructField {
+            visibility: field.visibility,
             name: field.name,
             type_id: r#type,
             span: field.span,
         };
         ok(field, warnings, errors)
     }
+
+    /// Whether the field can be used by the code at `span`. Private fields can only be used
+    /// within the module, and so the file, that declares them.
+    pub(crate) fn is_accessible_from(&self, span: &Span) -> bool {
+        if self.visibility.is_public() {
+            return true;
+        }
+        match (self.span.path(), span.path()) {
+            (Some(declared_in), Some(used_in)) => declared_in == used_in,
+            // Code without a file is generated by the compiler.
+            _ => true,
+        }
+    }
 }
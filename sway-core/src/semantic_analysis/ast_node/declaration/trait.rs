@@ -1,24 +1,33 @@
 use derivative::Derivative;
-use sway_types::{Ident, Spanned};
+use sway_types::{Ident, Span, Spanned};
 
 use crate::{
     error::{err, ok},
+    namespace::Items,
     semantic_analysis::{
         ast_node::{type_check_interface_surface, type_check_trait_methods},
-        Mode, TypeCheckContext, TypedCodeBlock,
+        IsConstant, Mode, TypeCheckContext, TypedCodeBlock, TypedExpression,
+        TypedExpressionVariant,
     },
     style::is_upper_camel_case,
-    type_engine::{insert_type, CopyTypes, TypeMapping},
-    CallPath, CompileError, CompileResult, FunctionDeclaration, FunctionParameter, Namespace,
-    Supertrait, TraitDeclaration, TypeInfo, TypedDeclaration, TypedFunctionDeclaration, Visibility,
+    type_engine::{insert_type, CopyTypes, TypeId, TypeMapping, TypeParameter},
+    CallPath, CompileError, CompileResult, Expression, FunctionDeclaration, FunctionParameter,
+    Namespace, Supertrait, TraitConstant, TraitDeclaration, TypeInfo, TypedDeclaration,
+    TypedFunctionDeclaration, Visibility,
 };
 
-use super::{EnforceTypeArguments, TypedFunctionParameter, TypedTraitFn};
+use super::{
+    EnforceTypeArguments, MonomorphizeHelper, TypedConstantDeclaration, TypedFunctionParameter,
+    TypedTraitFn,
+};
 
 #[derive(Clone, Debug, Derivative)]
 #[derivative(PartialEq, Eq)]
 pub struct TypedTraitDeclaration {
     pub name: Ident,
+    pub(crate) type_parameters: Vec<TypeParameter>,
+    pub(crate) associated_types: Vec<TypeParameter>,
+    pub(crate) constants: Vec<TypedTraitConstant>,
     pub interface_surface: Vec<TypedTraitFn>,
     // NOTE: deriving partialeq and hash on this element may be important in the
     // future, but I am not sure. For now, adding this would 2x the amount of
@@ -30,8 +39,55 @@ pub struct TypedTraitDeclaration {
     pub(crate) visibility: Visibility,
 }
 
+/// An associated constant of a trait. The `value` is only type checked as part of each
+/// implementation of the trait that does not provide its own value.
+#[derive(Clone, Debug, Derivative)]
+#[derivative(PartialEq, Eq)]
+pub struct TypedTraitConstant {
+    pub name: Ident,
+    pub type_id: TypeId,
+    #[derivative(PartialEq = "ignore")]
+    #[derivative(Eq(bound = ""))]
+    pub(crate) type_span: Span,
+    #[derivative(PartialEq = "ignore")]
+    #[derivative(Eq(bound = ""))]
+    pub(crate) value: Option<Expression>,
+}
+
+impl CopyTypes for TypedTraitConstant {
+    fn copy_types(&mut self, type_mapping: &TypeMapping) {
+        self.type_id.update_type(type_mapping, &self.type_span);
+    }
+}
+
+impl TypedTraitConstant {
+    /// This function is used in trait declarations to insert "placeholder" constants, which
+    /// allows the methods to use the constants declared in the trait.
+    pub(crate) fn to_dummy_constant(&self) -> TypedConstantDeclaration {
+        TypedConstantDeclaration {
+            name: self.name.clone(),
+            value: TypedExpression {
+                expression: TypedExpressionVariant::Tuple { fields: vec![] },
+                return_type: self.type_id,
+                is_constant: IsConstant::Yes,
+                span: self.name.span(),
+            },
+            visibility: Visibility::Public,
+        }
+    }
+}
+
 impl CopyTypes for TypedTraitDeclaration {
     fn copy_types(&mut self, type_mapping: &TypeMapping) {
+        self.type_parameters
+            .iter_mut()
+            .for_each(|x| x.copy_types(type_mapping));
+        self.associated_types
+            .iter_mut()
+            .for_each(|x| x.copy_types(type_mapping));
+        self.constants
+            .iter_mut()
+            .for_each(|x| x.copy_types(type_mapping));
         self.interface_surface
             .iter_mut()
             .for_each(|x| x.copy_types(type_mapping));
@@ -39,6 +95,34 @@ impl CopyTypes for TypedTraitDeclaration {
     }
 }
 
+impl Spanned for TypedTraitDeclaration {
+    fn span(&self) -> Span {
+        self.name.span()
+    }
+}
+
+impl MonomorphizeHelper for TypedTraitDeclaration {
+    type Output = TypedTraitDeclaration;
+
+    fn type_parameters(&self) -> &[TypeParameter] {
+        &self.type_parameters
+    }
+
+    fn name(&self) -> &Ident {
+        &self.name
+    }
+
+    fn monomorphize_inner(
+        self,
+        type_mapping: &TypeMapping,
+        _namespace: &mut Items,
+    ) -> Self::Output {
+        let mut new_decl = self;
+        new_decl.copy_types(type_mapping);
+        new_decl
+    }
+}
+
 impl TypedTraitDeclaration {
     pub(crate) fn type_check(
         ctx: TypeCheckContext,
@@ -49,6 +133,31 @@ impl TypedTraitDeclaration {
 
         is_upper_camel_case(&trait_decl.name).ok(&mut warnings, &mut errors);
 
+        // A temporary namespace for checking within the trait's scope.
+        let mut trait_namespace = ctx.namespace.clone();
+        let mut ctx = ctx.scoped(&mut trait_namespace);
+
+        // type check the type parameters and the associated types
+        // insert them into the namespace
+        let mut type_parameters = vec![];
+        for type_parameter in trait_decl.type_parameters.into_iter() {
+            type_parameters.push(check!(
+                TypeParameter::type_check(ctx.by_ref(), type_parameter),
+                return err(warnings, errors),
+                warnings,
+                errors
+            ));
+        }
+        let mut associated_types = vec![];
+        for associated_type in trait_decl.associated_types.into_iter() {
+            associated_types.push(check!(
+                TypeParameter::type_check(ctx.by_ref(), associated_type),
+                return err(warnings, errors),
+                warnings,
+                errors
+            ));
+        }
+
         // type check the interface surface
         let interface_surface = check!(
             type_check_interface_surface(trait_decl.interface_surface.to_vec(), ctx.namespace),
@@ -57,9 +166,33 @@ impl TypedTraitDeclaration {
             errors
         );
 
-        // A temporary namespace for checking within the trait's scope.
-        let mut trait_namespace = ctx.namespace.clone();
-        let ctx = ctx.scoped(&mut trait_namespace);
+        // type check the types of the associated constants
+        let constants = trait_decl
+            .constants
+            .into_iter()
+            .map(
+                |TraitConstant {
+                     name,
+                     type_ascription,
+                     type_span,
+                     value,
+                 }| TypedTraitConstant {
+                    name,
+                    type_id: check!(
+                        ctx.resolve_type_with_self(
+                            insert_type(type_ascription),
+                            &type_span,
+                            EnforceTypeArguments::Yes
+                        ),
+                        insert_type(TypeInfo::ErrorRecovery),
+                        warnings,
+                        errors,
+                    ),
+                    type_span,
+                    value,
+                },
+            )
+            .collect::<Vec<_>>();
 
         // Recursively handle supertraits: make their interfaces and methods available to this trait
         check!(
@@ -82,6 +215,7 @@ impl TypedTraitDeclaration {
                 .iter()
                 .map(|x| x.to_dummy_func(Mode::NonAbi))
                 .collect(),
+            constants.iter().map(|x| x.to_dummy_constant()).collect(),
         );
         // check the methods for errors but throw them away and use vanilla [FunctionDeclaration]s
         let ctx = ctx.with_self_type(insert_type(TypeInfo::SelfType));
@@ -93,6 +227,9 @@ impl TypedTraitDeclaration {
         );
         let typed_trait_decl = TypedTraitDeclaration {
             name: trait_decl.name.clone(),
+            type_parameters,
+            associated_types,
+            constants,
             interface_surface,
             methods: trait_decl.methods.to_vec(),
             supertraits: trait_decl.supertraits.to_vec(),
@@ -119,6 +256,7 @@ fn handle_supertraits(
         {
             Some(TypedDeclaration::TraitDeclaration(TypedTraitDeclaration {
                 ref interface_surface,
+                ref constants,
                 ref methods,
                 ref supertraits,
                 ..
@@ -131,6 +269,7 @@ fn handle_supertraits(
                         .iter()
                         .map(|x| x.to_dummy_func(Mode::NonAbi))
                         .collect(),
+                    constants.iter().map(|x| x.to_dummy_constant()).collect(),
                 );
 
                 // insert dummy versions of the methods of all of the supertraits
@@ -144,6 +283,7 @@ fn handle_supertraits(
                     supertrait.name.clone(),
                     insert_type(TypeInfo::SelfType),
                     dummy_funcs,
+                    vec![],
                 );
 
                 // Recurse to insert dummy versions of interfaces and methods of the *super*
@@ -0,0 +1,128 @@
+use crate::{
+    error::*,
+    namespace::*,
+    parse_tree::*,
+    semantic_analysis::*,
+    type_engine::{
+        insert_type, look_up_type_id, CopyTypes, CreateTypeId, TypeId, TypeMapping, TypeParameter,
+    },
+    TypeInfo,
+};
+use sway_types::{Ident, Span, Spanned};
+
+/// A type alias, e.g. `type Balance = u64;`, whose aliased type has been resolved in the scope of
+/// the declaring module.
+///
+/// A generic alias like `type Map<V> = StorageMap<b256, V>;` is monomorphized along with its type
+/// parameters whenever it is used, just like a generic struct or enum.
+#[derive(Clone, Debug, Eq)]
+pub struct TypedTypeAliasDeclaration {
+    pub name: Ident,
+    pub(crate) type_parameters: Vec<TypeParameter>,
+    pub type_id: TypeId,
+    pub(crate) visibility: Visibility,
+    pub(crate) span: Span,
+}
+
+impl PartialEq for TypedTypeAliasDeclaration {
+    fn eq(&self, other: &Self) -> bool {
+        self.name == other.name
+            && self.type_parameters == other.type_parameters
+            && look_up_type_id(self.type_id) == look_up_type_id(other.type_id)
+            && self.visibility == other.visibility
+    }
+}
+
+impl CopyTypes for TypedTypeAliasDeclaration {
+    fn copy_types(&mut self, type_mapping: &TypeMapping) {
+        self.type_id.update_type(type_mapping, &self.span);
+        self.type_parameters
+            .iter_mut()
+            .for_each(|x| x.copy_types(type_mapping));
+    }
+}
+
+impl CreateTypeId for TypedTypeAliasDeclaration {
+    fn create_type_id(&self) -> TypeId {
+        insert_type(look_up_type_id(self.type_id))
+    }
+}
+
+impl Spanned for TypedTypeAliasDeclaration {
+    fn span(&self) -> Span {
+        self.span.clone()
+    }
+}
+
+impl MonomorphizeHelper for TypedTypeAliasDeclaration {
+    type Output = TypedTypeAliasDeclaration;
+
+    fn type_parameters(&self) -> &[TypeParameter] {
+        &self.type_parameters
+    }
+
+    fn name(&self) -> &Ident {
+        &self.name
+    }
+
+    fn monomorphize_inner(self, type_mapping: &TypeMapping, namespace: &mut Items) -> Self::Output {
+        monomorphize_inner(self, type_mapping, namespace)
+    }
+}
+
+impl TypedTypeAliasDeclaration {
+    pub(crate) fn type_check(
+        ctx: TypeCheckContext,
+        decl: TypeAliasDeclaration,
+    ) -> CompileResult<Self> {
+        let mut warnings = vec![];
+        let mut errors = vec![];
+
+        let TypeAliasDeclaration {
+            name,
+            type_parameters,
+            type_info,
+            type_span,
+            visibility,
+            span,
+        } = decl;
+
+        // create a namespace for the decl, used to create a scope for generics
+        let mut decl_namespace = ctx.namespace.clone();
+        let mut ctx = ctx.scoped(&mut decl_namespace);
+
+        // type check the type parameters
+        // insert them into the namespace
+        let mut new_type_parameters = vec![];
+        for type_parameter in type_parameters.into_iter() {
+            new_type_parameters.push(check!(
+                TypeParameter::type_check(ctx.by_ref(), type_parameter),
+                return err(warnings, errors),
+                warnings,
+                errors
+            ));
+        }
+
+        // resolve the aliased type, so that uses of the alias in other modules don't depend on
+        // the symbols in scope here
+        let type_id = check!(
+            ctx.resolve_type_with_self(
+                insert_type(type_info),
+                &type_span,
+                EnforceTypeArguments::Yes
+            ),
+            insert_type(TypeInfo::ErrorRecovery),
+            warnings,
+            errors,
+        );
+
+        let decl = TypedTypeAliasDeclaration {
+            name,
+            type_parameters: new_type_parameters,
+            type_id,
+            visibility,
+            span,
+        };
+        ok(decl, warnings, errors)
+    }
+}
@@ -18,6 +18,7 @@ pub enum TypedIntrinsicFunctionKind {
     SizeOfType { type_id: TypeId, type_span: Span },
     IsRefType { type_id: TypeId, type_span: Span },
     GetStorageKey,
+    AddrOf { exp: Box<TypedExpression> },
 }
 
 // NOTE: Hash and PartialEq must uphold the invariant:
@@ -45,6 +46,7 @@ impl PartialEq for TypedIntrinsicFunctionKind {
                 },
             ) => look_up_type_id(*l_type_id) == look_up_type_id(*r_type_id),
             (GetStorageKey, GetStorageKey) => true,
+            (AddrOf { exp: l_exp }, AddrOf { exp: r_exp }) => *l_exp == *r_exp,
             _ => false,
         }
     }
@@ -54,7 +56,7 @@ impl CopyTypes for TypedIntrinsicFunctionKind {
     fn copy_types(&mut self, type_mapping: &TypeMapping) {
         use TypedIntrinsicFunctionKind::*;
         match self {
-            SizeOfVal { exp } => {
+            SizeOfVal { exp } | AddrOf { exp } => {
                 exp.copy_types(type_mapping);
             }
             SizeOfType { type_id, type_span } => {
@@ -76,6 +78,7 @@ impl fmt::Display for TypedIntrinsicFunctionKind {
             SizeOfType { type_id, .. } => format!("size_of({})", look_up_type_id(*type_id)),
             IsRefType { type_id, .. } => format!("is_ref_type({})", look_up_type_id(*type_id)),
             GetStorageKey => "get_storage_key".to_string(),
+            AddrOf { exp } => format!("addr_of({})", exp),
         };
         write!(f, "{}", s)
     }
@@ -85,7 +88,7 @@ impl DeterministicallyAborts for TypedIntrinsicFunctionKind {
     fn deterministically_aborts(&self) -> bool {
         use TypedIntrinsicFunctionKind::*;
         match self {
-            SizeOfVal { exp } => exp.deterministically_aborts(),
+            SizeOfVal { exp } | AddrOf { exp } => exp.deterministically_aborts(),
             SizeOfType { .. } | GetStorageKey | IsRefType { .. } => false,
         }
     }
@@ -95,7 +98,7 @@ impl UnresolvedTypeCheck for TypedIntrinsicFunctionKind {
     fn check_for_unresolved_types(&self) -> Vec<CompileError> {
         use TypedIntrinsicFunctionKind::*;
         match self {
-            SizeOfVal { exp } => exp.check_for_unresolved_types(),
+            SizeOfVal { exp } | AddrOf { exp } => exp.check_for_unresolved_types(),
             SizeOfType { type_id, .. } => type_id.check_for_unresolved_types(),
             IsRefType { type_id, .. } => type_id.check_for_unresolved_types(),
             GetStorageKey => vec![],
@@ -167,6 +170,19 @@ impl TypedIntrinsicFunctionKind {
                 TypedIntrinsicFunctionKind::GetStorageKey,
                 insert_type(TypeInfo::B256),
             ),
+            IntrinsicFunctionKind::AddrOf { exp } => {
+                let ctx = ctx
+                    .with_help_text("")
+                    .with_type_annotation(insert_type(TypeInfo::Unknown));
+                let exp = check!(
+                    TypedExpression::type_check(ctx, *exp),
+                    return err(warnings, errors),
+                    warnings,
+                    errors
+                );
+                let intrinsic_function = TypedIntrinsicFunctionKind::AddrOf { exp: Box::new(exp) };
+                (intrinsic_function, insert_type(TypeInfo::RawUntypedPtr))
+            }
         };
         ok((intrinsic_function, return_type), warnings, errors)
     }
@@ -105,6 +105,7 @@ pub(crate) enum Pattern {
     U32(Range<u32>),
     U64(Range<u64>),
     B256([u8; 32]),
+    U256([u8; 32]),
     Boolean(bool),
     Byte(Range<u8>),
     Numeric(Range<u64>),
@@ -129,6 +130,7 @@ impl Pattern {
                 Literal::U32(x) => Pattern::U32(Range::from_single(x)),
                 Literal::U64(x) => Pattern::U64(Range::from_single(x)),
                 Literal::B256(x) => Pattern::B256(x),
+                Literal::U256(x) => Pattern::U256(x),
                 Literal::Boolean(b) => Pattern::Boolean(b),
                 Literal::Byte(x) => Pattern::Byte(Range::from_single(x)),
                 Literal::Numeric(x) => Pattern::Numeric(Range::from_single(x)),
@@ -190,10 +192,79 @@ impl Pattern {
                     )),
                 })
             }
+            Scrutinee::Range { start, end, span } => check!(
+                Pattern::from_range_bounds(start, end, &span),
+                return err(warnings, errors),
+                warnings,
+                errors
+            ),
+            Scrutinee::Or { elems, .. } => {
+                let mut new_elems = PatStack::empty();
+                for elem in elems.into_iter() {
+                    new_elems.push(check!(
+                        Pattern::from_scrutinee(elem),
+                        return err(warnings, errors),
+                        warnings,
+                        errors
+                    ));
+                }
+                Pattern::Or(new_elems)
+            }
         };
         ok(pat, warnings, errors)
     }
 
+    /// Converts the bounds of an inclusive range scrutinee to a `Pattern`. An
+    /// unsuffixed bound takes on the type of the other bound, so `0..=9u8`
+    /// results in a `Pattern::U8`.
+    fn from_range_bounds(start: Literal, end: Literal, span: &Span) -> CompileResult<Self> {
+        let (first, last) = match (start.as_integer(), end.as_integer()) {
+            (Some(first), Some(last)) => (first, last),
+            _ => {
+                let errors = vec![CompileError::MatchRangePatternNotNumeric { span: span.clone() }];
+                return err(vec![], errors);
+            }
+        };
+        let kind = match (&start, &end) {
+            (Literal::Numeric(_), end) => end,
+            (start, _) => start,
+        };
+        match kind {
+            Literal::U8(_) => match (u8::try_from(first), u8::try_from(last)) {
+                (Ok(first), Ok(last)) => Range::from_double(first, last, span).map(Pattern::U8),
+                _ => err(
+                    vec![],
+                    vec![CompileError::Internal(
+                        "u8 range out of bounds",
+                        span.clone(),
+                    )],
+                ),
+            },
+            Literal::U16(_) => match (u16::try_from(first), u16::try_from(last)) {
+                (Ok(first), Ok(last)) => Range::from_double(first, last, span).map(Pattern::U16),
+                _ => err(
+                    vec![],
+                    vec![CompileError::Internal(
+                        "u16 range out of bounds",
+                        span.clone(),
+                    )],
+                ),
+            },
+            Literal::U32(_) => match (u32::try_from(first), u32::try_from(last)) {
+                (Ok(first), Ok(last)) => Range::from_double(first, last, span).map(Pattern::U32),
+                _ => err(
+                    vec![],
+                    vec![CompileError::Internal(
+                        "u32 range out of bounds",
+                        span.clone(),
+                    )],
+                ),
+            },
+            Literal::U64(_) => Range::from_double(first, last, span).map(Pattern::U64),
+            _ => Range::from_double(first, last, span).map(Pattern::Numeric),
+        }
+    }
+
     /// Converts a `PatStack` to a `Pattern`. If the `PatStack` is of lenth 1,
     /// this function returns the single element, if it is of length > 1, this
     /// function wraps the provided `PatStack` in a `Pattern::Or(..)`.
@@ -366,6 +437,16 @@ impl Pattern {
                 }
                 Pattern::B256(*b)
             }
+            Pattern::U256(n) => {
+                if !args.is_empty() {
+                    errors.push(CompileError::Internal(
+                        "malformed constructor request",
+                        span.clone(),
+                    ));
+                    return err(warnings, errors);
+                }
+                Pattern::U256(*n)
+            }
             Pattern::Boolean(b) => {
                 if !args.is_empty() {
                     errors.push(CompileError::Internal(
@@ -521,6 +602,7 @@ impl Pattern {
             Pattern::U32(_) => 0,
             Pattern::U64(_) => 0,
             Pattern::B256(_) => 0,
+            Pattern::U256(_) => 0,
             Pattern::Boolean(_) => 0,
             Pattern::Byte(_) => 0,
             Pattern::Numeric(_) => 0,
@@ -569,6 +651,7 @@ impl Pattern {
             (Pattern::U32(a), Pattern::U32(b)) => a == b,
             (Pattern::U64(a), Pattern::U64(b)) => a == b,
             (Pattern::B256(a), Pattern::B256(b)) => a == b,
+            (Pattern::U256(a), Pattern::U256(b)) => a == b,
             (Pattern::Boolean(a), Pattern::Boolean(b)) => a == b,
             (Pattern::Byte(a), Pattern::Byte(b)) => a == b,
             (Pattern::Numeric(a), Pattern::Numeric(b)) => a == b,
@@ -702,6 +785,7 @@ impl Pattern {
             Pattern::Enum(_) => 11,
             Pattern::Tuple(_) => 12,
             Pattern::Or(_) => 13,
+            Pattern::U256(_) => 14,
         }
     }
 }
@@ -716,6 +800,7 @@ impl fmt::Display for Pattern {
             Pattern::U64(range) => format!("{}", range),
             Pattern::Numeric(range) => format!("{}", range),
             Pattern::B256(n) => format!("{:#?}", n),
+            Pattern::U256(n) => format!("{:#?}", n),
             Pattern::Boolean(b) => format!("{}", b),
             Pattern::Byte(range) => format!("{}", range),
             Pattern::String(s) => s.clone(),
@@ -745,6 +830,7 @@ impl std::cmp::Ord for Pattern {
             (Pattern::U32(x), Pattern::U32(y)) => x.cmp(y),
             (Pattern::U64(x), Pattern::U64(y)) => x.cmp(y),
             (Pattern::B256(x), Pattern::B256(y)) => x.cmp(y),
+            (Pattern::U256(x), Pattern::U256(y)) => x.cmp(y),
             (Pattern::Boolean(x), Pattern::Boolean(y)) => x.cmp(y),
             (Pattern::Byte(x), Pattern::Byte(y)) => x.cmp(y),
             (Pattern::Numeric(x), Pattern::Numeric(y)) => x.cmp(y),
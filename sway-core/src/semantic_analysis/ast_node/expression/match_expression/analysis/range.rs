@@ -178,7 +178,7 @@ where
 
     /// Creates a `Range<T>` and ensures that it is a "valid `Range<T>`"
     /// (i.e.) that `first` is <= to `last`
-    fn from_double(first: T, last: T, span: &Span) -> CompileResult<Range<T>> {
+    pub(crate) fn from_double(first: T, last: T, span: &Span) -> CompileResult<Range<T>> {
         let warnings = vec![];
         let mut errors = vec![];
         if last < first {
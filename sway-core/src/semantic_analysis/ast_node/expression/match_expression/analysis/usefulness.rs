@@ -195,9 +195,13 @@ use super::{
 /// is an imaginary additional wildcard pattern. The match expression is
 /// exhaustive if the imaginary additional wildcard pattern has an empty
 /// `WitnessReport`.
+///
+/// Each scrutinee is paired with whether or not its match arm has an `if`
+/// guard. Because a guard may reject any value, a guarded arm is checked for
+/// its reachability but is never counted towards exhaustivity.
 pub(crate) fn check_match_expression_usefulness(
     type_id: TypeId,
-    scrutinees: Vec<Scrutinee>,
+    scrutinees: Vec<(Scrutinee, bool)>,
     span: Span,
 ) -> CompileResult<(WitnessReport, Vec<(Scrutinee, bool)>)> {
     let mut warnings = vec![];
@@ -210,7 +214,7 @@ pub(crate) fn check_match_expression_usefulness(
         warnings,
         errors
     );
-    for scrutinee in scrutinees.iter() {
+    for (scrutinee, has_guard) in scrutinees.iter() {
         let pat = check!(
             Pattern::from_scrutinee(scrutinee.clone()),
             return err(warnings, errors),
@@ -224,7 +228,9 @@ pub(crate) fn check_match_expression_usefulness(
             warnings,
             errors
         );
-        matrix.push(v);
+        if !has_guard {
+            matrix.push(v);
+        }
         // if an arm has witnesses to its usefulness then it is reachable
         arms_reachability.push((scrutinee.clone(), witness_report.has_witnesses()));
     }
@@ -2,22 +2,37 @@ use crate::{
     error::{err, ok},
     semantic_analysis::{
         ast_node::expression::typed_expression::{
-            instantiate_struct_field_access, instantiate_tuple_index_access,
-            instantiate_unsafe_downcast,
+            instantiate_if_expression, instantiate_lazy_operator, instantiate_struct_field_access,
+            instantiate_tuple_index_access, instantiate_unsafe_downcast,
         },
-        namespace::Namespace,
-        IsConstant, TypedEnumVariant, TypedExpression, TypedExpressionVariant,
+        IsConstant, TypeCheckContext, TypedEnumVariant, TypedExpression, TypedExpressionVariant,
     },
-    type_engine::unify,
-    CompileResult, Ident, Literal,
+    type_engine::{insert_type, unify},
+    CompileError, CompileResult, Ident, LazyOp, Literal, OpVariant, TypeInfo,
 };
 
 use sway_types::span::Span;
 
 use super::typed_scrutinee::{TypedScrutinee, TypedScrutineeVariant, TypedStructScrutineeField};
 
+/// A single requirement that a desugared if expression must include in the conditional.
+#[allow(clippy::large_enum_variant)]
+#[derive(Debug, Clone)]
+pub(crate) enum MatchReq {
+    /// The two expressions must be equal.
+    Eq(TypedExpression, TypedExpression),
+    /// The expression must lie within the inclusive range `start..=end`.
+    Range {
+        exp: TypedExpression,
+        start: TypedExpression,
+        end: TypedExpression,
+    },
+    /// The requirements of at least one of the alternatives must hold.
+    Or(Vec<MatchReqMap>),
+}
+
 /// List of requirements that a desugared if expression must include in the conditional.
-pub(crate) type MatchReqMap = Vec<(TypedExpression, TypedExpression)>;
+pub(crate) type MatchReqMap = Vec<MatchReq>;
 /// List of variable declarations that must be placed inside of the body of the if expression.
 pub(crate) type MatchDeclMap = Vec<(Ident, TypedExpression)>;
 /// This is the result type given back by the matcher.
@@ -66,7 +81,7 @@ pub(crate) type MatcherResult = (MatchReqMap, MatchDeclMap);
 pub(crate) fn matcher(
     exp: &TypedExpression,
     scrutinee: TypedScrutinee,
-    namespace: &mut Namespace,
+    ctx: TypeCheckContext,
 ) -> CompileResult<MatcherResult> {
     let mut warnings = vec![];
     let mut errors = vec![];
@@ -85,11 +100,13 @@ pub(crate) fn matcher(
         TypedScrutineeVariant::CatchAll => ok((vec![], vec![]), warnings, errors),
         TypedScrutineeVariant::Literal(value) => match_literal(exp, value, span),
         TypedScrutineeVariant::Variable(name) => match_variable(exp, name, span),
-        TypedScrutineeVariant::StructScrutinee(fields) => match_struct(exp, fields, namespace),
+        TypedScrutineeVariant::StructScrutinee(fields) => match_struct(exp, fields, ctx),
         TypedScrutineeVariant::EnumScrutinee { value, variant } => {
-            match_enum(exp, variant, *value, span, namespace)
+            match_enum(exp, variant, *value, span, ctx)
         }
-        TypedScrutineeVariant::Tuple(elems) => match_tuple(exp, elems, span, namespace),
+        TypedScrutineeVariant::Tuple(elems) => match_tuple(exp, elems, span, ctx),
+        TypedScrutineeVariant::Range { start, end } => match_range(exp, start, end, span),
+        TypedScrutineeVariant::Or(elems) => match_or(exp, elems, span, ctx),
     }
 }
 
@@ -98,7 +115,7 @@ fn match_literal(
     scrutinee: Literal,
     span: Span,
 ) -> CompileResult<MatcherResult> {
-    let match_req_map = vec![(
+    let match_req_map = vec![MatchReq::Eq(
         exp.to_owned(),
         TypedExpression {
             expression: TypedExpressionVariant::Literal(scrutinee),
@@ -124,7 +141,7 @@ fn match_variable(
 fn match_struct(
     exp: &TypedExpression,
     fields: Vec<TypedStructScrutineeField>,
-    namespace: &mut Namespace,
+    mut ctx: TypeCheckContext,
 ) -> CompileResult<MatcherResult> {
     let mut warnings = vec![];
     let mut errors = vec![];
@@ -150,7 +167,7 @@ fn match_struct(
             // or if the scrutinee has a more complex agenda
             Some(scrutinee) => {
                 let (mut new_match_req_map, mut new_match_decl_map) = check!(
-                    matcher(&subfield, scrutinee, namespace),
+                    matcher(&subfield, scrutinee, ctx.by_ref()),
                     return err(warnings, errors),
                     warnings,
                     errors
@@ -169,13 +186,13 @@ fn match_enum(
     variant: TypedEnumVariant,
     scrutinee: TypedScrutinee,
     span: Span,
-    namespace: &mut Namespace,
+    ctx: TypeCheckContext,
 ) -> CompileResult<MatcherResult> {
     let mut warnings = vec![];
     let mut errors = vec![];
     let (mut match_req_map, unsafe_downcast) = instantiate_unsafe_downcast(exp, variant, span);
     let (mut new_match_req_map, match_decl_map) = check!(
-        matcher(&unsafe_downcast, scrutinee, namespace),
+        matcher(&unsafe_downcast, scrutinee, ctx),
         return err(warnings, errors),
         warnings,
         errors
@@ -188,7 +205,7 @@ fn match_tuple(
     exp: &TypedExpression,
     elems: Vec<TypedScrutinee>,
     span: Span,
-    namespace: &mut Namespace,
+    mut ctx: TypeCheckContext,
 ) -> CompileResult<MatcherResult> {
     let mut warnings = vec![];
     let mut errors = vec![];
@@ -202,7 +219,7 @@ fn match_tuple(
             errors
         );
         let (mut new_match_req_map, mut new_match_decl_map) = check!(
-            matcher(&tuple_index_access, elem, namespace),
+            matcher(&tuple_index_access, elem, ctx.by_ref()),
             return err(warnings, errors),
             warnings,
             errors
@@ -212,3 +229,217 @@ fn match_tuple(
     }
     ok((match_req_map, match_decl_map), warnings, errors)
 }
+
+fn match_range(
+    exp: &TypedExpression,
+    start: Literal,
+    end: Literal,
+    span: Span,
+) -> CompileResult<MatcherResult> {
+    let to_bound = |value: Literal| TypedExpression {
+        expression: TypedExpressionVariant::Literal(value),
+        return_type: exp.return_type,
+        is_constant: IsConstant::No,
+        span: span.clone(),
+    };
+    let match_req_map = vec![MatchReq::Range {
+        exp: exp.to_owned(),
+        start: to_bound(start),
+        end: to_bound(end),
+    }];
+    let match_decl_map = vec![];
+    ok((match_req_map, match_decl_map), vec![], vec![])
+}
+
+fn match_or(
+    exp: &TypedExpression,
+    elems: Vec<TypedScrutinee>,
+    span: Span,
+    mut ctx: TypeCheckContext,
+) -> CompileResult<MatcherResult> {
+    let mut warnings = vec![];
+    let mut errors = vec![];
+    let mut alternatives = vec![];
+    for elem in elems.into_iter() {
+        alternatives.push(check!(
+            matcher(exp, elem, ctx.by_ref()),
+            return err(warnings, errors),
+            warnings,
+            errors
+        ));
+    }
+
+    // Every alternative binds the same variables, but to different values. Each variable is
+    // therefore declared as an if expression which picks the value from the first alternative
+    // whose requirements hold, e.g. `let x = if <alt 1> { <x from alt 1> } else { <x from alt 2> }`.
+    let mut match_decl_map = vec![];
+    let names = match alternatives.first() {
+        Some((_, decls)) => decls
+            .iter()
+            .map(|(name, _)| name.clone())
+            .collect::<Vec<_>>(),
+        None => vec![],
+    };
+    for name in names.into_iter() {
+        let mut value: Option<TypedExpression> = None;
+        for (reqs, decls) in alternatives.iter().rev() {
+            let alternative_value = match decls.iter().find(|(decl_name, _)| *decl_name == name) {
+                Some((_, alternative_value)) => alternative_value.clone(),
+                None => {
+                    errors.push(CompileError::Internal(
+                        "variable is not bound in all alternatives of an or-pattern",
+                        span,
+                    ));
+                    return err(warnings, errors);
+                }
+            };
+            let conditional = check!(
+                instantiate_match_conditional(ctx.by_ref(), reqs.clone()),
+                return err(warnings, errors),
+                warnings,
+                errors
+            );
+            value = Some(match (value, conditional) {
+                (Some(else_value), Some(conditional)) => check!(
+                    instantiate_if_expression(
+                        conditional,
+                        alternative_value.clone(),
+                        Some(else_value),
+                        alternative_value.span.clone(),
+                        alternative_value.return_type,
+                        ctx.self_type()
+                    ),
+                    return err(warnings, errors),
+                    warnings,
+                    errors
+                ),
+                _ => alternative_value,
+            });
+        }
+        if let Some(value) = value {
+            match_decl_map.push((name, value));
+        }
+    }
+
+    let match_req_map = vec![MatchReq::Or(
+        alternatives.into_iter().map(|(reqs, _)| reqs).collect(),
+    )];
+    ok((match_req_map, match_decl_map), warnings, errors)
+}
+
+/// Creates the conditional of a desugared if expression from a [MatchReqMap]. All of the
+/// requirements are joined with `&&`, and the alternatives of an or-pattern with `||`.
+/// Returns `None` if there are no requirements, i.e. the conditional is always true.
+pub(crate) fn instantiate_match_conditional(
+    mut ctx: TypeCheckContext,
+    match_req_map: MatchReqMap,
+) -> CompileResult<Option<TypedExpression>> {
+    let mut warnings = vec![];
+    let mut errors = vec![];
+    // build the conditional in reverse so that the requirements are checked in order
+    let mut conditional: Option<TypedExpression> = None;
+    for req in match_req_map.into_iter().rev() {
+        let new_condition = match req {
+            MatchReq::Eq(left_req, right_req) => {
+                let joined_span = Span::join(left_req.span.clone(), right_req.span.clone());
+                let args = vec![left_req, right_req];
+                check!(
+                    TypedExpression::core_ops(ctx.by_ref(), OpVariant::Equals, args, joined_span),
+                    continue,
+                    warnings,
+                    errors
+                )
+            }
+            MatchReq::Range { exp, start, end } => {
+                let joined_span = Span::join(exp.span.clone(), end.span.clone());
+                let lower = check!(
+                    TypedExpression::core_ops(
+                        ctx.by_ref(),
+                        OpVariant::GreaterThanOrEqualTo,
+                        vec![exp.clone(), start],
+                        joined_span.clone(),
+                    ),
+                    continue,
+                    warnings,
+                    errors
+                );
+                let upper = check!(
+                    TypedExpression::core_ops(
+                        ctx.by_ref(),
+                        OpVariant::LessThanOrEqualTo,
+                        vec![exp, end],
+                        joined_span.clone(),
+                    ),
+                    continue,
+                    warnings,
+                    errors
+                );
+                instantiate_lazy_operator(
+                    LazyOp::And,
+                    lower,
+                    upper,
+                    insert_type(TypeInfo::Boolean),
+                    joined_span,
+                )
+            }
+            MatchReq::Or(alternatives) => {
+                let mut alternative_conditionals = vec![];
+                for alternative in alternatives.into_iter() {
+                    match check!(
+                        instantiate_match_conditional(ctx.by_ref(), alternative),
+                        continue,
+                        warnings,
+                        errors
+                    ) {
+                        Some(alternative_conditional) => {
+                            alternative_conditionals.push(alternative_conditional)
+                        }
+                        // an alternative without requirements always matches
+                        None => {
+                            alternative_conditionals.clear();
+                            break;
+                        }
+                    }
+                }
+                let mut or_conditional: Option<TypedExpression> = None;
+                for alternative_conditional in alternative_conditionals.into_iter().rev() {
+                    or_conditional = Some(match or_conditional {
+                        Some(inner_condition) => {
+                            let joined_span = Span::join(
+                                alternative_conditional.span.clone(),
+                                inner_condition.span.clone(),
+                            );
+                            instantiate_lazy_operator(
+                                LazyOp::Or,
+                                alternative_conditional,
+                                inner_condition,
+                                insert_type(TypeInfo::Boolean),
+                                joined_span,
+                            )
+                        }
+                        None => alternative_conditional,
+                    });
+                }
+                match or_conditional {
+                    Some(or_conditional) => or_conditional,
+                    None => continue,
+                }
+            }
+        };
+        conditional = Some(match conditional {
+            Some(inner_condition) => {
+                let joined_span =
+                    Span::join(new_condition.span.clone(), inner_condition.span.clone());
+                instantiate_lazy_operator(
+                    LazyOp::And,
+                    new_condition,
+                    inner_condition,
+                    insert_type(TypeInfo::Boolean),
+                    joined_span,
+                )
+            }
+            None => new_condition,
+        });
+    }
+    ok(conditional, warnings, errors)
+}
@@ -17,6 +17,12 @@ use super::matcher::{matcher, MatchReqMap};
 #[derive(Debug)]
 pub(crate) struct TypedMatchBranch {
     pub(crate) conditions: MatchReqMap,
+    /// The declarations of the variables bound by the scrutinee when the branch has an `if`
+    /// guard. These are placed ahead of the branch's conditional, so that the guard and the
+    /// result share them. Without a guard, they are placed within the result instead.
+    pub(crate) declarations: Vec<TypedAstNode>,
+    /// The `if` guard of the branch.
+    pub(crate) guard: Option<TypedExpression>,
     pub(crate) result: TypedExpression,
     #[allow(dead_code)]
     span: Span,
@@ -33,6 +39,7 @@ impl TypedMatchBranch {
 
         let MatchBranch {
             scrutinee,
+            guard,
             result,
             span: branch_span,
         } = branch;
@@ -47,7 +54,7 @@ impl TypedMatchBranch {
 
         // calculate the requirements map and the declarations map
         let (match_req_map, match_decl_map) = check!(
-            matcher(typed_value, typed_scrutinee, ctx.namespace),
+            matcher(typed_value, typed_scrutinee, ctx.by_ref()),
             return err(warnings, errors),
             warnings,
             errors
@@ -77,6 +84,30 @@ impl TypedMatchBranch {
             });
         }
 
+        // type check the guard, which may refer to the variables that we just declared
+        let typed_guard = match guard {
+            None => None,
+            Some(guard) => {
+                let ctx = ctx
+                    .by_ref()
+                    .with_help_text("match guards must be of type bool")
+                    .with_type_annotation(insert_type(TypeInfo::Boolean));
+                Some(check!(
+                    TypedExpression::type_check(ctx, guard),
+                    return err(warnings, errors),
+                    warnings,
+                    errors
+                ))
+            }
+        };
+
+        // the variables of a guarded branch are declared once, ahead of both the guard and the
+        // result, rather than within the result
+        let declarations = match typed_guard {
+            Some(_) => std::mem::take(&mut code_block_contents),
+            None => vec![],
+        };
+
         // type check the branch result
         let typed_result = {
             let ctx = ctx
@@ -139,6 +170,8 @@ impl TypedMatchBranch {
         // return!
         let branch = TypedMatchBranch {
             conditions: match_req_map,
+            declarations,
+            guard: typed_guard,
             result: new_result,
             span: branch_span,
         };
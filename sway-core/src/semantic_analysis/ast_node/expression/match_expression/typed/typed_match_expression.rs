@@ -6,13 +6,14 @@ use crate::{
         ast_node::expression::typed_expression::{
             instantiate_if_expression, instantiate_lazy_operator,
         },
-        IsConstant, TypeCheckContext, TypedExpression, TypedExpressionVariant,
+        IsConstant, TypeCheckContext, TypedAstNode, TypedAstNodeContent, TypedCodeBlock,
+        TypedExpression, TypedExpressionVariant,
     },
     type_engine::{insert_type, TypeId},
     CompileError, CompileResult, LazyOp, Literal, MatchBranch, TypeInfo,
 };
 
-use super::typed_match_branch::TypedMatchBranch;
+use super::{matcher::instantiate_match_conditional, typed_match_branch::TypedMatchBranch};
 
 #[derive(Debug)]
 pub(crate) struct TypedMatchExpression {
@@ -72,35 +73,35 @@ impl TypedMatchExpression {
 
         // for every branch of the match expression, in reverse
         for TypedMatchBranch {
-            conditions, result, ..
+            conditions,
+            declarations,
+            guard,
+            result,
+            ..
         } in branches.into_iter().rev()
         {
-            // create the conditional that will act as the conditional for the if statement, in reverse
-            let mut conditional: Option<TypedExpression> = None;
-            for (left_req, right_req) in conditions.into_iter().rev() {
-                let joined_span = Span::join(left_req.span.clone(), right_req.span.clone());
-                let args = vec![left_req, right_req];
-                let new_condition = check!(
-                    TypedExpression::core_ops_eq(ctx.by_ref(), args, joined_span),
-                    continue,
-                    warnings,
-                    errors
-                );
-                conditional = Some(match conditional {
-                    Some(inner_condition) => {
-                        let joined_span =
-                            Span::join(inner_condition.span.clone(), new_condition.span.clone());
-                        instantiate_lazy_operator(
-                            LazyOp::And,
-                            new_condition,
-                            inner_condition,
-                            insert_type(TypeInfo::Boolean),
-                            joined_span,
-                        )
-                    }
-                    None => new_condition,
-                });
-            }
+            // create the conditional that will act as the conditional for the if statement
+            let conditional = check!(
+                instantiate_match_conditional(ctx.by_ref(), conditions),
+                continue,
+                warnings,
+                errors
+            );
+
+            // the guard is only evaluated once all of the other requirements hold
+            let conditional = match (conditional, guard) {
+                (Some(conditional), Some(guard)) => {
+                    let joined_span = Span::join(conditional.span.clone(), guard.span.clone());
+                    Some(instantiate_lazy_operator(
+                        LazyOp::And,
+                        conditional,
+                        guard,
+                        insert_type(TypeInfo::Boolean),
+                        joined_span,
+                    ))
+                }
+                (conditional, guard) => conditional.or(guard),
+            };
 
             // add to the if expression that we are building using the result component
             // of the match branch and using the conditional that we just built
@@ -159,6 +160,25 @@ impl TypedMatchExpression {
                     )
                 }
             });
+
+            // declare the variables bound by a guarded branch ahead of its conditional
+            if !declarations.is_empty() {
+                typed_if_exp = typed_if_exp.map(|typed_if_exp| {
+                    let span = typed_if_exp.span.clone();
+                    let return_type = typed_if_exp.return_type;
+                    let mut contents = declarations;
+                    contents.push(TypedAstNode {
+                        content: TypedAstNodeContent::Expression(typed_if_exp),
+                        span: span.clone(),
+                    });
+                    TypedExpression {
+                        expression: TypedExpressionVariant::CodeBlock(TypedCodeBlock { contents }),
+                        return_type,
+                        is_constant: IsConstant::No,
+                        span,
+                    }
+                });
+            }
         }
 
         // return!
@@ -3,7 +3,7 @@ use sway_types::{Ident, Span, Spanned};
 use crate::{
     error::{err, ok},
     semantic_analysis::{declaration::EnforceTypeArguments, TypeCheckContext, TypedEnumVariant},
-    type_engine::{insert_type, CreateTypeId, TypeArgument, TypeId},
+    type_engine::{insert_type, unify, CreateTypeId, TypeArgument, TypeId},
     CompileError, CompileResult, Literal, Scrutinee, StructScrutineeField, TypeInfo,
 };
 
@@ -26,6 +26,11 @@ pub(crate) enum TypedScrutineeVariant {
         value: Box<TypedScrutinee>,
     },
     Tuple(Vec<TypedScrutinee>),
+    Range {
+        start: Literal,
+        end: Literal,
+    },
+    Or(Vec<TypedScrutinee>),
 }
 
 #[derive(Debug, Clone)]
@@ -225,7 +230,124 @@ impl TypedScrutinee {
                     span,
                 }
             }
+            Scrutinee::Range { start, end, span } => {
+                let (first, last) = match (start.as_integer(), end.as_integer()) {
+                    (Some(first), Some(last)) => (first, last),
+                    _ => {
+                        errors.push(CompileError::MatchRangePatternNotNumeric { span });
+                        return err(warnings, errors);
+                    }
+                };
+                if first > last {
+                    errors.push(CompileError::MatchRangePatternEmpty {
+                        start: start.to_string(),
+                        end: end.to_string(),
+                        span,
+                    });
+                    return err(warnings, errors);
+                }
+                // a bound without a type suffix takes the type of the other bound, and so must
+                // fit within it
+                let max = match (&start, &end) {
+                    (Literal::U8(_), _) | (_, Literal::U8(_)) => Some(("u8", u8::MAX as u64)),
+                    (Literal::U16(_), _) | (_, Literal::U16(_)) => Some(("u16", u16::MAX as u64)),
+                    (Literal::U32(_), _) | (_, Literal::U32(_)) => Some(("u32", u32::MAX as u64)),
+                    _ => None,
+                };
+                if let Some((ty, max)) = max {
+                    if last > max {
+                        errors.push(CompileError::IntegerTooLarge {
+                            span,
+                            ty: ty.to_string(),
+                        });
+                        return err(warnings, errors);
+                    }
+                }
+                // both bounds must be of the same type
+                let type_id = insert_type(start.to_typeinfo());
+                let (mut new_warnings, new_errors) =
+                    unify(type_id, insert_type(end.to_typeinfo()), &span, "");
+                warnings.append(&mut new_warnings);
+                errors.append(&mut new_errors.into_iter().map(|x| x.into()).collect());
+                TypedScrutinee {
+                    variant: TypedScrutineeVariant::Range { start, end },
+                    type_id,
+                    span,
+                }
+            }
+            Scrutinee::Or { elems, span } => {
+                let mut typed_elems: Vec<TypedScrutinee> = vec![];
+                for elem in elems.into_iter() {
+                    typed_elems.push(check!(
+                        TypedScrutinee::type_check(ctx.by_ref(), elem),
+                        return err(warnings, errors),
+                        warnings,
+                        errors
+                    ));
+                }
+                // all of the alternatives must be of the same type and bind the same variables
+                let type_id = typed_elems[0].type_id;
+                let expected_vars = typed_elems[0].bound_variables();
+                for elem in typed_elems.iter().skip(1) {
+                    let (mut new_warnings, new_errors) =
+                        unify(elem.type_id, type_id, &elem.span, "");
+                    warnings.append(&mut new_warnings);
+                    errors.append(&mut new_errors.into_iter().map(|x| x.into()).collect());
+                    let vars = elem.bound_variables();
+                    for var in expected_vars.iter() {
+                        if !vars.contains(var) {
+                            errors.push(CompileError::MatchOrPatternVariableNotBound {
+                                var: var.clone(),
+                                span: elem.span.clone(),
+                            });
+                        }
+                    }
+                    for var in vars.into_iter() {
+                        if !expected_vars.contains(&var) {
+                            errors.push(CompileError::MatchOrPatternVariableNotBound {
+                                span: var.span(),
+                                var,
+                            });
+                        }
+                    }
+                }
+                if !errors.is_empty() {
+                    return err(warnings, errors);
+                }
+                TypedScrutinee {
+                    variant: TypedScrutineeVariant::Or(typed_elems),
+                    type_id,
+                    span,
+                }
+            }
         };
         ok(typed_scrutinee, warnings, errors)
     }
+
+    /// Returns the names of the variables that this scrutinee binds when it matches.
+    fn bound_variables(&self) -> Vec<Ident> {
+        match &self.variant {
+            TypedScrutineeVariant::CatchAll
+            | TypedScrutineeVariant::Literal(_)
+            | TypedScrutineeVariant::Range { .. } => vec![],
+            TypedScrutineeVariant::Variable(name) => vec![name.clone()],
+            TypedScrutineeVariant::StructScrutinee(fields) => fields
+                .iter()
+                .flat_map(|field| match &field.scrutinee {
+                    Some(scrutinee) => scrutinee.bound_variables(),
+                    None => vec![field.field.clone()],
+                })
+                .collect(),
+            TypedScrutineeVariant::EnumScrutinee { value, .. } => value.bound_variables(),
+            TypedScrutineeVariant::Tuple(elems) => elems
+                .iter()
+                .flat_map(|elem| elem.bound_variables())
+                .collect(),
+            // every alternative binds the same variables, which is checked during type checking
+            TypedScrutineeVariant::Or(elems) => elems
+                .first()
+                .map(|elem| elem.bound_variables())
+                .unwrap_or_default(),
+        }
+    }
 }
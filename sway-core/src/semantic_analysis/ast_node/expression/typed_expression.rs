@@ -207,7 +207,9 @@ impl UnresolvedTypeCheck for TypedExpression {
             | StorageAccess { .. }
             | Literal(_)
             | AbiName(_)
-            | FunctionParameter => {}
+            | FunctionParameter
+            | Break
+            | Continue => {}
         }
         res
     }
@@ -280,6 +282,8 @@ impl DeterministicallyAborts for TypedExpression {
             AbiName(_) => false,
             EnumTag { exp } => exp.deterministically_aborts(),
             UnsafeDowncast { exp, .. } => exp.deterministically_aborts(),
+            // `break` and `continue` never fall through to the code which follows them.
+            Break | Continue => true,
         }
     }
 }
@@ -295,8 +299,10 @@ pub(crate) fn error_recovery_expr(span: Span) -> TypedExpression {
 
 #[allow(clippy::too_many_arguments)]
 impl TypedExpression {
-    pub(crate) fn core_ops_eq(
+    /// Creates a call to the `core::ops` method which implements `op_variant` for `arguments`.
+    pub(crate) fn core_ops(
         ctx: TypeCheckContext,
+        op_variant: OpVariant,
         arguments: Vec<TypedExpression>,
         span: Span,
     ) -> CompileResult<TypedExpression> {
@@ -308,7 +314,7 @@ impl TypedExpression {
                 Ident::new_with_override("ops", span.clone()),
             ],
             suffix: Op {
-                op_variant: OpVariant::Equals,
+                op_variant,
                 span: span.clone(),
             }
             .to_var_name(),
@@ -381,7 +387,34 @@ impl TypedExpression {
             | TypedExpressionVariant::StorageAccess { .. }
             | TypedExpressionVariant::FunctionApplication { .. }
             | TypedExpressionVariant::EnumTag { .. }
-            | TypedExpressionVariant::UnsafeDowncast { .. } => vec![],
+            | TypedExpressionVariant::UnsafeDowncast { .. }
+            | TypedExpressionVariant::Break
+            | TypedExpressionVariant::Continue => vec![],
+        }
+    }
+
+    /// recurse into `self` and find whether it contains a `break` or `continue` of the enclosing
+    /// loop. Like [TypedExpression::gather_return_statements], this only looks into the control
+    /// flow expressions that may contain statements.
+    pub(crate) fn contains_loop_exit(&self) -> bool {
+        match &self.expression {
+            TypedExpressionVariant::Break | TypedExpressionVariant::Continue => true,
+            TypedExpressionVariant::IfExp {
+                condition,
+                then,
+                r#else,
+            } => {
+                condition.contains_loop_exit()
+                    || then.contains_loop_exit()
+                    || r#else
+                        .as_ref()
+                        .map(|r#else| r#else.contains_loop_exit())
+                        .unwrap_or(false)
+            }
+            TypedExpressionVariant::CodeBlock(TypedCodeBlock { contents, .. }) => {
+                contents.iter().any(TypedAstNode::contains_loop_exit)
+            }
+            _ => false,
         }
     }
 
@@ -512,6 +545,13 @@ impl TypedExpression {
                     .with_help_text("");
                 Self::type_check_array_index(ctx, *prefix, *index, span)
             }
+            Expression::ArrayLength { array, span } => {
+                let ctx = ctx
+                    .by_ref()
+                    .with_type_annotation(insert_type(TypeInfo::Unknown))
+                    .with_help_text("");
+                Self::type_check_array_length(ctx, *array, span)
+            }
             Expression::StorageAccess { field_names, .. } => {
                 let ctx = ctx
                     .by_ref()
@@ -522,6 +562,18 @@ impl TypedExpression {
             Expression::IntrinsicFunction { kind, span } => {
                 Self::type_check_intrinsic_function(ctx.by_ref(), kind, span)
             }
+            Expression::Break { span } => Self::type_check_loop_exit(
+                &ctx,
+                TypedExpressionVariant::Break,
+                CompileError::BreakOutsideLoop { span: span.clone() },
+                span,
+            ),
+            Expression::Continue { span } => Self::type_check_loop_exit(
+                &ctx,
+                TypedExpressionVariant::Continue,
+                CompileError::ContinueOutsideLoop { span: span.clone() },
+                span,
+            ),
         };
         let mut typed_expression = match res.value {
             Some(r) => r,
@@ -576,6 +628,26 @@ impl TypedExpression {
         ok(typed_expression, warnings, errors)
     }
 
+    /// Type check a `break` or `continue`, which must be within a loop body. Both have the unit
+    /// type, although they never evaluate to a value as they always jump away.
+    fn type_check_loop_exit(
+        ctx: &TypeCheckContext,
+        expression: TypedExpressionVariant,
+        outside_loop_error: CompileError,
+        span: Span,
+    ) -> CompileResult<TypedExpression> {
+        if !ctx.within_loop() {
+            return err(vec![], vec![outside_loop_error]);
+        }
+        let exp = TypedExpression {
+            expression,
+            return_type: insert_type(TypeInfo::Tuple(Vec::new())),
+            is_constant: IsConstant::No,
+            span,
+        };
+        ok(exp, vec![], vec![])
+    }
+
     fn type_check_literal(lit: Literal, span: Span) -> CompileResult<TypedExpression> {
         let return_type = match &lit {
             Literal::String(s) => TypeInfo::Str(s.as_str().len() as u64),
@@ -584,6 +656,7 @@ impl TypedExpression {
             Literal::U16(_) => TypeInfo::UnsignedInteger(IntegerBits::Sixteen),
             Literal::U32(_) => TypeInfo::UnsignedInteger(IntegerBits::ThirtyTwo),
             Literal::U64(_) => TypeInfo::UnsignedInteger(IntegerBits::SixtyFour),
+            Literal::U256(_) => TypeInfo::UnsignedInteger(IntegerBits::TwoFiftySix),
             Literal::Boolean(_) => TypeInfo::Boolean,
             Literal::Byte(_) => TypeInfo::Byte,
             Literal::B256(_) => TypeInfo::B256,
@@ -835,7 +908,7 @@ impl TypedExpression {
 
         let scrutinees = branches
             .iter()
-            .map(|branch| branch.scrutinee.clone())
+            .map(|branch| (branch.scrutinee.clone(), branch.guard.is_some()))
             .collect::<Vec<_>>();
 
         // type check the match expression and create a TypedMatchExpression object
@@ -992,6 +1065,21 @@ impl TypedExpression {
             errors
         );
 
+        // a struct can only be instantiated where all of its fields can be used
+        let private_fields = struct_decl
+            .fields
+            .iter()
+            .filter(|def_field| !def_field.is_accessible_from(&span))
+            .map(|def_field| format!("\"{}\"", def_field.name))
+            .collect::<Vec<_>>();
+        if !private_fields.is_empty() {
+            errors.push(CompileError::StructHasPrivateFields {
+                struct_name: struct_decl.name.clone(),
+                private_fields: private_fields.join(", "),
+                span: span.clone(),
+            });
+        }
+
         // match up the names with their type annotations from the declaration
         let mut typed_fields_buf = vec![];
         for def_field in struct_decl.fields.iter_mut() {
@@ -1254,12 +1342,28 @@ impl TypedExpression {
                 errors.push(CompileError::AmbiguousPath { span });
                 return err(warnings, errors);
             }
-            check!(
-                instantiate_enum(ctx, enum_decl, call_path.suffix, args, type_arguments),
-                return err(warnings, errors),
-                warnings,
-                errors
-            )
+            // An enum may also have associated constants, which don't share names with variants.
+            if args.is_empty()
+                && type_arguments.is_empty()
+                && !enum_decl
+                    .variants
+                    .iter()
+                    .any(|variant| variant.name == call_path.suffix)
+            {
+                check!(
+                    Self::type_check_associated_constant(ctx, call_path, span),
+                    return err(warnings, errors),
+                    warnings,
+                    errors
+                )
+            } else {
+                check!(
+                    instantiate_enum(ctx, enum_decl, call_path.suffix, args, type_arguments),
+                    return err(warnings, errors),
+                    warnings,
+                    errors
+                )
+            }
 
         // Otherwise, our prefix should point to some module ending with an enum or function.
         } else if ctx
@@ -1308,7 +1412,26 @@ impl TypedExpression {
                 }
             }
 
-        // If prefix is neither a module or enum, there's nothing to be found.
+        // Otherwise, the prefix may be a type with an associated constant, like `Self::X`.
+        } else if args.is_empty()
+            && type_arguments.is_empty()
+            && (enum_name.as_str() == "Self"
+                || matches!(
+                    ctx.namespace
+                        .root()
+                        .resolve_symbol(&abs_enum_mod_path, enum_name)
+                        .value,
+                    Some(TypedDeclaration::StructDeclaration(_))
+                ))
+        {
+            check!(
+                Self::type_check_associated_constant(ctx, call_path, span),
+                return err(warnings, errors),
+                warnings,
+                errors
+            )
+
+        // If prefix is neither a module, enum or type, there's nothing to be found.
         } else {
             errors.push(CompileError::SymbolNotFound {
                 name: call_path.suffix.clone(),
@@ -1319,6 +1442,45 @@ impl TypedExpression {
         ok(exp, warnings, errors)
     }
 
+    /// Type checks a path to an associated constant of a type, like `Type::X` or `Self::X`, by
+    /// inlining the value of the constant.
+    fn type_check_associated_constant(
+        ctx: TypeCheckContext,
+        call_path: CallPath,
+        span: Span,
+    ) -> CompileResult<TypedExpression> {
+        let mut warnings = vec![];
+        let mut errors = vec![];
+        let (type_name, mod_path) = call_path.prefixes.split_last().expect("empty call path");
+        let type_id = if type_name.as_str() == "Self" {
+            insert_type(TypeInfo::SelfType)
+        } else {
+            insert_type(TypeInfo::Custom {
+                name: type_name.clone(),
+                type_arguments: vec![],
+            })
+        };
+        let constant_path = mod_path.iter().chain(std::iter::once(&call_path.suffix));
+        let constant_path: Vec<Ident> = if call_path.is_absolute {
+            constant_path.cloned().collect()
+        } else {
+            ctx.namespace.find_module_path(constant_path)
+        };
+        let self_type = ctx.self_type();
+        let constant = check!(
+            ctx.namespace
+                .find_constant_for_type(type_id, &constant_path, self_type),
+            return err(warnings, errors),
+            warnings,
+            errors
+        );
+        let exp = TypedExpression {
+            span,
+            ..constant.value
+        };
+        ok(exp, warnings, errors)
+    }
+
     #[allow(clippy::too_many_arguments)]
     fn type_check_abi_cast(
         mut ctx: TypeCheckContext,
@@ -1415,15 +1577,19 @@ impl TypedExpression {
             address: Some(Box::new(address_expr.clone())),
         });
 
-        let mut functions_buf = abi
-            .interface_surface
+        // the methods of the abis this abi inherits from are callable through it as well
+        let mut abis = abi.super_abis(ctx.namespace);
+        abis.insert(0, abi);
+
+        let mut functions_buf = abis
             .iter()
+            .flat_map(|abi| abi.interface_surface.iter())
             .map(|x| x.to_dummy_func(Mode::ImplAbiFn))
             .collect::<Vec<_>>();
         // calls of ABI methods do not result in any codegen of the ABI method block
         // they instead just use the CALL opcode and the return type
-        let mut type_checked_fn_buf = Vec::with_capacity(abi.methods.len());
-        for method in &abi.methods {
+        let mut type_checked_fn_buf = vec![];
+        for method in abis.iter().flat_map(|abi| abi.methods.iter()) {
             let ctx = ctx
                 .by_ref()
                 .with_help_text("")
@@ -1438,8 +1604,12 @@ impl TypedExpression {
         }
 
         functions_buf.append(&mut type_checked_fn_buf);
-        ctx.namespace
-            .insert_trait_implementation(abi_name.clone(), return_type, functions_buf);
+        ctx.namespace.insert_trait_implementation(
+            abi_name.clone(),
+            return_type,
+            functions_buf,
+            vec![],
+        );
         let exp = TypedExpression {
             expression: TypedExpressionVariant::AbiCast {
                 abi_name,
@@ -1595,6 +1765,44 @@ impl TypedExpression {
         }
     }
 
+    fn type_check_array_length(
+        ctx: TypeCheckContext,
+        array: Expression,
+        span: Span,
+    ) -> CompileResult<Self> {
+        let mut warnings = Vec::new();
+        let mut errors = Vec::new();
+
+        let array_span = array.span();
+        let array_te = check!(
+            TypedExpression::type_check(ctx, array),
+            return err(warnings, errors),
+            warnings,
+            errors
+        );
+
+        // The length is part of the array's type, so it becomes a literal.
+        match look_up_type_id(array_te.return_type) {
+            TypeInfo::Array(_, count) => ok(
+                TypedExpression {
+                    expression: TypedExpressionVariant::Literal(Literal::U64(count as u64)),
+                    return_type: insert_type(TypeInfo::UnsignedInteger(IntegerBits::SixtyFour)),
+                    is_constant: IsConstant::Yes,
+                    span,
+                },
+                warnings,
+                errors,
+            ),
+            other => {
+                errors.push(CompileError::NotIterable {
+                    actually: other.to_string(),
+                    span: array_span,
+                });
+                err(warnings, errors)
+            }
+        }
+    }
+
     fn type_check_intrinsic_function(
         ctx: TypeCheckContext,
         kind: IntrinsicFunctionKind,
@@ -1668,6 +1876,11 @@ impl TypedExpression {
                         }),
                         new_type,
                     ),
+                    IntegerBits::TwoFiftySix => {
+                        let mut bytes = [0u8; 32];
+                        bytes[24..].copy_from_slice(&num.to_be_bytes());
+                        (Ok(Literal::U256(bytes)), new_type)
+                    }
                 },
                 TypeInfo::Numeric => (
                     num.to_string().parse().map(Literal::U64).map_err(|e| {
@@ -36,7 +36,7 @@ pub(crate) fn type_check_method_application(
         ));
     }
 
-    let method = check!(
+    let mut method = check!(
         resolve_method_name(
             ctx.by_ref(),
             &method_name,
@@ -49,6 +49,17 @@ pub(crate) fn type_check_method_application(
         errors
     );
 
+    // A method with its own type parameters gets fresh copies of them for every call, the same
+    // as a generic function, so that one call doesn't decide the types for all the others.
+    if !method.type_parameters.is_empty() {
+        method = check!(
+            ctx.monomorphize(method, vec![], EnforceTypeArguments::No, Some(&span)),
+            return err(warnings, errors),
+            warnings,
+            errors
+        );
+    }
+
     let contract_caller = if method.is_contract_call {
         args_buf.pop_front()
     } else {
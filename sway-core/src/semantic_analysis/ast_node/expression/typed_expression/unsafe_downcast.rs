@@ -2,8 +2,8 @@ use sway_types::Span;
 
 use crate::{
     semantic_analysis::{
-        ast_node::expression::match_expression::MatchReqMap, IsConstant, TypedEnumVariant,
-        TypedExpressionVariant,
+        ast_node::expression::match_expression::{MatchReq, MatchReqMap},
+        IsConstant, TypedEnumVariant, TypedExpressionVariant,
     },
     type_engine::{insert_type, IntegerBits},
     Literal, TypeInfo,
@@ -17,7 +17,7 @@ pub(crate) fn instantiate_unsafe_downcast(
     variant: TypedEnumVariant,
     span: Span,
 ) -> (MatchReqMap, TypedExpression) {
-    let match_req_map = vec![(
+    let match_req_map = vec![MatchReq::Eq(
         TypedExpression {
             expression: TypedExpressionVariant::EnumTag {
                 exp: Box::new(exp.clone()),
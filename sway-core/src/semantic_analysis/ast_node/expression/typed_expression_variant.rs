@@ -111,6 +111,10 @@ pub enum TypedExpressionVariant {
         exp: Box<TypedExpression>,
         variant: TypedEnumVariant,
     },
+    /// exits the innermost enclosing loop
+    Break,
+    /// jumps to the next iteration of the innermost enclosing loop
+    Continue,
 }
 
 // NOTE: Hash and PartialEq must uphold the invariant:
@@ -307,6 +311,7 @@ impl PartialEq for TypedExpressionVariant {
                 },
             ) => *l_exp == *r_exp && l_variant == r_variant,
             (Self::EnumTag { exp: l_exp }, Self::EnumTag { exp: r_exp }) => *l_exp == *r_exp,
+            (Self::Break, Self::Break) | (Self::Continue, Self::Continue) => true,
             _ => false,
         }
     }
@@ -407,7 +412,7 @@ impl CopyTypes for TypedExpressionVariant {
                 exp.copy_types(type_mapping);
                 variant.copy_types(type_mapping);
             }
-            AbiName(_) => (),
+            AbiName(_) | Break | Continue => (),
         }
     }
 }
@@ -494,6 +499,8 @@ impl fmt::Display for TypedExpressionVariant {
             TypedExpressionVariant::UnsafeDowncast { exp, variant } => {
                 format!("({} as {})", look_up_type_id(exp.return_type), variant.name)
             }
+            TypedExpressionVariant::Break => "break".into(),
+            TypedExpressionVariant::Continue => "continue".into(),
         };
         write!(f, "{}", s)
     }
@@ -128,7 +128,10 @@ impl DeterministicallyAborts for TypedAstNode {
             Declaration(_) => false,
             Expression(exp) | ImplicitReturnExpression(exp) => exp.deterministically_aborts(),
             WhileLoop(TypedWhileLoop { condition, body }) => {
-                condition.deterministically_aborts() || body.deterministically_aborts()
+                // a `break` or `continue` only leaves the loop body, not the loop itself
+                condition.deterministically_aborts()
+                    || (body.deterministically_aborts()
+                        && !body.contents.iter().any(TypedAstNode::contains_loop_exit))
             }
             SideEffect => false,
         }
@@ -199,6 +202,26 @@ impl TypedAstNode {
         }
     }
 
+    /// recurse into `self` and find whether it contains a `break` or `continue` which leaves the
+    /// enclosing loop body -- those within a nested loop only leave that nested loop.
+    pub(crate) fn contains_loop_exit(&self) -> bool {
+        match &self.content {
+            TypedAstNodeContent::ReturnStatement(TypedReturnStatement { expr })
+            | TypedAstNodeContent::ImplicitReturnExpression(expr)
+            | TypedAstNodeContent::Expression(expr)
+            | TypedAstNodeContent::Declaration(TypedDeclaration::VariableDeclaration(
+                TypedVariableDeclaration { body: expr, .. },
+            ))
+            | TypedAstNodeContent::Declaration(TypedDeclaration::Reassignment(
+                TypedReassignment { rhs: expr, .. },
+            )) => expr.contains_loop_exit(),
+            TypedAstNodeContent::WhileLoop(TypedWhileLoop { condition, .. }) => {
+                condition.contains_loop_exit()
+            }
+            TypedAstNodeContent::SideEffect | TypedAstNodeContent::Declaration(_) => false,
+        }
+    }
+
     fn type_info(&self) -> TypeInfo {
         // return statement should be ()
         use TypedAstNodeContent::*;
@@ -247,9 +270,13 @@ impl TypedAstNode {
                         ctx.namespace.find_module_path(&a.call_path)
                     };
                     let mut res = match a.import_type {
-                        ImportType::Star => ctx.namespace.star_import(&path),
-                        ImportType::SelfImport => ctx.namespace.self_import(&path, a.alias),
-                        ImportType::Item(s) => ctx.namespace.item_import(&path, &s, a.alias),
+                        ImportType::Star => ctx.namespace.star_import(&path, a.visibility),
+                        ImportType::SelfImport => {
+                            ctx.namespace.self_import(&path, a.alias, a.visibility)
+                        }
+                        ImportType::Item(s) => {
+                            ctx.namespace.item_import(&path, &s, a.alias, a.visibility)
+                        }
                     };
                     warnings.append(&mut res.warnings);
                     errors.append(&mut res.errors);
@@ -391,6 +418,7 @@ impl TypedAstNode {
                                 impl_trait.trait_name.clone(),
                                 implementing_for_type_id,
                                 impl_trait.methods.clone(),
+                                impl_trait.constants.clone(),
                             );
                             TypedDeclaration::ImplTrait(impl_trait)
                         }
@@ -405,6 +433,7 @@ impl TypedAstNode {
                                 impl_trait.trait_name.clone(),
                                 impl_trait.implementing_for_type_id,
                                 impl_trait.methods.clone(),
+                                impl_trait.constants.clone(),
                             );
                             TypedDeclaration::ImplTrait(impl_trait)
                         }
@@ -426,6 +455,24 @@ impl TypedAstNode {
                             );
                             decl
                         }
+                        Declaration::TypeAliasDeclaration(decl) => {
+                            let decl = check!(
+                                TypedTypeAliasDeclaration::type_check(ctx.by_ref(), decl),
+                                return err(warnings, errors),
+                                warnings,
+                                errors
+                            );
+                            let name = decl.name.clone();
+                            let decl = TypedDeclaration::TypeAliasDeclaration(decl);
+                            // insert the type alias decl into namespace
+                            check!(
+                                ctx.namespace.insert_symbol(name, decl.clone()),
+                                return err(warnings, errors),
+                                warnings,
+                                errors
+                            );
+                            decl
+                        }
                         Declaration::AbiDeclaration(abi_decl) => {
                             let abi_decl = check!(
                                 TypedAbiDeclaration::type_check(ctx.by_ref(), abi_decl),
@@ -560,7 +607,8 @@ impl TypedAstNode {
                             "A while loop's loop body cannot implicitly return a value. Try \
                              assigning it to a mutable variable declared outside of the loop \
                              instead.",
-                        );
+                        )
+                        .with_within_loop(true);
                     let (typed_body, _block_implicit_return) = check!(
                         TypedCodeBlock::type_check(ctx, body),
                         (
@@ -1063,6 +1111,13 @@ fn reassign_storage_subfield(
             .find(|x| x.name.as_str() == field.as_str())
         {
             Some(struct_field) => {
+                if !struct_field.is_accessible_from(&field.span()) {
+                    errors.push(CompileError::StructFieldIsPrivate {
+                        field_name: field.clone(),
+                        struct_name: type_checked_buf.last().unwrap().type_id.to_string(),
+                        span: field.span(),
+                    });
+                }
                 curr_type = struct_field.type_id;
                 type_checked_buf.push(TypeCheckedStorageReassignDescriptor {
                     name: field.clone(),
@@ -99,7 +99,8 @@ impl TypedSubmodule {
 ///    if trait B is implemented for type T, then trait A_i is also implemented for type T for
 ///    every A_i such that A_i is a supertrait of B.
 ///
-/// This nicely works for transitive supertraits as well.
+/// This nicely works for transitive supertraits as well. The same condition applies to abis,
+/// which may only have other abis as supertraits, with `T` always being the contract.
 ///
 fn check_supertraits(
     typed_tree_nodes: &[TypedAstNode],
@@ -114,12 +115,12 @@ fn check_supertraits(
             ..
         })) = &node.content
         {
-            if let CompileResult {
-                value: Some(TypedDeclaration::TraitDeclaration(tr)),
-                ..
-            } = namespace.resolve_call_path(trait_name)
+            if let Some((tr_name, tr_supertraits)) = namespace
+                .resolve_call_path(trait_name)
+                .value
+                .and_then(name_and_supertraits)
             {
-                for supertrait in &tr.supertraits {
+                for supertrait in tr_supertraits {
                     if !typed_tree_nodes.iter().any(|search_node| {
                         if let TypedAstNodeContent::Declaration(TypedDeclaration::ImplTrait(
                             TypedImplTrait {
@@ -129,20 +130,17 @@ fn check_supertraits(
                             },
                         )) = &search_node.content
                         {
-                            if let (
-                                CompileResult {
-                                    value: Some(TypedDeclaration::TraitDeclaration(tr1)),
-                                    ..
-                                },
-                                CompileResult {
-                                    value: Some(TypedDeclaration::TraitDeclaration(tr2)),
-                                    ..
-                                },
-                            ) = (
-                                namespace.resolve_call_path(search_node_trait_name),
-                                namespace.resolve_call_path(&supertrait.name),
+                            if let (Some((tr1_name, _)), Some((tr2_name, _))) = (
+                                namespace
+                                    .resolve_call_path(search_node_trait_name)
+                                    .value
+                                    .and_then(name_and_supertraits),
+                                namespace
+                                    .resolve_call_path(&supertrait.name)
+                                    .value
+                                    .and_then(name_and_supertraits),
                             ) {
-                                return (tr1.name == tr2.name)
+                                return (tr1_name == tr2_name)
                                     && (look_up_type_id(*implementing_for_type_id)
                                         == look_up_type_id(*search_node_type_implementing_for));
                             }
@@ -159,8 +157,8 @@ fn check_supertraits(
                         });
                         errors.push(CompileError::SupertraitImplRequired {
                             supertrait_name: supertrait.name.clone(),
-                            trait_name: tr.name.clone(),
-                            span: tr.name.span().clone(),
+                            trait_name: tr_name.clone(),
+                            span: tr_name.span().clone(),
                         });
                     }
                 }
@@ -169,3 +167,12 @@ fn check_supertraits(
     }
     errors
 }
+
+/// The name and supertraits of a trait or abi declaration.
+fn name_and_supertraits(decl: &TypedDeclaration) -> Option<(&Ident, &[Supertrait])> {
+    match decl {
+        TypedDeclaration::TraitDeclaration(tr) => Some((&tr.name, &tr.supertraits)),
+        TypedDeclaration::AbiDeclaration(abi) => Some((&abi.name, &abi.supertraits)),
+        _ => None,
+    }
+}
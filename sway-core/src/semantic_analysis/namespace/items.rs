@@ -9,6 +9,7 @@ use std::sync::Arc;
 type SymbolMap = im::OrdMap<Ident, TypedDeclaration>;
 type UseSynonyms = im::HashMap<Ident, Vec<Ident>>;
 type UseAliases = im::HashMap<String, Ident>;
+type PubUseSymbols = im::HashSet<Ident>;
 
 /// The set of items that exist within some lexical scope via declaration or importing.
 #[derive(Clone, Debug, Default, PartialEq)]
@@ -26,6 +27,9 @@ pub struct Items {
     /// Aliases are introduced with syntax like `use foo::bar as baz;` syntax, where `baz` is an
     /// alias for `bar`.
     pub(crate) use_aliases: UseAliases,
+    /// The subset of `use_synonyms` that were imported with `pub use` and are therefore
+    /// re-exported, i.e. may be imported from this module by other modules.
+    pub(crate) pub_use_symbols: PubUseSymbols,
     /// If there is a storage declaration (which are only valid in contracts), store it here.
     pub(crate) declared_storage: Option<TypedStorageDeclaration>,
 }
@@ -112,6 +116,7 @@ impl Items {
         trait_name: CallPath,
         implementing_for_type_id: TypeId,
         functions_buf: Vec<TypedFunctionDeclaration>,
+        constants: Vec<TypedConstantDeclaration>,
     ) {
         let new_prefixes = if trait_name.prefixes.is_empty() {
             self.use_synonyms
@@ -126,8 +131,12 @@ impl Items {
             prefixes: new_prefixes,
             is_absolute: trait_name.is_absolute,
         };
-        self.implemented_traits
-            .insert(trait_name, implementing_for_type_id, functions_buf);
+        self.implemented_traits.insert(
+            trait_name,
+            implementing_for_type_id,
+            functions_buf,
+            constants,
+        );
     }
 
     pub(crate) fn get_methods_for_type(
@@ -138,6 +147,14 @@ impl Items {
             .get_methods_for_type(implementing_for_type_id)
     }
 
+    pub(crate) fn get_constants_for_type(
+        &self,
+        implementing_for_type_id: TypeId,
+    ) -> Vec<TypedConstantDeclaration> {
+        self.implemented_traits
+            .get_constants_for_type(implementing_for_type_id)
+    }
+
     pub(crate) fn get_canonical_path(&self, symbol: &Ident) -> &[Ident] {
         self.use_synonyms.get(symbol).map(|v| &v[..]).unwrap_or(&[])
     }
@@ -201,23 +218,20 @@ impl Items {
                     },
                     ProjectionKind::StructField { name: field_name },
                 ) => {
-                    let field_type_opt = {
-                        fields.iter().find_map(
-                            |TypedStructField {
-                                 type_id: r#type,
-                                 name,
-                                 ..
-                             }| {
-                                if name == field_name {
-                                    Some(r#type)
-                                } else {
-                                    None
-                                }
-                            },
-                        )
-                    };
-                    let field_type = match field_type_opt {
-                        Some(field_type) => field_type,
+                    let field_opt = fields
+                        .iter()
+                        .find(|TypedStructField { name, .. }| name == field_name);
+                    let field_type = match field_opt {
+                        Some(field) => {
+                            if !field.is_accessible_from(&field_name.span()) {
+                                errors.push(CompileError::StructFieldIsPrivate {
+                                    field_name: field_name.clone(),
+                                    struct_name: struct_name.to_string(),
+                                    span: field_name.span(),
+                                });
+                            }
+                            &field.type_id
+                        }
                         None => {
                             // gather available fields for the error message
                             let available_fields = fields
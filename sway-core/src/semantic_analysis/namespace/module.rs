@@ -91,10 +91,17 @@ impl Module {
     /// Given a path to a `src` module, create synonyms to every symbol in that module to the given
     /// `dst` module.
     ///
-    /// This is used when an import path contains an asterisk.
+    /// This is used when an import path contains an asterisk. Along with the public symbols
+    /// declared within `src`, the symbols that `src` re-exports via `pub use` are also imported.
+    /// If `visibility` is public, all imported symbols are in turn re-exported from `dst`.
     ///
     /// Paths are assumed to be relative to `self`.
-    pub(crate) fn star_import(&mut self, src: &Path, dst: &Path) -> CompileResult<()> {
+    pub(crate) fn star_import(
+        &mut self,
+        src: &Path,
+        dst: &Path,
+        visibility: Visibility,
+    ) -> CompileResult<()> {
         let mut warnings = vec![];
         let mut errors = vec![];
         let src_ns = check!(
@@ -114,6 +121,7 @@ impl Module {
                     None
                 }
             })
+            .chain(src_ns.pub_use_symbols.iter().cloned())
             .collect::<Vec<_>>();
 
         let dst_ns = &mut self[dst];
@@ -124,6 +132,9 @@ impl Module {
                     name: symbol.clone(),
                 });
             }
+            if visibility == Visibility::Public {
+                dst_ns.pub_use_symbols.insert(symbol.clone());
+            }
             dst_ns.use_synonyms.insert(symbol, src.to_vec());
         }
         ok((), warnings, errors)
@@ -138,13 +149,17 @@ impl Module {
         src: &Path,
         dst: &Path,
         alias: Option<Ident>,
+        visibility: Visibility,
     ) -> CompileResult<()> {
         let (last_item, src) = src.split_last().expect("guaranteed by grammar");
-        self.item_import(src, last_item, dst, alias)
+        self.item_import(src, last_item, dst, alias, visibility)
     }
 
     /// Pull a single `item` from the given `src` module and import it into the `dst` module.
     ///
+    /// The `item` may either be declared within `src` or be re-exported by `src` via `pub use`.
+    /// If `visibility` is public, the item is in turn re-exported from `dst`.
+    ///
     /// Paths are assumed to be relative to `self`.
     pub(crate) fn item_import(
         &mut self,
@@ -152,6 +167,7 @@ impl Module {
         item: &Ident,
         dst: &Path,
         alias: Option<Ident>,
+        visibility: Visibility,
     ) -> CompileResult<()> {
         let mut warnings = vec![];
         let mut errors = vec![];
@@ -161,68 +177,110 @@ impl Module {
             warnings,
             errors
         );
-        let mut impls_to_insert = vec![];
-        match src_ns.symbols.get(item).cloned() {
+        let decl = match src_ns.symbols.get(item) {
             Some(decl) => {
                 if decl.visibility() != Visibility::Public {
                     errors.push(CompileError::ImportPrivateSymbol { name: item.clone() });
                 }
-                // if this is a const, insert it into the local namespace directly
-                if let TypedDeclaration::VariableDeclaration(TypedVariableDeclaration {
-                    is_mutable: VariableMutability::ExportedConst,
-                    ref name,
-                    ..
-                }) = decl
-                {
-                    self[dst].insert_symbol(alias.unwrap_or_else(|| name.clone()), decl.clone());
-                    return ok((), warnings, errors);
+                decl.clone()
+            }
+            None if src_ns.use_synonyms.contains_key(item) => {
+                if !src_ns.pub_use_symbols.contains(item) {
+                    errors.push(CompileError::ImportPrivateSymbol { name: item.clone() });
+                    return err(warnings, errors);
                 }
-                let a = decl.return_type().value;
-                //  if this is an enum or struct, import its implementations
-                let mut res = match a {
-                    Some(a) => src_ns.implemented_traits.get_call_path_and_type_info(a),
-                    None => vec![],
-                };
-                impls_to_insert.append(&mut res);
-                // no matter what, import it this way though.
-                let dst_ns = &mut self[dst];
-                match alias {
-                    Some(alias) => {
-                        if dst_ns.use_synonyms.contains_key(&alias) {
-                            errors.push(CompileError::ShadowsOtherSymbol {
-                                name: alias.clone(),
-                            });
-                        }
-                        dst_ns.use_synonyms.insert(alias.clone(), src.to_vec());
-                        dst_ns
-                            .use_aliases
-                            .insert(alias.as_str().to_string(), item.clone());
-                    }
-                    None => {
-                        if dst_ns.use_synonyms.contains_key(item) {
-                            errors.push(CompileError::ShadowsOtherSymbol { name: item.clone() });
-                        }
-                        dst_ns.use_synonyms.insert(item.clone(), src.to_vec());
-                    }
-                };
+                check!(
+                    self.resolve_reexport(src, item),
+                    return err(warnings, errors),
+                    warnings,
+                    errors
+                )
             }
             None => {
                 errors.push(CompileError::SymbolNotFound { name: item.clone() });
                 return err(warnings, errors);
             }
         };
-
+        //  if this is an enum or struct, import its implementations
+        let impls_to_insert = match decl.return_type().value {
+            Some(a) => src_ns.implemented_traits.get_call_path_and_type_info(a),
+            None => vec![],
+        };
+        let dst_name = alias.clone().unwrap_or_else(|| item.clone());
         let dst_ns = &mut self[dst];
+
+        // if this is a const, insert it into the local namespace directly
+        if let TypedDeclaration::VariableDeclaration(TypedVariableDeclaration {
+            is_mutable: VariableMutability::ExportedConst,
+            ..
+        }) = decl
+        {
+            dst_ns.insert_symbol(dst_name, decl);
+            return ok((), warnings, errors);
+        }
+        // no matter what, import it this way though.
+        if visibility == Visibility::Public {
+            dst_ns.pub_use_symbols.insert(dst_name.clone());
+        }
+        if dst_ns.use_synonyms.contains_key(&dst_name) {
+            errors.push(CompileError::ShadowsOtherSymbol {
+                name: dst_name.clone(),
+            });
+        }
+        dst_ns.use_synonyms.insert(dst_name, src.to_vec());
+        if let Some(alias) = alias {
+            dst_ns
+                .use_aliases
+                .insert(alias.as_str().to_string(), item.clone());
+        }
+
         impls_to_insert
             .into_iter()
-            .for_each(|((call_path, type_info), methods)| {
+            .for_each(|((call_path, type_info), methods, constants)| {
                 dst_ns
                     .implemented_traits
-                    .insert(call_path, type_info, methods);
+                    .insert(call_path, type_info, methods, constants);
             });
 
         ok((), warnings, errors)
     }
+
+    /// Follow the chain of `use` synonyms and aliases for an `item` re-exported by the `src`
+    /// module until we find its original declaration.
+    ///
+    /// Paths are assumed to be relative to `self`.
+    fn resolve_reexport(&self, src: &Path, item: &Ident) -> CompileResult<TypedDeclaration> {
+        let mut path = src.to_vec();
+        let mut name = item.clone();
+        let mut visited = vec![];
+        while let Some(module) = self.submodule(&path) {
+            if let Some(decl) = module.symbols.get(&name) {
+                return ok(decl.clone(), vec![], vec![]);
+            }
+            let next_path = match module.use_synonyms.get(&name) {
+                Some(next_path) => next_path.clone(),
+                None => break,
+            };
+            if visited.contains(&(path.clone(), name.clone())) {
+                return err(
+                    vec![],
+                    vec![CompileError::ReexportCycle { name: item.clone() }],
+                );
+            }
+            let next_name = module
+                .use_aliases
+                .get(name.as_str())
+                .cloned()
+                .unwrap_or_else(|| name.clone());
+            visited.push((path, name));
+            path = next_path;
+            name = next_name;
+        }
+        err(
+            vec![],
+            vec![CompileError::SymbolNotFound { name: item.clone() }],
+        )
+    }
 }
 
 impl std::ops::Deref for Module {
@@ -1,6 +1,7 @@
 use crate::{
+    parse_tree::Visibility,
     semantic_analysis::{
-        ast_node::TypedExpression,
+        ast_node::{TypedConstantDeclaration, TypedExpression},
         declaration::{EnforceTypeArguments, Monomorphize, MonomorphizeHelper},
     },
     type_engine::*,
@@ -157,14 +158,31 @@ impl Namespace {
             .find_method_for_type(&self.mod_path, r#type, method_path, self_type, args_buf)
     }
 
+    /// Short-hand for calling [Root::find_constant_for_type] on `root` with the `mod_path`.
+    pub(crate) fn find_constant_for_type(
+        &mut self,
+        r#type: TypeId,
+        constant_path: &Path,
+        self_type: TypeId,
+    ) -> CompileResult<TypedConstantDeclaration> {
+        self.root
+            .find_constant_for_type(&self.mod_path, r#type, constant_path, self_type)
+    }
+
     /// Short-hand for performing a [Module::star_import] with `mod_path` as the destination.
-    pub(crate) fn star_import(&mut self, src: &Path) -> CompileResult<()> {
-        self.root.star_import(src, &self.mod_path)
+    pub(crate) fn star_import(&mut self, src: &Path, visibility: Visibility) -> CompileResult<()> {
+        self.root.star_import(src, &self.mod_path, visibility)
     }
 
     /// Short-hand for performing a [Module::self_import] with `mod_path` as the destination.
-    pub(crate) fn self_import(&mut self, src: &Path, alias: Option<Ident>) -> CompileResult<()> {
-        self.root.self_import(src, &self.mod_path, alias)
+    pub(crate) fn self_import(
+        &mut self,
+        src: &Path,
+        alias: Option<Ident>,
+        visibility: Visibility,
+    ) -> CompileResult<()> {
+        self.root
+            .self_import(src, &self.mod_path, alias, visibility)
     }
 
     /// Short-hand for performing a [Module::item_import] with `mod_path` as the destination.
@@ -173,8 +191,10 @@ impl Namespace {
         src: &Path,
         item: &Ident,
         alias: Option<Ident>,
+        visibility: Visibility,
     ) -> CompileResult<()> {
-        self.root.item_import(src, item, &self.mod_path, alias)
+        self.root
+            .item_import(src, item, &self.mod_path, alias, visibility)
     }
 
     /// "Enter" the submodule at the given path by returning a new [SubmoduleNamespace].
@@ -113,6 +113,21 @@ impl Root {
                         );
                         new_decl.create_type_id()
                     }
+                    Some(TypedDeclaration::TypeAliasDeclaration(decl)) => {
+                        let new_decl = check!(
+                            decl.monomorphize(
+                                type_arguments,
+                                enforce_type_arguments,
+                                Some(span),
+                                self,
+                                mod_path // NOTE: Once `TypeInfo::Custom` takes a `CallPath`, this will need to change
+                            ),
+                            return err(warnings, errors),
+                            warnings,
+                            errors
+                        );
+                        new_decl.create_type_id()
+                    }
                     Some(TypedDeclaration::GenericTypeForFunctionScope { name, type_id }) => {
                         insert_type(TypeInfo::Ref(type_id, name.span()))
                     }
@@ -218,11 +233,18 @@ impl Root {
         let mut methods = local_methods;
         methods.append(&mut type_methods);
 
-        match methods
+        // A type may implement a generic trait more than once, e.g. both `From<u64>` and
+        // `From<b256>`, in which case the method whose parameters fit the arguments is chosen.
+        let matching_methods = methods
             .into_iter()
-            .find(|TypedFunctionDeclaration { name, .. }| name == method_name)
+            .filter(|TypedFunctionDeclaration { name, .. }| name == method_name)
+            .collect::<Vec<_>>();
+        match matching_methods
+            .iter()
+            .find(|method| arguments_fit_parameters(args_buf, &method.parameters))
+            .or_else(|| matching_methods.first())
         {
-            Some(o) => ok(o, warnings, errors),
+            Some(o) => ok(o.clone(), warnings, errors),
             None => {
                 if args_buf.get(0).map(|x| look_up_type_id(x.return_type))
                     != Some(TypeInfo::ErrorRecovery)
@@ -236,6 +258,91 @@ impl Root {
             }
         }
     }
+
+    /// Given a type and the path to one of its associated constants (plus a `self_type` to
+    /// potentially resolve it), find that constant in the namespace.
+    ///
+    /// This function will generate a missing constant error if the constant is not found.
+    ///
+    /// This method should only be called on the root namespace. `mod_path` is the current module,
+    /// `constant_path` is assumed to be absolute.
+    pub(crate) fn find_constant_for_type(
+        &mut self,
+        mod_path: &Path,
+        mut type_id: TypeId,
+        constant_path: &Path,
+        self_type: TypeId,
+    ) -> CompileResult<TypedConstantDeclaration> {
+        let mut warnings = vec![];
+        let mut errors = vec![];
+
+        // grab the local constants from the local module
+        let local_module = check!(
+            self.check_submodule(mod_path),
+            return err(warnings, errors),
+            warnings,
+            errors
+        );
+        let mut constants = local_module.get_constants_for_type(type_id);
+
+        // split into the constant name and constant prefix
+        let (constant_name, constant_prefix) =
+            constant_path.split_last().expect("constant path is empty");
+
+        type_id.replace_self_type(self_type);
+
+        // resolve the type
+        let type_id = check!(
+            self.resolve_type(
+                type_id,
+                &constant_name.span(),
+                EnforceTypeArguments::No,
+                constant_prefix
+            ),
+            return err(warnings, errors),
+            warnings,
+            errors
+        );
+
+        // grab the constants from where the type is declared
+        let type_module = check!(
+            self.check_submodule(constant_prefix),
+            return err(warnings, errors),
+            warnings,
+            errors
+        );
+        constants.append(&mut type_module.get_constants_for_type(type_id));
+
+        match constants
+            .into_iter()
+            .find(|TypedConstantDeclaration { name, .. }| name == constant_name)
+        {
+            Some(o) => ok(o, warnings, errors),
+            None => {
+                errors.push(CompileError::ConstantNotFound {
+                    constant_name: constant_name.clone(),
+                    type_name: type_id.to_string(),
+                });
+                err(warnings, errors)
+            }
+        }
+    }
+}
+
+/// Whether each of the arguments is of the type of its parameter, without needing any inference.
+fn arguments_fit_parameters(
+    args_buf: &VecDeque<TypedExpression>,
+    parameters: &[TypedFunctionParameter],
+) -> bool {
+    args_buf.len() == parameters.len()
+        && args_buf.iter().zip(parameters).all(|(arg, parameter)| {
+            let arg_type = look_up_type_id(arg.return_type);
+            let parameter_type = look_up_type_id(parameter.type_id);
+            match (&arg_type, &parameter_type) {
+                (TypeInfo::Numeric, TypeInfo::UnsignedInteger(_)) => true,
+                _ => arg_type.is_subset_of(&parameter_type),
+            }
+        })
 }
 
 impl std::ops::Deref for Root {
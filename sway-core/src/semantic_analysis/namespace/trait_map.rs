@@ -1,4 +1,5 @@
 use crate::{
+    semantic_analysis::TypedConstantDeclaration,
     type_engine::{create_type_mapping, look_up_type_id, CopyTypes, TypeId},
     CallPath, TypeInfo, TypedFunctionDeclaration,
 };
@@ -27,8 +28,16 @@ type TraitName = CallPath;
 // However, we need this structure to be able to maintain the
 // difference between 3 and 4, as in practice, 1 and 2 might not yet
 // be resolved.
-type TraitMapInner = im::Vector<((TraitName, TypeId), TraitMethods)>;
+type TraitMapInner = im::Vector<((TraitName, TypeId), TraitItems)>;
 type TraitMethods = im::HashMap<String, TypedFunctionDeclaration>;
+type TraitConstants = im::HashMap<String, TypedConstantDeclaration>;
+
+/// The methods and associated constants of a single trait implementation.
+#[derive(Clone, Debug, Default, PartialEq)]
+struct TraitItems {
+    methods: TraitMethods,
+    constants: TraitConstants,
+}
 
 #[derive(Clone, Debug, Default, PartialEq)]
 pub(crate) struct TraitMap {
@@ -41,35 +50,50 @@ impl TraitMap {
         trait_name: CallPath,
         incoming_type_id: TypeId,
         methods: Vec<TypedFunctionDeclaration>,
+        constants: Vec<TypedConstantDeclaration>,
     ) {
-        let mut methods_map = im::HashMap::new();
+        let mut items = TraitItems::default();
         for method in methods.into_iter() {
-            methods_map.insert(method.name.as_str().to_string(), method);
+            items
+                .methods
+                .insert(method.name.as_str().to_string(), method);
+        }
+        for constant in constants.into_iter() {
+            items
+                .constants
+                .insert(constant.name.as_str().to_string(), constant);
         }
         self.trait_map
-            .push_back(((trait_name, incoming_type_id), methods_map));
+            .push_back(((trait_name, incoming_type_id), items));
     }
 
     pub(crate) fn extend(&mut self, other: TraitMap) {
-        for ((trait_name, type_implementing_for), methods) in other.trait_map.into_iter() {
+        for ((trait_name, type_implementing_for), items) in other.trait_map.into_iter() {
             self.insert(
                 trait_name,
                 type_implementing_for,
-                methods.values().cloned().collect(),
+                items.methods.values().cloned().collect(),
+                items.constants.values().cloned().collect(),
             );
         }
     }
 
+    #[allow(clippy::type_complexity)]
     pub(crate) fn get_call_path_and_type_info(
         &self,
         incoming_type_id: TypeId,
-    ) -> Vec<((CallPath, TypeId), Vec<TypedFunctionDeclaration>)> {
+    ) -> Vec<(
+        (CallPath, TypeId),
+        Vec<TypedFunctionDeclaration>,
+        Vec<TypedConstantDeclaration>,
+    )> {
         let mut ret = vec![];
-        for ((call_path, map_type_id), methods) in self.trait_map.iter() {
+        for ((call_path, map_type_id), items) in self.trait_map.iter() {
             if look_up_type_id(incoming_type_id).is_subset_of(&look_up_type_id(*map_type_id)) {
                 ret.push((
                     (call_path.clone(), *map_type_id),
-                    methods.values().cloned().collect(),
+                    items.methods.values().cloned().collect(),
+                    items.constants.values().cloned().collect(),
                 ));
             }
         }
@@ -85,10 +109,10 @@ impl TraitMap {
         if look_up_type_id(incoming_type_id) == TypeInfo::ErrorRecovery {
             return methods;
         }
-        for ((_, map_type_id), trait_methods) in self.trait_map.iter() {
+        for ((_, map_type_id), items) in self.trait_map.iter() {
             if look_up_type_id(incoming_type_id).is_subset_of(&look_up_type_id(*map_type_id)) {
                 let type_mapping = create_type_mapping(*map_type_id, incoming_type_id);
-                let mut trait_methods = trait_methods.values().cloned().collect::<Vec<_>>();
+                let mut trait_methods = items.methods.values().cloned().collect::<Vec<_>>();
                 trait_methods
                     .iter_mut()
                     .for_each(|x| x.copy_types(&type_mapping));
@@ -97,4 +121,26 @@ impl TraitMap {
         }
         methods
     }
+
+    pub(crate) fn get_constants_for_type(
+        &self,
+        incoming_type_id: TypeId,
+    ) -> Vec<TypedConstantDeclaration> {
+        let mut constants = vec![];
+        // small performance gain in bad case
+        if look_up_type_id(incoming_type_id) == TypeInfo::ErrorRecovery {
+            return constants;
+        }
+        for ((_, map_type_id), items) in self.trait_map.iter() {
+            if look_up_type_id(incoming_type_id).is_subset_of(&look_up_type_id(*map_type_id)) {
+                let type_mapping = create_type_mapping(*map_type_id, incoming_type_id);
+                let mut trait_constants = items.constants.values().cloned().collect::<Vec<_>>();
+                trait_constants
+                    .iter_mut()
+                    .for_each(|x| x.copy_types(&type_mapping));
+                constants.append(&mut trait_constants);
+            }
+        }
+        constants
+    }
 }
@@ -314,6 +314,13 @@ impl Dependencies {
                     deps.gather_from_typeinfo(&variant.type_info)
                 })
                 .gather_from_type_parameters(type_parameters),
+            Declaration::TypeAliasDeclaration(TypeAliasDeclaration {
+                type_info,
+                type_parameters,
+                ..
+            }) => self
+                .gather_from_typeinfo(type_info)
+                .gather_from_type_parameters(type_parameters),
             Declaration::Reassignment(decl) => self.gather_from_expr(&decl.rhs),
             Declaration::TraitDeclaration(TraitDeclaration {
                 interface_surface,
@@ -358,8 +365,12 @@ impl Dependencies {
             Declaration::AbiDeclaration(AbiDeclaration {
                 interface_surface,
                 methods,
+                supertraits,
                 ..
             }) => self
+                .gather_from_iter(supertraits.iter(), |deps, sup| {
+                    deps.gather_from_call_path(&sup.name, false, false)
+                })
                 .gather_from_iter(interface_surface.iter(), |deps, sig| {
                     deps.gather_from_iter(sig.parameters.iter(), |deps, param| {
                         deps.gather_from_typeinfo(&look_up_type_id(param.type_id))
@@ -433,6 +444,7 @@ impl Dependencies {
             Expression::ArrayIndex { prefix, index, .. } => {
                 self.gather_from_expr(prefix).gather_from_expr(index)
             }
+            Expression::ArrayLength { array, .. } => self.gather_from_expr(array),
             Expression::StructExpression {
                 struct_name,
                 fields,
@@ -474,7 +486,9 @@ impl Dependencies {
                 self.gather_from_iter(fields.iter(), |deps, field| deps.gather_from_expr(field))
             }
             Expression::TupleIndex { prefix, .. } => self.gather_from_expr(prefix),
-            Expression::StorageAccess { .. } => self,
+            Expression::StorageAccess { .. }
+            | Expression::Break { .. }
+            | Expression::Continue { .. } => self,
             Expression::IntrinsicFunction { kind, .. } => match kind {
                 IntrinsicFunctionKind::SizeOfVal { exp } => self.gather_from_expr(exp),
                 _ => self,
@@ -484,12 +498,16 @@ impl Dependencies {
 
     fn gather_from_match_branch(self, branch: &MatchBranch) -> Self {
         let MatchBranch {
-            scrutinee, result, ..
+            scrutinee,
+            guard,
+            result,
+            ..
         } = branch;
         self.gather_from_iter(
             scrutinee.gather_approximate_typeinfo_dependencies().iter(),
             |deps, type_info| deps.gather_from_typeinfo(type_info),
         )
+        .gather_from_opt_expr(guard.as_ref())
         .gather_from_expr(result)
     }
 
@@ -666,6 +684,7 @@ fn decl_name(decl: &Declaration) -> Option<DependentSymbol> {
         Declaration::EnumDeclaration(decl) => dep_sym(decl.name.clone()),
         Declaration::TraitDeclaration(decl) => dep_sym(decl.name.clone()),
         Declaration::AbiDeclaration(decl) => dep_sym(decl.name.clone()),
+        Declaration::TypeAliasDeclaration(decl) => dep_sym(decl.name.clone()),
 
         // These have the added complexity of converting CallPath and/or TypeInfo into a name.
         Declaration::ImplSelf(decl) => {
@@ -715,6 +734,7 @@ fn type_info_name(type_info: &TypeInfo) -> String {
             IntegerBits::Sixteen => "uint16",
             IntegerBits::ThirtyTwo => "uint32",
             IntegerBits::SixtyFour => "uint64",
+            IntegerBits::TwoFiftySix => "uint256",
         },
         TypeInfo::Boolean => "bool",
         TypeInfo::Custom { name, .. } => name.as_str(),
@@ -723,6 +743,8 @@ fn type_info_name(type_info: &TypeInfo) -> String {
         TypeInfo::SelfType => "self",
         TypeInfo::Byte => "byte",
         TypeInfo::B256 => "b256",
+        TypeInfo::RawUntypedPtr => "raw_ptr",
+        TypeInfo::RawUntypedSlice => "raw_slice",
         TypeInfo::Numeric => "numeric",
         TypeInfo::Contract => "contract",
         TypeInfo::ErrorRecovery => "err_recov",
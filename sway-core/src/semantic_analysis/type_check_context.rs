@@ -46,6 +46,8 @@ pub struct TypeCheckContext<'ns> {
     /// Tracks the purity of the context, e.g. whether or not we should be allowed to write to
     /// storage.
     purity: Purity,
+    /// Whether or not we're within the body of a loop, where `break` and `continue` may be used.
+    within_loop: bool,
 }
 
 impl<'ns> TypeCheckContext<'ns> {
@@ -70,6 +72,7 @@ impl<'ns> TypeCheckContext<'ns> {
             self_type: insert_type(TypeInfo::Contract),
             mode: Mode::NonAbi,
             purity: Purity::default(),
+            within_loop: false,
         }
     }
 
@@ -89,6 +92,7 @@ impl<'ns> TypeCheckContext<'ns> {
             mode: self.mode,
             help_text: self.help_text,
             purity: self.purity,
+            within_loop: self.within_loop,
         }
     }
 
@@ -101,6 +105,7 @@ impl<'ns> TypeCheckContext<'ns> {
             mode: self.mode,
             help_text: self.help_text,
             purity: self.purity,
+            within_loop: self.within_loop,
         }
     }
 
@@ -149,6 +154,14 @@ impl<'ns> TypeCheckContext<'ns> {
         Self { self_type, ..self }
     }
 
+    /// Map this `TypeCheckContext` instance to a new one that is or isn't within a loop body.
+    pub(crate) fn with_within_loop(self, within_loop: bool) -> Self {
+        Self {
+            within_loop,
+            ..self
+        }
+    }
+
     // A set of accessor methods. We do this rather than making the fields `pub` in order to ensure
     // that these are only updated via the `with_*` methods that produce a new `TypeCheckContext`.
 
@@ -172,6 +185,10 @@ impl<'ns> TypeCheckContext<'ns> {
         self.self_type
     }
 
+    pub(crate) fn within_loop(&self) -> bool {
+        self.within_loop
+    }
+
     // Provide some convenience functions around the inner context.
 
     /// Short-hand for calling the `monomorphize` method on `Namespace` with the context's known
@@ -20,17 +20,41 @@ pub struct SourceMap {
     paths: Vec<PathBuf>,
     /// Mapping from opcode index to source location
     map: HashMap<usize, SourceMapSpan>,
+    /// The directory of the project being compiled, if known.
+    /// Relative paths are resolved against it, and it may be replaced with `set_project_dir` to
+    /// resolve them within a copy of the project.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    project_dir: Option<PathBuf>,
+    /// Paths of dependencies within the project directory, e.g. vendored dependencies.
+    /// Source files of these are recorded relative to the project directory.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    project_dependency_paths: Vec<PathBuf>,
 }
 impl SourceMap {
     pub fn new() -> Self {
         Self::default()
     }
 
+    /// Sets the directory of the project being compiled.
+    ///
+    /// Must be called prior to inserting dependencies for dependencies within the project
+    /// directory to be recorded relative to it.
+    pub fn set_project_dir<P: AsRef<Path>>(&mut self, path: P) {
+        self.project_dir = Some(path.as_ref().to_owned());
+    }
+
     /// Inserts dependency path. Unsupported locations are ignored for now.
     pub fn insert_dependency<P: AsRef<Path>>(&mut self, path: P) {
+        let path = path.as_ref();
+        if let Some(project_dir) = &self.project_dir {
+            if path != project_dir && path.starts_with(project_dir) {
+                self.project_dependency_paths.push(path.to_owned());
+                return;
+            }
+        }
         if let Some(home) = home_dir() {
             let forc = home.join(".forc/");
-            if let Ok(unprefixed) = path.as_ref().strip_prefix(forc) {
+            if let Ok(unprefixed) = path.strip_prefix(forc) {
                 self.dependency_paths.push(unprefixed.to_owned());
             }
         }
@@ -39,12 +63,13 @@ impl SourceMap {
 
     pub fn insert(&mut self, pc: usize, span: &Span) {
         if let Some(path) = span.path() {
+            let path = self.recorded_path(path);
             let path_index = self
                 .paths
                 .iter()
-                .position(|p| *p == **path)
+                .position(|p| *p == path)
                 .unwrap_or_else(|| {
-                    self.paths.push((**path).to_owned());
+                    self.paths.push(path);
                     self.paths.len() - 1
                 });
             self.map.insert(
@@ -76,9 +101,30 @@ impl SourceMap {
                 }
             }
 
-            (p.to_owned(), sms.range)
+            match &self.project_dir {
+                Some(project_dir) if p.is_relative() => (project_dir.join(p), sms.range),
+                _ => (p.to_owned(), sms.range),
+            }
         })
     }
+
+    /// The path under which a source file is recorded.
+    ///
+    /// Files belonging to dependencies within the project directory are recorded relative to the
+    /// project directory, so that the source map remains valid for any copy of the project.
+    fn recorded_path(&self, path: &Path) -> PathBuf {
+        match &self.project_dir {
+            Some(project_dir)
+                if self
+                    .project_dependency_paths
+                    .iter()
+                    .any(|dep| path.starts_with(dep)) =>
+            {
+                path.strip_prefix(project_dir).unwrap_or(path).to_owned()
+            }
+            _ => path.to_owned(),
+        }
+    }
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
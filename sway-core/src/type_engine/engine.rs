@@ -51,6 +51,8 @@ impl Engine {
             (B256, B256) => (vec![], vec![]),
             (Numeric, Numeric) => (vec![], vec![]),
             (Contract, Contract) => (vec![], vec![]),
+            (RawUntypedPtr, RawUntypedPtr) => (vec![], vec![]),
+            (RawUntypedSlice, RawUntypedSlice) => (vec![], vec![]),
             (Str(l), Str(r)) => {
                 let warnings = vec![];
                 let mut errors = vec![];
@@ -110,6 +112,20 @@ impl Engine {
                 (warnings, errors)
             }
 
+            // `u256` values live in memory rather than in a register, so unlike the other integer
+            // widths they can't be implicitly cast to or from one another.
+            (UnsignedInteger(received_width), UnsignedInteger(expected_width))
+                if (received_width == IntegerBits::TwoFiftySix)
+                    != (expected_width == IntegerBits::TwoFiftySix) =>
+            {
+                let errors = vec![TypeError::MismatchedType {
+                    expected,
+                    received,
+                    help_text,
+                    span: span.clone(),
+                }];
+                (vec![], errors)
+            }
             (UnsignedInteger(received_width), UnsignedInteger(expected_width)) => {
                 // E.g., in a variable declaration `let a: u32 = 10u64` the 'expected' type will be
                 // the annotation `u32`, and the 'received' type is 'self' of the initialiser, or
@@ -6,6 +6,7 @@ pub enum IntegerBits {
     Sixteen,
     ThirtyTwo,
     SixtyFour,
+    TwoFiftySix,
 }
 
 impl fmt::Display for IntegerBits {
@@ -16,6 +17,7 @@ impl fmt::Display for IntegerBits {
             Sixteen => "sixteen",
             ThirtyTwo => "thirty two",
             SixtyFour => "sixty four",
+            TwoFiftySix => "two hundred fifty six",
         };
         write!(f, "{}", s)
     }
@@ -53,13 +53,11 @@ impl Default for ResolvedType {
 
 impl ResolvedType {
     pub(crate) fn is_copy_type(&self) -> bool {
-        matches!(
-            self,
-            ResolvedType::Boolean
-                | ResolvedType::Byte
-                | ResolvedType::Unit
-                | ResolvedType::UnsignedInteger(_)
-        )
+        match self {
+            ResolvedType::UnsignedInteger(bits) => *bits != IntegerBits::TwoFiftySix,
+            ResolvedType::Boolean | ResolvedType::Byte | ResolvedType::Unit => true,
+            _ => false,
+        }
     }
 
     #[allow(dead_code)]
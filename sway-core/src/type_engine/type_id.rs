@@ -144,6 +144,8 @@ impl ReplaceSelfType for TypeId {
             | TypeInfo::ContractCaller { .. }
             | TypeInfo::Byte
             | TypeInfo::B256
+            | TypeInfo::RawUntypedPtr
+            | TypeInfo::RawUntypedSlice
             | TypeInfo::Numeric
             | TypeInfo::Contract
             | TypeInfo::ErrorRecovery => {}
@@ -86,6 +86,10 @@ pub enum TypeInfo {
     Storage {
         fields: Vec<TypedStructField>,
     },
+    /// A pointer into memory with no type attached to what it points at.
+    RawUntypedPtr,
+    /// A pointer into memory along with a length in bytes.
+    RawUntypedSlice,
 }
 
 // NOTE: Hash and PartialEq must uphold the invariant:
@@ -184,6 +188,12 @@ impl Hash for TypeInfo {
                 state.write_u8(19);
                 fields.hash(state);
             }
+            TypeInfo::RawUntypedPtr => {
+                state.write_u8(20);
+            }
+            TypeInfo::RawUntypedSlice => {
+                state.write_u8(21);
+            }
         }
     }
 }
@@ -199,6 +209,8 @@ impl PartialEq for TypeInfo {
             (Self::SelfType, Self::SelfType) => true,
             (Self::Byte, Self::Byte) => true,
             (Self::B256, Self::B256) => true,
+            (Self::RawUntypedPtr, Self::RawUntypedPtr) => true,
+            (Self::RawUntypedSlice, Self::RawUntypedSlice) => true,
             (Self::Numeric, Self::Numeric) => true,
             (Self::Contract, Self::Contract) => true,
             (Self::ErrorRecovery, Self::ErrorRecovery) => true,
@@ -290,6 +302,7 @@ impl fmt::Display for TypeInfo {
                 IntegerBits::Sixteen => "u16",
                 IntegerBits::ThirtyTwo => "u32",
                 IntegerBits::SixtyFour => "u64",
+                IntegerBits::TwoFiftySix => "u256",
             }
             .into(),
             Boolean => "bool".into(),
@@ -305,6 +318,8 @@ impl fmt::Display for TypeInfo {
             SelfType => "Self".into(),
             Byte => "byte".into(),
             B256 => "b256".into(),
+            RawUntypedPtr => "raw_ptr".into(),
+            RawUntypedSlice => "raw_slice".into(),
             Numeric => "numeric".into(),
             Contract => "contract".into(),
             ErrorRecovery => "unknown due to error".into(),
@@ -346,6 +361,7 @@ impl JsonAbiString for TypeInfo {
                 IntegerBits::Sixteen => "u16",
                 IntegerBits::ThirtyTwo => "u32",
                 IntegerBits::SixtyFour => "u64",
+                IntegerBits::TwoFiftySix => "u256",
             }
             .into(),
             Boolean => "bool".into(),
@@ -361,6 +377,8 @@ impl JsonAbiString for TypeInfo {
             SelfType => "Self".into(),
             Byte => "byte".into(),
             B256 => "b256".into(),
+            RawUntypedPtr => "raw untyped ptr".into(),
+            RawUntypedSlice => "raw untyped slice".into(),
             Numeric => "numeric".into(),
             Contract => "contract".into(),
             ErrorRecovery => "unknown due to error".into(),
@@ -392,6 +410,7 @@ impl TypeInfo {
                     Sixteen => "u16",
                     ThirtyTwo => "u32",
                     SixtyFour => "u64",
+                    TwoFiftySix => "u256",
                 }
                 .into()
             }
@@ -421,6 +440,8 @@ impl TypeInfo {
             }
             Byte => "byte".into(),
             B256 => "b256".into(),
+            RawUntypedPtr => "rawptr".into(),
+            RawUntypedSlice => "rawslice".into(),
             Struct { fields, .. } => {
                 let names = fields
                     .iter()
@@ -683,6 +704,8 @@ impl TypeInfo {
             | SelfType
             | Byte
             | B256
+            | RawUntypedPtr
+            | RawUntypedSlice
             | Numeric
             | Contract
             | Storage { .. }
@@ -719,7 +742,9 @@ impl TypeInfo {
             | TypeInfo::Contract
             | TypeInfo::ErrorRecovery
             | TypeInfo::Array(_, _)
-            | TypeInfo::Storage { .. } => {
+            | TypeInfo::Storage { .. }
+            | TypeInfo::RawUntypedPtr
+            | TypeInfo::RawUntypedSlice => {
                 errors.push(CompileError::Unimplemented(
                     "matching on this type is unsupported right now",
                     span.clone(),
@@ -832,6 +857,8 @@ impl TypeInfo {
             | TypeInfo::ContractCaller { .. }
             | TypeInfo::Byte
             | TypeInfo::B256
+            | TypeInfo::RawUntypedPtr
+            | TypeInfo::RawUntypedSlice
             | TypeInfo::Numeric
             | TypeInfo::Contract
             | TypeInfo::ErrorRecovery => {}
@@ -1037,6 +1064,7 @@ impl TypeInfo {
     /// 1) in the case where `self` is not a `TypeInfo::Struct`
     /// 2) in the case where `subfields` is empty
     /// 3) in the case where a `subfield` does not exist on `self`
+    /// 4) in the case where a `subfield` is private and used outside of its module
     pub(crate) fn apply_subfields(
         &self,
         subfields: &[Ident],
@@ -1064,6 +1092,13 @@ impl TypeInfo {
                         return err(warnings, errors);
                     }
                 };
+                if !field.is_accessible_from(&first.span()) {
+                    errors.push(CompileError::StructFieldIsPrivate {
+                        field_name: first.clone(),
+                        struct_name: name.to_string(),
+                        span: first.span(),
+                    });
+                }
                 let field = if rest.is_empty() {
                     field
                 } else {
@@ -0,0 +1,14 @@
+script;
+
+fn main() -> bool {
+    let a = 0x0202020202020202020202020202020202020202020202020202020202020202u256;
+    cmp(a, 2u256)
+}
+
+fn cmp(a: u256, b: u256) -> bool {
+    asm(lhs: a, rhs: b, sz, res) {
+        addi sz zero i32;
+        meq res lhs rhs sz;
+        res: bool
+    }
+}
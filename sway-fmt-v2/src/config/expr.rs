@@ -42,7 +42,7 @@ impl Default for Expressions {
             expr_brace_style: ExprBraceStyle::AlwaysSameLine,
             trailing_semicolon: true,
             space_before_colon: false,
-            space_after_colon: false,
+            space_after_colon: true,
             type_combinator_layout: TypeCombinatorLayout::Wide,
             spaces_around_ranges: false,
             match_block_trailing_comma: false,
@@ -7,6 +7,8 @@ pub struct Ordering {
     /// Reorder import and extern crate statements alphabetically.
     pub reorder_imports: bool,
     /// Reorder module statements alphabetically in group.
+    ///
+    /// Off by default, as dependencies are type-checked in the order in which they're declared.
     pub reorder_modules: bool,
     /// Reorder `impl` items.
     pub reorder_impl_items: bool,
@@ -16,7 +18,7 @@ impl Default for Ordering {
     fn default() -> Self {
         Self {
             reorder_imports: true,
-            reorder_modules: true,
+            reorder_modules: false,
             reorder_impl_items: false,
         }
     }
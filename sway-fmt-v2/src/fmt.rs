@@ -1,8 +1,21 @@
-use crate::utils::{
-    indent_style::Shape, newline_style::apply_newline_style, program_type::insert_program_type,
+use crate::{
+    config::heuristics::WidthHeuristics,
+    items::item_use::format_use_items,
+    utils::{
+        comments::{
+            write_comments_before, write_comments_before_close, write_trailing_comments,
+            CommentCheckpoint, CommentMap,
+        },
+        dependency::sorted_dependencies,
+        indent_style::Shape,
+        newline_style::apply_newline_style,
+        program_type::insert_program_type,
+    },
 };
-use std::{path::Path, sync::Arc};
+use std::{borrow::Cow, path::Path, sync::Arc};
 use sway_core::BuildConfig;
+use sway_parse::ItemKind;
+use sway_types::Spanned;
 
 pub use crate::{
     config::manifest::Config,
@@ -13,6 +26,11 @@ pub use crate::{
 pub struct Formatter {
     pub shape: Shape,
     pub config: Config,
+    /// The comments of the source being formatted.
+    pub(crate) comments: CommentMap,
+    /// Set while attempting to format code onto a single line, in which case constructs with
+    /// several possible layouts only use their single line layout.
+    pub(crate) single_line: bool,
 }
 
 pub type FormattedCode = String;
@@ -25,6 +43,15 @@ pub trait Format {
     ) -> Result<(), FormatterError>;
 }
 
+/// A point to which the [Formatter] and the code formatted so far can be restored, for trying
+/// out a layout.
+#[derive(Clone, Copy, Debug)]
+pub(crate) struct FormatterCheckpoint {
+    len: usize,
+    shape: Shape,
+    comments: CommentCheckpoint,
+}
+
 impl Formatter {
     pub fn from_dir(dir: &Path) -> Result<Self, ConfigError> {
         let config = match Config::from_dir(dir) {
@@ -32,8 +59,10 @@ impl Formatter {
             Err(ConfigError::NotFound) => Config::default(),
             Err(e) => return Err(e),
         };
-        let shape = Shape::default();
-        Ok(Self { config, shape })
+        Ok(Self {
+            config,
+            ..Default::default()
+        })
     }
     pub fn format(
         &mut self,
@@ -42,7 +71,8 @@ impl Formatter {
     ) -> Result<FormattedCode, FormatterError> {
         let path = build_config.map(|build_config| build_config.canonical_root_module());
         let src_len = src.len();
-        let module = sway_parse::parse_file(src, path)?;
+        let module = sway_parse::parse_file(src.clone(), path)?;
+        self.comments = CommentMap::from_src(src);
         // Get parsed items
         let items = module.items;
         // Get the program type (script, predicate, contract or library)
@@ -54,18 +84,66 @@ impl Formatter {
         // which will reduce the number of reallocations
         let mut raw_formatted_code = String::with_capacity(src_len);
 
-        // Insert program type to the formatted code.
+        // Insert the comments preceding the program type, then the program type itself.
+        write_comments_before(
+            &mut raw_formatted_code,
+            self,
+            None,
+            program_type.span().start(),
+        )?;
+        let program_type_end = module.semicolon_token.span().end();
         insert_program_type(&mut raw_formatted_code, program_type);
+        write_trailing_comments(&mut raw_formatted_code, self, program_type_end)?;
+        raw_formatted_code.push('\n');
+
+        // The program type is followed by a blank line, unless it is directly followed by a
+        // comment, in which case the blank lines of the source are kept.
+        let first_start = module
+            .dependencies
+            .first()
+            .map(|dependency| dependency.span().start())
+            .or_else(|| items.first().map(|item| item.span().start()))
+            .unwrap_or(src_len);
+        let mut prev_end = None;
+        if self.comments.has_comments_in(program_type_end..first_start) {
+            prev_end = Some(program_type_end);
+        } else if first_start < src_len {
+            raw_formatted_code.push('\n');
+        }
+
+        // Insert the dependencies, sorted if `reorder_modules` is set. As they are only sorted
+        // within groups, the blank lines between them are those found at the same index.
+        let sorted = sorted_dependencies(&module.dependencies, self);
+        for (dependency, in_place) in sorted.into_iter().zip(&module.dependencies) {
+            let span = in_place.span();
+            write_comments_before(&mut raw_formatted_code, self, prev_end, span.start())?;
+            dependency.format(&mut raw_formatted_code, self)?;
+            write_trailing_comments(&mut raw_formatted_code, self, span.end())?;
+            raw_formatted_code.push('\n');
+            prev_end = Some(span.end());
+        }
 
         // Insert parsed & formatted items into the formatted code.
-        let mut iter = items.iter().peekable();
-        while let Some(item) = iter.next() {
+        let mut remaining = &items[..];
+        while let Some(item) = remaining.first() {
+            // Consecutive imports are formatted together so that they may be sorted and merged.
+            if let ItemKind::Use(..) = item.value {
+                let num_items =
+                    format_use_items(remaining, &mut raw_formatted_code, self, &mut prev_end)?;
+                remaining = &remaining[num_items..];
+                continue;
+            }
+            let span = item.span();
+            write_comments_before(&mut raw_formatted_code, self, prev_end, span.start())?;
+            raw_formatted_code.push_str(&self.indent_str());
             // format Annotated<ItemKind>
             item.format(&mut raw_formatted_code, self)?;
-            if iter.peek().is_some() {
-                raw_formatted_code.push('\n');
-            }
+            write_trailing_comments(&mut raw_formatted_code, self, span.end())?;
+            raw_formatted_code.push('\n');
+            prev_end = Some(span.end());
+            remaining = &remaining[1..];
         }
+        write_comments_before_close(&mut raw_formatted_code, self, prev_end, src_len)?;
 
         let mut formatted_code = String::from(&raw_formatted_code);
         apply_newline_style(
@@ -76,16 +154,130 @@ impl Formatter {
         );
         Ok(formatted_code)
     }
+
+    /// Increase the block indentation by one level.
+    pub(crate) fn indent(&mut self) {
+        self.shape = self.shape.block_indent(self.config.whitespace.tab_spaces);
+    }
+
+    /// Decrease the block indentation by one level.
+    pub(crate) fn unindent(&mut self) {
+        self.shape.indent = self.shape.indent.block_unindent(self);
+    }
+
+    /// The indentation of the current line.
+    pub(crate) fn indent_str(&self) -> Cow<'static, str> {
+        self.shape.indent.to_string(self)
+    }
+
+    /// The widths used to decide whether a construct fits onto a single line.
+    pub(crate) fn width_heuristics(&self) -> WidthHeuristics {
+        if !self.config.heuristics.use_small_heuristics {
+            return WidthHeuristics::off();
+        }
+        self.config
+            .heuristics
+            .heuristics_pref
+            .to_width_heuristics(&self.config.whitespace)
+    }
+
+    pub(crate) fn checkpoint(&self, formatted_code: &FormattedCode) -> FormatterCheckpoint {
+        FormatterCheckpoint {
+            len: formatted_code.len(),
+            shape: self.shape,
+            comments: self.comments.checkpoint(),
+        }
+    }
+
+    pub(crate) fn restore(
+        &mut self,
+        formatted_code: &mut FormattedCode,
+        checkpoint: FormatterCheckpoint,
+    ) {
+        formatted_code.truncate(checkpoint.len);
+        self.shape = checkpoint.shape;
+        self.comments.restore(checkpoint.comments);
+    }
+
+    /// Format onto the current line using `f`, in single line mode.
+    ///
+    /// Returns whether the result fits within `width` characters as well as the `max_width` of
+    /// the line. If it does not, the attempt is undone.
+    pub(crate) fn try_single_line<F>(
+        &mut self,
+        formatted_code: &mut FormattedCode,
+        width: usize,
+        f: F,
+    ) -> Result<bool, FormatterError>
+    where
+        F: FnOnce(&mut FormattedCode, &mut Formatter) -> Result<(), FormatterError>,
+    {
+        let checkpoint = self.checkpoint(formatted_code);
+        let single_line = std::mem::replace(&mut self.single_line, true);
+        let res = f(formatted_code, self);
+        self.single_line = single_line;
+        res?;
+        let written = &formatted_code[checkpoint.len..];
+        let fits = !written.contains('\n')
+            && written.chars().count() <= width
+            && self.last_line_width(formatted_code) <= self.config.whitespace.max_width;
+        if !fits {
+            self.restore(formatted_code, checkpoint);
+        }
+        Ok(fits)
+    }
+
+    /// Format with `width` columns reserved at the end of the line, e.g. for a `,` or `;`
+    /// following what is formatted by `f`.
+    pub(crate) fn with_reserved_width<F>(
+        &mut self,
+        width: usize,
+        f: F,
+    ) -> Result<(), FormatterError>
+    where
+        F: FnOnce(&mut Formatter) -> Result<(), FormatterError>,
+    {
+        let max_width = self.config.whitespace.max_width;
+        self.config.whitespace.max_width = max_width.saturating_sub(width);
+        let res = f(self);
+        self.config.whitespace.max_width = max_width;
+        res
+    }
+
+    /// The width of the last line of `code`, counting tabs as `tab_spaces` columns.
+    pub(crate) fn last_line_width(&self, code: &str) -> usize {
+        let line = code.rsplit('\n').next().unwrap_or_default();
+        self.line_width(line)
+    }
+
+    /// Whether every line of code written since `start` fits within the `max_width`.
+    pub(crate) fn lines_fit(&self, code: &str, start: usize) -> bool {
+        let line_start = code[..start].rfind('\n').map_or(0, |ix| ix + 1);
+        code[line_start..]
+            .split('\n')
+            .all(|line| self.line_width(line) <= self.config.whitespace.max_width)
+    }
+
+    fn line_width(&self, line: &str) -> usize {
+        let tab_spaces = self.config.whitespace.tab_spaces;
+        line.chars()
+            .map(|c| if c == '\t' { tab_spaces } else { 1 })
+            .sum()
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::{Config, Formatter};
-    use crate::utils::indent_style::Shape;
+    use crate::{config::imports::ImportGranularity, utils::indent_style::Shape};
     use std::sync::Arc;
 
     fn get_formatter(config: Config, shape: Shape) -> Formatter {
-        Formatter { config, shape }
+        Formatter {
+            config,
+            shape,
+            ..Default::default()
+        }
     }
 
     #[test]
@@ -94,7 +286,8 @@ mod tests {
 pub const TEST:u16=10;"#;
         let correct_sway_code = r#"contract;
 
-pub const TEST: u16 = 10;"#;
+pub const TEST: u16 = 10;
+"#;
         let mut formatter = Formatter::default();
         let formatted_sway_code =
             Formatter::format(&mut formatter, Arc::from(sway_code_to_format), None).unwrap();
@@ -111,7 +304,8 @@ pub struct Foo {
 "#;
         let correct_sway_code = r#"contract;
 
-pub struct Foo { bar: u64, baz: bool }"#;
+pub struct Foo { bar: u64, baz: bool }
+"#;
         let mut config = Config::default();
         config.structures.struct_lit_single_line = true;
         config.structures.struct_field_align_threshold = 40;
@@ -134,7 +328,8 @@ pub struct Foo {
 pub struct Foo {
     barbazfoo: u64,
     baz      : bool,
-}"#;
+}
+"#;
         let mut config = Config::default();
         config.structures.struct_field_align_threshold = 40;
         let mut formatter = get_formatter(config, Shape::default());
@@ -152,7 +347,8 @@ pub struct Foo {
 "#;
         let correct_sway_code = r#"contract;
 
-pub struct Foo { bar: u64, baz: bool }"#;
+pub struct Foo { bar: u64, baz: bool }
+"#;
         let mut config = Config::default();
         config.structures.struct_lit_single_line = true;
         config.whitespace.max_width = 300;
@@ -175,13 +371,37 @@ pub struct Foo {
 pub struct Foo {
     bar: u64,
     baz: bool,
-}"#;
+}
+"#;
         let mut formatter = Formatter::default();
         let formatted_sway_code =
             Formatter::format(&mut formatter, Arc::from(sway_code_to_format), None).unwrap();
         assert!(correct_sway_code == formatted_sway_code)
     }
 
+    #[test]
+    fn test_struct_pub_fields_alignment() {
+        let sway_code_to_format = r#"contract;
+pub struct Foo {
+   pub   bar: u64,
+   bazbaz  : bool,
+}
+"#;
+        let correct_sway_code = r#"contract;
+
+pub struct Foo {
+    pub bar: u64,
+    bazbaz : bool,
+}
+"#;
+        let mut config = Config::default();
+        config.structures.struct_field_align_threshold = 40;
+        let mut formatter = get_formatter(config, Shape::default());
+        let formatted_sway_code =
+            Formatter::format(&mut formatter, Arc::from(sway_code_to_format), None).unwrap();
+        assert!(correct_sway_code == formatted_sway_code)
+    }
+
     #[test]
     fn test_enum_without_variant_alignment() {
         let sway_code_to_format = r#"contract;
@@ -195,12 +415,13 @@ enum Color {
         let correct_sway_code = r#"contract;
 
 enum Color {
-    Blue : (),
-    Green : (),
-    Red : (),
-    Silver : (),
-    Grey : (),
-}"#;
+    Blue: (),
+    Green: (),
+    Red: (),
+    Silver: (),
+    Grey: (),
+}
+"#;
         let mut formatter = Formatter::default();
         let formatted_sway_code =
             Formatter::format(&mut formatter, Arc::from(sway_code_to_format), None).unwrap();
@@ -219,12 +440,13 @@ enum Color {
         let correct_sway_code = r#"contract;
 
 enum Color {
-    Blue   : (),
-    Green  : (),
-    Red    : (),
-    Silver : (),
-    Grey   : (),
-}"#;
+    Blue  : (),
+    Green : (),
+    Red   : (),
+    Silver: (),
+    Grey  : (),
+}
+"#;
 
         // Creating a config with enum_variant_align_threshold that exceeds longest variant length
         let mut formatter = Formatter::default();
@@ -250,7 +472,27 @@ abi StorageMapExample {
     fn insert_into_map1(key: u64, value: u64);
 
     fn hello(key: u64, value: u64);
+}
+"#;
+        let mut formatter = Formatter::default();
+        let formatted_sway_code =
+            Formatter::format(&mut formatter, Arc::from(sway_code_to_format), None).unwrap();
+        assert!(correct_sway_code == formatted_sway_code)
+    }
+
+    #[test]
+    fn test_item_abi_with_supertraits() {
+        let sway_code_to_format = r#"contract;
+
+abi Token:Ownable+  Pausable {
+    fn total_supply() -> u64;
 }"#;
+        let correct_sway_code = r#"contract;
+
+abi Token: Ownable + Pausable {
+    fn total_supply() -> u64;
+}
+"#;
         let mut formatter = Formatter::default();
         let formatted_sway_code =
             Formatter::format(&mut formatter, Arc::from(sway_code_to_format), None).unwrap();
@@ -266,8 +508,356 @@ pub const TEST1: u16 = 10;"#;
         let correct_sway_code = r#"contract;
 
 pub const TEST: u16 = 10;
-pub const TEST1: u16 = 10;"#;
+pub const TEST1: u16 = 10;
+"#;
+
+        let mut formatter = Formatter::default();
+        let formatted_sway_code =
+            Formatter::format(&mut formatter, Arc::from(sway_code_to_format), None).unwrap();
+        assert!(correct_sway_code == formatted_sway_code)
+    }
+
+    #[test]
+    fn test_item_fn() {
+        let sway_code_to_format = r#"script;
+fn add(a:u64,b:u64)->u64 {
+    let res=a+b;
+    res
+}
+fn main() {
+    let x=add(1,2);
+    x.log();
+}"#;
+        let correct_sway_code = r#"script;
+
+fn add(a: u64, b: u64) -> u64 {
+    let res = a + b;
+    res
+}
+fn main() {
+    let x = add(1, 2);
+    x.log();
+}
+"#;
+        let mut formatter = Formatter::default();
+        let formatted_sway_code =
+            Formatter::format(&mut formatter, Arc::from(sway_code_to_format), None).unwrap();
+        assert!(correct_sway_code == formatted_sway_code)
+    }
+
+    #[test]
+    fn test_item_trait_and_impl() {
+        let sway_code_to_format = r#"library double;
+trait Double {
+    fn double(self)->Self;
+}
+impl Double for u64 {
+    fn double(self)->Self { self*2 }
+}
+impl<T> Wrapper<T> {
+}"#;
+        let correct_sway_code = r#"library double;
+
+trait Double {
+    fn double(self) -> Self;
+}
+impl Double for u64 {
+    fn double(self) -> Self {
+        self * 2
+    }
+}
+impl<T> Wrapper<T> {}
+"#;
+        let mut formatter = Formatter::default();
+        let formatted_sway_code =
+            Formatter::format(&mut formatter, Arc::from(sway_code_to_format), None).unwrap();
+        assert!(correct_sway_code == formatted_sway_code)
+    }
+
+    #[test]
+    fn test_item_generic_trait_and_impl() {
+        let sway_code_to_format = r#"library container;
+trait Container<T> {
+    type Item;
+    const CAPACITY:u64=4;
+    fn first(self)->Self::Item;
+}
+impl Container<bool> for Pair {
+    type Item=u64;
+    const CAPACITY:u64=2;
+    fn first(self)->Self::Item { self.a }
+}"#;
+        let correct_sway_code = r#"library container;
+
+trait Container<T> {
+    type Item;
+    const CAPACITY: u64 = 4;
+    fn first(self) -> Self::Item;
+}
+impl Container<bool> for Pair {
+    type Item = u64;
+    const CAPACITY: u64 = 2;
+    fn first(self) -> Self::Item {
+        self.a
+    }
+}
+"#;
+        let mut formatter = Formatter::default();
+        let formatted_sway_code =
+            Formatter::format(&mut formatter, Arc::from(sway_code_to_format), None).unwrap();
+        assert!(correct_sway_code == formatted_sway_code)
+    }
+
+    #[test]
+    fn test_item_storage() {
+        let sway_code_to_format = r#"contract;
+storage {
+    balances:StorageMap<Address,u64>=StorageMap{},
+    total :u64=0,
+}"#;
+        let correct_sway_code = r#"contract;
+
+storage {
+    balances: StorageMap<Address, u64> = StorageMap {},
+    total: u64 = 0,
+}
+"#;
+        let mut formatter = Formatter::default();
+        let formatted_sway_code =
+            Formatter::format(&mut formatter, Arc::from(sway_code_to_format), None).unwrap();
+        assert!(correct_sway_code == formatted_sway_code)
+    }
+
+    #[test]
+    fn test_item_use_sorting() {
+        let sway_code_to_format = r#"contract;
+use std::storage::StorageMap;
+use std::{hash::sha256, address::Address};
+use std::hash::keccak256;"#;
+        let correct_sway_code = r#"contract;
+
+use std::hash::keccak256;
+use std::storage::StorageMap;
+use std::{address::Address, hash::sha256};
+"#;
+        let mut formatter = Formatter::default();
+        let formatted_sway_code =
+            Formatter::format(&mut formatter, Arc::from(sway_code_to_format), None).unwrap();
+        assert!(correct_sway_code == formatted_sway_code)
+    }
+
+    #[test]
+    fn test_item_use_crate_granularity() {
+        let sway_code_to_format = r#"contract;
+use std::storage::StorageMap;
+use std::{hash::sha256, address::Address};
+use std::hash::keccak256;
+use core::ops::Eq;"#;
+        let correct_sway_code = r#"contract;
+
+use core::ops::Eq;
+use std::{
+    address::Address,
+    hash::{keccak256, sha256},
+    storage::StorageMap,
+};
+"#;
+        let mut config = Config::default();
+        config.imports.imports_granularity = ImportGranularity::Crate;
+        let mut formatter = get_formatter(config, Shape::default());
+        let formatted_sway_code =
+            Formatter::format(&mut formatter, Arc::from(sway_code_to_format), None).unwrap();
+        assert!(correct_sway_code == formatted_sway_code)
+    }
+
+    #[test]
+    fn test_comments() {
+        let sway_code_to_format = r#"// A script.
+script;
+// The entry point.
+fn main() {
+    /* unused */
+    let x = 1; // one
+
+
+    // The result.
+    x
+}
+// The end."#;
+        let correct_sway_code = r#"// A script.
+script;
+// The entry point.
+fn main() {
+    /* unused */
+    let x = 1; // one
+
+    // The result.
+    x
+}
+// The end.
+"#;
+        let mut formatter = Formatter::default();
+        let formatted_sway_code =
+            Formatter::format(&mut formatter, Arc::from(sway_code_to_format), None).unwrap();
+        assert!(correct_sway_code == formatted_sway_code)
+    }
+
+    #[test]
+    fn test_match_and_if() {
+        let sway_code_to_format = r#"script;
+fn main() -> u64 {
+    let x = if true {1} else {2};
+    match x { 1 => {x}, _ => 0, }
+}"#;
+        let correct_sway_code = r#"script;
+
+fn main() -> u64 {
+    let x = if true { 1 } else { 2 };
+    match x {
+        1 => {
+            x
+        }
+        _ => 0,
+    }
+}
+"#;
+        let mut formatter = Formatter::default();
+        let formatted_sway_code =
+            Formatter::format(&mut formatter, Arc::from(sway_code_to_format), None).unwrap();
+        assert!(correct_sway_code == formatted_sway_code)
+    }
+
+    #[test]
+    fn test_match_patterns_and_guards() {
+        let sway_code_to_format = r#"script;
+fn main() -> u64 {
+    let x = 5;
+    match x { 1|2|3 => 1, 4..=9 => 2, y if y>100 => y, _ => 0, }
+}"#;
+        let correct_sway_code = r#"script;
+
+fn main() -> u64 {
+    let x = 5;
+    match x {
+        1 | 2 | 3 => 1,
+        4..=9 => 2,
+        y if y > 100 => y,
+        _ => 0,
+    }
+}
+"#;
+        let mut formatter = Formatter::default();
+        let formatted_sway_code =
+            Formatter::format(&mut formatter, Arc::from(sway_code_to_format), None).unwrap();
+        assert!(correct_sway_code == formatted_sway_code)
+    }
+
+    #[test]
+    fn test_type_alias() {
+        let sway_code_to_format = r#"library aliases;
+type Balance=u64;
+pub type Map < V > = StorageMap<b256,V> ;"#;
+        let correct_sway_code = r#"library aliases;
+
+type Balance = u64;
+pub type Map<V> = StorageMap<b256, V>;
+"#;
+        let mut formatter = Formatter::default();
+        let formatted_sway_code =
+            Formatter::format(&mut formatter, Arc::from(sway_code_to_format), None).unwrap();
+        assert!(correct_sway_code == formatted_sway_code)
+    }
+
+    #[test]
+    fn test_while_break_continue() {
+        let sway_code_to_format = r#"script;
+fn main() {
+    let mut i = 0;
+    while true { i = i + 1;
+        if i == 5 {continue;}
+        if i > 10 {break;}
+    }
+}"#;
+        let correct_sway_code = r#"script;
+
+fn main() {
+    let mut i = 0;
+    while true {
+        i = i + 1;
+        if i == 5 {
+            continue;
+        }
+        if i > 10 {
+            break;
+        }
+    }
+}
+"#;
+        let mut formatter = Formatter::default();
+        let formatted_sway_code =
+            Formatter::format(&mut formatter, Arc::from(sway_code_to_format), None).unwrap();
+        assert!(correct_sway_code == formatted_sway_code)
+    }
+
+    #[test]
+    fn test_for_loops() {
+        let sway_code_to_format = r#"script;
+fn main() {
+    let mut sum = 0;
+    for i in 0..10 { sum = sum + i; }
+    for (a,b) in [(1,2),(3,4)]
+    {
+        if a == 3 {continue;}
+        sum = sum + a * b;
+    }
+}"#;
+        let correct_sway_code = r#"script;
 
+fn main() {
+    let mut sum = 0;
+    for i in 0..10 {
+        sum = sum + i;
+    }
+    for (a, b) in [(1, 2), (3, 4)] {
+        if a == 3 {
+            continue;
+        }
+        sum = sum + a * b;
+    }
+}
+"#;
+        let mut formatter = Formatter::default();
+        let formatted_sway_code =
+            Formatter::format(&mut formatter, Arc::from(sway_code_to_format), None).unwrap();
+        assert!(correct_sway_code == formatted_sway_code)
+    }
+
+    #[test]
+    fn test_doc_comments() {
+        let sway_code_to_format = r#"library docs;
+/// A point.
+pub struct Point { x: u64, y: u64 }
+impl Point {
+        /// The origin.
+    // Not part of the docs.
+    #[storage(read)]
+    /// Reads storage.   
+    fn origin() -> Point { Point { x: 0, y: 0 } }
+}"#;
+        let correct_sway_code = r#"library docs;
+
+/// A point.
+pub struct Point { x: u64, y: u64 }
+impl Point {
+    /// The origin.
+    // Not part of the docs.
+    #[storage(read)]
+    /// Reads storage.
+    fn origin() -> Point {
+        Point { x: 0, y: 0 }
+    }
+}
+"#;
         let mut formatter = Formatter::default();
         let formatted_sway_code =
             Formatter::format(&mut formatter, Arc::from(sway_code_to_format), None).unwrap();
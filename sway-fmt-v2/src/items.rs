@@ -4,6 +4,7 @@ mod item_enum;
 mod item_fn;
 mod item_impl;
 mod item_storage;
-mod item_struct;
-mod item_trait;
-mod item_use;
+pub(crate) mod item_struct;
+pub(crate) mod item_trait;
+mod item_type_alias;
+pub(crate) mod item_use;
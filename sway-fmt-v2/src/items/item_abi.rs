@@ -1,11 +1,10 @@
 use crate::{
-    config::items::ItemBraceStyle,
     fmt::{Format, FormattedCode, Formatter},
-    utils::{attribute::FormatDecl, bracket::CurlyBrace},
+    items::item_trait::{write_fn_defs, write_signatures},
+    utils::{bracket::CurlyBrace, ty::write_colon},
     FormatterError,
 };
-use std::fmt::Write;
-use sway_parse::{token::Delimiter, ItemAbi};
+use sway_parse::ItemAbi;
 use sway_types::Spanned;
 
 impl Format for ItemAbi {
@@ -14,101 +13,29 @@ impl Format for ItemAbi {
         formatted_code: &mut FormattedCode,
         formatter: &mut Formatter,
     ) -> Result<(), FormatterError> {
-        // Add enum token
+        // Add abi token
         formatted_code.push_str(self.abi_token.span().as_str());
         formatted_code.push(' ');
 
         // Add name of the abi
         formatted_code.push_str(self.name.as_str());
+
+        // `: Super + Abis`
+        if let Some((_colon_token, super_traits)) = &self.super_traits {
+            write_colon(formatted_code, formatter);
+            super_traits.format(formatted_code, formatter)?;
+        }
         Self::open_curly_brace(formatted_code, formatter)?;
 
-        // Add item fields
         // abi_items
-        let mut abi_items_iter = self.abi_items.get().iter().peekable();
-        while let Some(item) = abi_items_iter.next() {
-            let attribute_list = item.0.attribute_list.clone();
-            // add indent + format attribute if it exists
-            if !attribute_list.is_empty() {
-                formatted_code.push_str(&formatter.shape.indent.to_string(formatter));
-                for attr in attribute_list {
-                    attr.format(formatted_code, formatter)?;
-                }
-            }
-            // add indent + format item
-            formatted_code.push_str(&formatter.shape.indent.to_string(formatter));
-            writeln!(
-                formatted_code,
-                "{}{}",
-                item.0.value.span().as_str(), // FnSignature formatting (todo!)
-                item.1.span().as_str()        // SemicolonToken
-            )?;
-
-            if abi_items_iter.peek().is_some() {
-                formatted_code.push('\n');
-            }
-        }
+        write_signatures::<Self, _>(&self.abi_items, formatted_code, formatter)?;
 
         // abi_defs_opt
-        if let Some(abi_defs) = self.abi_defs_opt.clone() {
-            let mut iter = abi_defs.get().iter().peekable();
-            while let Some(item) = iter.next() {
-                let attribute_list = item.attribute_list.clone();
-                // add indent + format attribute if it exists
-                if !attribute_list.is_empty() {
-                    formatted_code.push_str(&formatter.shape.indent.to_string(formatter));
-                    for attr in attribute_list {
-                        attr.format(formatted_code, formatter)?;
-                    }
-                }
-
-                // add indent + format item
-                formatted_code.push_str(&formatter.shape.indent.to_string(formatter));
-                item.value.format(formatted_code, formatter)?; // ItemFn formatting (todo!)
-                if iter.peek().is_some() {
-                    formatted_code.push('\n');
-                }
-            }
+        if let Some(abi_defs) = &self.abi_defs_opt {
+            write_fn_defs::<Self>(abi_defs, formatted_code, formatter)?;
         }
-        Self::close_curly_brace(formatted_code, formatter)?;
         Ok(())
     }
 }
 
-impl CurlyBrace for ItemAbi {
-    fn open_curly_brace(
-        line: &mut String,
-        formatter: &mut Formatter,
-    ) -> Result<(), FormatterError> {
-        let brace_style = formatter.config.items.item_brace_style;
-        let extra_width = formatter.config.whitespace.tab_spaces;
-        let mut shape = formatter.shape;
-        let open_brace = Delimiter::Brace.as_open_char();
-        match brace_style {
-            ItemBraceStyle::AlwaysNextLine => {
-                // Add openning brace to the next line.
-                write!(line, "\n{}\n", open_brace)?;
-                shape = shape.block_indent(extra_width);
-            }
-            _ => {
-                // Add opening brace to the same line
-                writeln!(line, " {}", open_brace)?;
-                shape = shape.block_indent(extra_width);
-            }
-        }
-
-        formatter.shape = shape;
-        Ok(())
-    }
-    fn close_curly_brace(
-        line: &mut String,
-        formatter: &mut Formatter,
-    ) -> Result<(), FormatterError> {
-        line.push(Delimiter::Brace.as_close_char());
-        // If shape is becoming left-most alligned or - indent just have the defualt shape
-        formatter.shape = formatter
-            .shape
-            .shrink_left(formatter.config.whitespace.tab_spaces)
-            .unwrap_or_default();
-        Ok(())
-    }
-}
+impl CurlyBrace for ItemAbi {}
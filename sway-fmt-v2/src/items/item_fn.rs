@@ -1,15 +1,189 @@
 use crate::{
+    config::lists::ListTactic,
     fmt::{Format, FormattedCode, Formatter},
+    utils::{
+        bracket::CurlyBrace,
+        code_block::write_code_block_contents,
+        lists::{List, ListItem},
+        ty::write_ty_annotation,
+    },
     FormatterError,
 };
-use sway_parse::ItemFn;
+use sway_parse::{keywords::MutToken, FnArg, FnArgs, FnSignature, ItemFn};
+use sway_types::{Span, Spanned};
 
 impl Format for ItemFn {
     fn format(
         &self,
-        _formatted_code: &mut FormattedCode,
+        formatted_code: &mut FormattedCode,
+        formatter: &mut Formatter,
+    ) -> Result<(), FormatterError> {
+        self.fn_signature.format(formatted_code, formatter)?;
+
+        let body = self.body.get();
+        let body_span = self.body.span();
+        let body_is_empty = body.statements.is_empty()
+            && body.final_expr_opt.is_none()
+            && !formatter
+                .comments
+                .has_comments_in(body_span.start()..body_span.end());
+        if body_is_empty && !formatter.config.items.empty_item_single_line {
+            Self::open_curly_brace(formatted_code, formatter)?;
+            formatted_code.push('\n');
+            return Self::close_curly_brace(formatted_code, formatter);
+        }
+        // With `fn_single_line`, a body of a single expression may share the line of the
+        // signature, e.g. `fn foo() -> u64 { 5 }`.
+        if formatter.config.expressions.fn_single_line && !formatted_code.ends_with('\n') {
+            if let (true, Some(expr)) = (body.statements.is_empty(), &body.final_expr_opt) {
+                let has_comments = formatter
+                    .comments
+                    .has_comments_in(body_span.start()..body_span.end());
+                let max_width = formatter.config.whitespace.max_width;
+                if !has_comments
+                    && formatter.try_single_line(formatted_code, max_width, |code, formatter| {
+                        code.push_str(" { ");
+                        expr.format(code, formatter)?;
+                        code.push_str(" }");
+                        Ok(())
+                    })?
+                {
+                    return Ok(());
+                }
+            }
+        }
+        Self::open_curly_brace(formatted_code, formatter)?;
+        write_code_block_contents(&self.body, formatted_code, formatter)
+    }
+}
+
+impl CurlyBrace for ItemFn {}
+
+impl Format for FnSignature {
+    fn format(
+        &self,
+        formatted_code: &mut FormattedCode,
+        formatter: &mut Formatter,
+    ) -> Result<(), FormatterError> {
+        let checkpoint = formatter.checkpoint(formatted_code);
+        let start = formatted_code.len();
+        format_fn_signature(self, formatted_code, formatter, false)?;
+        // The arguments are written vertically if the rest of the signature doesn't fit after
+        // them, e.g. a long return type.
+        if !formatter.single_line && !formatter.lines_fit(formatted_code, start) {
+            formatter.restore(formatted_code, checkpoint);
+            format_fn_signature(self, formatted_code, formatter, true)?;
+        }
+        Ok(())
+    }
+}
+
+/// e.g. `pub fn foo<T>(self, a: T) -> u64`, followed by a where clause if any.
+fn format_fn_signature(
+    fn_signature: &FnSignature,
+    formatted_code: &mut FormattedCode,
+    formatter: &mut Formatter,
+    vertical_args: bool,
+) -> Result<(), FormatterError> {
+    if let Some(visibility_token) = &fn_signature.visibility {
+        formatted_code.push_str(visibility_token.span().as_str());
+        formatted_code.push(' ');
+    }
+    // `fn name`
+    formatted_code.push_str(fn_signature.fn_token.span().as_str());
+    formatted_code.push(' ');
+    formatted_code.push_str(fn_signature.name.as_str());
+    if let Some(generics) = &fn_signature.generics {
+        generics.format(formatted_code, formatter)?;
+    }
+
+    // `(self, a: T)`
+    let self_param = match &fn_signature.arguments.get() {
+        FnArgs::NonStatic {
+            self_token,
+            mutable_self,
+            ..
+        } => Some(SelfParam {
+            span: match mutable_self {
+                Some(mut_token) => Span::join(mut_token.span(), self_token.span()),
+                None => self_token.span(),
+            },
+            mutable_self,
+        }),
+        FnArgs::Static(_) => None,
+    };
+    let mut items = vec![];
+    if let Some(self_param) = &self_param {
+        items.push(ListItem::new(self_param.span.clone(), self_param));
+    }
+    let args = match fn_signature.arguments.get() {
+        FnArgs::Static(args) => Some(args),
+        FnArgs::NonStatic { args_opt, .. } => args_opt.as_ref().map(|(_comma_token, args)| args),
+    };
+    if let Some(args) = args {
+        items.extend(args.into_iter().map(|arg| ListItem::new(arg.span(), arg)));
+    }
+    let num_args = items.len();
+    let width = formatter.config.whitespace.max_width;
+    let mut list = List::new(
+        "(",
+        ")",
+        items,
+        fn_signature.arguments.span(),
+        width,
+        formatter,
+    );
+    list.tactic = formatter
+        .config
+        .expressions
+        .fn_args_layout
+        .to_list_tactic(num_args);
+    if vertical_args && num_args > 0 {
+        list.tactic = ListTactic::Vertical;
+    }
+    list.format(formatted_code, formatter)?;
+
+    // ` -> Ty`
+    if let Some((right_arrow_token, ty)) = &fn_signature.return_type_opt {
+        formatted_code.push(' ');
+        formatted_code.push_str(right_arrow_token.span().as_str());
+        formatted_code.push(' ');
+        ty.format(formatted_code, formatter)?;
+    }
+    if let Some(where_clause) = &fn_signature.where_clause_opt {
+        where_clause.format(formatted_code, formatter)?;
+    }
+    Ok(())
+}
+
+/// The `self` parameter of a method, e.g. `mut self`.
+struct SelfParam<'a> {
+    span: Span,
+    mutable_self: &'a Option<MutToken>,
+}
+
+impl<'a> Format for SelfParam<'a> {
+    fn format(
+        &self,
+        formatted_code: &mut FormattedCode,
         _formatter: &mut Formatter,
     ) -> Result<(), FormatterError> {
-        todo!()
+        if let Some(mut_token) = self.mutable_self {
+            formatted_code.push_str(mut_token.span().as_str());
+            formatted_code.push(' ');
+        }
+        formatted_code.push_str("self");
+        Ok(())
+    }
+}
+
+impl Format for FnArg {
+    fn format(
+        &self,
+        formatted_code: &mut FormattedCode,
+        formatter: &mut Formatter,
+    ) -> Result<(), FormatterError> {
+        self.pattern.format(formatted_code, formatter)?;
+        write_ty_annotation(&self.ty, formatted_code, formatter)
     }
 }
@@ -1,15 +1,79 @@
 use crate::{
     fmt::{Format, FormattedCode, Formatter},
+    utils::{
+        attribute::annotated_span, bracket::CurlyBrace, item::write_block_items, lists::ListItem,
+    },
     FormatterError,
 };
-use sway_parse::ItemImpl;
+use sway_parse::{ImplType, ItemImpl, ItemImplItem};
+use sway_types::Spanned;
 
 impl Format for ItemImpl {
     fn format(
         &self,
-        _formatted_code: &mut FormattedCode,
-        _formatter: &mut Formatter,
+        formatted_code: &mut FormattedCode,
+        formatter: &mut Formatter,
     ) -> Result<(), FormatterError> {
-        todo!()
+        // `impl<T>`
+        formatted_code.push_str(self.impl_token.span().as_str());
+        if let Some(generic_params) = &self.generic_params_opt {
+            generic_params.format(formatted_code, formatter)?;
+        }
+        formatted_code.push(' ');
+        // `Trait for `
+        if let Some((path_type, for_token)) = &self.trait_opt {
+            path_type.format(formatted_code, formatter)?;
+            formatted_code.push(' ');
+            formatted_code.push_str(for_token.span().as_str());
+            formatted_code.push(' ');
+        }
+        self.ty.format(formatted_code, formatter)?;
+        if let Some(where_clause) = &self.where_clause_opt {
+            where_clause.format(formatted_code, formatter)?;
+        }
+
+        Self::open_curly_brace(formatted_code, formatter)?;
+        let items: Vec<ListItem> = self
+            .contents
+            .get()
+            .iter()
+            .map(|item| ListItem::new(annotated_span(item), item))
+            .collect();
+        write_block_items::<Self, _>(&items, &self.contents, formatted_code, formatter)
+    }
+}
+
+impl CurlyBrace for ItemImpl {}
+
+impl Format for ItemImplItem {
+    fn format(
+        &self,
+        formatted_code: &mut FormattedCode,
+        formatter: &mut Formatter,
+    ) -> Result<(), FormatterError> {
+        match self {
+            ItemImplItem::Fn(item_fn) => item_fn.format(formatted_code, formatter),
+            ItemImplItem::Const(item_const) => item_const.format(formatted_code, formatter),
+            ItemImplItem::Type(impl_type) => impl_type.format(formatted_code, formatter),
+        }
+    }
+}
+
+impl Format for ImplType {
+    fn format(
+        &self,
+        formatted_code: &mut FormattedCode,
+        formatter: &mut Formatter,
+    ) -> Result<(), FormatterError> {
+        // `type Name = ty;`
+        formatted_code.push_str(self.type_token.span().as_str());
+        formatted_code.push(' ');
+        formatted_code.push_str(self.name.as_str());
+        formatted_code.push(' ');
+        formatted_code.push_str(self.eq_token.span().as_str());
+        formatted_code.push(' ');
+        self.ty.format(formatted_code, formatter)?;
+        formatted_code.push_str(self.semicolon_token.span().as_str());
+        Ok(())
     }
 }
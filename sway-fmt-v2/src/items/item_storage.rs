@@ -1,15 +1,90 @@
 use crate::{
     fmt::{Format, FormattedCode, Formatter},
+    utils::{
+        bracket::CurlyBrace, code_block::write_rhs, item::write_block_items, lists::ListItem,
+        ty::write_colon,
+    },
     FormatterError,
 };
-use sway_parse::ItemStorage;
+use sway_parse::{ItemStorage, StorageField};
+use sway_types::{Span, Spanned};
 
 impl Format for ItemStorage {
     fn format(
         &self,
-        _formatted_code: &mut FormattedCode,
-        _formatter: &mut Formatter,
+        formatted_code: &mut FormattedCode,
+        formatter: &mut Formatter,
     ) -> Result<(), FormatterError> {
-        todo!()
+        let storage_field_align_threshold =
+            formatter.config.structures.storage_field_align_threshold;
+
+        // Add storage token
+        formatted_code.push_str(self.storage_token.span().as_str());
+        Self::open_curly_brace(formatted_code, formatter)?;
+
+        // Find the maximum length of the field names that is still smaller than the
+        // storage_field_align_threshold, to which the shorter names are padded.
+        let max_valid_name_length = self
+            .fields
+            .get()
+            .into_iter()
+            .map(|field| field.name.as_str().len())
+            .filter(|length| *length < storage_field_align_threshold)
+            .max()
+            .unwrap_or_default();
+        let aligned_fields: Vec<AlignedStorageField> = self
+            .fields
+            .get()
+            .into_iter()
+            .map(|field| AlignedStorageField {
+                field,
+                name_width: max_valid_name_length,
+            })
+            .collect();
+        let items: Vec<ListItem> = aligned_fields
+            .iter()
+            .map(|field| ListItem::new(storage_field_span(field.field), field))
+            .collect();
+        write_block_items::<Self, _>(&items, &self.fields, formatted_code, formatter)
+    }
+}
+
+impl CurlyBrace for ItemStorage {}
+
+fn storage_field_span(field: &StorageField) -> Span {
+    match &field.initializer {
+        Some((_eq_token, expr)) => Span::join(field.name.span(), expr.span()),
+        None => Span::join(field.name.span(), field.ty.span()),
+    }
+}
+
+/// A storage field on its own line, e.g. `balance: u64 = 0,`, with its `:` aligned with those of
+/// the other fields.
+struct AlignedStorageField<'a> {
+    field: &'a StorageField,
+    /// The width to which the name is padded.
+    name_width: usize,
+}
+
+impl<'a> Format for AlignedStorageField<'a> {
+    fn format(
+        &self,
+        formatted_code: &mut FormattedCode,
+        formatter: &mut Formatter,
+    ) -> Result<(), FormatterError> {
+        let name = self.field.name.as_str();
+        formatted_code.push_str(name);
+        for _ in name.len()..self.name_width {
+            formatted_code.push(' ');
+        }
+        write_colon(formatted_code, formatter);
+        self.field.ty.format(formatted_code, formatter)?;
+        if let Some((eq_token, expr)) = &self.field.initializer {
+            formatted_code.push(' ');
+            formatted_code.push_str(eq_token.span().as_str());
+            write_rhs(expr, formatted_code, formatter)?;
+        }
+        formatted_code.push(',');
+        Ok(())
     }
 }
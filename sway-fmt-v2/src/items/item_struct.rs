@@ -1,13 +1,14 @@
 use crate::{
-    config::items::ItemBraceStyle,
     fmt::{Format, FormattedCode, Formatter},
     utils::{
-        bracket::{AngleBracket, CurlyBrace},
-        item_len::ItemLen,
+        bracket::CurlyBrace,
+        item::write_block_items,
+        lists::{List, ListItem},
+        ty::write_colon,
     },
     FormatterError,
 };
-use sway_parse::ItemStruct;
+use sway_parse::{keywords::CommaToken, punctuated::Punctuated, Braces, ItemStruct, TypeField};
 use sway_types::Spanned;
 
 impl Format for ItemStruct {
@@ -16,8 +17,6 @@ impl Format for ItemStruct {
         formatted_code: &mut FormattedCode,
         formatter: &mut Formatter,
     ) -> Result<(), FormatterError> {
-        // Get the unformatted
-
         // Get struct_variant_align_threshold from config.
         let struct_variant_align_threshold =
             formatter.config.structures.struct_field_align_threshold;
@@ -25,204 +24,149 @@ impl Format for ItemStruct {
         // Should small structs formatted into a single line.
         let struct_lit_single_line = formatter.config.structures.struct_lit_single_line;
 
-        // Get the width limit of a struct to be formatted into single line if struct_lit_single_line is true.
-        let config_whitespace = formatter.config.whitespace;
-        let width_heuristics = formatter
-            .config
-            .heuristics
-            .heuristics_pref
-            .to_width_heuristics(&config_whitespace);
-        let struct_lit_width = width_heuristics.struct_lit_width;
-
-        let multiline = !struct_lit_single_line || self.get_formatted_len() > struct_lit_width;
-        format_struct(
-            self,
-            formatted_code,
-            formatter,
-            multiline,
-            struct_variant_align_threshold,
-        )?;
-        Ok(())
-    }
-}
-
-/// Format the struct if the multiline is passed as false struct will be formatted into a single line.
-///
-/// Example (multiline : false):
-/// struct Foo { bar: u64,  baz: bool }
-///
-/// Example (multiline : true):
-/// struct Foo {
-///  bar: u64,
-///  baz: bool,
-/// }
-fn format_struct(
-    item_struct: &ItemStruct,
-    formatted_code: &mut FormattedCode,
-    formatter: &mut Formatter,
-    multiline: bool,
-    struct_variant_align_threshold: usize,
-) -> Result<(), FormatterError> {
-    // If there is a visibility token add it to the formatted_code with a ` ` after it.
-    if let Some(visibility) = &item_struct.visibility {
-        formatted_code.push_str(visibility.span().as_str());
-        formatted_code.push(' ');
-    }
-    // Add struct token
-    formatted_code.push_str(item_struct.struct_token.span().as_str());
-    formatted_code.push(' ');
-
-    // Add struct name
-    formatted_code.push_str(item_struct.name.as_str());
-
-    // Check if there is generic provided
-    if let Some(generics) = &item_struct.generics {
-        // Push angle brace
-        ItemStruct::open_angle_bracket(formatted_code, formatter)?;
-        // Get generics fields
-        let generics = generics.parameters.inner.value_separator_pairs.clone();
-        for (index, generic) in generics.iter().enumerate() {
-            // Push ident
-            formatted_code.push_str(generic.0.as_str());
-            if index != generics.len() - 1 {
-                // Push `, ` if this is not the last generic
-                formatted_code.push_str(", ");
-            }
+        // If there is a visibility token add it to the formatted_code with a ` ` after it.
+        if let Some(visibility) = &self.visibility {
+            formatted_code.push_str(visibility.span().as_str());
+            formatted_code.push(' ');
         }
-    }
-
-    // Handle openning brace
-    if multiline {
-        ItemStruct::open_curly_brace(formatted_code, formatter)?;
-        formatted_code.push('\n');
-    } else {
-        // Push a single whitespace before `{`
-        formatted_code.push(' ');
-        // Push open brace
-        formatted_code.push('{');
-        // Push a single whitespace after `{`
+        // Add struct token
+        formatted_code.push_str(self.struct_token.span().as_str());
         formatted_code.push(' ');
-    }
-
-    let items = item_struct
-        .fields
-        .clone()
-        .into_inner()
-        .value_separator_pairs;
 
-    // In first iteration we are going to be collecting the lengths of the enum variants.
-    let variant_length: Vec<usize> = items
-        .iter()
-        .map(|variant| variant.0.name.as_str().len())
-        .collect();
+        // Add struct name
+        formatted_code.push_str(self.name.as_str());
 
-    // Find the maximum length in the variant_length vector that is still smaller than enum_variant_align_threshold.
-    let mut max_valid_variant_length = 0;
-
-    variant_length.iter().for_each(|length| {
-        if *length > max_valid_variant_length && *length < struct_variant_align_threshold {
-            max_valid_variant_length = *length;
-        }
-    });
-    for (item_index, item) in items.iter().enumerate() {
-        if multiline {
-            formatted_code.push_str(&formatter.shape.indent.to_string(formatter));
+        // Check if there is generic provided
+        if let Some(generics) = &self.generics {
+            generics.format(formatted_code, formatter)?;
         }
-        let type_field = &item.0;
-        // Add name
-        formatted_code.push_str(type_field.name.as_str());
-        let current_variant_length = variant_length[item_index];
-        if current_variant_length < max_valid_variant_length {
-            // We need to add alignment between : and ty
-            // max_valid_variant_length: the length of the variant that we are taking as a reference to allign
-            // current_variant_length: the length of the current variant that we are trying to format
-            let required_alignment = max_valid_variant_length - current_variant_length;
-            // TODO: Improve handling this
-            formatted_code.push_str(&(0..required_alignment).map(|_| ' ').collect::<String>());
+        if let Some(where_clause) = &self.where_clause_opt {
+            where_clause.format(formatted_code, formatter)?;
         }
-        // Add `:`
-        formatted_code.push_str(type_field.colon_token.ident().as_str());
-        formatted_code.push(' ');
-        // TODO: We are currently converting ty to string directly but we will probably need to format ty before adding.
-        // Add ty
-        formatted_code.push_str(type_field.ty.span().as_str());
-        // Add `, ` if this isn't the last field.
-        if !multiline && item_index != items.len() - 1 {
-            formatted_code.push_str(", ");
-        } else if multiline {
-            formatted_code.push_str(",\n");
+
+        // Small structs may be formatted into a single line, e.g. `struct Foo { bar: u64 }`,
+        // if they fit within the struct_lit_width.
+        let fields_span = self.fields.span();
+        if struct_lit_single_line
+            && self.where_clause_opt.is_none()
+            && !formatter
+                .comments
+                .has_comments_in(fields_span.start()..fields_span.end())
+        {
+            let items = self
+                .fields
+                .get()
+                .into_iter()
+                .map(|type_field| ListItem::new(type_field.span(), type_field))
+                .collect();
+            let width = formatter.width_heuristics().struct_lit_width;
+            let mut list = List::new("{", "}", items, fields_span, width, formatter);
+            list.padded = true;
+            if formatter.try_single_line(formatted_code, usize::MAX, |code, formatter| {
+                code.push(' ');
+                list.format(code, formatter)
+            })? {
+                return Ok(());
+            }
         }
-    }
-    if !multiline {
-        // Push a ' '
-        formatted_code.push(' ');
-    }
-    // Handle closing brace
-    ItemStruct::close_curly_brace(formatted_code, formatter)?;
-    Ok(())
-}
 
-impl ItemLen for ItemStruct {
-    fn get_formatted_len(&self) -> usize {
-        // TODO while determininig the length we may want to format to some degree and take length.
-        let str_item = &self.span().as_str().len();
-        *str_item as usize
+        Self::open_curly_brace(formatted_code, formatter)?;
+        write_type_fields::<Self>(
+            &self.fields,
+            struct_variant_align_threshold,
+            formatted_code,
+            formatter,
+        )
     }
 }
 
-impl CurlyBrace for ItemStruct {
-    fn open_curly_brace(
-        line: &mut String,
+impl CurlyBrace for ItemStruct {}
+
+impl Format for TypeField {
+    fn format(
+        &self,
+        formatted_code: &mut FormattedCode,
         formatter: &mut Formatter,
     ) -> Result<(), FormatterError> {
-        let brace_style = formatter.config.items.item_brace_style;
-        let extra_width = formatter.config.whitespace.tab_spaces;
-        let mut shape = formatter.shape;
-        match brace_style {
-            ItemBraceStyle::AlwaysNextLine => {
-                // Add openning brace to the next line.
-                line.push_str("\n{");
-                shape = shape.block_indent(extra_width);
-            }
-            _ => {
-                // Add opening brace to the same line
-                line.push_str(" {");
-                shape = shape.block_indent(extra_width);
-            }
+        if let Some(visibility) = &self.visibility {
+            formatted_code.push_str(visibility.span().as_str());
+            formatted_code.push(' ');
         }
-
-        formatter.shape = shape;
-        Ok(())
+        formatted_code.push_str(self.name.as_str());
+        write_colon(formatted_code, formatter);
+        self.ty.format(formatted_code, formatter)
     }
+}
 
-    fn close_curly_brace(
-        line: &mut String,
+/// A field of a struct or variant of an enum on its own line, with its `:` aligned with those of
+/// the other fields.
+struct AlignedTypeField<'a> {
+    type_field: &'a TypeField,
+    /// The width to which the name is padded.
+    name_width: usize,
+}
+
+impl<'a> Format for AlignedTypeField<'a> {
+    fn format(
+        &self,
+        formatted_code: &mut FormattedCode,
         formatter: &mut Formatter,
     ) -> Result<(), FormatterError> {
-        line.push('}');
-        // If shape is becoming left-most alligned or - indent just have the defualt shape
-        formatter.shape = formatter
-            .shape
-            .shrink_left(formatter.config.whitespace.tab_spaces)
-            .unwrap_or_default();
+        if let Some(visibility) = &self.type_field.visibility {
+            formatted_code.push_str(visibility.span().as_str());
+            formatted_code.push(' ');
+        }
+        let name = self.type_field.name.as_str();
+        formatted_code.push_str(name);
+        for _ in type_field_name_width(self.type_field)..self.name_width {
+            formatted_code.push(' ');
+        }
+        write_colon(formatted_code, formatter);
+        self.type_field.ty.format(formatted_code, formatter)?;
+        formatted_code.push(',');
         Ok(())
     }
 }
 
-impl AngleBracket for ItemStruct {
-    fn open_angle_bracket(
-        line: &mut String,
-        _formatter: &mut Formatter,
-    ) -> Result<(), FormatterError> {
-        line.push('<');
-        Ok(())
-    }
+/// The width of the name of a field, including its `pub ` if it has one.
+fn type_field_name_width(type_field: &TypeField) -> usize {
+    let pub_width = match type_field.visibility {
+        Some(_) => "pub ".len(),
+        None => 0,
+    };
+    pub_width + type_field.name.as_str().len()
+}
 
-    fn close_angle_bracket(
-        line: &mut String,
-        _formatter: &mut Formatter,
-    ) -> Result<(), FormatterError> {
-        line.push('>');
-        Ok(())
-    }
+/// Write the fields of a struct or the variants of an enum on their own lines, followed by the
+/// closing brace.
+///
+/// The names of the fields which are shorter than the `align_threshold` are padded to the
+/// length of the longest of them, aligning their types.
+pub(crate) fn write_type_fields<B: CurlyBrace>(
+    fields: &Braces<Punctuated<TypeField, CommaToken>>,
+    align_threshold: usize,
+    formatted_code: &mut FormattedCode,
+    formatter: &mut Formatter,
+) -> Result<(), FormatterError> {
+    // Find the maximum length of the names that is still smaller than the align_threshold.
+    let max_valid_name_length = fields
+        .get()
+        .into_iter()
+        .map(type_field_name_width)
+        .filter(|length| *length < align_threshold)
+        .max()
+        .unwrap_or_default();
+    let aligned_fields: Vec<AlignedTypeField> = fields
+        .get()
+        .into_iter()
+        .map(|type_field| AlignedTypeField {
+            type_field,
+            name_width: max_valid_name_length,
+        })
+        .collect();
+    let items: Vec<ListItem> = aligned_fields
+        .iter()
+        .map(|field| ListItem::new(field.type_field.span(), field))
+        .collect();
+    write_block_items::<B, _>(&items, fields, formatted_code, formatter)
 }
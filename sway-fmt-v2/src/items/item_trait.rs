@@ -1,15 +1,146 @@
 use crate::{
     fmt::{Format, FormattedCode, Formatter},
+    utils::{
+        attribute::annotated_span,
+        bracket::CurlyBrace,
+        code_block::write_rhs,
+        item::write_block_items,
+        lists::ListItem,
+        ty::{write_colon, write_ty_annotation},
+    },
     FormatterError,
 };
-use sway_parse::ItemTrait;
+use sway_parse::{
+    attribute::Annotated, keywords::SemicolonToken, Braces, ItemFn, ItemTrait, ItemTraitItem,
+    Parse, TraitConst, TraitType,
+};
+use sway_types::{Span, Spanned};
 
 impl Format for ItemTrait {
     fn format(
         &self,
-        _formatted_code: &mut FormattedCode,
+        formatted_code: &mut FormattedCode,
+        formatter: &mut Formatter,
+    ) -> Result<(), FormatterError> {
+        if let Some(visibility_token) = &self.visibility {
+            formatted_code.push_str(visibility_token.span().as_str());
+            formatted_code.push(' ');
+        }
+        // `trait Name`
+        formatted_code.push_str(self.trait_token.span().as_str());
+        formatted_code.push(' ');
+        formatted_code.push_str(self.name.as_str());
+        if let Some(generics) = &self.generics {
+            generics.format(formatted_code, formatter)?;
+        }
+        // `: Super + Traits`
+        if let Some((_colon_token, super_traits)) = &self.super_traits {
+            write_colon(formatted_code, formatter);
+            super_traits.format(formatted_code, formatter)?;
+        }
+
+        Self::open_curly_brace(formatted_code, formatter)?;
+        write_signatures::<Self, _>(&self.trait_items, formatted_code, formatter)?;
+        if let Some(trait_defs) = &self.trait_defs_opt {
+            write_fn_defs::<Self>(trait_defs, formatted_code, formatter)?;
+        }
+        Ok(())
+    }
+}
+
+impl CurlyBrace for ItemTrait {}
+
+impl Format for ItemTraitItem {
+    fn format(
+        &self,
+        formatted_code: &mut FormattedCode,
+        formatter: &mut Formatter,
+    ) -> Result<(), FormatterError> {
+        match self {
+            ItemTraitItem::Fn(fn_signature) => fn_signature.format(formatted_code, formatter),
+            ItemTraitItem::Const(trait_const) => trait_const.format(formatted_code, formatter),
+            ItemTraitItem::Type(trait_type) => trait_type.format(formatted_code, formatter),
+        }
+    }
+}
+
+impl Format for TraitConst {
+    fn format(
+        &self,
+        formatted_code: &mut FormattedCode,
+        formatter: &mut Formatter,
+    ) -> Result<(), FormatterError> {
+        // `const NAME: ty`
+        formatted_code.push_str(self.const_token.span().as_str());
+        formatted_code.push(' ');
+        formatted_code.push_str(self.name.as_str());
+        write_ty_annotation(&self.ty, formatted_code, formatter)?;
+        // ` = expr`
+        if let Some((eq_token, expr)) = &self.default_opt {
+            formatted_code.push(' ');
+            formatted_code.push_str(eq_token.span().as_str());
+            write_rhs(expr, formatted_code, formatter)?;
+        }
+        Ok(())
+    }
+}
+
+impl Format for TraitType {
+    fn format(
+        &self,
+        formatted_code: &mut FormattedCode,
         _formatter: &mut Formatter,
     ) -> Result<(), FormatterError> {
-        todo!()
+        formatted_code.push_str(self.type_token.span().as_str());
+        formatted_code.push(' ');
+        formatted_code.push_str(self.name.as_str());
+        Ok(())
     }
 }
+
+/// An item declared within a `trait` or `abi` along with its `;`.
+impl<T: Parse + Format + Spanned> Format for (Annotated<T>, SemicolonToken) {
+    fn format(
+        &self,
+        formatted_code: &mut FormattedCode,
+        formatter: &mut Formatter,
+    ) -> Result<(), FormatterError> {
+        self.0.format(formatted_code, formatter)?;
+        formatted_code.push_str(self.1.span().as_str());
+        Ok(())
+    }
+}
+
+/// Write the items declared within a `trait` or `abi`, e.g. function signatures, followed by the
+/// closing brace.
+pub(crate) fn write_signatures<B: CurlyBrace, T: Parse + Format + Spanned>(
+    signatures: &Braces<Vec<(Annotated<T>, SemicolonToken)>>,
+    formatted_code: &mut FormattedCode,
+    formatter: &mut Formatter,
+) -> Result<(), FormatterError> {
+    let items: Vec<ListItem> = signatures
+        .get()
+        .iter()
+        .map(|signature| {
+            let span = Span::join(annotated_span(&signature.0), signature.1.span());
+            ListItem::new(span, signature)
+        })
+        .collect();
+    write_block_items::<B, _>(&items, signatures, formatted_code, formatter)
+}
+
+/// Write the block of methods that may follow the signatures of a `trait` or `abi`, e.g.
+/// `trait Foo { fn foo(); } { fn bar() {} }`.
+pub(crate) fn write_fn_defs<B: CurlyBrace>(
+    fn_defs: &Braces<Vec<Annotated<ItemFn>>>,
+    formatted_code: &mut FormattedCode,
+    formatter: &mut Formatter,
+) -> Result<(), FormatterError> {
+    B::open_curly_brace(formatted_code, formatter)?;
+    let items: Vec<ListItem> = fn_defs
+        .get()
+        .iter()
+        .map(|item_fn| ListItem::new(annotated_span(item_fn), item_fn))
+        .collect();
+    write_block_items::<B, _>(&items, fn_defs, formatted_code, formatter)
+}
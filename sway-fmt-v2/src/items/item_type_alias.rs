@@ -0,0 +1,34 @@
+use crate::{
+    fmt::{Format, FormattedCode, Formatter},
+    FormatterError,
+};
+use sway_parse::ItemTypeAlias;
+use sway_types::Spanned;
+
+impl Format for ItemTypeAlias {
+    fn format(
+        &self,
+        formatted_code: &mut FormattedCode,
+        formatter: &mut Formatter,
+    ) -> Result<(), FormatterError> {
+        // Check if visibility token exists if so add it.
+        if let Some(visibility_token) = &self.visibility {
+            formatted_code.push_str(visibility_token.span().as_str());
+            formatted_code.push(' ');
+        }
+
+        // `type Name<T> = ty;`
+        formatted_code.push_str(self.type_token.span().as_str());
+        formatted_code.push(' ');
+        formatted_code.push_str(self.name.as_str());
+        if let Some(generics) = &self.generics {
+            generics.format(formatted_code, formatter)?;
+        }
+        formatted_code.push(' ');
+        formatted_code.push_str(self.eq_token.span().as_str());
+        formatted_code.push(' ');
+        self.ty.format(formatted_code, formatter)?;
+        formatted_code.push_str(self.semicolon_token.span().as_str());
+        Ok(())
+    }
+}
@@ -1,15 +1,491 @@
+//! Imports are formatted as a model of their trees, so that consecutive imports may be merged,
+//! split, sorted and regrouped according to the `imports` and `ordering` sections of the config.
 use crate::{
+    config::{
+        imports::{GroupImports, ImportGranularity},
+        lists::ListTactic,
+    },
     fmt::{Format, FormattedCode, Formatter},
+    utils::{
+        comments::{write_comments_before, write_trailing_comments},
+        lists::{List, ListItem},
+    },
     FormatterError,
 };
-use sway_parse::ItemUse;
+use std::cmp::Ordering;
+use sway_parse::{Item, ItemKind, ItemUse, UseTree};
+use sway_types::{Span, Spanned};
 
 impl Format for ItemUse {
     fn format(
         &self,
-        _formatted_code: &mut FormattedCode,
-        _formatter: &mut Formatter,
+        formatted_code: &mut FormattedCode,
+        formatter: &mut Formatter,
     ) -> Result<(), FormatterError> {
-        todo!()
+        let mut import = Import {
+            is_pub: self.visibility.is_some(),
+            tree: import_tree_of(self),
+        };
+        import.normalize(formatter.config.ordering.reorder_imports);
+        import.format(formatted_code, formatter)
+    }
+}
+
+/// Format a run of consecutive `use` items, starting with the first of `items`, returning the
+/// number of items formatted.
+///
+/// The run ends at an item with attributes or at a comment, which would otherwise be displaced
+/// by merging and sorting.
+pub(crate) fn format_use_items(
+    items: &[Item],
+    formatted_code: &mut FormattedCode,
+    formatter: &mut Formatter,
+    prev_end: &mut Option<usize>,
+) -> Result<usize, FormatterError> {
+    let mut run = vec![];
+    let mut blank_line_before = vec![];
+    let mut run_end = None;
+    for item in items {
+        let item_use = match &item.value {
+            ItemKind::Use(item_use) if item.attribute_list.is_empty() => item_use,
+            _ => break,
+        };
+        let span = item.span();
+        let start = run_end.unwrap_or_else(|| span.start());
+        if formatter.comments.has_comments_in(start..span.end()) {
+            break;
+        }
+        blank_line_before.push(match run_end {
+            Some(run_end) => formatter.comments.newlines_in(run_end..span.start()) > 1,
+            None => false,
+        });
+        run.push(item_use);
+        run_end = Some(span.end());
+    }
+
+    // An import which can't be part of a run is formatted on its own.
+    let first = &items[0];
+    let first_span = first.span();
+    write_comments_before(formatted_code, formatter, *prev_end, first_span.start())?;
+    let run_end = match run_end {
+        Some(run_end) => run_end,
+        None => {
+            formatted_code.push_str(&formatter.indent_str());
+            first.format(formatted_code, formatter)?;
+            write_trailing_comments(formatted_code, formatter, first_span.end())?;
+            formatted_code.push('\n');
+            *prev_end = Some(first_span.end());
+            return Ok(1);
+        }
+    };
+
+    let imports = run.iter().map(|item_use| Import {
+        is_pub: item_use.visibility.is_some(),
+        tree: import_tree_of(item_use),
+    });
+    let groups = group_imports(
+        imports.zip(blank_line_before).collect(),
+        formatter.config.imports.group_imports,
+    );
+    let reorder_imports = formatter.config.ordering.reorder_imports;
+    let granularity = formatter.config.imports.imports_granularity;
+    let num_groups = groups.len();
+    for (group_ix, group) in groups.into_iter().enumerate() {
+        let mut imports = merge_imports(group, granularity);
+        imports
+            .iter_mut()
+            .for_each(|import| import.normalize(reorder_imports));
+        if reorder_imports {
+            imports.sort_by(|a, b| a.tree.cmp(&b.tree).then(a.is_pub.cmp(&b.is_pub)));
+            imports.dedup();
+        }
+        let num_imports = imports.len();
+        for (ix, import) in imports.iter().enumerate() {
+            formatted_code.push_str(&formatter.indent_str());
+            import.format(formatted_code, formatter)?;
+            if group_ix + 1 == num_groups && ix + 1 == num_imports {
+                write_trailing_comments(formatted_code, formatter, run_end)?;
+            }
+            formatted_code.push('\n');
+        }
+        if group_ix + 1 < num_groups {
+            formatted_code.push('\n');
+        }
+    }
+    *prev_end = Some(run_end);
+    Ok(run.len())
+}
+
+/// A `use` item, e.g. `use std::{hash::sha256, storage::*};`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+struct Import {
+    is_pub: bool,
+    tree: ImportTree,
+}
+
+/// The tree of names imported by a `use` item.
+#[derive(Clone, Debug, PartialEq, Eq)]
+enum ImportTree {
+    /// e.g. `std::hash::sha256`. The prefix is empty for an import from the root, e.g. `::std`.
+    Path(String, Box<ImportTree>),
+    Name(String),
+    Rename(String, String),
+    Glob,
+    Group(Vec<ImportTree>),
+}
+
+fn import_tree_of(item_use: &ItemUse) -> ImportTree {
+    let tree = ImportTree::from(&item_use.tree);
+    match item_use.root_import {
+        Some(_) => ImportTree::Path(String::new(), Box::new(tree)),
+        None => tree,
+    }
+}
+
+impl From<&UseTree> for ImportTree {
+    fn from(use_tree: &UseTree) -> Self {
+        match use_tree {
+            UseTree::Group { imports } => {
+                Self::Group(imports.get().into_iter().map(Self::from).collect())
+            }
+            UseTree::Name { name } => Self::Name(name.as_str().to_string()),
+            UseTree::Rename { name, alias, .. } => {
+                Self::Rename(name.as_str().to_string(), alias.as_str().to_string())
+            }
+            UseTree::Glob { .. } => Self::Glob,
+            UseTree::Path { prefix, suffix, .. } => {
+                Self::Path(prefix.as_str().to_string(), Box::new(Self::from(&**suffix)))
+            }
+        }
+    }
+}
+
+impl Import {
+    /// Unwrap groups of a single import other than `self`, e.g. `a::{b}` becomes `a::b`, and sort
+    /// the groups if `reorder_imports` is set.
+    fn normalize(&mut self, reorder_imports: bool) {
+        self.tree.normalize(reorder_imports);
+    }
+}
+
+impl ImportTree {
+    fn normalize(&mut self, reorder_imports: bool) {
+        match self {
+            Self::Path(_, suffix) => {
+                suffix.normalize(reorder_imports);
+                // `a::self` is not a valid import, unlike `a::{self}`.
+                if suffix.is_self() {
+                    let self_import = std::mem::replace(&mut **suffix, Self::Glob);
+                    **suffix = Self::Group(vec![self_import]);
+                }
+            }
+            Self::Group(trees) => {
+                trees
+                    .iter_mut()
+                    .for_each(|tree| tree.normalize(reorder_imports));
+                if reorder_imports {
+                    trees.sort();
+                    trees.dedup();
+                }
+                if trees.len() == 1 && !trees[0].is_self() {
+                    *self = trees.pop().unwrap();
+                }
+            }
+            Self::Name(_) | Self::Rename(..) | Self::Glob => (),
+        }
+    }
+
+    /// Whether this imports the module itself, e.g. the `self` of `a::{self as b}`.
+    fn is_self(&self) -> bool {
+        matches!(self, Self::Name(name) | Self::Rename(name, _) if name == "self")
+    }
+
+    /// The first segment of the path, e.g. `std` for `std::hash::sha256`.
+    fn first_segment(&self) -> Option<&str> {
+        match self {
+            Self::Path(prefix, _) | Self::Name(prefix) | Self::Rename(prefix, _) => Some(prefix),
+            Self::Glob | Self::Group(_) => None,
+        }
+    }
+
+    /// Collect each path to an imported name within the tree, along with the name.
+    fn flatten(&self, prefix: &mut Vec<String>, paths: &mut Vec<(Vec<String>, ImportTree)>) {
+        match self {
+            Self::Path(segment, suffix) => {
+                prefix.push(segment.clone());
+                suffix.flatten(prefix, paths);
+                prefix.pop();
+            }
+            Self::Group(trees) => trees.iter().for_each(|tree| tree.flatten(prefix, paths)),
+            Self::Name(_) | Self::Rename(..) | Self::Glob => {
+                paths.push((prefix.clone(), self.clone()))
+            }
+        }
+    }
+
+    /// The segments of the path by which imports are sorted.
+    fn segments(&self) -> Vec<Segment<'_>> {
+        match self {
+            Self::Path(prefix, suffix) => {
+                let mut segments = vec![Segment::Ident(prefix, None)];
+                segments.extend(suffix.segments());
+                segments
+            }
+            Self::Name(name) => vec![Segment::Ident(name, None)],
+            Self::Rename(name, alias) => vec![Segment::Ident(name, Some(alias))],
+            Self::Glob => vec![Segment::Glob],
+            Self::Group(trees) => vec![Segment::Group(trees)],
+        }
+    }
+}
+
+/// A segment of an import path, ordered as by `rustfmt`: `self`, `super` and `crate` come first,
+/// followed by names in snake_case, CamelCase and then UPPER_SNAKE_CASE, globs and groups.
+#[derive(PartialEq, Eq)]
+enum Segment<'a> {
+    Ident(&'a str, Option<&'a str>),
+    Glob,
+    Group(&'a [ImportTree]),
+}
+
+impl<'a> Segment<'a> {
+    fn rank(&self) -> u8 {
+        match self {
+            Self::Ident("self", _) => 0,
+            Self::Ident("super", _) => 1,
+            Self::Ident("crate", _) => 2,
+            Self::Ident(..) => 3,
+            Self::Glob => 4,
+            Self::Group(_) => 5,
+        }
+    }
+}
+
+fn is_upper_snake_case(name: &str) -> bool {
+    name.chars()
+        .all(|c| c.is_uppercase() || c.is_ascii_digit() || c == '_')
+}
+
+fn name_case_rank(name: &str) -> u8 {
+    if is_upper_snake_case(name) && name.chars().any(char::is_uppercase) {
+        2
+    } else if name.starts_with(char::is_uppercase) {
+        1
+    } else {
+        0
+    }
+}
+
+impl<'a> Ord for Segment<'a> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        match (self, other) {
+            (Self::Ident(a, a_alias), Self::Ident(b, b_alias)) if self.rank() == other.rank() => {
+                name_case_rank(a)
+                    .cmp(&name_case_rank(b))
+                    .then_with(|| a.cmp(b))
+                    .then_with(|| a_alias.cmp(b_alias))
+            }
+            (Self::Group(a), Self::Group(b)) => a.cmp(b),
+            _ => self.rank().cmp(&other.rank()),
+        }
+    }
+}
+
+impl<'a> PartialOrd for Segment<'a> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for ImportTree {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.segments().cmp(&other.segments())
+    }
+}
+
+impl PartialOrd for ImportTree {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+/// The imported names of several imports, merged by their common path prefixes.
+#[derive(Default)]
+struct ImportTrie {
+    names: Vec<ImportTree>,
+    children: Vec<(String, ImportTrie)>,
+}
+
+impl ImportTrie {
+    fn insert(&mut self, path: &[String], name: ImportTree) {
+        match path.split_first() {
+            None => {
+                if !self.names.contains(&name) {
+                    self.names.push(name);
+                }
+            }
+            Some((segment, rest)) => {
+                let ix = match self.children.iter().position(|(child, _)| child == segment) {
+                    Some(ix) => ix,
+                    None => {
+                        self.children.push((segment.clone(), ImportTrie::default()));
+                        self.children.len() - 1
+                    }
+                };
+                self.children[ix].1.insert(rest, name);
+            }
+        }
+    }
+
+    fn into_trees(self) -> Vec<ImportTree> {
+        let mut trees = self.names;
+        for (segment, child) in self.children {
+            let mut suffixes = child.into_trees();
+            let suffix = match suffixes.len() {
+                1 => suffixes.pop().unwrap(),
+                _ => ImportTree::Group(suffixes),
+            };
+            trees.push(ImportTree::Path(segment, Box::new(suffix)));
+        }
+        trees
+    }
+}
+
+/// Split the imports into groups according to `group_imports`, given whether a blank line
+/// preceded each of them.
+fn group_imports(imports: Vec<(Import, bool)>, group_imports: GroupImports) -> Vec<Vec<Import>> {
+    let mut groups: Vec<Vec<Import>> = vec![];
+    match group_imports {
+        GroupImports::Preserve => {
+            for (import, blank_line_before) in imports {
+                match groups.last_mut() {
+                    Some(group) if !blank_line_before => group.push(import),
+                    _ => groups.push(vec![import]),
+                }
+            }
+        }
+        GroupImports::StdExternalCrate => {
+            let mut std_external_crate = [vec![], vec![], vec![]];
+            for (import, _) in imports {
+                let ix = match import.tree.first_segment() {
+                    Some("std" | "core") => 0,
+                    Some("self" | "super" | "crate") => 2,
+                    _ => 1,
+                };
+                std_external_crate[ix].push(import);
+            }
+            groups.extend(
+                std_external_crate
+                    .into_iter()
+                    .filter(|group| !group.is_empty()),
+            );
+        }
+        GroupImports::One => groups.push(imports.into_iter().map(|(import, _)| import).collect()),
+    }
+    groups
+}
+
+/// Merge or split the imports of a group according to the `imports_granularity`. Imports with
+/// different visibility are never merged.
+fn merge_imports(imports: Vec<Import>, granularity: ImportGranularity) -> Vec<Import> {
+    if let ImportGranularity::Preserve = granularity {
+        return imports;
+    }
+    let mut merged = vec![];
+    for is_pub in [false, true] {
+        let mut paths = vec![];
+        for import in imports.iter().filter(|import| import.is_pub == is_pub) {
+            import.tree.flatten(&mut vec![], &mut paths);
+        }
+        let mut tries: Vec<(Option<Vec<String>>, ImportTrie)> = vec![];
+        for (path, name) in paths {
+            // The imports sharing a key are merged into a single `use` item.
+            let key = match granularity {
+                ImportGranularity::Item => None,
+                ImportGranularity::Module => Some(path.clone()),
+                ImportGranularity::Crate => Some(path.iter().take(1).cloned().collect()),
+                ImportGranularity::One | ImportGranularity::Preserve => Some(vec![]),
+            };
+            let ix = match tries.iter().position(|(k, _)| key.is_some() && k == &key) {
+                Some(ix) => ix,
+                None => {
+                    tries.push((key, ImportTrie::default()));
+                    tries.len() - 1
+                }
+            };
+            tries[ix].1.insert(&path, name);
+        }
+        for (_key, trie) in tries {
+            let mut trees = trie.into_trees();
+            let tree = match trees.len() {
+                1 => trees.pop().unwrap(),
+                _ => ImportTree::Group(trees),
+            };
+            merged.push(Import { is_pub, tree });
+        }
+    }
+    merged
+}
+
+impl Format for Import {
+    fn format(
+        &self,
+        formatted_code: &mut FormattedCode,
+        formatter: &mut Formatter,
+    ) -> Result<(), FormatterError> {
+        if self.is_pub {
+            formatted_code.push_str("pub ");
+        }
+        formatted_code.push_str("use ");
+        self.tree.format(formatted_code, formatter)?;
+        formatted_code.push(';');
+        Ok(())
+    }
+}
+
+impl Format for ImportTree {
+    fn format(
+        &self,
+        formatted_code: &mut FormattedCode,
+        formatter: &mut Formatter,
+    ) -> Result<(), FormatterError> {
+        match self {
+            Self::Path(prefix, suffix) => {
+                formatted_code.push_str(prefix);
+                formatted_code.push_str("::");
+                suffix.format(formatted_code, formatter)?;
+            }
+            Self::Name(name) => formatted_code.push_str(name),
+            Self::Rename(name, alias) => {
+                formatted_code.push_str(name);
+                formatted_code.push_str(" as ");
+                formatted_code.push_str(alias);
+            }
+            Self::Glob => formatted_code.push('*'),
+            Self::Group(trees) => {
+                // The trees are not part of the source, so have no spans within it.
+                let items = trees
+                    .iter()
+                    .map(|tree| ListItem::new(Span::dummy(), tree))
+                    .collect();
+                let imports = &formatter.config.imports;
+                let has_nested_groups = trees.iter().any(|tree| {
+                    let mut paths = vec![];
+                    tree.flatten(&mut vec![], &mut paths);
+                    paths.len() > 1
+                });
+                let tactic = match imports.imports_layout {
+                    ListTactic::Mixed if has_nested_groups => ListTactic::Vertical,
+                    tactic => tactic,
+                };
+                let indent_style = imports.imports_indent;
+                let max_width = formatter.config.whitespace.max_width;
+                let mut list = List::new("{", "}", items, Span::dummy(), max_width, formatter);
+                list.tactic = tactic;
+                list.indent_style = indent_style;
+                list.format(formatted_code, formatter)?;
+            }
+        }
+        Ok(())
     }
 }
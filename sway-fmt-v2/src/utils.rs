@@ -1,7 +1,15 @@
 pub mod attribute;
 pub mod bracket;
+pub mod code_block;
+pub mod comments;
+pub mod dependency;
+pub mod expr;
 pub mod indent_style;
 pub mod item;
-pub mod item_len;
+pub mod lists;
+pub mod literal;
 pub mod newline_style;
+pub mod path;
+pub mod pattern;
 pub mod program_type;
+pub mod ty;
@@ -1,5 +1,6 @@
 use crate::{
     fmt::{Format, FormattedCode, Formatter},
+    utils::comments::{write_comments_before, write_doc_comment},
     FormatterError,
 };
 use std::fmt::Write;
@@ -8,23 +9,63 @@ use sway_parse::{
     token::Delimiter,
     Parse,
 };
-use sway_types::Spanned;
+use sway_types::{Span, Spanned};
 
 use super::bracket::{Parenthesis, SquareBracket};
 
-impl<T: Parse + Format> Format for Annotated<T> {
+impl<T: Parse + Format + Spanned> Format for Annotated<T> {
     fn format(
         &self,
         formatted_code: &mut FormattedCode,
         formatter: &mut Formatter,
     ) -> Result<(), FormatterError> {
-        let attributes = &self.attribute_list;
+        write_attributes(
+            &self.attribute_list,
+            self.value.span().start(),
+            formatted_code,
+            formatter,
+        )?;
+        self.value.format(formatted_code, formatter)
+    }
+}
 
-        for attr in attributes {
+/// Write the attributes of a value beginning at `value_start`, each on its own line and separated
+/// by the comments between them.
+///
+/// Each attribute ends with a newline, so the next line is indented to match the first, which the
+/// caller has indented.
+pub(crate) fn write_attributes(
+    attributes: &[AttributeDecl],
+    value_start: usize,
+    formatted_code: &mut FormattedCode,
+    formatter: &mut Formatter,
+) -> Result<(), FormatterError> {
+    let mut prev_end = None;
+    for attr in attributes {
+        let span = attr.span();
+        if prev_end.is_some() {
+            write_comments_before(formatted_code, formatter, prev_end, span.start())?;
+            formatted_code.push_str(&formatter.indent_str());
+        }
+        if attr.is_doc_comment() {
+            write_doc_comment(formatted_code, formatter, span.start()..span.end())?;
+        } else {
             attr.format(formatted_code, formatter)?;
         }
+        prev_end = Some(span.end());
+    }
+    if prev_end.is_some() {
+        write_comments_before(formatted_code, formatter, prev_end, value_start)?;
+        formatted_code.push_str(&formatter.indent_str());
+    }
+    Ok(())
+}
 
-        self.value.format(formatted_code, formatter)
+/// The span of an annotated value, including its attributes.
+pub(crate) fn annotated_span<T: Parse + Spanned>(annotated: &Annotated<T>) -> Span {
+    match annotated.attribute_list.first() {
+        Some(attr) => Span::join(attr.span(), annotated.value.span()),
+        None => annotated.value.span(),
     }
 }
 
@@ -45,27 +86,18 @@ impl FormatDecl for AttributeDecl {
         let attr = self.attribute.clone().into_inner();
         // name e.g. `storage`
         line.push_str(attr.name.span().as_str());
-        // `(`
-        Self::open_parenthesis(line, formatter)?;
-        // format and add args `read, write`
+        // format and add args e.g. `(read, write)`
         if let Some(args) = attr.args {
-            let args = args.into_inner().value_separator_pairs;
-            let mut buf = args
-                .iter()
-                .map(|arg| format!("{}{}", arg.0.as_str(), arg.1.span().as_str()))
+            Self::open_parenthesis(line, formatter)?;
+            let args = args
+                .into_inner()
+                .into_iter()
+                .map(|arg| arg.as_str().to_string())
                 .collect::<Vec<String>>()
-                .join(" ");
-            if args.len() == 1 {
-                buf.pop(); // pop the ending comma
-                line.push_str(&buf);
-            } else {
-                buf.pop(); // pop the ending space
-                buf.pop(); // pop the ending comma
-                line.push_str(&buf);
-            }
+                .join(", ");
+            line.push_str(&args);
+            Self::close_parenthesis(line, formatter)?;
         }
-        // ')'
-        Self::close_parenthesis(line, formatter)?;
         // `]\n`
         Self::close_square_bracket(line, formatter)?;
         Ok(())
@@ -1,20 +1,58 @@
 //! The purpose of this file is to house the traits and associated functions for formatting opening and closing delimiters.
 //! This allows us to avoid matching a second time for the `ItemKind` and keeps the code pertaining to individual formatting
 //! contained to each item's file.
-use crate::{Formatter, FormatterError};
+use crate::{config::items::ItemBraceStyle, Formatter, FormatterError};
+use sway_parse::token::Delimiter;
 
 pub trait CurlyBrace {
     /// Handles brace open scenerio. Checks the config for the placement of the brace.
     /// Modifies the current shape of the formatter.
-    fn open_curly_brace(line: &mut String, formatter: &mut Formatter)
-        -> Result<(), FormatterError>;
+    ///
+    /// With `SameLineWhere`, the brace begins a new line if `line` ends with a where clause,
+    /// which is followed by a newline.
+    fn open_curly_brace(
+        line: &mut String,
+        formatter: &mut Formatter,
+    ) -> Result<(), FormatterError> {
+        let follows_where_clause = line.ends_with('\n');
+        match formatter.config.items.item_brace_style {
+            ItemBraceStyle::AlwaysNextLine => {
+                if !follows_where_clause {
+                    line.push('\n');
+                }
+                line.push_str(&formatter.indent_str());
+            }
+            ItemBraceStyle::PreferSameLine => {
+                if follows_where_clause {
+                    line.pop();
+                }
+                line.push(' ');
+            }
+            ItemBraceStyle::SameLineWhere => {
+                if follows_where_clause {
+                    line.push_str(&formatter.indent_str());
+                } else {
+                    line.push(' ');
+                }
+            }
+        }
+        line.push(Delimiter::Brace.as_open_char());
+        formatter.indent();
+        Ok(())
+    }
 
     /// Handles brace close scenerio.
-    /// Currently it simply pushes a `}` and modifies the shape.
+    /// Restores the shape of the formatter and pushes a `}` at its indentation, the contents
+    /// having ended with a newline.
     fn close_curly_brace(
         line: &mut String,
         formatter: &mut Formatter,
-    ) -> Result<(), FormatterError>;
+    ) -> Result<(), FormatterError> {
+        formatter.unindent();
+        line.push_str(&formatter.indent_str());
+        line.push(Delimiter::Brace.as_close_char());
+        Ok(())
+    }
 }
 
 pub trait SquareBracket {
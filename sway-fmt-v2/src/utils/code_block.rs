@@ -0,0 +1,171 @@
+use crate::{
+    fmt::{Format, FormattedCode, Formatter},
+    utils::{
+        comments::{write_comments_before, write_comments_before_close, write_trailing_comments},
+        expr::is_block_like,
+        ty::write_ty_annotation,
+    },
+    FormatterError,
+};
+use sway_parse::{Braces, CodeBlockContents, Expr, Statement, StatementLet};
+use sway_types::Spanned;
+
+/// Write a block of code, e.g. `{ let a = 5; a }`, on multiple lines unless it is empty.
+pub(crate) fn write_code_block(
+    block: &Braces<CodeBlockContents>,
+    formatted_code: &mut FormattedCode,
+    formatter: &mut Formatter,
+) -> Result<(), FormatterError> {
+    formatted_code.push('{');
+    formatter.indent();
+    write_code_block_contents(block, formatted_code, formatter)
+}
+
+/// Write the contents of a block of code and its closing brace, the opening brace having
+/// already been written and the indentation increased.
+pub(crate) fn write_code_block_contents(
+    block: &Braces<CodeBlockContents>,
+    formatted_code: &mut FormattedCode,
+    formatter: &mut Formatter,
+) -> Result<(), FormatterError> {
+    let contents = block.get();
+    let span = block.span();
+    let (open_end, close_start) = (span.start() + 1, span.end() - 1);
+    if contents.statements.is_empty()
+        && contents.final_expr_opt.is_none()
+        && !formatter.comments.has_comments_in(open_end..close_start)
+    {
+        formatter.unindent();
+        formatted_code.push('}');
+        return Ok(());
+    }
+    write_trailing_comments(formatted_code, formatter, open_end)?;
+    formatted_code.push('\n');
+
+    let trailing_semicolon = formatter.config.expressions.trailing_semicolon;
+    let mut prev_end = None;
+    for (ix, statement) in contents.statements.iter().enumerate() {
+        let statement_span = statement.span();
+        write_comments_before(formatted_code, formatter, prev_end, statement_span.start())?;
+        formatted_code.push_str(&formatter.indent_str());
+        match statement {
+            // Without `trailing_semicolon`, a final `return` statement loses its `;`.
+            Statement::Expr {
+                expr: expr @ Expr::Return { .. },
+                semicolon_token_opt: Some(..),
+            } if !trailing_semicolon
+                && ix + 1 == contents.statements.len()
+                && contents.final_expr_opt.is_none() =>
+            {
+                expr.format(formatted_code, formatter)?
+            }
+            _ => statement.format(formatted_code, formatter)?,
+        }
+        write_trailing_comments(formatted_code, formatter, statement_span.end())?;
+        formatted_code.push('\n');
+        prev_end = Some(statement_span.end());
+    }
+    if let Some(final_expr) = &contents.final_expr_opt {
+        let final_expr_span = final_expr.span();
+        write_comments_before(formatted_code, formatter, prev_end, final_expr_span.start())?;
+        formatted_code.push_str(&formatter.indent_str());
+        final_expr.format(formatted_code, formatter)?;
+        // With `trailing_semicolon`, a final `return` gains a `;`.
+        if trailing_semicolon && matches!(**final_expr, Expr::Return { .. }) {
+            formatted_code.push(';');
+        }
+        write_trailing_comments(formatted_code, formatter, final_expr_span.end())?;
+        formatted_code.push('\n');
+        prev_end = Some(final_expr_span.end());
+    }
+
+    write_comments_before_close(formatted_code, formatter, prev_end, close_start)?;
+    formatter.unindent();
+    formatted_code.push_str(&formatter.indent_str());
+    formatted_code.push('}');
+    Ok(())
+}
+
+impl Format for Statement {
+    fn format(
+        &self,
+        formatted_code: &mut FormattedCode,
+        formatter: &mut Formatter,
+    ) -> Result<(), FormatterError> {
+        match self {
+            Self::Let(statement_let) => statement_let.format(formatted_code, formatter),
+            Self::Item(item) => item.format(formatted_code, formatter),
+            Self::Expr {
+                expr,
+                semicolon_token_opt,
+            } => {
+                match semicolon_token_opt {
+                    Some(semicolon_token) => {
+                        formatter.with_reserved_width(1, |formatter| {
+                            expr.format(formatted_code, formatter)
+                        })?;
+                        formatted_code.push_str(semicolon_token.span().as_str());
+                    }
+                    None => expr.format(formatted_code, formatter)?,
+                }
+                Ok(())
+            }
+        }
+    }
+}
+
+impl Format for StatementLet {
+    fn format(
+        &self,
+        formatted_code: &mut FormattedCode,
+        formatter: &mut Formatter,
+    ) -> Result<(), FormatterError> {
+        // `let `
+        formatted_code.push_str(self.let_token.span().as_str());
+        formatted_code.push(' ');
+        // pattern, e.g. `mut a`
+        self.pattern.format(formatted_code, formatter)?;
+        // `: ty`
+        if let Some((_colon_token, ty)) = &self.ty_opt {
+            write_ty_annotation(ty, formatted_code, formatter)?;
+        }
+        // ` = expr;`
+        formatted_code.push(' ');
+        formatted_code.push_str(self.eq_token.span().as_str());
+        formatter.with_reserved_width(1, |formatter| {
+            write_rhs(&self.expr, formatted_code, formatter)
+        })?;
+        formatted_code.push_str(self.semicolon_token.span().as_str());
+        Ok(())
+    }
+}
+
+/// Write the right hand side of an assignment, preceded by a space, moving it onto the next line
+/// if that helps it fit within the `max_width`.
+pub(crate) fn write_rhs(
+    expr: &Expr,
+    formatted_code: &mut FormattedCode,
+    formatter: &mut Formatter,
+) -> Result<(), FormatterError> {
+    let checkpoint = formatter.checkpoint(formatted_code);
+    let start = formatted_code.len();
+    formatted_code.push(' ');
+    expr.format(formatted_code, formatter)?;
+    if formatter.single_line || is_block_like(expr) || formatter.lines_fit(formatted_code, start) {
+        return Ok(());
+    }
+    formatter.restore(formatted_code, checkpoint);
+    formatter.indent();
+    formatted_code.push('\n');
+    formatted_code.push_str(&formatter.indent_str());
+    let next_line_start = formatted_code.len();
+    expr.format(formatted_code, formatter)?;
+    formatter.unindent();
+    // Keep the expression on the same line if moving it doesn't help.
+    if !formatter.lines_fit(formatted_code, next_line_start) {
+        formatter.restore(formatted_code, checkpoint);
+        formatted_code.push(' ');
+        expr.format(formatted_code, formatter)?;
+    }
+    Ok(())
+}
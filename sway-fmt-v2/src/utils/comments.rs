@@ -0,0 +1,354 @@
+//! Comments are not part of the parsed syntax tree, so they are collected from the source
+//! separately and written back out between the elements of items and blocks.
+//!
+//! Comments are emitted at the boundaries between elements, such as items, statements, fields
+//! and list items. A comment that lies within an element, e.g. in the middle of an expression,
+//! is emitted at the next boundary so that it is never lost.
+use crate::{
+    fmt::{FormattedCode, Formatter},
+    FormatterError,
+};
+use std::{ops::Range, sync::Arc};
+
+/// A single `//` or `/* */` comment within the source.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Comment {
+    /// The byte range of the comment within the source.
+    pub span: Range<usize>,
+    /// The text of the comment, including its delimiters.
+    pub text: String,
+}
+
+impl Comment {
+    fn is_block(&self) -> bool {
+        self.text.starts_with("/*")
+    }
+}
+
+/// The comments of a source file, tracking which have already been written out.
+#[derive(Clone, Debug, Default)]
+pub struct CommentMap {
+    src: Arc<str>,
+    comments: Vec<Comment>,
+    emitted: Vec<bool>,
+    /// The indices of emitted comments in the order they were emitted, so that emission may be
+    /// undone when formatting is retried with a different layout.
+    log: Vec<usize>,
+}
+
+/// A point to which the [CommentMap] can be restored.
+#[derive(Clone, Copy, Debug)]
+pub struct CommentCheckpoint {
+    log_len: usize,
+}
+
+impl CommentMap {
+    /// Collect the comments from the given source.
+    pub fn from_src(src: Arc<str>) -> Self {
+        let comments = scan_comments(&src);
+        Self {
+            emitted: vec![false; comments.len()],
+            comments,
+            src,
+            log: vec![],
+        }
+    }
+
+    pub fn checkpoint(&self) -> CommentCheckpoint {
+        CommentCheckpoint {
+            log_len: self.log.len(),
+        }
+    }
+
+    /// Mark the comments emitted since the checkpoint as pending once more.
+    pub fn restore(&mut self, checkpoint: CommentCheckpoint) {
+        while self.log.len() > checkpoint.log_len {
+            let ix = self.log.pop().unwrap();
+            self.emitted[ix] = false;
+        }
+    }
+
+    /// Whether any pending comment begins within the given byte range.
+    pub fn has_comments_in(&self, range: Range<usize>) -> bool {
+        self.pending_in(range).next().is_some()
+    }
+
+    /// The number of newlines within the given byte range of the source.
+    pub fn newlines_in(&self, range: Range<usize>) -> usize {
+        self.src
+            .get(range)
+            .map(|text| text.matches('\n').count())
+            .unwrap_or_default()
+    }
+
+    /// Take the pending comments that begin before `pos`.
+    fn take_before(&mut self, pos: usize) -> Vec<Comment> {
+        let ixs: Vec<usize> = self.pending_in(0..pos).collect();
+        ixs.into_iter().map(|ix| self.take(ix)).collect()
+    }
+
+    /// Take the pending comments that begin within the given byte range.
+    fn take_in(&mut self, range: Range<usize>) -> Vec<Comment> {
+        let ixs: Vec<usize> = self.pending_in(range).collect();
+        ixs.into_iter().map(|ix| self.take(ix)).collect()
+    }
+
+    /// Take the pending comments that follow `pos` on the same line, with only whitespace and
+    /// separators in between.
+    fn take_trailing(&mut self, mut pos: usize) -> Vec<Comment> {
+        let mut trailing = vec![];
+        let ixs: Vec<usize> = self.pending_in(pos..self.src.len()).collect();
+        for ix in ixs {
+            let comment = &self.comments[ix];
+            let between = &self.src[pos..comment.span.start];
+            if !between.chars().all(|c| matches!(c, ' ' | '\t' | ',' | ';')) {
+                break;
+            }
+            pos = comment.span.end;
+            let is_multiline = comment.text.contains('\n');
+            trailing.push(self.take(ix));
+            if is_multiline || !trailing.last().unwrap().is_block() {
+                break;
+            }
+        }
+        trailing
+    }
+
+    fn take(&mut self, ix: usize) -> Comment {
+        self.emitted[ix] = true;
+        self.log.push(ix);
+        self.comments[ix].clone()
+    }
+
+    fn pending_in(&self, range: Range<usize>) -> impl Iterator<Item = usize> + '_ {
+        let start = self
+            .comments
+            .partition_point(|comment| comment.span.start < range.start);
+        let end = self
+            .comments
+            .partition_point(|comment| comment.span.start < range.end);
+        (start..end.max(start)).filter(move |ix| !self.emitted[*ix])
+    }
+}
+
+/// Write the comments and blank lines separating two elements of a block, such as items,
+/// statements or fields, where the next element begins at `next_start`.
+///
+/// `prev_end` is the end of the previous element, or `None` for the first element of a block, in
+/// which case leading blank lines are removed. Otherwise, blank lines within the source are kept
+/// within the bounds given by the config.
+pub(crate) fn write_comments_before(
+    formatted_code: &mut FormattedCode,
+    formatter: &mut Formatter,
+    prev_end: Option<usize>,
+    next_start: usize,
+) -> Result<(), FormatterError> {
+    write_comments(formatted_code, formatter, prev_end, next_start, true)
+}
+
+/// Write the comments between the last element of a block, ending at `prev_end`, and its closing
+/// delimiter at `close_pos`. Blank lines before the closing delimiter are removed.
+pub(crate) fn write_comments_before_close(
+    formatted_code: &mut FormattedCode,
+    formatter: &mut Formatter,
+    prev_end: Option<usize>,
+    close_pos: usize,
+) -> Result<(), FormatterError> {
+    write_comments(formatted_code, formatter, prev_end, close_pos, false)
+}
+
+/// Write the comments that follow `pos` on the same line, e.g. `let x = 5; // five`.
+pub(crate) fn write_trailing_comments(
+    formatted_code: &mut FormattedCode,
+    formatter: &mut Formatter,
+    pos: usize,
+) -> Result<(), FormatterError> {
+    for comment in formatter.comments.take_trailing(pos) {
+        formatted_code.push(' ');
+        formatted_code.push_str(&normalize(&comment, formatter));
+    }
+    Ok(())
+}
+
+/// Write a `///` doc comment, which the parser keeps as a `doc` attribute, followed by a newline.
+///
+/// The comment is also found within the [CommentMap], where it's marked as written.
+pub(crate) fn write_doc_comment(
+    formatted_code: &mut FormattedCode,
+    formatter: &mut Formatter,
+    span: Range<usize>,
+) -> Result<(), FormatterError> {
+    let text = formatter.comments.src[span.clone()].trim_end().to_string();
+    formatter.comments.take_in(span);
+    for line in wrap(&text, formatter) {
+        formatted_code.push_str(&line);
+    }
+    formatted_code.push('\n');
+    Ok(())
+}
+
+fn write_comments(
+    formatted_code: &mut FormattedCode,
+    formatter: &mut Formatter,
+    prev_end: Option<usize>,
+    next_start: usize,
+    blank_line_before_next: bool,
+) -> Result<(), FormatterError> {
+    let comments = formatter.comments.take_before(next_start);
+    let mut last_end = prev_end;
+    for comment in &comments {
+        write_blank_lines(formatted_code, formatter, last_end, comment.span.start);
+        formatted_code.push_str(&formatter.shape.indent.to_string(formatter));
+        for line in wrap(&normalize(comment, formatter), formatter) {
+            formatted_code.push_str(&line);
+        }
+        formatted_code.push('\n');
+        last_end = Some(comment.span.end);
+    }
+    if blank_line_before_next {
+        write_blank_lines(formatted_code, formatter, last_end, next_start);
+    }
+    Ok(())
+}
+
+/// Write the blank lines found between `prev_end` and `next_start`, bounded by the config.
+fn write_blank_lines(
+    formatted_code: &mut FormattedCode,
+    formatter: &Formatter,
+    prev_end: Option<usize>,
+    next_start: usize,
+) {
+    let prev_end = match prev_end {
+        Some(prev_end) if prev_end <= next_start => prev_end,
+        _ => return,
+    };
+    let newlines = formatter.comments.newlines_in(prev_end..next_start);
+    let items = &formatter.config.items;
+    let blank_lines = newlines
+        .saturating_sub(1)
+        .max(items.blank_lines_lower_bound)
+        .min(items.blank_lines_upper_bound);
+    for _ in 0..blank_lines {
+        formatted_code.push('\n');
+    }
+}
+
+/// Convert a single line `/* */` comment into a `//` comment if `normalize_comments` is set.
+fn normalize(comment: &Comment, formatter: &Formatter) -> String {
+    let text = &comment.text;
+    let is_plain_block = text.starts_with("/*")
+        && !text.starts_with("/**")
+        && !text.starts_with("/*!")
+        && !text.contains('\n');
+    if formatter.config.comments.normalize_comments && is_plain_block {
+        let inner = text[2..text.len() - 2].trim();
+        if !inner.contains("*/") && !inner.contains("/*") {
+            return format!("// {}", inner).trim_end().to_string();
+        }
+    }
+    text.clone()
+}
+
+/// Break a `//` comment into lines that fit within `comment_width` if `wrap_comments` is set.
+///
+/// Each line but the first is preceded by the current indentation, and all but the last are
+/// followed by a newline.
+fn wrap(text: &str, formatter: &Formatter) -> Vec<String> {
+    let comments = &formatter.config.comments;
+    let indent = formatter.shape.indent;
+    let max_len = comments
+        .comment_width
+        .min(formatter.config.whitespace.max_width)
+        .saturating_sub(indent.width());
+    let prefix = ["///", "//!", "//"]
+        .into_iter()
+        .find(|prefix| text.starts_with(prefix));
+    let prefix = match prefix {
+        Some(prefix) if comments.wrap_comments && text.chars().count() > max_len => prefix,
+        _ => return vec![text.to_string()],
+    };
+    let mut lines = vec![];
+    let mut line = prefix.to_string();
+    for word in text[prefix.len()..].split_whitespace() {
+        if line.len() > prefix.len() && line.chars().count() + 1 + word.chars().count() > max_len {
+            lines.push(line);
+            line = prefix.to_string();
+        }
+        line.push(' ');
+        line.push_str(word);
+    }
+    lines.push(line);
+    let indent = indent.to_string(formatter);
+    let num_lines = lines.len();
+    lines
+        .into_iter()
+        .enumerate()
+        .map(|(ix, line)| {
+            let mut line = if ix == 0 {
+                line
+            } else {
+                format!("{}{}", indent, line)
+            };
+            if ix + 1 < num_lines {
+                line.push('\n');
+            }
+            line
+        })
+        .collect()
+}
+
+/// Find the comments within the source, skipping over string and character literals.
+fn scan_comments(src: &str) -> Vec<Comment> {
+    let bytes = src.as_bytes();
+    let mut comments = vec![];
+    let mut ix = 0;
+    while ix < bytes.len() {
+        match (bytes[ix], bytes.get(ix + 1)) {
+            (quote @ (b'"' | b'\''), _) => {
+                ix += 1;
+                while ix < bytes.len() && bytes[ix] != quote {
+                    ix += if bytes[ix] == b'\\' { 2 } else { 1 };
+                }
+                ix += 1;
+            }
+            (b'/', Some(b'/')) => {
+                let start = ix;
+                while ix < bytes.len() && bytes[ix] != b'\n' {
+                    ix += 1;
+                }
+                let text = src[start..ix].trim_end().to_string();
+                comments.push(Comment {
+                    span: start..start + text.len(),
+                    text,
+                });
+            }
+            (b'/', Some(b'*')) => {
+                let start = ix;
+                let mut depth = 0;
+                while ix < bytes.len() {
+                    match (bytes[ix], bytes.get(ix + 1)) {
+                        (b'/', Some(b'*')) => {
+                            depth += 1;
+                            ix += 2;
+                        }
+                        (b'*', Some(b'/')) => {
+                            depth -= 1;
+                            ix += 2;
+                            if depth == 0 {
+                                break;
+                            }
+                        }
+                        _ => ix += 1,
+                    }
+                }
+                let end = ix.min(bytes.len());
+                comments.push(Comment {
+                    span: start..end,
+                    text: src[start..end].to_string(),
+                });
+            }
+            _ => ix += 1,
+        }
+    }
+    comments
+}
@@ -0,0 +1,69 @@
+use crate::{
+    fmt::{Format, FormattedCode, Formatter},
+    FormatterError,
+};
+use sway_parse::Dependency;
+use sway_types::Spanned;
+
+impl Format for Dependency {
+    fn format(
+        &self,
+        formatted_code: &mut FormattedCode,
+        _formatter: &mut Formatter,
+    ) -> Result<(), FormatterError> {
+        // `dep `
+        formatted_code.push_str(self.dep_token.span().as_str());
+        formatted_code.push(' ');
+        // `path/to/module`
+        formatted_code.push_str(self.path.prefix.as_str());
+        for (_forward_slash_token, suffix) in &self.path.suffixes {
+            formatted_code.push('/');
+            formatted_code.push_str(suffix.as_str());
+        }
+        // `;`
+        formatted_code.push_str(self.semicolon_token.span().as_str());
+        Ok(())
+    }
+}
+
+/// Returns the dependencies in the order they should be written.
+///
+/// If `reorder_modules` is set, each group of dependencies separated by blank lines is sorted by
+/// path. Dependencies are left in place if there are comments between them.
+pub(crate) fn sorted_dependencies<'a>(
+    dependencies: &'a [Dependency],
+    formatter: &Formatter,
+) -> Vec<&'a Dependency> {
+    let mut sorted: Vec<&Dependency> = dependencies.iter().collect();
+    let (first, last) = match (dependencies.first(), dependencies.last()) {
+        (Some(first), Some(last)) => (first.span(), last.span()),
+        _ => return sorted,
+    };
+    if !formatter.config.ordering.reorder_modules
+        || formatter
+            .comments
+            .has_comments_in(first.start()..last.end())
+    {
+        return sorted;
+    }
+    let path = |dependency: &Dependency| {
+        let span = dependency.path.span();
+        span.as_str().to_string()
+    };
+    let mut group_start = 0;
+    for ix in 0..sorted.len() {
+        // A group ends at a dependency which is followed by a blank line.
+        let ends_group = match sorted.get(ix + 1) {
+            Some(next) => {
+                let range = sorted[ix].span().end()..next.span().start();
+                formatter.comments.newlines_in(range) > 1
+            }
+            None => true,
+        };
+        if ends_group {
+            sorted[group_start..=ix].sort_by_key(|dependency| path(dependency));
+            group_start = ix + 1;
+        }
+    }
+    sorted
+}
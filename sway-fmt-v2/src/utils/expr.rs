@@ -0,0 +1,913 @@
+use crate::{
+    config::{
+        expr::ExprBraceStyle,
+        lists::{ListTactic, SeparatorTactic},
+    },
+    fmt::{Format, FormattedCode, Formatter},
+    utils::{
+        code_block::{write_code_block, write_code_block_contents, write_rhs},
+        comments::{write_comments_before, write_comments_before_close, write_trailing_comments},
+        lists::{List, ListItem},
+        ty::{write_colon, write_ty_annotation},
+    },
+    FormatterError,
+};
+use std::ops::ControlFlow;
+use sway_parse::{
+    brackets::{Parens, SquareBrackets},
+    keywords::CommaToken,
+    punctuated::Punctuated,
+    AsmBlock, AsmRegisterDeclaration, Assignable, Braces, CodeBlockContents, Expr,
+    ExprArrayDescriptor, ExprStructField, ExprTupleDescriptor, ForIterable, IfCondition, IfExpr,
+    MatchBranch, MatchBranchKind,
+};
+use sway_types::{Ident, Span, Spanned};
+
+impl Format for Expr {
+    fn format(
+        &self,
+        formatted_code: &mut FormattedCode,
+        formatter: &mut Formatter,
+    ) -> Result<(), FormatterError> {
+        let width_heuristics = formatter.width_heuristics();
+        match self {
+            Self::Path(path) => path.format(formatted_code, formatter)?,
+            Self::Literal(literal) => literal.format(formatted_code, formatter)?,
+            Self::AbiCast { abi_token, args } => {
+                formatted_code.push_str(abi_token.span().as_str());
+                let abi_cast_args = args.get();
+                let items = vec![
+                    ListItem::new(abi_cast_args.name.span(), &abi_cast_args.name),
+                    ListItem::new(abi_cast_args.address.span(), &*abi_cast_args.address),
+                ];
+                let width = width_heuristics.fn_call_width;
+                List::new("(", ")", items, args.span(), width, formatter)
+                    .format(formatted_code, formatter)?;
+            }
+            Self::Struct { path, fields } => {
+                path.format(formatted_code, formatter)?;
+                formatted_code.push(' ');
+                struct_fields_list(fields, formatter).format(formatted_code, formatter)?;
+            }
+            Self::Tuple(tuple) => {
+                let exprs: Vec<&Expr> = match tuple.get() {
+                    ExprTupleDescriptor::Nil => vec![],
+                    ExprTupleDescriptor::Cons { head, tail, .. } => {
+                        std::iter::once(&**head).chain(tail).collect()
+                    }
+                };
+                let items = exprs
+                    .iter()
+                    .map(|expr| ListItem::new(expr.span(), *expr))
+                    .collect();
+                let width = width_heuristics.fn_call_width;
+                let mut list = List::new("(", ")", items, tuple.span(), width, formatter);
+                // A tuple of a single element requires a trailing comma, e.g. `(a,)`.
+                if exprs.len() == 1 {
+                    list.trailing_comma = SeparatorTactic::Always;
+                }
+                list.format(formatted_code, formatter)?;
+            }
+            Self::Parens(expr) => {
+                formatted_code.push('(');
+                expr.get().format(formatted_code, formatter)?;
+                formatted_code.push(')');
+            }
+            Self::Block(block) => write_code_block(block, formatted_code, formatter)?,
+            Self::Array(array) => match array.get() {
+                ExprArrayDescriptor::Sequence(exprs) => {
+                    let items = exprs
+                        .into_iter()
+                        .map(|expr| ListItem::new(expr.span(), expr))
+                        .collect();
+                    let width = width_heuristics.array_width;
+                    List::new("[", "]", items, array.span(), width, formatter)
+                        .format(formatted_code, formatter)?;
+                }
+                ExprArrayDescriptor::Repeat { value, length, .. } => {
+                    formatted_code.push('[');
+                    value.format(formatted_code, formatter)?;
+                    formatted_code.push_str("; ");
+                    length.format(formatted_code, formatter)?;
+                    formatted_code.push(']');
+                }
+            },
+            Self::Asm(asm_block) => asm_block.format(formatted_code, formatter)?,
+            Self::Return {
+                return_token,
+                expr_opt,
+            } => {
+                formatted_code.push_str(return_token.span().as_str());
+                if let Some(expr) = expr_opt {
+                    formatted_code.push(' ');
+                    expr.format(formatted_code, formatter)?;
+                }
+            }
+            Self::If(if_expr) => if_expr.format(formatted_code, formatter)?,
+            Self::Match {
+                match_token,
+                value,
+                branches,
+            } => {
+                formatted_code.push_str(match_token.span().as_str());
+                formatted_code.push(' ');
+                let value_start = formatted_code.len();
+                value.format(formatted_code, formatter)?;
+                write_expr_open_brace(formatted_code, formatter, value_start);
+                write_match_branches(branches, formatted_code, formatter)?;
+            }
+            Self::While {
+                while_token,
+                condition,
+                block,
+            } => {
+                formatted_code.push_str(while_token.span().as_str());
+                formatted_code.push(' ');
+                let condition_start = formatted_code.len();
+                condition.format(formatted_code, formatter)?;
+                write_expr_open_brace(formatted_code, formatter, condition_start);
+                write_code_block_contents(block, formatted_code, formatter)?;
+            }
+            Self::For {
+                for_token,
+                pattern,
+                in_token,
+                iterable,
+                block,
+            } => {
+                formatted_code.push_str(for_token.span().as_str());
+                formatted_code.push(' ');
+                let iterable_start = formatted_code.len();
+                pattern.format(formatted_code, formatter)?;
+                formatted_code.push(' ');
+                formatted_code.push_str(in_token.span().as_str());
+                formatted_code.push(' ');
+                iterable.format(formatted_code, formatter)?;
+                write_expr_open_brace(formatted_code, formatter, iterable_start);
+                write_code_block_contents(block, formatted_code, formatter)?;
+            }
+            Self::Break { break_token } => formatted_code.push_str(break_token.span().as_str()),
+            Self::Continue { continue_token } => {
+                formatted_code.push_str(continue_token.span().as_str())
+            }
+            Self::FuncApp { func, args } => {
+                func.format(formatted_code, formatter)?;
+                call_args_list(args, formatter).format(formatted_code, formatter)?;
+            }
+            Self::Index { .. }
+            | Self::MethodCall { .. }
+            | Self::FieldProjection { .. }
+            | Self::TupleFieldProjection { .. } => format_chain(self, formatted_code, formatter)?,
+            Self::Ref { ref_token, expr } => {
+                formatted_code.push_str(ref_token.span().as_str());
+                formatted_code.push(' ');
+                expr.format(formatted_code, formatter)?;
+            }
+            Self::Deref { deref_token, expr } => {
+                formatted_code.push_str(deref_token.span().as_str());
+                formatted_code.push(' ');
+                expr.format(formatted_code, formatter)?;
+            }
+            Self::Not { bang_token, expr } => {
+                formatted_code.push_str(bang_token.span().as_str());
+                expr.format(formatted_code, formatter)?;
+            }
+            Self::Reassignment {
+                assignable,
+                reassignment_op,
+                expr,
+            } => {
+                assignable.format(formatted_code, formatter)?;
+                formatted_code.push(' ');
+                formatted_code.push_str(reassignment_op.span.as_str());
+                write_rhs(expr, formatted_code, formatter)?;
+            }
+            _ => format_binary(self, formatted_code, formatter)?,
+        }
+        Ok(())
+    }
+}
+
+impl Format for ForIterable {
+    fn format(
+        &self,
+        formatted_code: &mut FormattedCode,
+        formatter: &mut Formatter,
+    ) -> Result<(), FormatterError> {
+        match self {
+            Self::Range {
+                start,
+                double_dot_token,
+                end,
+            } => {
+                start.format(formatted_code, formatter)?;
+                formatted_code.push_str(double_dot_token.span().as_str());
+                end.format(formatted_code, formatter)?;
+            }
+            Self::Expr(expr) => expr.format(formatted_code, formatter)?,
+        }
+        Ok(())
+    }
+}
+
+/// Whether the expression is written as a block spanning multiple lines, e.g. a `match`.
+pub(crate) fn is_block_like(expr: &Expr) -> bool {
+    matches!(
+        expr,
+        Expr::Block(..)
+            | Expr::Asm(..)
+            | Expr::If(..)
+            | Expr::Match { .. }
+            | Expr::While { .. }
+            | Expr::For { .. }
+            | Expr::Struct { .. }
+    )
+}
+
+/// Write the opening brace of a control flow block, e.g. following the condition of a `while`.
+///
+/// The brace begins a new line if the config requires it, or if the code written since `start`
+/// spans multiple lines. The indentation is increased for the contents of the block.
+fn write_expr_open_brace(
+    formatted_code: &mut FormattedCode,
+    formatter: &mut Formatter,
+    start: usize,
+) {
+    let next_line = matches!(
+        formatter.config.expressions.expr_brace_style,
+        ExprBraceStyle::AlwaysNextLine
+    ) || formatted_code[start..].contains('\n');
+    if next_line {
+        formatted_code.push('\n');
+        formatted_code.push_str(&formatter.indent_str());
+        formatted_code.push('{');
+    } else {
+        formatted_code.push_str(" {");
+    }
+    formatter.indent();
+}
+
+/// The arguments of a function or method call, e.g. `(a, b)`.
+fn call_args_list<'a>(
+    args: &'a Parens<Punctuated<Expr, CommaToken>>,
+    formatter: &Formatter,
+) -> List<'a> {
+    let exprs: Vec<&Expr> = args.get().into_iter().collect();
+    let items = exprs
+        .iter()
+        .map(|expr| ListItem::new(expr.span(), *expr))
+        .collect();
+    let width = formatter.width_heuristics().fn_call_width;
+    let mut list = List::new("(", ")", items, args.span(), width, formatter);
+    list.overflow_last = matches!(
+        exprs.last(),
+        Some(
+            Expr::Struct { .. }
+                | Expr::Block(..)
+                | Expr::Match { .. }
+                | Expr::Asm(..)
+                | Expr::Array(..)
+        )
+    );
+    list
+}
+
+/// The fields of a struct literal or the contract call arguments of a method call, e.g.
+/// `{ a: 1, b }`.
+fn struct_fields_list<'a>(
+    fields: &'a Braces<Punctuated<ExprStructField, CommaToken>>,
+    formatter: &Formatter,
+) -> List<'a> {
+    let items = fields
+        .get()
+        .into_iter()
+        .map(|field| ListItem::new(field.span(), field))
+        .collect();
+    let width = formatter.width_heuristics().struct_lit_width;
+    let mut list = List::new("{", "}", items, fields.span(), width, formatter);
+    list.padded = true;
+    if !formatter.config.structures.struct_lit_single_line {
+        list.tactic = ListTactic::Vertical;
+    }
+    list
+}
+
+impl Format for ExprStructField {
+    fn format(
+        &self,
+        formatted_code: &mut FormattedCode,
+        formatter: &mut Formatter,
+    ) -> Result<(), FormatterError> {
+        formatted_code.push_str(self.field_name.as_str());
+        if let Some((_colon_token, expr)) = &self.expr_opt {
+            write_colon(formatted_code, formatter);
+            expr.format(formatted_code, formatter)?;
+        }
+        Ok(())
+    }
+}
+
+impl Format for Assignable {
+    fn format(
+        &self,
+        formatted_code: &mut FormattedCode,
+        formatter: &mut Formatter,
+    ) -> Result<(), FormatterError> {
+        match self {
+            Self::Var(name) => formatted_code.push_str(name.as_str()),
+            Self::Index { target, arg } => {
+                target.format(formatted_code, formatter)?;
+                formatted_code.push('[');
+                arg.get().format(formatted_code, formatter)?;
+                formatted_code.push(']');
+            }
+            Self::FieldProjection {
+                target,
+                dot_token,
+                name,
+            } => {
+                target.format(formatted_code, formatter)?;
+                formatted_code.push_str(dot_token.span().as_str());
+                formatted_code.push_str(name.as_str());
+            }
+            Self::TupleFieldProjection {
+                target,
+                dot_token,
+                field_span,
+                ..
+            } => {
+                target.format(formatted_code, formatter)?;
+                formatted_code.push_str(dot_token.span().as_str());
+                formatted_code.push_str(field_span.as_str());
+            }
+        }
+        Ok(())
+    }
+}
+
+/// The operands of a binary operator expression, along with the operator.
+fn binary_parts(expr: &Expr) -> Option<(&Expr, &'static str, &Expr)> {
+    let parts = match expr {
+        Expr::Mul { lhs, rhs, .. } => (lhs, "*", rhs),
+        Expr::Div { lhs, rhs, .. } => (lhs, "/", rhs),
+        Expr::Modulo { lhs, rhs, .. } => (lhs, "%", rhs),
+        Expr::Add { lhs, rhs, .. } => (lhs, "+", rhs),
+        Expr::Sub { lhs, rhs, .. } => (lhs, "-", rhs),
+        Expr::Shl { lhs, rhs, .. } => (lhs, "<<", rhs),
+        Expr::Shr { lhs, rhs, .. } => (lhs, ">>", rhs),
+        Expr::BitAnd { lhs, rhs, .. } => (lhs, "&", rhs),
+        Expr::BitXor { lhs, rhs, .. } => (lhs, "^", rhs),
+        Expr::BitOr { lhs, rhs, .. } => (lhs, "|", rhs),
+        Expr::Equal { lhs, rhs, .. } => (lhs, "==", rhs),
+        Expr::NotEqual { lhs, rhs, .. } => (lhs, "!=", rhs),
+        Expr::LessThan { lhs, rhs, .. } => (lhs, "<", rhs),
+        Expr::GreaterThan { lhs, rhs, .. } => (lhs, ">", rhs),
+        Expr::LessThanEq { lhs, rhs, .. } => (lhs, "<=", rhs),
+        Expr::GreaterThanEq { lhs, rhs, .. } => (lhs, ">=", rhs),
+        Expr::LogicalAnd { lhs, rhs, .. } => (lhs, "&&", rhs),
+        Expr::LogicalOr { lhs, rhs, .. } => (lhs, "||", rhs),
+        _ => return None,
+    };
+    Some((&**parts.0, parts.1, &**parts.2))
+}
+
+/// Format a binary operator expression, e.g. `a + b + c`.
+///
+/// If it doesn't fit within the `max_width`, the line is broken before each operator:
+/// ```sway,ignore
+/// a
+///     + b
+///     + c
+/// ```
+fn format_binary(
+    expr: &Expr,
+    formatted_code: &mut FormattedCode,
+    formatter: &mut Formatter,
+) -> Result<(), FormatterError> {
+    let (mut first, op, rhs) = match binary_parts(expr) {
+        Some(parts) => parts,
+        None => {
+            formatted_code.push_str(expr.span().as_str());
+            return Ok(());
+        }
+    };
+    // Collect the operands of a chain of the same operator, which associates to the left.
+    let mut operands = vec![rhs];
+    while let Some((lhs, lhs_op, rhs)) = binary_parts(first) {
+        if lhs_op != op {
+            break;
+        }
+        operands.push(rhs);
+        first = lhs;
+    }
+    operands.reverse();
+
+    let write_single_line = |code: &mut FormattedCode, formatter: &mut Formatter| {
+        first.format(code, formatter)?;
+        for operand in &operands {
+            code.push(' ');
+            code.push_str(op);
+            code.push(' ');
+            operand.format(code, formatter)?;
+        }
+        Ok(())
+    };
+    if formatter.single_line {
+        return write_single_line(formatted_code, formatter);
+    }
+    if formatter.try_single_line(formatted_code, usize::MAX, write_single_line)? {
+        return Ok(());
+    }
+    first.format(formatted_code, formatter)?;
+    formatter.indent();
+    for operand in &operands {
+        formatted_code.push('\n');
+        formatted_code.push_str(&formatter.indent_str());
+        formatted_code.push_str(op);
+        formatted_code.push(' ');
+        operand.format(formatted_code, formatter)?;
+    }
+    formatter.unindent();
+    Ok(())
+}
+
+/// A field access, method call or index following an expression.
+enum ChainLink<'a> {
+    MethodCall {
+        name: &'a Ident,
+        contract_args_opt: &'a Option<Braces<Punctuated<ExprStructField, CommaToken>>>,
+        args: &'a Parens<Punctuated<Expr, CommaToken>>,
+    },
+    Field(&'a Ident),
+    TupleField(&'a Span),
+    Index(&'a SquareBrackets<Box<Expr>>),
+}
+
+impl<'a> ChainLink<'a> {
+    fn format(
+        &self,
+        formatted_code: &mut FormattedCode,
+        formatter: &mut Formatter,
+    ) -> Result<(), FormatterError> {
+        match self {
+            Self::MethodCall {
+                name,
+                contract_args_opt,
+                args,
+            } => {
+                formatted_code.push('.');
+                formatted_code.push_str(name.as_str());
+                if let Some(contract_args) = contract_args_opt {
+                    formatted_code.push(' ');
+                    struct_fields_list(contract_args, formatter)
+                        .format(formatted_code, formatter)?;
+                }
+                call_args_list(args, formatter).format(formatted_code, formatter)?;
+            }
+            Self::Field(name) => {
+                formatted_code.push('.');
+                formatted_code.push_str(name.as_str());
+            }
+            Self::TupleField(field_span) => {
+                formatted_code.push('.');
+                formatted_code.push_str(field_span.as_str());
+            }
+            Self::Index(arg) => {
+                formatted_code.push('[');
+                arg.get().format(formatted_code, formatter)?;
+                formatted_code.push(']');
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Split a chain such as `a.b.c()[0]` into its root expression and the links that follow.
+fn flatten_chain(expr: &Expr) -> (&Expr, Vec<ChainLink<'_>>) {
+    let mut links = vec![];
+    let mut current = expr;
+    loop {
+        current = match current {
+            Expr::MethodCall {
+                target,
+                name,
+                contract_args_opt,
+                args,
+                ..
+            } => {
+                links.push(ChainLink::MethodCall {
+                    name,
+                    contract_args_opt,
+                    args,
+                });
+                target
+            }
+            Expr::FieldProjection { target, name, .. } => {
+                links.push(ChainLink::Field(name));
+                target
+            }
+            Expr::TupleFieldProjection {
+                target, field_span, ..
+            } => {
+                links.push(ChainLink::TupleField(field_span));
+                target
+            }
+            Expr::Index { target, arg } => {
+                links.push(ChainLink::Index(arg));
+                target
+            }
+            _ => break,
+        };
+    }
+    links.reverse();
+    (current, links)
+}
+
+fn write_chain_flat(
+    root: &Expr,
+    links: &[ChainLink],
+    formatted_code: &mut FormattedCode,
+    formatter: &mut Formatter,
+) -> Result<(), FormatterError> {
+    root.format(formatted_code, formatter)?;
+    for link in links {
+        link.format(formatted_code, formatter)?;
+    }
+    Ok(())
+}
+
+/// Format a chain of method calls and field accesses on a single line if it fits within the
+/// `chain_width`, and otherwise with each link on its own line:
+/// ```sway,ignore
+/// self.storage
+///     .balances
+///     .get(id)
+/// ```
+fn format_chain(
+    expr: &Expr,
+    formatted_code: &mut FormattedCode,
+    formatter: &mut Formatter,
+) -> Result<(), FormatterError> {
+    let (root, links) = flatten_chain(expr);
+    let num_calls = links
+        .iter()
+        .filter(|link| matches!(link, ChainLink::MethodCall { .. }))
+        .count();
+    if formatter.single_line || num_calls < 2 {
+        return write_chain_flat(root, &links, formatted_code, formatter);
+    }
+    let chain_width = formatter.width_heuristics().chain_width;
+    if formatter.try_single_line(formatted_code, chain_width, |code, formatter| {
+        write_chain_flat(root, &links, code, formatter)
+    })? {
+        return Ok(());
+    }
+
+    let root_start = formatted_code.len();
+    root.format(formatted_code, formatter)?;
+    let mut links = links.iter().peekable();
+    // A short root, such as `self`, stays on the same line as the first link.
+    let root_width = formatted_code[root_start..].chars().count();
+    if root_width <= formatter.config.whitespace.tab_spaces {
+        if let Some(link) = links.next() {
+            link.format(formatted_code, formatter)?;
+        }
+    }
+    formatter.indent();
+    for link in links {
+        if !matches!(link, ChainLink::Index(..)) {
+            formatted_code.push('\n');
+            formatted_code.push_str(&formatter.indent_str());
+        }
+        link.format(formatted_code, formatter)?;
+    }
+    formatter.unindent();
+    Ok(())
+}
+
+impl Format for IfExpr {
+    fn format(
+        &self,
+        formatted_code: &mut FormattedCode,
+        formatter: &mut Formatter,
+    ) -> Result<(), FormatterError> {
+        let span = self.span();
+        if is_simple_if_else(self) && !formatter.comments.has_comments_in(span.start()..span.end())
+        {
+            if formatter.single_line {
+                return write_if_single_line(self, formatted_code, formatter);
+            }
+            let width = formatter.width_heuristics().single_line_if_else_max_width;
+            if formatter.try_single_line(formatted_code, width, |code, formatter| {
+                write_if_single_line(self, code, formatter)
+            })? {
+                return Ok(());
+            }
+        }
+        write_if_multi_line(self, formatted_code, formatter)
+    }
+}
+
+/// The expression of a block containing nothing else, other than control flow.
+fn simple_block_expr(block: &Braces<CodeBlockContents>) -> Option<&Expr> {
+    let contents = block.get();
+    match &contents.final_expr_opt {
+        Some(expr) if contents.statements.is_empty() && !expr.is_control_flow() => Some(expr),
+        _ => None,
+    }
+}
+
+/// Whether the `if` expression may be written on a single line, e.g. `if a { b } else { c }`.
+fn is_simple_if_else(if_expr: &IfExpr) -> bool {
+    match &if_expr.else_opt {
+        Some((_else_token, ControlFlow::Break(else_block))) => {
+            simple_block_expr(&if_expr.then_block).is_some()
+                && simple_block_expr(else_block).is_some()
+        }
+        _ => false,
+    }
+}
+
+fn write_if_condition(
+    condition: &IfCondition,
+    formatted_code: &mut FormattedCode,
+    formatter: &mut Formatter,
+) -> Result<(), FormatterError> {
+    match condition {
+        IfCondition::Expr(expr) => expr.format(formatted_code, formatter),
+        IfCondition::Let {
+            let_token,
+            lhs,
+            eq_token,
+            rhs,
+        } => {
+            formatted_code.push_str(let_token.span().as_str());
+            formatted_code.push(' ');
+            lhs.format(formatted_code, formatter)?;
+            formatted_code.push(' ');
+            formatted_code.push_str(eq_token.span().as_str());
+            formatted_code.push(' ');
+            rhs.format(formatted_code, formatter)
+        }
+    }
+}
+
+fn write_if_single_line(
+    if_expr: &IfExpr,
+    formatted_code: &mut FormattedCode,
+    formatter: &mut Formatter,
+) -> Result<(), FormatterError> {
+    let (then_expr, else_expr) = match (&if_expr.else_opt, simple_block_expr(&if_expr.then_block)) {
+        (Some((_, ControlFlow::Break(else_block))), Some(then_expr)) => {
+            match simple_block_expr(else_block) {
+                Some(else_expr) => (then_expr, else_expr),
+                None => return write_if_multi_line(if_expr, formatted_code, formatter),
+            }
+        }
+        _ => return write_if_multi_line(if_expr, formatted_code, formatter),
+    };
+    formatted_code.push_str(if_expr.if_token.span().as_str());
+    formatted_code.push(' ');
+    write_if_condition(&if_expr.condition, formatted_code, formatter)?;
+    formatted_code.push_str(" { ");
+    then_expr.format(formatted_code, formatter)?;
+    formatted_code.push_str(" } else { ");
+    else_expr.format(formatted_code, formatter)?;
+    formatted_code.push_str(" }");
+    Ok(())
+}
+
+fn write_if_multi_line(
+    if_expr: &IfExpr,
+    formatted_code: &mut FormattedCode,
+    formatter: &mut Formatter,
+) -> Result<(), FormatterError> {
+    formatted_code.push_str(if_expr.if_token.span().as_str());
+    formatted_code.push(' ');
+    let condition_start = formatted_code.len();
+    write_if_condition(&if_expr.condition, formatted_code, formatter)?;
+    write_expr_open_brace(formatted_code, formatter, condition_start);
+    write_code_block_contents(&if_expr.then_block, formatted_code, formatter)?;
+
+    if let Some((else_token, tail)) = &if_expr.else_opt {
+        // Comments between the `then` block and the `else` are kept there, on their own lines.
+        let then_end = if_expr.then_block.span().end();
+        let else_start = else_token.span().start();
+        if formatter.comments.has_comments_in(then_end..else_start) {
+            formatted_code.push('\n');
+            write_comments_before_close(formatted_code, formatter, None, else_start)?;
+            formatted_code.push_str(&formatter.indent_str());
+        } else {
+            match formatter.config.expressions.expr_brace_style {
+                ExprBraceStyle::AlwaysSameLine => formatted_code.push(' '),
+                _ => {
+                    formatted_code.push('\n');
+                    formatted_code.push_str(&formatter.indent_str());
+                }
+            }
+        }
+        formatted_code.push_str(else_token.span().as_str());
+        match tail {
+            ControlFlow::Continue(else_if_expr) => {
+                formatted_code.push(' ');
+                write_if_multi_line(else_if_expr, formatted_code, formatter)?;
+            }
+            ControlFlow::Break(else_block) => {
+                let else_end = formatted_code.len();
+                write_expr_open_brace(formatted_code, formatter, else_end);
+                write_code_block_contents(else_block, formatted_code, formatter)?;
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Write the branches of a `match` expression and its closing brace, the opening brace having
+/// already been written and the indentation increased.
+fn write_match_branches(
+    branches: &Braces<Vec<MatchBranch>>,
+    formatted_code: &mut FormattedCode,
+    formatter: &mut Formatter,
+) -> Result<(), FormatterError> {
+    let span = branches.span();
+    let (open_end, close_start) = (span.start() + 1, span.end() - 1);
+    if branches.get().is_empty() && !formatter.comments.has_comments_in(open_end..close_start) {
+        formatter.unindent();
+        formatted_code.push('}');
+        return Ok(());
+    }
+    write_trailing_comments(formatted_code, formatter, open_end)?;
+    formatted_code.push('\n');
+    let mut prev_end = None;
+    for branch in branches.get() {
+        let branch_span = branch.span();
+        write_comments_before(formatted_code, formatter, prev_end, branch_span.start())?;
+        formatted_code.push_str(&formatter.indent_str());
+        branch.format(formatted_code, formatter)?;
+        write_trailing_comments(formatted_code, formatter, branch_span.end())?;
+        formatted_code.push('\n');
+        prev_end = Some(branch_span.end());
+    }
+    write_comments_before_close(formatted_code, formatter, prev_end, close_start)?;
+    formatter.unindent();
+    formatted_code.push_str(&formatter.indent_str());
+    formatted_code.push('}');
+    Ok(())
+}
+
+impl Format for MatchBranch {
+    fn format(
+        &self,
+        formatted_code: &mut FormattedCode,
+        formatter: &mut Formatter,
+    ) -> Result<(), FormatterError> {
+        self.pattern.format(formatted_code, formatter)?;
+        if let Some((if_token, guard)) = &self.guard_opt {
+            formatted_code.push(' ');
+            formatted_code.push_str(if_token.span().as_str());
+            formatted_code.push(' ');
+            guard.format(formatted_code, formatter)?;
+        }
+        formatted_code.push(' ');
+        formatted_code.push_str(self.fat_right_arrow_token.span().as_str());
+        formatted_code.push(' ');
+        let block_trailing_comma = formatter.config.expressions.match_block_trailing_comma;
+        match &self.kind {
+            MatchBranchKind::Block { block, .. } => {
+                write_code_block(block, formatted_code, formatter)?;
+                if block_trailing_comma {
+                    formatted_code.push(',');
+                }
+            }
+            MatchBranchKind::Expr { expr, .. } => {
+                let checkpoint = formatter.checkpoint(formatted_code);
+                let start = formatted_code.len();
+                expr.format(formatted_code, formatter)?;
+                let is_multiline = formatted_code[start..].contains('\n')
+                    || !formatter.lines_fit(formatted_code, start);
+                if !is_multiline
+                    || formatter.single_line
+                    || !formatter.config.expressions.force_multiline_blocks
+                {
+                    formatted_code.push(',');
+                    return Ok(());
+                }
+                // With `force_multiline_blocks`, a body spanning multiple lines is wrapped in a block.
+                formatter.restore(formatted_code, checkpoint);
+                formatted_code.push_str("{\n");
+                formatter.indent();
+                formatted_code.push_str(&formatter.indent_str());
+                expr.format(formatted_code, formatter)?;
+                formatted_code.push('\n');
+                formatter.unindent();
+                formatted_code.push_str(&formatter.indent_str());
+                formatted_code.push('}');
+                if block_trailing_comma {
+                    formatted_code.push(',');
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+impl Format for AsmBlock {
+    fn format(
+        &self,
+        formatted_code: &mut FormattedCode,
+        formatter: &mut Formatter,
+    ) -> Result<(), FormatterError> {
+        formatted_code.push_str(self.asm_token.span().as_str());
+        let registers = self.registers.get();
+        let items = registers
+            .into_iter()
+            .map(|register| ListItem::new(asm_register_span(register), register))
+            .collect();
+        let width = formatter.width_heuristics().fn_call_width;
+        List::new("(", ")", items, self.registers.span(), width, formatter)
+            .format(formatted_code, formatter)?;
+        formatted_code.push_str(" {");
+
+        let contents = self.contents.get();
+        let span = self.contents.span();
+        let (open_end, close_start) = (span.start() + 1, span.end() - 1);
+        if contents.instructions.is_empty()
+            && contents.final_expr_opt.is_none()
+            && !formatter.comments.has_comments_in(open_end..close_start)
+        {
+            formatted_code.push('}');
+            return Ok(());
+        }
+        write_trailing_comments(formatted_code, formatter, open_end)?;
+        formatted_code.push('\n');
+        formatter.indent();
+        let mut prev_end = None;
+        for (instruction, semicolon_token) in &contents.instructions {
+            let instruction_span = Span::join(instruction.span(), semicolon_token.span());
+            write_comments_before(
+                formatted_code,
+                formatter,
+                prev_end,
+                instruction_span.start(),
+            )?;
+            formatted_code.push_str(&formatter.indent_str());
+            // e.g. `add r1 r2 r3;`
+            formatted_code.push_str(instruction.op_code_ident().as_str());
+            let args = instruction
+                .register_arg_idents()
+                .into_iter()
+                .chain(instruction.immediate_ident_opt());
+            for arg in args {
+                formatted_code.push(' ');
+                formatted_code.push_str(arg.as_str());
+            }
+            formatted_code.push_str(semicolon_token.span().as_str());
+            write_trailing_comments(formatted_code, formatter, instruction_span.end())?;
+            formatted_code.push('\n');
+            prev_end = Some(instruction_span.end());
+        }
+        if let Some(final_expr) = &contents.final_expr_opt {
+            let final_expr_span = match &final_expr.ty_opt {
+                Some((_colon_token, ty)) => Span::join(final_expr.register.span(), ty.span()),
+                None => final_expr.register.span(),
+            };
+            write_comments_before(formatted_code, formatter, prev_end, final_expr_span.start())?;
+            formatted_code.push_str(&formatter.indent_str());
+            // e.g. `r3: u64`
+            formatted_code.push_str(final_expr.register.as_str());
+            if let Some((_colon_token, ty)) = &final_expr.ty_opt {
+                write_ty_annotation(ty, formatted_code, formatter)?;
+            }
+            write_trailing_comments(formatted_code, formatter, final_expr_span.end())?;
+            formatted_code.push('\n');
+            prev_end = Some(final_expr_span.end());
+        }
+        write_comments_before_close(formatted_code, formatter, prev_end, close_start)?;
+        formatter.unindent();
+        formatted_code.push_str(&formatter.indent_str());
+        formatted_code.push('}');
+        Ok(())
+    }
+}
+
+fn asm_register_span(register: &AsmRegisterDeclaration) -> Span {
+    match &register.value_opt {
+        Some((_colon_token, expr)) => Span::join(register.register.span(), expr.span()),
+        None => register.register.span(),
+    }
+}
+
+impl Format for AsmRegisterDeclaration {
+    fn format(
+        &self,
+        formatted_code: &mut FormattedCode,
+        formatter: &mut Formatter,
+    ) -> Result<(), FormatterError> {
+        formatted_code.push_str(self.register.as_str());
+        if let Some((_colon_token, expr)) = &self.value_opt {
+            write_colon(formatted_code, formatter);
+            expr.format(formatted_code, formatter)?;
+        }
+        Ok(())
+    }
+}
@@ -1,6 +1,15 @@
-use sway_parse::{Item, ItemKind::*};
+use sway_parse::{Braces, Item, ItemKind::*};
+use sway_types::Spanned;
 
-use crate::fmt::{Format, FormattedCode, Formatter, FormatterError};
+use crate::{
+    fmt::{Format, FormattedCode, Formatter, FormatterError},
+    utils::{
+        attribute::write_attributes,
+        bracket::CurlyBrace,
+        comments::{write_comments_before, write_comments_before_close, write_trailing_comments},
+        lists::ListItem,
+    },
+};
 
 impl Format for Item {
     fn format(
@@ -8,6 +17,12 @@ impl Format for Item {
         formatted_code: &mut FormattedCode,
         formatter: &mut Formatter,
     ) -> Result<(), FormatterError> {
+        write_attributes(
+            &self.attribute_list,
+            self.value.span().start(),
+            formatted_code,
+            formatter,
+        )?;
         match &self.value {
             Use(item_use) => item_use.format(formatted_code, formatter),
             Struct(item_struct) => item_struct.format(formatted_code, formatter),
@@ -18,6 +33,43 @@ impl Format for Item {
             Abi(item_abi) => item_abi.format(formatted_code, formatter),
             Const(item_const) => item_const.format(formatted_code, formatter),
             Storage(item_storage) => item_storage.format(formatted_code, formatter),
+            TypeAlias(item_type_alias) => item_type_alias.format(formatted_code, formatter),
         }
     }
 }
+
+/// Write the contents of a braced item such as an `impl`, each on its own line and separated by
+/// the comments and blank lines between them, followed by the closing brace of `B`.
+///
+/// The opening brace has already been written by `B::open_curly_brace`.
+pub(crate) fn write_block_items<B: CurlyBrace, T>(
+    items: &[ListItem],
+    braces: &Braces<T>,
+    formatted_code: &mut FormattedCode,
+    formatter: &mut Formatter,
+) -> Result<(), FormatterError> {
+    let span = braces.span();
+    let (open_end, close_start) = (span.start() + 1, span.end() - 1);
+    if items.is_empty() && !formatter.comments.has_comments_in(open_end..close_start) {
+        if formatter.config.items.empty_item_single_line {
+            formatter.unindent();
+            formatted_code.push('}');
+            return Ok(());
+        }
+        formatted_code.push('\n');
+        return B::close_curly_brace(formatted_code, formatter);
+    }
+    write_trailing_comments(formatted_code, formatter, open_end)?;
+    formatted_code.push('\n');
+    let mut prev_end = None;
+    for item in items {
+        write_comments_before(formatted_code, formatter, prev_end, item.span.start())?;
+        formatted_code.push_str(&formatter.indent_str());
+        item.value.format(formatted_code, formatter)?;
+        write_trailing_comments(formatted_code, formatter, item.span.end())?;
+        formatted_code.push('\n');
+        prev_end = Some(item.span.end());
+    }
+    write_comments_before_close(formatted_code, formatter, prev_end, close_start)?;
+    B::close_curly_brace(formatted_code, formatter)
+}
@@ -1,3 +0,0 @@
-pub trait ItemLen {
-    fn get_formatted_len(&self) -> usize;
-}
@@ -0,0 +1,338 @@
+//! Formatting of delimited, comma separated lists such as function arguments, struct literal
+//! fields and array elements.
+use crate::{
+    config::{
+        lists::{ListTactic, SeparatorTactic},
+        whitespace::IndentStyle,
+    },
+    fmt::{Format, FormattedCode, Formatter},
+    utils::comments::{
+        write_comments_before, write_comments_before_close, write_trailing_comments,
+    },
+    FormatterError,
+};
+use std::ops::Range;
+use sway_types::Span;
+
+/// An item of a [List] along with its span, used to place comments.
+pub(crate) struct ListItem<'a> {
+    pub(crate) span: Span,
+    pub(crate) value: &'a dyn Format,
+}
+
+impl<'a> ListItem<'a> {
+    pub(crate) fn new(span: Span, value: &'a dyn Format) -> Self {
+        Self { span, value }
+    }
+}
+
+/// A comma separated list of items between a pair of delimiters.
+pub(crate) struct List<'a> {
+    pub(crate) open: &'static str,
+    pub(crate) close: &'static str,
+    pub(crate) items: Vec<ListItem<'a>>,
+    /// The span of the list, including its delimiters.
+    pub(crate) span: Span,
+    pub(crate) tactic: ListTactic,
+    /// The maximum width of the items of the list when written on a single line.
+    pub(crate) width: usize,
+    /// Whether a single line list is padded with spaces inside its delimiters, e.g. `{ a, b }`.
+    pub(crate) padded: bool,
+    pub(crate) trailing_comma: SeparatorTactic,
+    /// Whether the last item may overflow onto the following lines while the others remain on
+    /// the first line, e.g. a struct literal as the last argument of a function call.
+    pub(crate) overflow_last: bool,
+    pub(crate) indent_style: IndentStyle,
+}
+
+impl<'a> List<'a> {
+    /// A list whose single line layout is limited to `width`, and which is otherwise written
+    /// vertically, taking its trailing comma from the config.
+    pub(crate) fn new(
+        open: &'static str,
+        close: &'static str,
+        items: Vec<ListItem<'a>>,
+        span: Span,
+        width: usize,
+        formatter: &Formatter,
+    ) -> Self {
+        Self {
+            open,
+            close,
+            items,
+            span,
+            tactic: ListTactic::HorizontalVertical,
+            width,
+            padded: false,
+            trailing_comma: formatter.config.lists.trailing_comma,
+            overflow_last: false,
+            indent_style: IndentStyle::Block,
+        }
+    }
+
+    /// The width of the single line layout given the width of its items, which is extended by
+    /// the delimiters and padding.
+    fn single_line_width(&self, items_width: usize) -> usize {
+        let padding = if self.padded { 2 } else { 0 };
+        items_width + self.open.len() + self.close.len() + padding
+    }
+
+    /// The range between the delimiters, within which comments belong to the list.
+    fn inner_range(&self) -> Range<usize> {
+        (self.span.start() + self.open.len())..self.span.end().saturating_sub(self.close.len())
+    }
+}
+
+impl<'a> Format for List<'a> {
+    fn format(
+        &self,
+        formatted_code: &mut FormattedCode,
+        formatter: &mut Formatter,
+    ) -> Result<(), FormatterError> {
+        let has_comments = formatter.comments.has_comments_in(self.inner_range());
+        if self.items.is_empty() && !has_comments {
+            formatted_code.push_str(self.open);
+            formatted_code.push_str(self.close);
+            return Ok(());
+        }
+        if has_comments && !formatter.single_line {
+            return self.write_vertical(formatted_code, formatter);
+        }
+        let width = match self.tactic {
+            ListTactic::Horizontal => return self.write_horizontal(formatted_code, formatter),
+            ListTactic::Vertical if self.items.len() > 1 && !formatter.single_line => {
+                return self.write_vertical(formatted_code, formatter)
+            }
+            ListTactic::LimitedHorizontalVertical(limit) => {
+                self.single_line_width(self.width.min(limit))
+            }
+            _ => self.single_line_width(self.width),
+        };
+        if formatter.try_single_line(formatted_code, width, |code, formatter| {
+            self.write_horizontal(code, formatter)
+        })? {
+            return Ok(());
+        }
+        if self.overflow_last && self.try_overflow_last(formatted_code, formatter)? {
+            return Ok(());
+        }
+        match (self.tactic, self.indent_style) {
+            (_, IndentStyle::Visual) => self.write_visual(formatted_code, formatter),
+            (ListTactic::Mixed, _) => self.write_mixed(formatted_code, formatter),
+            _ => self.write_vertical(formatted_code, formatter),
+        }
+    }
+}
+
+impl<'a> List<'a> {
+    /// e.g. `(a, b, c)`
+    fn write_horizontal(
+        &self,
+        formatted_code: &mut FormattedCode,
+        formatter: &mut Formatter,
+    ) -> Result<(), FormatterError> {
+        formatted_code.push_str(self.open);
+        if self.padded {
+            formatted_code.push(' ');
+        }
+        for (ix, item) in self.items.iter().enumerate() {
+            if ix > 0 {
+                formatted_code.push_str(", ");
+            }
+            item.value.format(formatted_code, formatter)?;
+        }
+        if let SeparatorTactic::Always = self.trailing_comma {
+            formatted_code.push(',');
+        }
+        if self.padded {
+            formatted_code.push(' ');
+        }
+        formatted_code.push_str(self.close);
+        Ok(())
+    }
+
+    /// Each item on its own line:
+    /// ```sway,ignore
+    /// (
+    ///     a,
+    ///     b,
+    /// )
+    /// ```
+    fn write_vertical(
+        &self,
+        formatted_code: &mut FormattedCode,
+        formatter: &mut Formatter,
+    ) -> Result<(), FormatterError> {
+        self.open_block(formatted_code, formatter)?;
+        let mut prev_end = None;
+        for (ix, item) in self.items.iter().enumerate() {
+            write_comments_before(formatted_code, formatter, prev_end, item.span.start())?;
+            formatted_code.push_str(&formatter.indent_str());
+            if ix + 1 < self.items.len() || self.vertical_trailing_comma() {
+                formatter.with_reserved_width(1, |formatter| {
+                    item.value.format(formatted_code, formatter)
+                })?;
+                formatted_code.push(',');
+            } else {
+                item.value.format(formatted_code, formatter)?;
+            }
+            write_trailing_comments(formatted_code, formatter, item.span.end())?;
+            formatted_code.push('\n');
+            prev_end = Some(item.span.end());
+        }
+        self.close_block(formatted_code, formatter, prev_end)
+    }
+
+    /// As many items on each line as fit within the `max_width`:
+    /// ```sway,ignore
+    /// {
+    ///     a, b, c,
+    ///     d,
+    /// }
+    /// ```
+    fn write_mixed(
+        &self,
+        formatted_code: &mut FormattedCode,
+        formatter: &mut Formatter,
+    ) -> Result<(), FormatterError> {
+        let max_width = formatter.config.whitespace.max_width;
+        self.open_block(formatted_code, formatter)?;
+        formatted_code.push_str(&formatter.indent_str());
+        let mut line_start = formatted_code.len();
+        for (ix, item) in self.items.iter().enumerate() {
+            let separator = if ix + 1 < self.items.len() || self.vertical_trailing_comma() {
+                ","
+            } else {
+                ""
+            };
+            let item_start = formatted_code.len();
+            let checkpoint = formatter.checkpoint(formatted_code);
+            if item_start > line_start {
+                formatted_code.push(' ');
+            }
+            item.value.format(formatted_code, formatter)?;
+            formatted_code.push_str(separator);
+            // Move the item onto the next line if it doesn't fit, unless it's the first on its line.
+            if formatter.last_line_width(formatted_code) > max_width && item_start > line_start {
+                formatter.restore(formatted_code, checkpoint);
+                formatted_code.push('\n');
+                formatted_code.push_str(&formatter.indent_str());
+                line_start = formatted_code.len();
+                item.value.format(formatted_code, formatter)?;
+                formatted_code.push_str(separator);
+            }
+        }
+        formatted_code.push('\n');
+        self.close_block(formatted_code, formatter, None)
+    }
+
+    /// Items continue after the opening delimiter, aligned with the first:
+    /// ```sway,ignore
+    /// {a, b,
+    ///  c}
+    /// ```
+    fn write_visual(
+        &self,
+        formatted_code: &mut FormattedCode,
+        formatter: &mut Formatter,
+    ) -> Result<(), FormatterError> {
+        let max_width = formatter.config.whitespace.max_width;
+        formatted_code.push_str(self.open);
+        let alignment = formatter.last_line_width(formatted_code);
+        let mut line_start = formatted_code.len();
+        for (ix, item) in self.items.iter().enumerate() {
+            let is_last = ix + 1 == self.items.len();
+            let item_start = formatted_code.len();
+            let checkpoint = formatter.checkpoint(formatted_code);
+            if item_start > line_start {
+                formatted_code.push(' ');
+            }
+            item.value.format(formatted_code, formatter)?;
+            let suffix = if is_last { self.close } else { "," };
+            formatted_code.push_str(suffix);
+            if formatter.last_line_width(formatted_code) > max_width && item_start > line_start {
+                formatter.restore(formatted_code, checkpoint);
+                formatted_code.push('\n');
+                formatted_code.push_str(&" ".repeat(alignment));
+                line_start = formatted_code.len();
+                item.value.format(formatted_code, formatter)?;
+                formatted_code.push_str(suffix);
+            }
+        }
+        Ok(())
+    }
+
+    /// e.g. `foo(a, Bar {` followed by the fields of `Bar` on the following lines.
+    fn try_overflow_last(
+        &self,
+        formatted_code: &mut FormattedCode,
+        formatter: &mut Formatter,
+    ) -> Result<bool, FormatterError> {
+        let (last, init) = match self.items.split_last() {
+            Some(split) => split,
+            None => return Ok(false),
+        };
+        let checkpoint = formatter.checkpoint(formatted_code);
+        let start = formatted_code.len();
+        formatted_code.push_str(self.open);
+        let single_line = std::mem::replace(&mut formatter.single_line, true);
+        let mut res = Ok(());
+        for item in init {
+            res = res.and_then(|_| item.value.format(formatted_code, formatter));
+            formatted_code.push_str(", ");
+        }
+        formatter.single_line = single_line;
+        res?;
+        let last_start = formatted_code.len();
+        let init_fits = !formatted_code[start..].contains('\n');
+        last.value.format(formatted_code, formatter)?;
+        formatted_code.push_str(self.close);
+        let last_first_line = formatted_code[last_start..]
+            .lines()
+            .next()
+            .unwrap_or_default();
+        let overflows = init_fits
+            && formatted_code[last_start..].contains('\n')
+            && last_first_line.ends_with(['{', '[', '('])
+            && formatter.lines_fit(formatted_code, start);
+        if !overflows {
+            formatter.restore(formatted_code, checkpoint);
+        }
+        Ok(overflows)
+    }
+
+    fn vertical_trailing_comma(&self) -> bool {
+        !matches!(self.trailing_comma, SeparatorTactic::Never)
+    }
+
+    /// Write the opening delimiter followed by a newline, and indent.
+    fn open_block(
+        &self,
+        formatted_code: &mut FormattedCode,
+        formatter: &mut Formatter,
+    ) -> Result<(), FormatterError> {
+        formatted_code.push_str(self.open);
+        write_trailing_comments(
+            formatted_code,
+            formatter,
+            self.span.start() + self.open.len(),
+        )?;
+        formatted_code.push('\n');
+        formatter.indent();
+        Ok(())
+    }
+
+    /// Write any remaining comments, unindent and write the closing delimiter.
+    fn close_block(
+        &self,
+        formatted_code: &mut FormattedCode,
+        formatter: &mut Formatter,
+        prev_end: Option<usize>,
+    ) -> Result<(), FormatterError> {
+        write_comments_before_close(formatted_code, formatter, prev_end, self.inner_range().end)?;
+        formatter.unindent();
+        formatted_code.push_str(&formatter.indent_str());
+        formatted_code.push_str(self.close);
+        Ok(())
+    }
+}
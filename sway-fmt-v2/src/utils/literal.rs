@@ -0,0 +1,40 @@
+use crate::{
+    config::literals::HexLiteralCase,
+    fmt::{Format, FormattedCode, Formatter},
+    FormatterError,
+};
+use sway_parse::Literal;
+use sway_types::Spanned;
+
+impl Format for Literal {
+    fn format(
+        &self,
+        formatted_code: &mut FormattedCode,
+        formatter: &mut Formatter,
+    ) -> Result<(), FormatterError> {
+        match self {
+            Self::Int(lit_int) => {
+                // The span of the literal excludes its type suffix, e.g. `u8` in `0u8`.
+                let text = lit_int.span.as_str();
+                let is_hex = text.starts_with("0x") || text.starts_with("0X");
+                // Only the digits change case, not the `0x` prefix or a type suffix.
+                match formatter.config.literals.hex_literal_case {
+                    HexLiteralCase::Upper if is_hex => {
+                        formatted_code.push_str("0x");
+                        formatted_code.push_str(&text[2..].to_uppercase());
+                    }
+                    HexLiteralCase::Lower if is_hex => {
+                        formatted_code.push_str("0x");
+                        formatted_code.push_str(&text[2..].to_lowercase());
+                    }
+                    _ => formatted_code.push_str(text),
+                }
+                if let Some((_ty, suffix_span)) = &lit_int.ty_opt {
+                    formatted_code.push_str(suffix_span.as_str());
+                }
+            }
+            _ => formatted_code.push_str(self.span().as_str()),
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,143 @@
+use crate::{
+    fmt::{Format, FormattedCode, Formatter},
+    FormatterError,
+};
+use sway_parse::{
+    brackets::AngleBrackets, GenericArgs, GenericParams, PathExpr, PathExprSegment, PathType,
+    PathTypeSegment, QualifiedPathRoot,
+};
+use sway_types::Spanned;
+
+impl Format for PathExpr {
+    fn format(
+        &self,
+        formatted_code: &mut FormattedCode,
+        formatter: &mut Formatter,
+    ) -> Result<(), FormatterError> {
+        format_root(&self.root_opt, formatted_code, formatter)?;
+        self.prefix.format(formatted_code, formatter)?;
+        for (double_colon_token, segment) in &self.suffix {
+            formatted_code.push_str(double_colon_token.span().as_str());
+            segment.format(formatted_code, formatter)?;
+        }
+        Ok(())
+    }
+}
+
+impl Format for PathExprSegment {
+    fn format(
+        &self,
+        formatted_code: &mut FormattedCode,
+        formatter: &mut Formatter,
+    ) -> Result<(), FormatterError> {
+        if let Some(tilde_token) = &self.fully_qualified {
+            formatted_code.push_str(tilde_token.span().as_str());
+        }
+        formatted_code.push_str(self.name.as_str());
+        if let Some((double_colon_token, generic_args)) = &self.generics_opt {
+            formatted_code.push_str(double_colon_token.span().as_str());
+            generic_args.format(formatted_code, formatter)?;
+        }
+        Ok(())
+    }
+}
+
+impl Format for PathType {
+    fn format(
+        &self,
+        formatted_code: &mut FormattedCode,
+        formatter: &mut Formatter,
+    ) -> Result<(), FormatterError> {
+        format_root(&self.root_opt, formatted_code, formatter)?;
+        self.prefix.format(formatted_code, formatter)?;
+        for (double_colon_token, segment) in &self.suffix {
+            formatted_code.push_str(double_colon_token.span().as_str());
+            segment.format(formatted_code, formatter)?;
+        }
+        Ok(())
+    }
+}
+
+impl Format for PathTypeSegment {
+    fn format(
+        &self,
+        formatted_code: &mut FormattedCode,
+        formatter: &mut Formatter,
+    ) -> Result<(), FormatterError> {
+        if let Some(tilde_token) = &self.fully_qualified {
+            formatted_code.push_str(tilde_token.span().as_str());
+        }
+        formatted_code.push_str(self.name.as_str());
+        if let Some((double_colon_token_opt, generic_args)) = &self.generics_opt {
+            if let Some(double_colon_token) = double_colon_token_opt {
+                formatted_code.push_str(double_colon_token.span().as_str());
+            }
+            generic_args.format(formatted_code, formatter)?;
+        }
+        Ok(())
+    }
+}
+
+/// e.g. `::` or `<T as Trait>::`
+fn format_root(
+    root_opt: &Option<(
+        Option<AngleBrackets<QualifiedPathRoot>>,
+        sway_parse::DoubleColonToken,
+    )>,
+    formatted_code: &mut FormattedCode,
+    formatter: &mut Formatter,
+) -> Result<(), FormatterError> {
+    if let Some((qualified_path_root_opt, double_colon_token)) = root_opt {
+        if let Some(qualified_path_root) = qualified_path_root_opt {
+            let root = &qualified_path_root.inner;
+            formatted_code.push('<');
+            root.ty.format(formatted_code, formatter)?;
+            if let Some((as_token, path_type)) = &root.as_trait {
+                formatted_code.push(' ');
+                formatted_code.push_str(as_token.span().as_str());
+                formatted_code.push(' ');
+                path_type.format(formatted_code, formatter)?;
+            }
+            formatted_code.push('>');
+        }
+        formatted_code.push_str(double_colon_token.span().as_str());
+    }
+    Ok(())
+}
+
+/// e.g. `<T, U>` in `Foo::<T, U>`
+impl Format for GenericArgs {
+    fn format(
+        &self,
+        formatted_code: &mut FormattedCode,
+        formatter: &mut Formatter,
+    ) -> Result<(), FormatterError> {
+        formatted_code.push('<');
+        for (ix, ty) in (&self.parameters.inner).into_iter().enumerate() {
+            if ix > 0 {
+                formatted_code.push_str(", ");
+            }
+            ty.format(formatted_code, formatter)?;
+        }
+        formatted_code.push('>');
+        Ok(())
+    }
+}
+
+/// e.g. `<T, U>` in `struct Foo<T, U>`
+impl Format for GenericParams {
+    fn format(
+        &self,
+        formatted_code: &mut FormattedCode,
+        _formatter: &mut Formatter,
+    ) -> Result<(), FormatterError> {
+        let params: Vec<&str> = (&self.parameters.inner)
+            .into_iter()
+            .map(|ident| ident.as_str())
+            .collect();
+        formatted_code.push('<');
+        formatted_code.push_str(&params.join(", "));
+        formatted_code.push('>');
+        Ok(())
+    }
+}
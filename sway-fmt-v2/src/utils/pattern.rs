@@ -0,0 +1,124 @@
+use crate::{
+    config::lists::SeparatorTactic,
+    fmt::{Format, FormattedCode, Formatter},
+    utils::{
+        lists::{List, ListItem},
+        ty::write_colon,
+    },
+    FormatterError,
+};
+use sway_parse::{Pattern, PatternStructField};
+use sway_types::Spanned;
+
+impl Format for Pattern {
+    fn format(
+        &self,
+        formatted_code: &mut FormattedCode,
+        formatter: &mut Formatter,
+    ) -> Result<(), FormatterError> {
+        let width_heuristics = formatter.width_heuristics();
+        match self {
+            Self::Wildcard { underscore_token } => {
+                formatted_code.push_str(underscore_token.span().as_str())
+            }
+            Self::Var { mutable, name } => {
+                if let Some(mut_token) = mutable {
+                    formatted_code.push_str(mut_token.span().as_str());
+                    formatted_code.push(' ');
+                }
+                formatted_code.push_str(name.as_str());
+            }
+            Self::Literal(literal) => literal.format(formatted_code, formatter)?,
+            Self::Constant(path) => path.format(formatted_code, formatter)?,
+            Self::Constructor { path, args } => {
+                path.format(formatted_code, formatter)?;
+                let items = args
+                    .get()
+                    .into_iter()
+                    .map(|pattern| ListItem::new(pattern.span(), pattern))
+                    .collect();
+                let width = width_heuristics.fn_call_width;
+                List::new("(", ")", items, args.span(), width, formatter)
+                    .format(formatted_code, formatter)?;
+            }
+            Self::Struct { path, fields } => {
+                path.format(formatted_code, formatter)?;
+                formatted_code.push(' ');
+                let items = fields
+                    .get()
+                    .into_iter()
+                    .map(|field| ListItem::new(field.span(), field))
+                    .collect();
+                let width = width_heuristics.struct_lit_width;
+                let mut list = List::new("{", "}", items, fields.span(), width, formatter);
+                list.padded = true;
+                // A trailing comma may not follow the rest pattern `..`.
+                if let Some(PatternStructField::Rest { .. }) = fields.get().into_iter().last() {
+                    list.trailing_comma = SeparatorTactic::Never;
+                }
+                list.format(formatted_code, formatter)?;
+            }
+            Self::Tuple(patterns) => {
+                let items: Vec<ListItem> = patterns
+                    .get()
+                    .into_iter()
+                    .map(|pattern| ListItem::new(pattern.span(), pattern))
+                    .collect();
+                let num_items = items.len();
+                let width = width_heuristics.fn_call_width;
+                let mut list = List::new("(", ")", items, patterns.span(), width, formatter);
+                // A pattern of a single element is only a tuple with a trailing comma, e.g. `(a,)`.
+                if num_items == 1 && patterns.get().final_value_opt.is_none() {
+                    list.trailing_comma = SeparatorTactic::Always;
+                } else if num_items == 1 {
+                    list.trailing_comma = SeparatorTactic::Never;
+                }
+                list.format(formatted_code, formatter)?;
+            }
+            Self::Range {
+                start,
+                double_dot_eq_token,
+                end,
+            } => {
+                start.format(formatted_code, formatter)?;
+                formatted_code.push_str(double_dot_eq_token.span().as_str());
+                end.format(formatted_code, formatter)?;
+            }
+            Self::Or {
+                lhs,
+                pipe_token,
+                rhs,
+            } => {
+                lhs.format(formatted_code, formatter)?;
+                formatted_code.push(' ');
+                formatted_code.push_str(pipe_token.span().as_str());
+                formatted_code.push(' ');
+                rhs.format(formatted_code, formatter)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+impl Format for PatternStructField {
+    fn format(
+        &self,
+        formatted_code: &mut FormattedCode,
+        formatter: &mut Formatter,
+    ) -> Result<(), FormatterError> {
+        match self {
+            Self::Rest { token } => formatted_code.push_str(token.span().as_str()),
+            Self::Field {
+                field_name,
+                pattern_opt,
+            } => {
+                formatted_code.push_str(field_name.as_str());
+                if let Some((_colon_token, pattern)) = pattern_opt {
+                    write_colon(formatted_code, formatter);
+                    pattern.format(formatted_code, formatter)?;
+                }
+            }
+        }
+        Ok(())
+    }
+}
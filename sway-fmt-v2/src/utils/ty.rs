@@ -0,0 +1,138 @@
+use crate::{
+    config::{expr::TypeCombinatorLayout, lists::SeparatorTactic},
+    fmt::{Format, FormattedCode, Formatter},
+    utils::lists::{List, ListItem},
+    FormatterError,
+};
+use sway_parse::{
+    brackets::SquareBrackets,
+    expr::Expr,
+    ty::{TyArrayDescriptor, TyTupleDescriptor},
+    Traits, Ty, WhereClause,
+};
+use sway_types::Spanned;
+
+impl Format for Ty {
+    fn format(
+        &self,
+        formatted_code: &mut FormattedCode,
+        formatter: &mut Formatter,
+    ) -> Result<(), FormatterError> {
+        match self {
+            Self::Path(path_type) => path_type.format(formatted_code, formatter),
+            Self::Tuple(tuple) => {
+                let tys: Vec<&Ty> = match tuple.get() {
+                    TyTupleDescriptor::Nil => vec![],
+                    TyTupleDescriptor::Cons { head, tail, .. } => {
+                        std::iter::once(&**head).chain(tail).collect()
+                    }
+                };
+                let items = tys.iter().map(|ty| ListItem::new(ty.span(), *ty)).collect();
+                let width = formatter.config.whitespace.max_width;
+                let mut list = List::new("(", ")", items, tuple.span(), width, formatter);
+                // A tuple of a single type requires a trailing comma, e.g. `(u64,)`.
+                if tys.len() == 1 {
+                    list.trailing_comma = SeparatorTactic::Always;
+                }
+                list.format(formatted_code, formatter)
+            }
+            Self::Array(array) => format_array_type(array, formatted_code, formatter),
+            Self::Str { str_token, length } => {
+                formatted_code.push_str(str_token.span().as_str());
+                formatted_code.push('[');
+                length.get().format(formatted_code, formatter)?;
+                formatted_code.push(']');
+                Ok(())
+            }
+            Self::Infer { underscore_token } => {
+                formatted_code.push_str(underscore_token.span().as_str());
+                Ok(())
+            }
+        }
+    }
+}
+
+/// e.g. `[u64; 5]`
+fn format_array_type(
+    array: &SquareBrackets<TyArrayDescriptor>,
+    formatted_code: &mut FormattedCode,
+    formatter: &mut Formatter,
+) -> Result<(), FormatterError> {
+    let descriptor = array.get();
+    formatted_code.push('[');
+    descriptor.ty.format(formatted_code, formatter)?;
+    formatted_code.push_str("; ");
+    Expr::format(&descriptor.length, formatted_code, formatter)?;
+    formatted_code.push(']');
+    Ok(())
+}
+
+impl Format for Traits {
+    fn format(
+        &self,
+        formatted_code: &mut FormattedCode,
+        formatter: &mut Formatter,
+    ) -> Result<(), FormatterError> {
+        self.prefix.format(formatted_code, formatter)?;
+        let add = match formatter.config.expressions.type_combinator_layout {
+            TypeCombinatorLayout::Wide => " + ",
+            TypeCombinatorLayout::Compressed => "+",
+        };
+        for (_add_token, path_type) in &self.suffixes {
+            formatted_code.push_str(add);
+            path_type.format(formatted_code, formatter)?;
+        }
+        Ok(())
+    }
+}
+
+/// A where clause begins on a new line, followed by each bound on its own line:
+/// ```sway,ignore
+/// where
+///     T: Foo + Bar,
+/// ```
+/// The clause ends with a newline.
+impl Format for WhereClause {
+    fn format(
+        &self,
+        formatted_code: &mut FormattedCode,
+        formatter: &mut Formatter,
+    ) -> Result<(), FormatterError> {
+        formatted_code.push('\n');
+        formatted_code.push_str(&formatter.indent_str());
+        formatted_code.push_str(self.where_token.span().as_str());
+        formatted_code.push('\n');
+        formatter.indent();
+        for bound in &self.bounds {
+            formatted_code.push_str(&formatter.indent_str());
+            formatted_code.push_str(bound.ty_name.as_str());
+            write_colon(formatted_code, formatter);
+            bound.bounds.format(formatted_code, formatter)?;
+            formatted_code.push_str(",\n");
+        }
+        formatter.unindent();
+        Ok(())
+    }
+}
+
+/// Write a `:` as used in type annotations and fields, spaced according to the config.
+pub(crate) fn write_colon(formatted_code: &mut FormattedCode, formatter: &Formatter) {
+    let expressions = &formatter.config.expressions;
+    if expressions.space_before_colon {
+        formatted_code.push(' ');
+    }
+    formatted_code.push(':');
+    if expressions.space_after_colon {
+        formatted_code.push(' ');
+    }
+}
+
+/// Write a type annotation, e.g. `: u64`.
+pub(crate) fn write_ty_annotation(
+    ty: &Ty,
+    formatted_code: &mut FormattedCode,
+    formatter: &mut Formatter,
+) -> Result<(), FormatterError> {
+    write_colon(formatted_code, formatter);
+    ty.format(formatted_code, formatter)
+}
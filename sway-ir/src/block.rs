@@ -154,6 +154,14 @@ impl Block {
             .map_or(false, |i| matches!(i, Instruction::Ret { .. }))
     }
 
+    /// Whether the last instruction of this block is a terminator, i.e., a branch or return.
+    pub fn is_terminated(&self, context: &Context) -> bool {
+        match context.blocks[self.0].instructions.last() {
+            Some(val) => val.is_terminator(context),
+            None => false,
+        }
+    }
+
     /// Replace a value within this block.
     ///
     /// For every instruction within the block, any reference to `old_val` is replaced with
@@ -22,6 +22,7 @@ pub enum ConstantValue {
     Bool(bool),
     Uint(u64),
     B256([u8; 32]),
+    U256([u8; 32]),
     String(Vec<u8>),
     Array(Vec<Constant>),
     Struct(Vec<Constant>),
@@ -64,7 +65,7 @@ impl Constant {
         }
     }
 
-    pub fn new_uint(nbits: u8, n: u64) -> Self {
+    pub fn new_uint(nbits: u16, n: u64) -> Self {
         Constant {
             ty: Type::Uint(nbits),
             value: ConstantValue::Uint(n),
@@ -78,6 +79,14 @@ impl Constant {
         }
     }
 
+    /// `bytes` is the big-endian representation of the value.
+    pub fn new_u256(bytes: [u8; 32]) -> Self {
+        Constant {
+            ty: Type::Uint(256),
+            value: ConstantValue::U256(bytes),
+        }
+    }
+
     pub fn new_string(string: Vec<u8>) -> Self {
         Constant {
             ty: Type::String(string.len() as u64),
@@ -117,7 +126,7 @@ impl Constant {
 
     pub fn get_uint(
         context: &mut Context,
-        nbits: u8,
+        nbits: u16,
         value: u64,
         span_md_idx: Option<MetadataIndex>,
     ) -> Value {
@@ -132,6 +141,14 @@ impl Constant {
         Value::new_constant(context, Constant::new_b256(value), span_md_idx)
     }
 
+    pub fn get_u256(
+        context: &mut Context,
+        value: [u8; 32],
+        span_md_idx: Option<MetadataIndex>,
+    ) -> Value {
+        Value::new_constant(context, Constant::new_u256(value), span_md_idx)
+    }
+
     pub fn get_string(
         context: &mut Context,
         value: Vec<u8>,
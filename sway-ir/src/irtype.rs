@@ -5,6 +5,8 @@
 //!   future.
 //! - [`Type::Union`] is a sum type which resembles a C union.  Each member of the union uses the
 //!   same storage and the size of the union is the size of the largest member.
+//! - [`Type::Slice`] is a pointer and a length in bytes, used for the Sway `raw_slice`.  It's
+//!   returned and logged as the data it points to rather than the two words themselves.
 //!
 //! [`Aggregate`] is an abstract collection of [`Type`]s used for structs, unions and arrays,
 //! though see below for future improvements around splitting arrays into a different construct.
@@ -15,8 +17,9 @@ use crate::context::Context;
 pub enum Type {
     Unit,
     Bool,
-    Uint(u8),
+    Uint(u16),
     B256,
+    Slice,
     String(u64),
     Array(Aggregate),
     Union(Aggregate),
@@ -26,7 +29,11 @@ pub enum Type {
 impl Type {
     /// Return whether this is a 'copy' type, one whose value will always fit in a register.
     pub fn is_copy_type(&self) -> bool {
-        matches!(self, Type::Unit | Type::Bool | Type::Uint(_))
+        match self {
+            Type::Unit | Type::Bool => true,
+            Type::Uint(nbits) => *nbits <= 64,
+            _ => false,
+        }
     }
 
     /// Return a string representation of type, used for printing.
@@ -45,6 +52,7 @@ impl Type {
             Type::Bool => "bool".into(),
             Type::Uint(nbits) => format!("u{}", nbits),
             Type::B256 => "b256".into(),
+            Type::Slice => "slice".into(),
             Type::String(n) => format!("string<{}>", n),
             Type::Array(agg) => {
                 let (ty, cnt) = &context.aggregates[agg.0].array_type();
@@ -69,6 +77,7 @@ impl Type {
             (Type::Bool, Type::Bool) => true,
             (Type::Uint(l), Type::Uint(r)) => l == r,
             (Type::B256, Type::B256) => true,
+            (Type::Slice, Type::Slice) => true,
             (Type::String(l), Type::String(r)) => l == r,
 
             (Type::Array(l), Type::Array(r)) => l.is_equivalent(context, r),
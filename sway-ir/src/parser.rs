@@ -405,7 +405,9 @@ mod ir_builder {
                 = ("unit" / "()") _ { IrAstTy::Unit }
                 / "bool" _ { IrAstTy::Bool }
                 / "u64" _ { IrAstTy::U64 }
+                / "u256" _ { IrAstTy::U256 }
                 / "b256" _ { IrAstTy::B256 }
+                / "slice" _ { IrAstTy::Slice }
                 / "string" _ "<" _ sz:decimal() ">" _ { IrAstTy::String(sz) }
                 / array_ty()
                 / struct_ty()
@@ -650,9 +652,15 @@ mod ir_builder {
         }
 
         fn as_constant(&self, context: &mut Context, val_ty: IrAstTy) -> Constant {
+            // Wide integers and b256 share the same literal syntax, so the type decides which is
+            // meant.
+            let value = match (&val_ty, self) {
+                (IrAstTy::U256, IrAstConstValue::B256(bs)) => ConstantValue::U256(*bs),
+                _ => self.as_constant_value(context),
+            };
             Constant {
                 ty: val_ty.to_ir_type(context),
-                value: self.as_constant_value(context),
+                value,
             }
         }
 
@@ -666,7 +674,10 @@ mod ir_builder {
                 IrAstConstValue::Undef(_) => unreachable!("Can't convert 'undef' to a value."),
                 IrAstConstValue::Unit => Constant::get_unit(context, span_md_idx),
                 IrAstConstValue::Bool(b) => Constant::get_bool(context, *b, span_md_idx),
-                IrAstConstValue::B256(bs) => Constant::get_b256(context, *bs, span_md_idx),
+                IrAstConstValue::B256(bs) => match val_ty {
+                    IrAstTy::U256 => Constant::get_u256(context, *bs, span_md_idx),
+                    _ => Constant::get_b256(context, *bs, span_md_idx),
+                },
                 IrAstConstValue::Number(n) => Constant::get_uint(context, 64, *n, span_md_idx),
                 IrAstConstValue::String(s) => Constant::get_string(context, s.clone(), span_md_idx),
                 IrAstConstValue::Array(..) => {
@@ -686,7 +697,9 @@ mod ir_builder {
         Unit,
         Bool,
         U64,
+        U256,
         B256,
+        Slice,
         String(u64),
         Array(Box<IrAstTy>, u64),
         Union(Vec<IrAstTy>),
@@ -699,7 +712,9 @@ mod ir_builder {
                 IrAstTy::Unit => Type::Unit,
                 IrAstTy::Bool => Type::Bool,
                 IrAstTy::U64 => Type::Uint(64),
+                IrAstTy::U256 => Type::Uint(256),
                 IrAstTy::B256 => Type::B256,
+                IrAstTy::Slice => Type::Slice,
                 IrAstTy::String(n) => Type::String(*n),
                 IrAstTy::Array(..) => Type::Array(self.to_ir_aggregate_type(context)),
                 IrAstTy::Union(_) => Type::Union(self.to_ir_aggregate_type(context)),
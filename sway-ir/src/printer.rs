@@ -728,6 +728,13 @@ impl Constant {
                     .collect::<Vec<String>>()
                     .concat()
             ),
+            ConstantValue::U256(bs) => format!(
+                "u256 0x{}",
+                bs.iter()
+                    .map(|b| format!("{b:02x}"))
+                    .collect::<Vec<String>>()
+                    .concat()
+            ),
             ConstantValue::String(bs) => format!(
                 "{} \"{}\"",
                 self.ty.as_string(context),
@@ -154,7 +154,7 @@ impl<'a> InstructionVerifier<'a> {
                     | Instruction::StateStoreQuadWord {
                         stored_val: dst_val,
                         key,
-                    } => self.verify_state_load_store(dst_val, &Type::B256, key)?,
+                    } => self.verify_state_load_store(dst_val, key)?,
                     Instruction::StateStoreWord {
                         stored_val: dst_val,
                         key,
@@ -193,9 +193,12 @@ impl<'a> InstructionVerifier<'a> {
                 Type::Uint(to_nbits) => from_nbits == *to_nbits,
                 _otherwise => false,
             },
-            Type::B256 | Type::String(_) | Type::Array(_) | Type::Union(_) | Type::Struct(_) => {
-                false
-            }
+            Type::B256
+            | Type::Slice
+            | Type::String(_)
+            | Type::Array(_)
+            | Type::Union(_)
+            | Type::Struct(_) => false,
         };
         if !is_valid {
             Err(IrError::VerifyBitcastBetweenInvalidTypes(
@@ -294,7 +297,11 @@ impl<'a> InstructionVerifier<'a> {
             rhs_value.get_type(self.context),
         ) {
             (Some(lhs_ty), Some(rhs_ty)) => match (lhs_ty, rhs_ty) {
-                (Type::Uint(lhs_nbits), Type::Uint(rhs_nbits)) => {
+                // Wide integers live in memory rather than registers and can't be compared
+                // directly.
+                (Type::Uint(lhs_nbits), Type::Uint(rhs_nbits))
+                    if lhs_nbits <= 64 && rhs_nbits <= 64 =>
+                {
                     if lhs_nbits != rhs_nbits {
                         Err(IrError::VerifyCmpTypeMismatch(
                             lhs_ty.as_string(self.context),
@@ -528,15 +535,14 @@ impl<'a> InstructionVerifier<'a> {
         }
     }
 
-    fn verify_state_load_store(
-        &self,
-        dst_val: &Value,
-        val_type: &Type,
-        key: &Value,
-    ) -> Result<(), IrError> {
-        if !matches!(self.get_pointer_type(dst_val), Some(ty) if ty.eq(self.context, val_type)) {
+    fn verify_state_load_store(&self, dst_val: &Value, key: &Value) -> Result<(), IrError> {
+        // Quad words can be loaded into or stored from any 32 byte value.
+        if !matches!(
+            self.get_pointer_type(dst_val),
+            Some(Type::B256 | Type::Uint(256))
+        ) {
             Err(IrError::VerifyStateDestBadType(
-                val_type.as_string(self.context),
+                Type::B256.as_string(self.context),
             ))
         } else if !matches!(self.get_pointer_type(key), Some(Type::B256)) {
             Err(IrError::VerifyStateKeyBadType)
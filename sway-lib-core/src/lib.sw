@@ -1,4 +1,6 @@
 library core;
 
 dep num;
+dep raw_ptr;
+dep raw_slice;
 dep ops;
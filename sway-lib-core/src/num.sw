@@ -89,3 +89,21 @@ impl b256 {
         256
     }
 }
+
+impl u256 {
+    /// The smallest value that can be represented by this integer type.
+    pub fn min() -> u256 {
+        0
+    }
+
+    /// The largest value that can be represented by this integer type,
+    /// 2<sup>256</sup> - 1.
+    pub fn max() -> u256 {
+        0xFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFu256
+    }
+
+    /// The size of this integer type in bits.
+    pub fn bits() -> u32 {
+        256
+    }
+}
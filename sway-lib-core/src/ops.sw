@@ -638,6 +638,368 @@ impl Shiftable for b256 {
     }
 }
 
+/////////////////////////////////////////////////
+// u256
+//
+// The VM has no 256 bit arithmetic, so these are built from 64 bit word
+// operations. They live after every other impl in this file as they rely
+// on the u64 and b256 impls above, and division relies on the rest of the
+// u256 impls.
+/////////////////////////////////////////////////
+
+impl Add for u256 {
+    fn add(self, other: Self) -> Self {
+        let result: u256 = 0;
+        // The lower words are added with wrapping enabled so that their carries can be read from
+        // the overflow register. The flags are restored before adding the most significant words
+        // so that overflowing them behaves just like `u64` addition.
+        asm(lhs: self, rhs: other, res: result, flags, wrap, l, r, carry, carry_1) {
+            move flags flag;
+            ori wrap flags i2;
+            flag wrap;
+            lw l lhs i3;
+            lw r rhs i3;
+            add l l r;
+            move carry of;
+            sw res l i3;
+            lw l lhs i2;
+            lw r rhs i2;
+            add l l r;
+            move carry_1 of;
+            add l l carry;
+            add carry carry_1 of;
+            sw res l i2;
+            lw l lhs i1;
+            lw r rhs i1;
+            add l l r;
+            move carry_1 of;
+            add l l carry;
+            add carry carry_1 of;
+            sw res l i1;
+            flag flags;
+            lw l lhs i0;
+            lw r rhs i0;
+            add l l r;
+            add l l carry;
+            sw res l i0;
+            res: u256
+        }
+    }
+}
+
+impl Subtract for u256 {
+    fn subtract(self, other: Self) -> Self {
+        let result: u256 = 0;
+        // Borrows between the lower words are read from the overflow register, which is all ones
+        // when a wrapping subtraction underflows. As with `add`, the most significant words are
+        // subtracted with the original flags so that underflow behaves just like `u64`.
+        asm(lhs: self, rhs: other, res: result, flags, wrap, l, r, borrow, borrow_1) {
+            move flags flag;
+            ori wrap flags i2;
+            flag wrap;
+            lw l lhs i3;
+            lw r rhs i3;
+            sub l l r;
+            andi borrow of i1;
+            sw res l i3;
+            lw l lhs i2;
+            lw r rhs i2;
+            sub l l r;
+            andi borrow_1 of i1;
+            sub l l borrow;
+            andi borrow of i1;
+            add borrow borrow borrow_1;
+            sw res l i2;
+            lw l lhs i1;
+            lw r rhs i1;
+            sub l l r;
+            andi borrow_1 of i1;
+            sub l l borrow;
+            andi borrow of i1;
+            add borrow borrow borrow_1;
+            sw res l i1;
+            flag flags;
+            lw l lhs i0;
+            lw r rhs i0;
+            sub l l r;
+            sub l l borrow;
+            sw res l i0;
+            res: u256
+        }
+    }
+}
+
+impl Multiply for u256 {
+    fn multiply(self, other: Self) -> Self {
+        let result: u256 = 0;
+        // Long multiplication a column at a time, least significant first. With wrapping enabled
+        // `mul` leaves the upper word of each 128 bit product in the overflow register, and each
+        // product is added into the three word accumulator `acc_0`, `acc_1`, `acc_2`.
+        //
+        // Anything left in the accumulator after the last column, or any nonzero product of words
+        // which lands entirely above 256 bits, has overflowed. That is signalled after the flags
+        // are restored so that it behaves just like `u64` multiplication.
+        asm(lhs: self, rhs: other, res: result, max: 18446744073709551615, flags, wrap, l, r, lo, hi, carry, carry_1, acc_0, acc_1, acc_2, overflow) {
+            move flags flag;
+            ori wrap flags i2;
+            flag wrap;
+            move acc_0 zero;
+            move acc_1 zero;
+            move acc_2 zero;
+            move overflow zero;
+            // Column 0.
+            lw l lhs i3;
+            lw r rhs i3;
+            mul lo l r;
+            move hi of;
+            add acc_0 acc_0 lo;
+            move carry of;
+            add acc_1 acc_1 hi;
+            move carry_1 of;
+            add acc_1 acc_1 carry;
+            add carry carry_1 of;
+            add acc_2 acc_2 carry;
+            sw res acc_0 i3;
+            move acc_0 acc_1;
+            move acc_1 acc_2;
+            move acc_2 zero;
+            // Column 1.
+            lw l lhs i3;
+            lw r rhs i2;
+            mul lo l r;
+            move hi of;
+            add acc_0 acc_0 lo;
+            move carry of;
+            add acc_1 acc_1 hi;
+            move carry_1 of;
+            add acc_1 acc_1 carry;
+            add carry carry_1 of;
+            add acc_2 acc_2 carry;
+            lw l lhs i2;
+            lw r rhs i3;
+            mul lo l r;
+            move hi of;
+            add acc_0 acc_0 lo;
+            move carry of;
+            add acc_1 acc_1 hi;
+            move carry_1 of;
+            add acc_1 acc_1 carry;
+            add carry carry_1 of;
+            add acc_2 acc_2 carry;
+            sw res acc_0 i2;
+            move acc_0 acc_1;
+            move acc_1 acc_2;
+            move acc_2 zero;
+            // Column 2.
+            lw l lhs i3;
+            lw r rhs i1;
+            mul lo l r;
+            move hi of;
+            add acc_0 acc_0 lo;
+            move carry of;
+            add acc_1 acc_1 hi;
+            move carry_1 of;
+            add acc_1 acc_1 carry;
+            add carry carry_1 of;
+            add acc_2 acc_2 carry;
+            lw l lhs i2;
+            lw r rhs i2;
+            mul lo l r;
+            move hi of;
+            add acc_0 acc_0 lo;
+            move carry of;
+            add acc_1 acc_1 hi;
+            move carry_1 of;
+            add acc_1 acc_1 carry;
+            add carry carry_1 of;
+            add acc_2 acc_2 carry;
+            lw l lhs i1;
+            lw r rhs i3;
+            mul lo l r;
+            move hi of;
+            add acc_0 acc_0 lo;
+            move carry of;
+            add acc_1 acc_1 hi;
+            move carry_1 of;
+            add acc_1 acc_1 carry;
+            add carry carry_1 of;
+            add acc_2 acc_2 carry;
+            sw res acc_0 i1;
+            move acc_0 acc_1;
+            move acc_1 acc_2;
+            move acc_2 zero;
+            // Column 3. Only the lower word of each product is needed, but the upper words and
+            // carries are kept to detect overflow.
+            lw l lhs i3;
+            lw r rhs i0;
+            mul lo l r;
+            or overflow overflow of;
+            add acc_0 acc_0 lo;
+            or overflow overflow of;
+            lw l lhs i2;
+            lw r rhs i1;
+            mul lo l r;
+            or overflow overflow of;
+            add acc_0 acc_0 lo;
+            or overflow overflow of;
+            lw l lhs i1;
+            lw r rhs i2;
+            mul lo l r;
+            or overflow overflow of;
+            add acc_0 acc_0 lo;
+            or overflow overflow of;
+            lw l lhs i0;
+            lw r rhs i3;
+            mul lo l r;
+            or overflow overflow of;
+            add acc_0 acc_0 lo;
+            or overflow overflow of;
+            sw res acc_0 i0;
+            or overflow overflow acc_1;
+            or overflow overflow acc_2;
+            // Products landing entirely above 256 bits.
+            lw l lhs i2;
+            lw r rhs i0;
+            mul lo l r;
+            or overflow overflow of;
+            or overflow overflow lo;
+            lw l lhs i1;
+            lw r rhs i1;
+            mul lo l r;
+            or overflow overflow of;
+            or overflow overflow lo;
+            lw r rhs i0;
+            mul lo l r;
+            or overflow overflow of;
+            or overflow overflow lo;
+            lw l lhs i0;
+            lw r rhs i2;
+            mul lo l r;
+            or overflow overflow of;
+            or overflow overflow lo;
+            lw r rhs i1;
+            mul lo l r;
+            or overflow overflow of;
+            or overflow overflow lo;
+            lw r rhs i0;
+            mul lo l r;
+            or overflow overflow of;
+            or overflow overflow lo;
+            gt overflow overflow zero;
+            flag flags;
+            add overflow max overflow;
+            res: u256
+        }
+    }
+}
+
+impl Eq for u256 {
+    fn eq(self, other: Self) -> bool {
+        // Both self and other are addresses of the values, so we can use MEQ.
+        asm(r1: self, r2: other, r3, r4) {
+            addi r3 zero i32;
+            meq r4 r1 r2 r3;
+            r4: bool
+        }
+    }
+}
+
+impl Ord for u256 {
+    fn gt(self, other: Self) -> bool {
+        compare_u256(self, other)
+    }
+
+    fn lt(self, other: Self) -> bool {
+        compare_u256(other, self)
+    }
+}
+
+impl u256 {
+    fn neq(self, other: Self) -> bool {
+        not(self.eq(other))
+    }
+
+    /// Reinterpret the 32 bytes of this value as a `b256`.
+    pub fn as_b256(self) -> b256 {
+        let result: b256 = 0x0000000000000000000000000000000000000000000000000000000000000000;
+        asm(res: result, val: self) {
+            mcpi res val i32;
+            res: b256
+        }
+    }
+
+    /// Downcast to `u64`. Overflows, just like `u64` arithmetic, if the value doesn't fit.
+    pub fn as_u64(self) -> u64 {
+        let(word_1, word_2, word_3, word_4) = decompose_u256(self);
+        if word_1.neq(0) || word_2.neq(0) || word_3.neq(0) {
+            signal_overflow();
+        }
+        word_4
+    }
+}
+
+impl b256 {
+    /// Reinterpret the 32 bytes of this value as a big-endian `u256`.
+    pub fn as_u256(self) -> u256 {
+        let result: u256 = 0;
+        asm(res: result, val: self) {
+            mcpi res val i32;
+            res: u256
+        }
+    }
+}
+
+impl u64 {
+    /// Upcast to `u256`.
+    pub fn as_u256(self) -> u256 {
+        compose_u256(0, 0, 0, self)
+    }
+}
+
+impl BitwiseAnd for u256 {
+    fn binary_and(self, other: Self) -> Self {
+        self.as_b256().binary_and(other.as_b256()).as_u256()
+    }
+}
+
+impl BitwiseOr for u256 {
+    fn binary_or(self, other: Self) -> Self {
+        self.as_b256().binary_or(other.as_b256()).as_u256()
+    }
+}
+
+impl BitwiseXor for u256 {
+    fn binary_xor(self, other: Self) -> Self {
+        self.as_b256().binary_xor(other.as_b256()).as_u256()
+    }
+}
+
+impl OrdEq for u256 {
+}
+
+impl Shiftable for u256 {
+    fn lsh(self, other: u64) -> Self {
+        self.as_b256().lsh(other).as_u256()
+    }
+    fn rsh(self, other: u64) -> Self {
+        self.as_b256().rsh(other).as_u256()
+    }
+}
+
+impl Divide for u256 {
+    fn divide(self, other: Self) -> Self {
+        let(quotient, _remainder) = divide_with_remainder(self, other);
+        quotient
+    }
+}
+
+impl Mod for u256 {
+    fn modulo(self, other: Self) -> Self {
+        let(_quotient, remainder) = divide_with_remainder(self, other);
+        remainder
+    }
+}
+
 /////////////////////////////////////////////////
 // Internal Helpers
 /////////////////////////////////////////////////
@@ -688,3 +1050,143 @@ fn decompose(val: b256) -> (u64, u64, u64, u64) {
     let w4 = get_word_from_b256(val, 24);
     (w1, w2, w3, w4)
 }
+
+/// Extract a single 64 bit word from a u256 value using the specified offset.
+fn get_word_from_u256(val: u256, offset: u64) -> u64 {
+    let mut empty: u64 = 0;
+    asm(r1: val, offset: offset, r2, res: empty) {
+        add r2 r1 offset;
+        lw res r2 i0;
+        res: u64
+    }
+}
+
+/// Build a single u256 value from 4 64 bit words, most significant first.
+fn compose_u256(word_1: u64, word_2: u64, word_3: u64, word_4: u64) -> u256 {
+    let res: u256 = 0;
+    asm(w1: word_1, w2: word_2, w3: word_3, w4: word_4, result: res) {
+        sw result w1 i0;
+        sw result w2 i1;
+        sw result w3 i2;
+        sw result w4 i3;
+        result: u256
+    }
+}
+
+/// Get 4 64 bit words from a single u256 value, most significant first.
+fn decompose_u256(val: u256) -> (u64, u64, u64, u64) {
+    let w1 = get_word_from_u256(val, 0);
+    let w2 = get_word_from_u256(val, 8);
+    let w3 = get_word_from_u256(val, 16);
+    let w4 = get_word_from_u256(val, 24);
+    (w1, w2, w3, w4)
+}
+
+/// Trigger the VM's arithmetic overflow behaviour, which panics unless wrapping has been enabled
+/// in the flags register. The `u256` operations which can't rely on a single overflowing word
+/// use this so that they behave like their `u64` counterparts.
+fn signal_overflow() {
+    asm(max: 18446744073709551615, res) {
+        add res max one;
+    };
+}
+
+/// Whether `left` is strictly greater than `right`, comparing from the least significant word
+/// upwards so that no branching is needed.
+fn compare_u256(left: u256, right: u256) -> bool {
+    asm(lhs: left, rhs: right, l, r, greater, equal, res) {
+        lw l lhs i3;
+        lw r rhs i3;
+        gt res l r;
+        lw l lhs i2;
+        lw r rhs i2;
+        gt greater l r;
+        eq equal l r;
+        and res res equal;
+        or res res greater;
+        lw l lhs i1;
+        lw r rhs i1;
+        gt greater l r;
+        eq equal l r;
+        and res res equal;
+        or res res greater;
+        lw l lhs i0;
+        lw r rhs i0;
+        gt greater l r;
+        eq equal l r;
+        and res res equal;
+        or res res greater;
+        res: bool
+    }
+}
+
+/// Shift a u256 left by a single bit, shifting `bit` in as the new least significant bit.
+fn shift_in_bit(val: u256, bit: u64) -> u256 {
+    let result: u256 = 0;
+    asm(val: val, bit: bit, res: result, word, carry, carry_1) {
+        lw word val i3;
+        srli carry word i63;
+        slli word word i1;
+        or word word bit;
+        sw res word i3;
+        lw word val i2;
+        srli carry_1 word i63;
+        slli word word i1;
+        or word word carry;
+        sw res word i2;
+        lw word val i1;
+        srli carry word i63;
+        slli word word i1;
+        or word word carry_1;
+        sw res word i1;
+        lw word val i0;
+        slli word word i1;
+        or word word carry;
+        sw res word i0;
+        res: u256
+    }
+}
+
+/// Divide one u256 by another using binary long division, returning the quotient and remainder.
+fn divide_with_remainder(dividend: u256, divisor: u256) -> (u256, u256) {
+    let(dividend_word_1, dividend_word_2, dividend_word_3, dividend_word_4) = decompose_u256(dividend);
+    let(divisor_word_1, divisor_word_2, divisor_word_3, divisor_word_4) = decompose_u256(divisor);
+
+    // Values which fit in a single word can use the native instructions, which also takes care
+    // of dividing by zero in the same way as `u64`.
+    if dividend_word_1.eq(0) && dividend_word_2.eq(0) && dividend_word_3.eq(0) && divisor_word_1.eq(0) && divisor_word_2.eq(0) && divisor_word_3.eq(0) {
+        let quotient = dividend_word_4.divide(divisor_word_4);
+        let remainder = dividend_word_4.modulo(divisor_word_4);
+        return (compose_u256(0, 0, 0, quotient), compose_u256(0, 0, 0, remainder));
+    }
+    if divisor_word_1.eq(0) && divisor_word_2.eq(0) && divisor_word_3.eq(0) && divisor_word_4.eq(0) {
+        let _ = dividend_word_4.divide(divisor_word_4);
+        return (compose_u256(0, 0, 0, 0), compose_u256(0, 0, 0, 0));
+    }
+
+    // If the top bit of the divisor is set then the quotient can only be 0 or 1. Handling this
+    // up front means the remainder below can never overflow when it's shifted.
+    if divisor_word_1.rsh(63).eq(1) {
+        if dividend.lt(divisor) {
+            return (compose_u256(0, 0, 0, 0), dividend);
+        } else {
+            return (compose_u256(0, 0, 0, 1), dividend.subtract(divisor));
+        }
+    }
+
+    let mut quotient = compose_u256(0, 0, 0, 0);
+    let mut remainder = compose_u256(0, 0, 0, 0);
+    let mut bit = 256;
+    while bit.gt(0) {
+        bit = bit.subtract(1);
+        let word = get_word_from_u256(dividend, 3.subtract(bit.divide(64)).multiply(8));
+        remainder = shift_in_bit(remainder, word.rsh(bit.modulo(64)).binary_and(1));
+        if remainder.lt(divisor) {
+            quotient = shift_in_bit(quotient, 0);
+        } else {
+            remainder = remainder.subtract(divisor);
+            quotient = shift_in_bit(quotient, 1);
+        }
+    }
+    (quotient, remainder)
+}
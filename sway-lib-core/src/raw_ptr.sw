@@ -0,0 +1,90 @@
+library raw_ptr;
+
+impl raw_ptr {
+    /// Returns `true` if the pointer is null.
+    pub fn is_null(self) -> bool {
+        asm(ptr: self, res) {
+            eq res ptr zero;
+            res: bool
+        }
+    }
+
+    /// Gets the address of the pointer as a `u64`.
+    pub fn addr(self) -> u64 {
+        asm(ptr: self) {
+            ptr: u64
+        }
+    }
+
+    /// Calculates the offset from the pointer, in bytes.
+    pub fn add(self, count: u64) -> raw_ptr {
+        asm(ptr: self, count: count, new) {
+            add new ptr count;
+            new: raw_ptr
+        }
+    }
+
+    /// Calculates the negative offset from the pointer, in bytes.
+    pub fn sub(self, count: u64) -> raw_ptr {
+        asm(ptr: self, count: count, new) {
+            sub new ptr count;
+            new: raw_ptr
+        }
+    }
+
+    /// Reads the given type of value from the address.
+    pub fn read<T>(self) -> T {
+        if __is_reference_type::<T>() {
+            asm(ptr: self) {
+                ptr: T
+            }
+        } else {
+            asm(ptr: self, val) {
+                lw val ptr i0;
+                val: T
+            }
+        }
+    }
+
+    /// Writes the given value to the address.
+    pub fn write<T>(self, val: T) {
+        if __is_reference_type::<T>() {
+            asm(dst: self, src: val, count: __size_of_val(val)) {
+                mcp dst src count;
+            };
+        } else {
+            asm(ptr: self, val: val) {
+                sw ptr val i0;
+            };
+        }
+    }
+
+    /// Copies `count` bytes from `self` to `dst`.
+    pub fn copy_to(self, dst: raw_ptr, count: u64) {
+        asm(dst: dst, src: self, count: count) {
+            mcp dst src count;
+        };
+    }
+
+    /// Reads a single byte from the address.
+    pub fn read_byte(self) -> u8 {
+        // `lb` can't be relied on here, so the byte is copied into the top
+        // of a word on the stack and shifted down instead.
+        asm(ptr: self, word, val) {
+            move word sp;
+            cfei i8;
+            mcpi word ptr i1;
+            lw val word i0;
+            srli val val i56;
+            cfsi i8;
+            val: u8
+        }
+    }
+
+    /// Writes a single byte to the address.
+    pub fn write_byte(self, val: u8) {
+        asm(ptr: self, val: val) {
+            sb ptr val i0;
+        };
+    }
+}
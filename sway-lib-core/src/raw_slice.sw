@@ -0,0 +1,30 @@
+library raw_slice;
+
+use ::raw_ptr::*;
+
+impl raw_slice {
+    /// Forms a slice from a pointer and a length in bytes.
+    pub fn from_parts(ptr: raw_ptr, number_of_bytes: u64) -> raw_slice {
+        asm(parts: (ptr, number_of_bytes)) {
+            parts: raw_slice
+        }
+    }
+
+    /// Returns the pointer to the slice.
+    pub fn ptr(self) -> raw_ptr {
+        let(ptr, _) = into_parts(self);
+        ptr
+    }
+
+    /// Returns the number of bytes in the slice.
+    pub fn number_of_bytes(self) -> u64 {
+        let(_, number_of_bytes) = into_parts(self);
+        number_of_bytes
+    }
+}
+
+fn into_parts(slice: raw_slice) -> (raw_ptr, u64) {
+    asm(parts: slice) {
+        parts: (raw_ptr, u64)
+    }
+}
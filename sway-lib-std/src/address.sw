@@ -1,12 +1,13 @@
 library address;
 //! A wrapper around the b256 type to help enhance type-safety.
 
+use ::convert::From;
 use ::intrinsics::size_of_val;
 use ::mem::{addr_of, eq};
 
 /// The Address type, a struct wrappper around the inner `value`.
 pub struct Address {
-    value: b256,
+    pub value: b256,
 }
 
 impl core::ops::Eq for Address {
@@ -15,19 +16,15 @@ impl core::ops::Eq for Address {
     }
 }
 
-pub trait From {
-    fn from(b: b256) -> Self;
-} {
-    fn into(addr: Address) -> b256 {
-        addr.value
-    }
-}
-
 /// Functions for casting between the b256 and Address types.
-impl From for Address {
+impl From<b256> for Address {
     fn from(bits: b256) -> Address {
         Address {
             value: bits,
         }
     }
+
+    fn into(self) -> b256 {
+        self.value
+    }
 }
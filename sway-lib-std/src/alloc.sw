@@ -3,6 +3,7 @@
 library alloc;
 
 use ::mem::copy;
+use core::raw_ptr::*;
 
 /// Allocates zeroed memory on the heap
 ///
@@ -45,3 +46,26 @@ pub fn realloc(ptr: u64, size: u64, new_size: u64) -> u64 {
         ptr
     }
 }
+
+/// Allocates zeroed memory on the heap in individual bytes, returning a
+/// `raw_ptr` to it. See `alloc` for how the heap is laid out.
+pub fn alloc_bytes(count: u64) -> raw_ptr {
+    asm(count: count, ptr) {
+        aloc count;
+        addi ptr hp i1;
+        ptr: raw_ptr
+    }
+}
+
+/// Reallocates the given area of memory in individual bytes.
+pub fn realloc_bytes(ptr: raw_ptr, count: u64, new_count: u64) -> raw_ptr {
+    if new_count > count {
+        let new_ptr = alloc_bytes(new_count);
+        if count > 0 {
+            ptr.copy_to(new_ptr, count);
+        }
+        new_ptr
+    } else {
+        ptr
+    }
+}
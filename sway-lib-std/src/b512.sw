@@ -4,7 +4,7 @@ library b512;
 /// Stores two b256s in contiguous memory.
 /// Guaranteed to be contiguous for use with ec-recover: std::ecr::ec_recover().
 pub struct B512 {
-    bytes: [b256;
+    pub bytes: [b256;
     2],
 }
 
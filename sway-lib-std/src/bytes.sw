@@ -0,0 +1,199 @@
+library bytes;
+
+use ::alloc::{alloc_bytes, realloc_bytes};
+use ::assert::assert;
+use ::option::Option;
+use core::raw_ptr::*;
+use core::raw_slice::*;
+
+struct RawBytes {
+    ptr: raw_ptr,
+    cap: u64,
+}
+
+impl RawBytes {
+    /// Create a new `RawBytes` with zero capacity.
+    fn new() -> Self {
+        RawBytes {
+            ptr: alloc_bytes(0),
+            cap: 0,
+        }
+    }
+
+    /// Creates a `RawBytes` (on the heap) with exactly the capacity for
+    /// `capacity` bytes. This is equivalent to calling `RawBytes::new` when
+    /// `capacity` is `0`.
+    fn with_capacity(capacity: u64) -> Self {
+        RawBytes {
+            ptr: alloc_bytes(capacity),
+            cap: capacity,
+        }
+    }
+
+    /// Gets the pointer of the allocation.
+    fn ptr(self) -> raw_ptr {
+        self.ptr
+    }
+
+    /// Gets the capacity of the allocation.
+    fn capacity(self) -> u64 {
+        self.cap
+    }
+
+    /// Grow the capacity of the buffer by doubling its current capacity. The
+    /// `realloc_bytes` function allocates memory on the heap and copies the
+    /// data from the old allocation to the new allocation.
+    fn grow(mut self) {
+        let new_cap = if self.cap == 0 {
+            1
+        } else {
+            2 * self.cap
+        };
+
+        self.ptr = realloc_bytes(self.ptr, self.cap, new_cap);
+        self.cap = new_cap;
+    }
+}
+
+/// A growable, heap allocated buffer of bytes.
+pub struct Bytes {
+    buf: RawBytes,
+    len: u64,
+}
+
+impl Bytes {
+    /// Constructs a new, empty `Bytes`.
+    ///
+    /// The buffer will not allocate until bytes are pushed onto it.
+    pub fn new() -> Self {
+        Bytes {
+            buf: ~RawBytes::new(),
+            len: 0,
+        }
+    }
+
+    /// Constructs a new, empty `Bytes` with the specified capacity.
+    ///
+    /// The buffer will be able to hold exactly `capacity` bytes without
+    /// reallocating. If `capacity` is 0, the buffer will not allocate.
+    pub fn with_capacity(capacity: u64) -> Self {
+        Bytes {
+            buf: ~RawBytes::with_capacity(capacity),
+            len: 0,
+        }
+    }
+
+    /// Constructs a new `Bytes` holding a copy of the bytes a `raw_slice`
+    /// points to.
+    pub fn from_raw_slice(slice: raw_slice) -> Self {
+        let number_of_bytes = slice.number_of_bytes();
+        let buf = ~RawBytes::with_capacity(number_of_bytes);
+        slice.ptr().copy_to(buf.ptr(), number_of_bytes);
+        Bytes {
+            buf,
+            len: number_of_bytes,
+        }
+    }
+
+    /// Returns a `raw_slice` of the bytes in the buffer, which is how they
+    /// are returned from contracts and scripts, and logged with `log_slice`.
+    ///
+    /// The slice points into this buffer, so it will see any later changes
+    /// to the bytes, but not any bytes pushed afterwards.
+    pub fn as_raw_slice(self) -> raw_slice {
+        ~raw_slice::from_parts(self.buf.ptr(), self.len)
+    }
+
+    /// Appends a byte to the back of the buffer.
+    pub fn push(mut self, byte: u8) {
+        // If there is insufficient capacity, grow the buffer.
+        if self.len == self.buf.capacity() {
+            self.buf.grow();
+        };
+
+        // Write `byte` at the end of the buffer.
+        self.buf.ptr().add(self.len).write_byte(byte);
+
+        // Increment length.
+        self.len += 1;
+    }
+
+    /// Removes the last byte from the buffer and returns it, or `None` if it
+    /// is empty.
+    pub fn pop(mut self) -> Option<u8> {
+        if self.len == 0 {
+            return Option::None;
+        };
+
+        self.len -= 1;
+        Option::Some(self.buf.ptr().add(self.len).read_byte())
+    }
+
+    /// Returns the byte at `index`, or `None` if `index` is out of bounds.
+    pub fn get(self, index: u64) -> Option<u8> {
+        // First check that index is within bounds.
+        if self.len <= index {
+            return Option::None;
+        };
+
+        Option::Some(self.buf.ptr().add(index).read_byte())
+    }
+
+    /// Overwrites the byte at `index`.
+    ///
+    /// ### Reverts
+    ///
+    /// Reverts if `index` is out of bounds.
+    pub fn set(mut self, index: u64, byte: u8) {
+        assert(index < self.len);
+        self.buf.ptr().add(index).write_byte(byte);
+    }
+
+    /// Appends all of the bytes in `other` to the back of the buffer.
+    pub fn append(mut self, other: Bytes) {
+        let new_len = self.len + other.len;
+        while self.buf.capacity() < new_len {
+            self.buf.grow();
+        }
+
+        other.buf.ptr().copy_to(self.buf.ptr().add(self.len), other.len);
+        self.len = new_len;
+    }
+
+    /// Returns the number of bytes in the buffer, also referred to as its
+    /// 'length'.
+    pub fn len(self) -> u64 {
+        self.len
+    }
+
+    /// Returns `true` if the buffer contains no bytes.
+    pub fn is_empty(self) -> bool {
+        self.len == 0
+    }
+
+    /// Gets the capacity of the allocation.
+    pub fn capacity(self) -> u64 {
+        self.buf.cap
+    }
+
+    /// Clears the buffer, removing all bytes.
+    ///
+    /// Note that this method has no effect on the allocated capacity of the
+    /// buffer.
+    pub fn clear(mut self) {
+        self.len = 0;
+    }
+}
+
+impl core::ops::Eq for Bytes {
+    fn eq(self, other: Self) -> bool {
+        if self.len != other.len {
+            return false;
+        }
+
+        asm(result, lhs: self.buf.ptr(), rhs: other.buf.ptr(), len: self.len) {
+            meq result lhs rhs len;
+            result: bool
+        }
+    }
+}
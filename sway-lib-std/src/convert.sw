@@ -0,0 +1,10 @@
+library convert;
+//! Traits for conversions between types.
+
+/// Used to do value-to-value conversions.
+pub trait From<T> {
+    /// Converts to this type from the input type.
+    fn from(b: T) -> Self;
+    /// Converts this type back into the input type.
+    fn into(self) -> T;
+}
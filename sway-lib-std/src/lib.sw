@@ -9,6 +9,7 @@ dep result;
 dep mem;
 dep alloc;
 dep constants;
+dep convert;
 dep contract_id;
 dep context;
 dep hash;
@@ -27,5 +28,7 @@ dep vm/mod;
 dep flags;
 dep u128;
 dep vec;
+dep bytes;
+dep string;
 
 use core::*;
@@ -1,6 +1,7 @@
 //! Allows logging of arbitrary types, emitted as either `Log` or `Logd` receipts.
 library logging;
 use ::intrinsics::{is_reference_type, size_of};
+use core::raw_slice::*;
 
 /// Log any stack type.
 /// If the type is a reference type, `log` is used.
@@ -17,3 +18,11 @@ pub fn log<T>(value: T) {
         };
     }
 }
+
+/// Log the bytes that a `raw_slice` points to, emitted as a `Logd` receipt.
+/// `log` would emit the pointer and length instead.
+pub fn log_slice(slice: raw_slice) {
+    asm(ptr: slice.ptr(), len: slice.number_of_bytes()) {
+        logd zero zero ptr len;
+    };
+}
@@ -0,0 +1,92 @@
+library string;
+
+use ::bytes::Bytes;
+use ::option::Option;
+use core::raw_slice::*;
+
+/// A growable string, stored as a buffer of UTF-8 encoded bytes.
+///
+/// The bytes aren't validated, so it's up to the constructor of a `String` to
+/// make sure they are UTF-8.
+pub struct String {
+    bytes: Bytes,
+}
+
+impl String {
+    /// Constructs a new, empty `String`.
+    ///
+    /// The string will not allocate until bytes are pushed onto it.
+    pub fn new() -> Self {
+        String {
+            bytes: ~Bytes::new(),
+        }
+    }
+
+    /// Constructs a new, empty `String` with the capacity for `capacity`
+    /// bytes.
+    pub fn with_capacity(capacity: u64) -> Self {
+        String {
+            bytes: ~Bytes::with_capacity(capacity),
+        }
+    }
+
+    /// Constructs a `String` from a buffer of ASCII bytes.
+    pub fn from_ascii(bytes: Bytes) -> Self {
+        String {
+            bytes,
+        }
+    }
+
+    /// Constructs a `String` holding a copy of the bytes a `raw_slice`
+    /// points to.
+    pub fn from_raw_slice(slice: raw_slice) -> Self {
+        String {
+            bytes: ~Bytes::from_raw_slice(slice),
+        }
+    }
+
+    /// Returns the bytes of the string.
+    pub fn as_bytes(self) -> Bytes {
+        self.bytes
+    }
+
+    /// Returns a `raw_slice` of the bytes of the string, which is how they
+    /// are returned from contracts and scripts, and logged with `log_slice`.
+    pub fn as_raw_slice(self) -> raw_slice {
+        self.bytes.as_raw_slice()
+    }
+
+    /// Appends all of the bytes of `other` to the end of the string.
+    pub fn push_str(mut self, other: String) {
+        self.bytes.append(other.bytes);
+    }
+
+    /// Returns the number of bytes in the string.
+    pub fn len(self) -> u64 {
+        self.bytes.len()
+    }
+
+    /// Returns `true` if the string has no bytes.
+    pub fn is_empty(self) -> bool {
+        self.bytes.is_empty()
+    }
+
+    /// Gets the capacity of the allocation, in bytes.
+    pub fn capacity(self) -> u64 {
+        self.bytes.capacity()
+    }
+
+    /// Truncates the string to zero length.
+    ///
+    /// Note that this method has no effect on the allocated capacity of the
+    /// string.
+    pub fn clear(mut self) {
+        self.bytes.clear();
+    }
+}
+
+impl core::ops::Eq for String {
+    fn eq(self, other: Self) -> bool {
+        self.bytes == other.bytes
+    }
+}
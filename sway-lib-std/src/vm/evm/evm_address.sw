@@ -2,12 +2,13 @@ library evm_address;
 
 //! A wrapper around the b256 type to help enhance type-safety.
 
+use ::convert::From;
 use ::intrinsics::size_of_val;
 use ::mem::{addr_of, eq};
 
 /// The Address type, a struct wrappper around the inner `value`.
 pub struct EvmAddress {
-    value: b256,
+    pub value: b256,
 }
 
 impl core::ops::Eq for EvmAddress {
@@ -16,16 +17,8 @@ impl core::ops::Eq for EvmAddress {
     }
 }
 
-pub trait From {
-    fn from(b: b256) -> Self;
-} {
-    fn into(addr: EvmAddress) -> b256 {
-        addr.value
-    }
-}
-
 /// Functions for casting between the b256 and Address types.
-impl From for EvmAddress {
+impl From<b256> for EvmAddress {
     fn from(bits: b256) -> EvmAddress {
         // An EVM address is only 20 bytes, so the first 12 are set to zero
         asm(r1: bits) {
@@ -36,4 +29,8 @@ impl From for EvmAddress {
             value: bits,
         }
     }
+
+    fn into(self) -> b256 {
+        self.value
+    }
 }
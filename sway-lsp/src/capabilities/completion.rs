@@ -43,7 +43,9 @@ fn get_kind(token_type: &TokenType) -> Option<CompletionItemKind> {
         TokenType::TraitDeclaration(_) | TokenType::ImplTrait => {
             Some(CompletionItemKind::INTERFACE)
         }
-        TokenType::StructDeclaration(_) | TokenType::Struct => Some(CompletionItemKind::STRUCT),
+        TokenType::StructDeclaration(_)
+        | TokenType::Struct
+        | TokenType::TypeAliasDeclaration(_) => Some(CompletionItemKind::STRUCT),
         TokenType::EnumDeclaration(_) | TokenType::EnumVariant | TokenType::EnumApplication => {
             Some(CompletionItemKind::ENUM)
         }
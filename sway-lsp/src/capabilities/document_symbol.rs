@@ -42,6 +42,7 @@ fn get_kind(token_type: &TokenType) -> SymbolKind {
         TokenType::StructDeclaration(_) | TokenType::Struct => SymbolKind::STRUCT,
         TokenType::EnumDeclaration(_) | TokenType::EnumApplication => SymbolKind::ENUM,
         TokenType::ConstantDeclaration(_) => SymbolKind::CONSTANT,
+        TokenType::TypeAliasDeclaration(_) => SymbolKind::TYPE_PARAMETER,
         TokenType::Library => SymbolKind::MODULE,
         TokenType::Reassignment => SymbolKind::OPERATOR,
         // currently we return `variable` type as default
@@ -1,6 +1,6 @@
 use crate::core::session::Session;
 use std::sync::Arc;
-use sway_fmt::{get_formatted_data, FormattingOptions};
+use sway_fmt_v2::Formatter;
 use tower_lsp::lsp_types::{
     DocumentFormattingParams, Position, Range, TextDocumentIdentifier, TextEdit,
 };
@@ -15,11 +15,12 @@ pub fn format_document(
     session.format_text(&url)
 }
 
-pub fn get_format_text_edits(text: Arc<str>, options: FormattingOptions) -> Option<Vec<TextEdit>> {
+pub fn get_format_text_edits(text: Arc<str>, mut formatter: Formatter) -> Option<Vec<TextEdit>> {
     // we only format if code is correct
 
-    match get_formatted_data(text.clone(), options, None) {
-        Ok((num_of_lines, formatted_text)) => {
+    match formatter.format(text.clone(), None) {
+        Ok(formatted_text) => {
+            let num_of_lines = formatted_text.split('\n').count();
             let text_lines_count = text.split('\n').count();
             let line_end = std::cmp::max(num_of_lines, text_lines_count) as u32;
 
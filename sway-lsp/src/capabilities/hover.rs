@@ -16,7 +16,9 @@ pub fn get_hover_data(session: Arc<Session>, params: HoverParams) -> Option<Hove
     match session.documents.get(url.path()) {
         Some(ref document) => {
             if let Some(token) = document.get_token_at_position(position) {
-                if token.is_initial_declaration() {
+                if token.is_initial_declaration()
+                    || matches!(token.token_type, TokenType::StructField(_))
+                {
                     Some(get_hover_format(token, &session.documents))
                 } else {
                     // todo: this logic is flawed at the moment
@@ -66,11 +68,23 @@ fn get_hover_format(token: &Token, documents: &Documents) -> Hover {
             extract_visibility(&trait_details.visibility),
             &token.name
         ),
+        TokenType::StructField(field_details) => format!(
+            "struct {}\n{}{}",
+            field_details.parent_ident,
+            extract_visibility(&field_details.visibility),
+            &token.name
+        ),
         TokenType::EnumDeclaration(enum_details) => format!(
             "{}enum {}",
             extract_visibility(&enum_details.visibility),
             &token.name
         ),
+        TokenType::TypeAliasDeclaration(type_alias_details) => format!(
+            "{}type {} = {}",
+            extract_visibility(&type_alias_details.visibility),
+            &token.name,
+            type_alias_details.aliased_type
+        ),
         _ => token.name.clone(),
     };
 
@@ -77,6 +77,7 @@ enum TokenTypeIndex {
     Function = 1,
     Namespace = 3,
     Parameter = 5,
+    Type = 7,
     Variable = 9,
     Enum = 10,
     Struct = 11,
@@ -94,6 +95,7 @@ fn get_type(token_type: &TokenType) -> u32 {
             TokenTypeIndex::Variable as u32
         }
         TokenType::EnumDeclaration(_) => TokenTypeIndex::Enum as u32,
+        TokenType::TypeAliasDeclaration(_) => TokenTypeIndex::Type as u32,
         TokenType::StructDeclaration(_) | TokenType::Struct => TokenTypeIndex::Struct as u32,
         TokenType::TraitDeclaration(_) | TokenType::ImplTrait => TokenTypeIndex::Interface as u32,
         // currently we return `variable` type as default
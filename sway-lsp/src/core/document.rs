@@ -161,7 +161,8 @@ impl TextDocument {
         let manifest =
             pkg::ManifestFile::from_dir(&manifest_dir, forc::utils::SWAY_GIT_TAG).unwrap();
         let lock_path = forc_util::lock_path(manifest.dir());
-        let plan = pkg::BuildPlan::from_lock_file(&lock_path, forc::utils::SWAY_GIT_TAG).unwrap();
+        let plan =
+            pkg::BuildPlan::from_lock_file(&lock_path, false, forc::utils::SWAY_GIT_TAG).unwrap();
         let res = pkg::check(&plan, silent_mode, forc::utils::SWAY_GIT_TAG).unwrap();
 
         match res {
@@ -1,13 +1,16 @@
 use crate::{
     core::token_type::{
         get_const_details, get_enum_details, get_function_details, get_struct_details,
-        get_struct_field_details, get_trait_details, TokenType, VariableDetails,
+        get_struct_field_declaration_details, get_struct_field_details, get_trait_details,
+        get_type_alias_details, TokenType, VariableDetails,
     },
     utils::common::{extract_var_body, get_range_from_span},
 };
 use sway_core::{
-    constants::TUPLE_NAME_PREFIX, parse_tree::MethodName, type_engine::TypeInfo, AstNode,
-    AstNodeContent, Declaration, Expression, FunctionDeclaration, FunctionParameter,
+    constants::{FOR_LOOP_VAR_NAME_PREFIX, TUPLE_NAME_PREFIX},
+    parse_tree::MethodName,
+    type_engine::TypeInfo,
+    AstNode, AstNodeContent, Declaration, Expression, FunctionDeclaration, FunctionParameter,
     IntrinsicFunctionKind, VariableDeclaration, WhileLoop,
 };
 use sway_types::{ident::Ident, span::Span, Spanned};
@@ -94,6 +97,7 @@ impl Token {
                 | TokenType::EnumDeclaration(_)
                 | TokenType::AbiDeclaration
                 | TokenType::ConstantDeclaration(_)
+                | TokenType::TypeAliasDeclaration(_)
                 | TokenType::StorageFieldDeclaration
         )
     }
@@ -171,7 +175,8 @@ fn handle_declaration(declaration: Declaration, tokens: &mut Vec<Token>) {
             let name = variable.name.as_str();
             // Don't collect tokens if the ident's name contains __tuple_
             // The individual tuple elements are handled in the subsequent VariableDeclaration's
-            if !name.contains(TUPLE_NAME_PREFIX) {
+            // Nor for the variables that hold the index and iterable of a desugared for loop
+            if !name.contains(TUPLE_NAME_PREFIX) && !name.contains(FOR_LOOP_VAR_NAME_PREFIX) {
                 tokens.push(Token::from_variable(&variable));
             }
 
@@ -203,7 +208,7 @@ fn handle_declaration(declaration: Declaration, tokens: &mut Vec<Token>) {
             for field in struct_dec.fields {
                 let token = Token::from_ident(
                     &field.name,
-                    TokenType::StructField(get_struct_field_details(ident)),
+                    TokenType::StructField(get_struct_field_declaration_details(ident, &field)),
                 );
                 tokens.push(token);
             }
@@ -274,6 +279,14 @@ fn handle_declaration(declaration: Declaration, tokens: &mut Vec<Token>) {
             );
             tokens.push(token);
         }
+        Declaration::TypeAliasDeclaration(type_alias_dec) => {
+            let ident = &type_alias_dec.name;
+            let token = Token::from_ident(
+                ident,
+                TokenType::TypeAliasDeclaration(get_type_alias_details(&type_alias_dec)),
+            );
+            tokens.push(token);
+        }
         Declaration::StorageDeclaration(storage_dec) => {
             for field in storage_dec.fields {
                 let ident = &field.name;
@@ -303,7 +316,9 @@ fn handle_expression(exp: Expression, tokens: &mut Vec<Token>) {
             handle_expression(*rhs, tokens);
         }
         Expression::VariableExpression { name, .. } => {
-            if !name.as_str().contains(TUPLE_NAME_PREFIX) {
+            if !name.as_str().contains(TUPLE_NAME_PREFIX)
+                && !name.as_str().contains(FOR_LOOP_VAR_NAME_PREFIX)
+            {
                 let token = Token::from_ident(&name, TokenType::VariableExpression);
                 tokens.push(token);
             }
@@ -363,6 +378,9 @@ fn handle_expression(exp: Expression, tokens: &mut Vec<Token>) {
             handle_expression(*value, tokens);
             for branch in branches {
                 // TODO: handle_scrutinee(branch.scrutinee, tokens);
+                if let Some(guard) = branch.guard {
+                    handle_expression(guard, tokens);
+                }
                 handle_expression(branch.result, tokens);
             }
         }
@@ -435,6 +453,7 @@ fn handle_expression(exp: Expression, tokens: &mut Vec<Token>) {
             handle_expression(*prefix, tokens);
             handle_expression(*index, tokens);
         }
+        Expression::ArrayLength { array, .. } => handle_expression(*array, tokens),
         Expression::StorageAccess { field_names, .. } => {
             for field in field_names {
                 let token = Token::from_ident(&field, TokenType::StorageAccess);
@@ -444,12 +463,13 @@ fn handle_expression(exp: Expression, tokens: &mut Vec<Token>) {
         Expression::IntrinsicFunction { kind, .. } => {
             handle_intrinsic_function(kind, tokens);
         }
+        Expression::Break { .. } | Expression::Continue { .. } => {}
     }
 }
 
 fn handle_intrinsic_function(kind: IntrinsicFunctionKind, tokens: &mut Vec<Token>) {
     match kind {
-        IntrinsicFunctionKind::SizeOfVal { exp } => {
+        IntrinsicFunctionKind::SizeOfVal { exp } | IntrinsicFunctionKind::AddrOf { exp } => {
             handle_expression(*exp, tokens);
         }
         IntrinsicFunctionKind::SizeOfType { .. } => {}
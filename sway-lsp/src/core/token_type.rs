@@ -1,6 +1,7 @@
 use crate::utils::function::extract_fn_signature;
 use sway_core::{
-    ConstantDeclaration, EnumDeclaration, StructDeclaration, TraitDeclaration, Visibility,
+    ConstantDeclaration, EnumDeclaration, StructDeclaration, StructField, TraitDeclaration,
+    TypeAliasDeclaration, Visibility,
 };
 use sway_types::{Ident, Span};
 
@@ -17,6 +18,7 @@ pub enum TokenType {
     ImplTrait,
     AbiDeclaration,
     ConstantDeclaration(ConstDetails),
+    TypeAliasDeclaration(TypeAliasDetails),
     TraitFunction,
     EnumVariant,
     StorageFieldDeclaration,
@@ -29,7 +31,7 @@ pub enum TokenType {
     AbiCast,
     StorageAccess,
     EnumApplication,
-    StructField(StructFieldDetails),
+    StructField(StructFieldDeclarationDetails),
     StructExpressionField(StructFieldDetails),
     FunctionParameter,
     Unknown,
@@ -49,6 +51,16 @@ pub fn get_struct_details(struct_dec: &StructDeclaration) -> StructDetails {
     }
 }
 
+pub fn get_struct_field_declaration_details(
+    ident: &Ident,
+    field: &StructField,
+) -> StructFieldDeclarationDetails {
+    StructFieldDeclarationDetails {
+        parent_ident: ident.clone(),
+        visibility: field.visibility,
+    }
+}
+
 pub fn get_struct_field_details(ident: &Ident) -> StructFieldDetails {
     StructFieldDetails {
         parent_ident: ident.clone(),
@@ -73,6 +85,13 @@ pub fn get_const_details(const_dec: &ConstantDeclaration) -> ConstDetails {
     }
 }
 
+pub fn get_type_alias_details(type_alias_dec: &TypeAliasDeclaration) -> TypeAliasDetails {
+    TypeAliasDetails {
+        visibility: type_alias_dec.visibility,
+        aliased_type: type_alias_dec.type_span.as_str().to_string(),
+    }
+}
+
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct FunctionDetails {
     pub signature: String,
@@ -108,12 +127,25 @@ pub struct ConstDetails {
     pub visibility: Visibility,
 }
 
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TypeAliasDetails {
+    pub visibility: Visibility,
+    pub aliased_type: String,
+}
+
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct VariableDetails {
     pub is_mutable: bool,
     pub var_body: VarBody,
 }
 
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct StructFieldDeclarationDetails {
+    // Used for looking up the parent struct that the field is a part of
+    pub parent_ident: Ident,
+    pub visibility: Visibility,
+}
+
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct StructFieldDetails {
     // Used for looking up the parent struct that the field is a part of
@@ -100,6 +100,12 @@ fn handle_declaration(declaration: &TypedDeclaration, tokens: &mut TokenMap) {
                 );
             }
         }
+        TypedDeclaration::TypeAliasDeclaration(type_alias_decl) => {
+            tokens.insert(
+                to_ident_key(&type_alias_decl.name),
+                TokenType::TypedDeclaration(declaration.clone()),
+            );
+        }
         TypedDeclaration::Reassignment(reassignment) => {
             handle_expression(&reassignment.rhs, tokens);
             tokens.insert(
@@ -329,12 +335,14 @@ fn handle_expression(expression: &TypedExpression, tokens: &mut TokenMap) {
                 TokenType::TypedExpression(expression.clone()),
             );
         }
+        TypedExpressionVariant::Break | TypedExpressionVariant::Continue => {}
     }
 }
 
 fn handle_intrinsic_function(kind: &TypedIntrinsicFunctionKind, tokens: &mut TokenMap) {
     match kind {
-        TypedIntrinsicFunctionKind::SizeOfVal { exp } => {
+        TypedIntrinsicFunctionKind::SizeOfVal { exp }
+        | TypedIntrinsicFunctionKind::AddrOf { exp } => {
             handle_expression(exp, tokens);
         }
         TypedIntrinsicFunctionKind::SizeOfType { .. } => {}
@@ -355,6 +363,9 @@ pub fn get_type_id(token: &TokenType) -> Option<TypeId> {
         TokenType::TypedDeclaration(dec) => match dec {
             TypedDeclaration::VariableDeclaration(var_decl) => Some(var_decl.type_ascription),
             TypedDeclaration::ConstantDeclaration(const_decl) => Some(const_decl.value.return_type),
+            TypedDeclaration::TypeAliasDeclaration(type_alias_decl) => {
+                Some(type_alias_decl.type_id)
+            }
             _ => None,
         },
         TokenType::TypedExpression(exp) => Some(exp.return_type),
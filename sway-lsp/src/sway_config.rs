@@ -1,5 +1,5 @@
 use serde_json::Value;
-use sway_fmt::FormattingOptions;
+use sway_fmt_v2::Formatter;
 
 const ALIGN_FIELDS_FIELD: &str = "alignFields";
 const TAB_SIZE_FIELD: &str = "tabSize";
@@ -31,15 +31,20 @@ impl SwayConfig {
     }
 }
 
-// note `FormattingOptions` and `SwayConfig` may be similar at this moment,
+// note `Formatter` and `SwayConfig` may be similar at this moment,
 // but they are not the same thing, `SwayConfig` contains all the data related to the LanguageServer
-// while `FormattingOptions` is only the part that is necessary for 'formating'
-impl From<SwayConfig> for FormattingOptions {
+// while `Formatter` is only the part that is necessary for 'formating'
+impl From<SwayConfig> for Formatter {
     fn from(config: SwayConfig) -> Self {
-        FormattingOptions {
-            align_fields: config.align_fields,
-            tab_size: config.tab_size as u32,
+        let mut formatter = Formatter::default();
+        formatter.config.whitespace.tab_spaces = config.tab_size as usize;
+        if !config.align_fields {
+            let structures = &mut formatter.config.structures;
+            structures.enum_variant_align_threshold = 0;
+            structures.struct_field_align_threshold = 0;
+            structures.storage_field_align_threshold = 0;
         }
+        formatter
     }
 }
 
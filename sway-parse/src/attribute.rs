@@ -10,6 +10,7 @@ impl<T: Parse> Parse for Annotated<T> {
     fn parse(parser: &mut Parser) -> ParseResult<Self> {
         let mut attribute_list = Vec::new();
         loop {
+            attribute_list.extend(parser.peek_doc_comments().map(AttributeDecl::doc_comment));
             if parser.peek::<HashToken>().is_some() {
                 attribute_list.push(parser.parse()?);
             } else {
@@ -37,6 +38,47 @@ pub struct AttributeDecl {
     pub attribute: SquareBrackets<Attribute>,
 }
 
+impl AttributeDecl {
+    /// A `///` doc comment, kept as the attribute `#[doc(text)]` whose single argument spans the
+    /// text of the comment.
+    pub fn doc_comment(doc_comment: &DocComment) -> AttributeDecl {
+        let span = doc_comment.span.clone();
+        let content = Punctuated {
+            value_separator_pairs: vec![],
+            final_value_opt: Some(Box::new(Ident::new(doc_comment.content_span.clone()))),
+        };
+        let attribute = Attribute {
+            name: Ident::new_with_override(DOC_ATTRIBUTE_NAME, span.clone()),
+            args: Some(Parens::synthesized(
+                content,
+                doc_comment.content_span.clone(),
+            )),
+        };
+        AttributeDecl {
+            hash_token: HashToken::synthesized(span.clone()),
+            attribute: SquareBrackets::synthesized(attribute, span),
+        }
+    }
+
+    /// Whether the attribute was written as a `///` doc comment.
+    pub fn is_doc_comment(&self) -> bool {
+        self.hash_token.span().as_str().starts_with("///")
+    }
+
+    /// The text of a doc comment, without the `///` or the single space that usually follows.
+    ///
+    /// Unlike the argument of the attribute, which is trimmed, this keeps any further
+    /// indentation, e.g. within a code block.
+    pub fn doc_comment_text(&self) -> Option<String> {
+        if !self.is_doc_comment() {
+            return None;
+        }
+        let args = self.attribute.get().args.as_ref()?;
+        let text = args.span().as_str().trim_end().to_string();
+        Some(text.strip_prefix(' ').map(str::to_string).unwrap_or(text))
+    }
+}
+
 impl Spanned for AttributeDecl {
     fn span(&self) -> Span {
         Span::join(self.hash_token.span(), self.attribute.span())
@@ -54,6 +96,9 @@ impl Parse for AttributeDecl {
     }
 }
 
+/// The name of the attribute holding documentation, which is usually written as a `///` comment.
+pub const DOC_ATTRIBUTE_NAME: &str = "doc";
+
 #[derive(Clone, Debug)]
 pub struct Attribute {
     pub name: Ident,
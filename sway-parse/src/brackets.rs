@@ -16,6 +16,15 @@ macro_rules! define_brackets (
                 }
             }
 
+            /// Create a delimited value that wasn't parsed from its delimiters, e.g. the brackets
+            /// of an attribute written as a doc comment.
+            pub fn synthesized(inner: T, span: Span) -> $ty_name<T> {
+                $ty_name {
+                    inner,
+                    span,
+                }
+            }
+
             pub fn get(&self) -> &T {
                 &self.inner
             }
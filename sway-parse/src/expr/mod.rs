@@ -35,6 +35,19 @@ pub enum Expr {
         condition: Box<Expr>,
         block: Braces<CodeBlockContents>,
     },
+    For {
+        for_token: ForToken,
+        pattern: Box<Pattern>,
+        in_token: InToken,
+        iterable: ForIterable,
+        block: Braces<CodeBlockContents>,
+    },
+    Break {
+        break_token: BreakToken,
+    },
+    Continue {
+        continue_token: ContinueToken,
+    },
     FuncApp {
         func: Box<Expr>,
         args: Parens<Punctuated<Expr, CommaToken>>,
@@ -202,6 +215,11 @@ impl Spanned for Expr {
             Expr::While {
                 while_token, block, ..
             } => Span::join(while_token.span(), block.span()),
+            Expr::For {
+                for_token, block, ..
+            } => Span::join(for_token.span(), block.span()),
+            Expr::Break { break_token } => break_token.span(),
+            Expr::Continue { continue_token } => continue_token.span(),
             Expr::FuncApp { func, args } => Span::join(func.span(), args.span()),
             Expr::Index { target, arg } => Span::join(target.span(), arg.span()),
             Expr::MethodCall { target, args, .. } => Span::join(target.span(), args.span()),
@@ -382,6 +400,43 @@ impl Parse for IfCondition {
     }
 }
 
+/// The value iterated over by a `for` loop, either an exclusive range, e.g. `0..n`, or an array.
+#[derive(Clone, Debug)]
+pub enum ForIterable {
+    Range {
+        start: Box<Expr>,
+        double_dot_token: DoubleDotToken,
+        end: Box<Expr>,
+    },
+    Expr(Box<Expr>),
+}
+
+impl Spanned for ForIterable {
+    fn span(&self) -> Span {
+        match self {
+            ForIterable::Range { start, end, .. } => Span::join(start.span(), end.span()),
+            ForIterable::Expr(expr) => expr.span(),
+        }
+    }
+}
+
+impl Parse for ForIterable {
+    fn parse(parser: &mut Parser) -> ParseResult<ForIterable> {
+        let start = Box::new(parse_condition(parser)?);
+        match parser.take() {
+            Some(double_dot_token) => {
+                let end = Box::new(parse_condition(parser)?);
+                Ok(ForIterable::Range {
+                    start,
+                    double_dot_token,
+                    end,
+                })
+            }
+            None => Ok(ForIterable::Expr(start)),
+        }
+    }
+}
+
 #[derive(Clone, Debug)]
 pub enum ExprTupleDescriptor {
     Nil,
@@ -405,6 +460,7 @@ pub enum ExprArrayDescriptor {
 #[derive(Clone, Debug)]
 pub struct MatchBranch {
     pub pattern: Pattern,
+    pub guard_opt: Option<(IfToken, Box<Expr>)>,
     pub fat_right_arrow_token: FatRightArrowToken,
     pub kind: MatchBranchKind,
 }
@@ -1116,6 +1172,25 @@ fn parse_atom(parser: &mut Parser, ctx: ParseExprCtx) -> ParseResult<Expr> {
             block,
         });
     }
+    if let Some(for_token) = parser.take() {
+        let pattern = parser.parse()?;
+        let in_token = parser.parse()?;
+        let iterable = parser.parse()?;
+        let block = parser.parse()?;
+        return Ok(Expr::For {
+            for_token,
+            pattern,
+            in_token,
+            iterable,
+            block,
+        });
+    }
+    if let Some(break_token) = parser.take() {
+        return Ok(Expr::Break { break_token });
+    }
+    if let Some(continue_token) = parser.take() {
+        return Ok(Expr::Continue { continue_token });
+    }
     if parser.peek::<OpenAngleBracketToken>().is_some()
         || parser.peek::<DoubleColonToken>().is_some()
         || parser.peek::<TildeToken>().is_some()
@@ -1218,10 +1293,15 @@ impl ParseToEnd for ExprArrayDescriptor {
 impl Parse for MatchBranch {
     fn parse(parser: &mut Parser) -> ParseResult<MatchBranch> {
         let pattern = parser.parse()?;
+        let guard_opt = match parser.take() {
+            Some(if_token) => Some((if_token, parser.parse()?)),
+            None => None,
+        };
         let fat_right_arrow_token = parser.parse()?;
         let kind = parser.parse()?;
         Ok(MatchBranch {
             pattern,
+            guard_opt,
             fat_right_arrow_token,
             kind,
         })
@@ -1306,7 +1386,8 @@ impl Expr {
                 | Expr::Asm(..)
                 | Expr::If(..)
                 | Expr::Match { .. }
-                | Expr::While { .. },
+                | Expr::While { .. }
+                | Expr::For { .. },
         )
     }
 }
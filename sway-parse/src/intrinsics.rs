@@ -1,5 +1,6 @@
 #[derive(Eq, PartialEq)]
 pub enum Intrinsic {
+    AddrOf,
     GetStorageKey,
     IsReferenceType,
     SizeOf,
@@ -10,6 +11,7 @@ impl Intrinsic {
     pub fn try_from_str(raw: &str) -> Option<Intrinsic> {
         use Intrinsic::*;
         Some(match raw {
+            "__addr_of" => AddrOf,
             "__get_storage_key" => GetStorageKey,
             "__is_reference_type" => IsReferenceType,
             "__size_of" => SizeOf,
@@ -4,6 +4,7 @@ use crate::priv_prelude::*;
 pub struct ItemAbi {
     pub abi_token: AbiToken,
     pub name: Ident,
+    pub super_traits: Option<(ColonToken, Traits)>,
     pub abi_items: Braces<Vec<(Annotated<FnSignature>, SemicolonToken)>>,
     pub abi_defs_opt: Option<Braces<Vec<Annotated<ItemFn>>>>,
 }
@@ -23,11 +24,19 @@ impl Parse for ItemAbi {
     fn parse(parser: &mut Parser) -> ParseResult<ItemAbi> {
         let abi_token = parser.parse()?;
         let name = parser.parse()?;
+        let super_traits = match parser.take() {
+            Some(colon_token) => {
+                let traits = parser.parse()?;
+                Some((colon_token, traits))
+            }
+            None => None,
+        };
         let abi_items = parser.parse()?;
         let abi_defs_opt = Braces::try_parse(parser)?;
         Ok(ItemAbi {
             abi_token,
             name,
+            super_traits,
             abi_items,
             abi_defs_opt,
         })
@@ -7,7 +7,7 @@ pub struct ItemImpl {
     pub trait_opt: Option<(PathType, ForToken)>,
     pub ty: Ty,
     pub where_clause_opt: Option<WhereClause>,
-    pub contents: Braces<Vec<Annotated<ItemFn>>>,
+    pub contents: Braces<Vec<Annotated<ItemImplItem>>>,
 }
 
 impl Spanned for ItemImpl {
@@ -52,3 +52,71 @@ impl Parse for ItemImpl {
         })
     }
 }
+
+/// An item within the body of an `impl`.
+#[allow(clippy::large_enum_variant)]
+#[derive(Clone, Debug)]
+pub enum ItemImplItem {
+    Fn(ItemFn),
+    Const(ItemConst),
+    Type(ImplType),
+}
+
+impl Spanned for ItemImplItem {
+    fn span(&self) -> Span {
+        match self {
+            ItemImplItem::Fn(item_fn) => item_fn.span(),
+            ItemImplItem::Const(item_const) => item_const.span(),
+            ItemImplItem::Type(impl_type) => impl_type.span(),
+        }
+    }
+}
+
+impl Parse for ItemImplItem {
+    fn parse(parser: &mut Parser) -> ParseResult<ItemImplItem> {
+        if parser.peek::<ConstToken>().is_some() || parser.peek2::<PubToken, ConstToken>().is_some()
+        {
+            let item_const = parser.parse()?;
+            return Ok(ItemImplItem::Const(item_const));
+        }
+        if parser.peek::<TypeToken>().is_some() {
+            let impl_type = parser.parse()?;
+            return Ok(ItemImplItem::Type(impl_type));
+        }
+        let item_fn = parser.parse()?;
+        Ok(ItemImplItem::Fn(item_fn))
+    }
+}
+
+/// The definition of a trait's associated type within an `impl`, e.g. `type Item = u64;`.
+#[derive(Clone, Debug)]
+pub struct ImplType {
+    pub type_token: TypeToken,
+    pub name: Ident,
+    pub eq_token: EqToken,
+    pub ty: Ty,
+    pub semicolon_token: SemicolonToken,
+}
+
+impl Spanned for ImplType {
+    fn span(&self) -> Span {
+        Span::join(self.type_token.span(), self.semicolon_token.span())
+    }
+}
+
+impl Parse for ImplType {
+    fn parse(parser: &mut Parser) -> ParseResult<ImplType> {
+        let type_token = parser.parse()?;
+        let name = parser.parse()?;
+        let eq_token = parser.parse()?;
+        let ty = parser.parse()?;
+        let semicolon_token = parser.parse()?;
+        Ok(ImplType {
+            type_token,
+            name,
+            eq_token,
+            ty,
+            semicolon_token,
+        })
+    }
+}
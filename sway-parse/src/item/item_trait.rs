@@ -5,8 +5,9 @@ pub struct ItemTrait {
     pub visibility: Option<PubToken>,
     pub trait_token: TraitToken,
     pub name: Ident,
+    pub generics: Option<GenericParams>,
     pub super_traits: Option<(ColonToken, Traits)>,
-    pub trait_items: Braces<Vec<(Annotated<FnSignature>, SemicolonToken)>>,
+    pub trait_items: Braces<Vec<(Annotated<ItemTraitItem>, SemicolonToken)>>,
     pub trait_defs_opt: Option<Braces<Vec<Annotated<ItemFn>>>>,
 }
 
@@ -24,6 +25,104 @@ impl Spanned for ItemTrait {
     }
 }
 
+/// An item within the body of a trait, which is followed by a `;`.
+#[allow(clippy::large_enum_variant)]
+#[derive(Clone, Debug)]
+pub enum ItemTraitItem {
+    Fn(FnSignature),
+    Const(TraitConst),
+    Type(TraitType),
+}
+
+impl Spanned for ItemTraitItem {
+    fn span(&self) -> Span {
+        match self {
+            ItemTraitItem::Fn(fn_signature) => fn_signature.span(),
+            ItemTraitItem::Const(trait_const) => trait_const.span(),
+            ItemTraitItem::Type(trait_type) => trait_type.span(),
+        }
+    }
+}
+
+impl Parse for ItemTraitItem {
+    fn parse(parser: &mut Parser) -> ParseResult<ItemTraitItem> {
+        if parser.peek::<ConstToken>().is_some() {
+            let trait_const = parser.parse()?;
+            return Ok(ItemTraitItem::Const(trait_const));
+        }
+        if parser.peek::<TypeToken>().is_some() {
+            let trait_type = parser.parse()?;
+            return Ok(ItemTraitItem::Type(trait_type));
+        }
+        let fn_signature = parser.parse()?;
+        Ok(ItemTraitItem::Fn(fn_signature))
+    }
+}
+
+/// An associated constant of a trait, e.g. `const ID: u64;`, with an optional default value.
+#[derive(Clone, Debug)]
+pub struct TraitConst {
+    pub const_token: ConstToken,
+    pub name: Ident,
+    pub colon_token: ColonToken,
+    pub ty: Ty,
+    pub default_opt: Option<(EqToken, Expr)>,
+}
+
+impl Spanned for TraitConst {
+    fn span(&self) -> Span {
+        let end = match &self.default_opt {
+            Some((_eq_token, expr)) => expr.span(),
+            None => self.ty.span(),
+        };
+        Span::join(self.const_token.span(), end)
+    }
+}
+
+impl Parse for TraitConst {
+    fn parse(parser: &mut Parser) -> ParseResult<TraitConst> {
+        let const_token = parser.parse()?;
+        let name = parser.parse()?;
+        let colon_token = parser.parse()?;
+        let ty = parser.parse()?;
+        let default_opt = match parser.take() {
+            Some(eq_token) => {
+                let expr = parser.parse()?;
+                Some((eq_token, expr))
+            }
+            None => None,
+        };
+        Ok(TraitConst {
+            const_token,
+            name,
+            colon_token,
+            ty,
+            default_opt,
+        })
+    }
+}
+
+/// An associated type of a trait, e.g. `type Item;`.
+#[derive(Clone, Debug)]
+pub struct TraitType {
+    pub type_token: TypeToken,
+    pub name: Ident,
+}
+
+impl Spanned for TraitType {
+    fn span(&self) -> Span {
+        Span::join(self.type_token.span(), self.name.span())
+    }
+}
+
+impl Parse for TraitType {
+    fn parse(parser: &mut Parser) -> ParseResult<TraitType> {
+        let type_token = parser.parse()?;
+        let name = parser.parse()?;
+        Ok(TraitType { type_token, name })
+    }
+}
+
 #[derive(Clone, Debug)]
 pub struct Traits {
     pub prefix: PathType,
@@ -35,6 +134,11 @@ impl Parse for ItemTrait {
         let visibility = parser.take();
         let trait_token = parser.parse()?;
         let name = parser.parse()?;
+        let generics = if parser.peek::<OpenAngleBracketToken>().is_some() {
+            Some(parser.parse()?)
+        } else {
+            None
+        };
         let super_traits = match parser.take() {
             Some(colon_token) => {
                 let traits = parser.parse()?;
@@ -48,6 +152,7 @@ impl Parse for ItemTrait {
             visibility,
             trait_token,
             name,
+            generics,
             super_traits,
             trait_items,
             trait_defs_opt,
@@ -8,6 +8,7 @@ pub mod item_impl;
 pub mod item_storage;
 pub mod item_struct;
 pub mod item_trait;
+pub mod item_type_alias;
 pub mod item_use;
 
 pub type Item = Annotated<ItemKind>;
@@ -33,6 +34,7 @@ pub enum ItemKind {
     Abi(ItemAbi),
     Const(ItemConst),
     Storage(ItemStorage),
+    TypeAlias(ItemTypeAlias),
 }
 
 impl Spanned for ItemKind {
@@ -47,6 +49,7 @@ impl Spanned for ItemKind {
             ItemKind::Abi(item_abi) => item_abi.span(),
             ItemKind::Const(item_const) => item_const.span(),
             ItemKind::Storage(item_storage) => item_storage.span(),
+            ItemKind::TypeAlias(item_type_alias) => item_type_alias.span(),
         }
     }
 }
@@ -93,12 +96,17 @@ impl Parse for ItemKind {
             let item_storage = parser.parse()?;
             return Ok(ItemKind::Storage(item_storage));
         }
+        if parser.peek::<TypeToken>().is_some() || parser.peek2::<PubToken, TypeToken>().is_some() {
+            let item_type_alias = parser.parse()?;
+            return Ok(ItemKind::TypeAlias(item_type_alias));
+        }
         Err(parser.emit_error(ParseErrorKind::ExpectedAnItem))
     }
 }
 
 #[derive(Clone, Debug)]
 pub struct TypeField {
+    pub visibility: Option<PubToken>,
     pub name: Ident,
     pub colon_token: ColonToken,
     pub ty: Ty,
@@ -106,16 +114,22 @@ pub struct TypeField {
 
 impl Spanned for TypeField {
     fn span(&self) -> Span {
-        Span::join(self.name.span(), self.ty.span())
+        let start = match &self.visibility {
+            Some(pub_token) => pub_token.span(),
+            None => self.name.span(),
+        };
+        Span::join(start, self.ty.span())
     }
 }
 
 impl Parse for TypeField {
     fn parse(parser: &mut Parser) -> ParseResult<TypeField> {
+        let visibility = parser.take();
         let name = parser.parse()?;
         let colon_token = parser.parse()?;
         let ty = parser.parse()?;
         Ok(TypeField {
+            visibility,
             name,
             colon_token,
             ty,
@@ -275,6 +289,7 @@ impl Parse for FnSignature {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::{ImplType, ItemImplItem, ItemTraitItem, TraitConst, TraitType};
 
     fn parse_item(input: &str) -> Item {
         let token_stream = crate::token::lex(&Arc::from(input), 0, input.len(), None).unwrap();
@@ -727,4 +742,175 @@ mod tests {
             panic!("Parsed ABI is not an ABI.");
         }
     }
+
+    #[test]
+    fn parse_doc_comments() {
+        let item = parse_item(
+            r#"
+            //// Not a doc comment.
+            /// The first line.
+            ///
+            ///     indented
+            #[storage(read)]
+            /// After an attribute.
+            fn f() -> bool {
+                false
+            }
+            "#,
+        );
+
+        assert!(matches!(item.value, ItemKind::Fn(_)));
+
+        assert_eq!(item.attribute_list.len(), 5);
+        let docs = item
+            .attribute_list
+            .iter()
+            .map(|attrib| attrib.doc_comment_text())
+            .collect::<Vec<_>>();
+        assert_eq!(
+            docs,
+            vec![
+                Some("The first line.".to_string()),
+                Some("".to_string()),
+                Some("    indented".to_string()),
+                None,
+                Some("After an attribute.".to_string()),
+            ]
+        );
+
+        let attrib = item.attribute_list.first().unwrap();
+        assert!(attrib.is_doc_comment());
+        assert_eq!(attrib.attribute.get().name.as_str(), "doc");
+        assert_eq!(attrib.span().as_str(), "/// The first line.");
+        let attrib = item.attribute_list.get(3).unwrap();
+        assert!(!attrib.is_doc_comment());
+        assert_eq!(attrib.attribute.get().name.as_str(), "storage");
+    }
+
+    #[test]
+    fn parse_doc_comments_nested() {
+        let item = parse_item(
+            r#"
+            abi A {
+                /// Gets the value.
+                fn f() -> u64;
+                // A plain comment.
+                fn g();
+            }
+            "#,
+        );
+
+        assert!(item.attribute_list.is_empty());
+        if let ItemKind::Abi(item_abi) = item.value {
+            let mut decls = item_abi.abi_items.get().iter();
+
+            let attribs = &decls.next().unwrap().0.attribute_list;
+            assert_eq!(attribs.len(), 1);
+            assert_eq!(
+                attribs[0].doc_comment_text(),
+                Some("Gets the value.".to_string())
+            );
+
+            assert!(decls.next().unwrap().0.attribute_list.is_empty());
+        } else {
+            panic!("Parsed ABI is not an ABI.");
+        }
+    }
+
+    #[test]
+    fn parse_generic_trait_with_associated_items() {
+        let item = parse_item(
+            r#"
+            trait Convert<T> {
+                type Output;
+                const ID: u64 = 1;
+                fn convert(t: T) -> Self::Output;
+            }
+            "#,
+        );
+
+        if let ItemKind::Trait(item_trait) = item.value {
+            let params = item_trait.generics.unwrap().parameters.into_inner();
+            assert_eq!(
+                params
+                    .into_iter()
+                    .map(|ident| ident.as_str().to_string())
+                    .collect::<Vec<_>>(),
+                vec!["T"]
+            );
+
+            let mut decls = item_trait.trait_items.get().iter();
+            assert!(matches!(
+                &decls.next().unwrap().0.value,
+                ItemTraitItem::Type(TraitType { name, .. }) if name.as_str() == "Output"
+            ));
+            assert!(matches!(
+                &decls.next().unwrap().0.value,
+                ItemTraitItem::Const(TraitConst { name, default_opt: Some(_), .. }) if name.as_str() == "ID"
+            ));
+            assert!(matches!(
+                &decls.next().unwrap().0.value,
+                ItemTraitItem::Fn(fn_signature) if fn_signature.name.as_str() == "convert"
+            ));
+            assert!(decls.next().is_none());
+        } else {
+            panic!("Parsed trait is not a trait.");
+        }
+    }
+
+    #[test]
+    fn parse_impl_with_associated_items() {
+        let item = parse_item(
+            r#"
+            impl Convert<u64> for Foo {
+                type Output = b256;
+                const ID: u64 = 2;
+                fn convert(t: u64) -> b256 {
+                    foo(t)
+                }
+            }
+            "#,
+        );
+
+        if let ItemKind::Impl(item_impl) = item.value {
+            let mut items = item_impl.contents.get().iter();
+            assert!(matches!(
+                &items.next().unwrap().value,
+                ItemImplItem::Type(ImplType { name, .. }) if name.as_str() == "Output"
+            ));
+            assert!(matches!(
+                &items.next().unwrap().value,
+                ItemImplItem::Const(ItemConst { name, .. }) if name.as_str() == "ID"
+            ));
+            assert!(matches!(&items.next().unwrap().value, ItemImplItem::Fn(_)));
+            assert!(items.next().is_none());
+        } else {
+            panic!("Parsed impl is not an impl.");
+        }
+    }
+
+    #[test]
+    fn parse_generic_type_alias() {
+        let item = parse_item(
+            r#"
+            pub type Map<V> = StorageMap<b256, V>;
+            "#,
+        );
+
+        if let ItemKind::TypeAlias(item_type_alias) = item.value {
+            assert!(item_type_alias.visibility.is_some());
+            assert_eq!(item_type_alias.name.as_str(), "Map");
+            let params = item_type_alias.generics.unwrap().parameters.into_inner();
+            assert_eq!(
+                params
+                    .into_iter()
+                    .map(|ident| ident.as_str().to_string())
+                    .collect::<Vec<_>>(),
+                vec!["V"]
+            );
+            assert!(matches!(item_type_alias.ty, Ty::Path(_)));
+        } else {
+            panic!("Parsed item is not a type alias.");
+        }
+    }
 }
@@ -52,8 +52,10 @@ define_keyword!(FnToken, "fn");
 define_keyword!(TraitToken, "trait");
 define_keyword!(ImplToken, "impl");
 define_keyword!(ForToken, "for");
+define_keyword!(InToken, "in");
 define_keyword!(AbiToken, "abi");
 define_keyword!(ConstToken, "const");
+define_keyword!(TypeToken, "type");
 define_keyword!(StorageToken, "storage");
 define_keyword!(StrToken, "str");
 define_keyword!(AsmToken, "asm");
@@ -64,6 +66,8 @@ define_keyword!(MatchToken, "match");
 define_keyword!(MutToken, "mut");
 define_keyword!(LetToken, "let");
 define_keyword!(WhileToken, "while");
+define_keyword!(BreakToken, "break");
+define_keyword!(ContinueToken, "continue");
 define_keyword!(WhereToken, "where");
 define_keyword!(RefToken, "ref");
 define_keyword!(DerefToken, "deref");
@@ -87,6 +91,12 @@ macro_rules! define_token (
             pub fn ident(&self) -> Ident {
                 Ident::new(self.span())
             }
+
+            /// Create a token that wasn't parsed from its punctuation, e.g. the `#` of an
+            /// attribute written as a doc comment.
+            pub fn synthesized(span: Span) -> $ty_name {
+                $ty_name { span }
+            }
         }
 
         impl From<$ty_name> for Ident {
@@ -164,8 +174,9 @@ define_token!(
     [Equals, GreaterThan],
     [GreaterThan, Equals]
 );
-define_token!(DotToken, "`.`", [Dot], []);
-define_token!(DoubleDotToken, "`..`", [Dot, Dot], [Dot]);
+define_token!(DotToken, "`.`", [Dot], [Dot]);
+define_token!(DoubleDotToken, "`..`", [Dot, Dot], [Dot, Equals]);
+define_token!(DoubleDotEqToken, "`..=`", [Dot, Dot, Equals], [Equals]);
 define_token!(BangToken, "`!`", [Bang], [Equals]);
 define_token!(PercentToken, "`%`", [Percent], []);
 define_token!(AddToken, "`+`", [Add], [Equals]);
@@ -31,7 +31,7 @@ pub use crate::{
         asm::{AsmBlock, AsmRegisterDeclaration},
         op_code::Instruction,
         AbiCastArgs, CodeBlockContents, Expr, ExprArrayDescriptor, ExprStructField,
-        ExprTupleDescriptor, IfCondition, IfExpr, MatchBranch, MatchBranchKind,
+        ExprTupleDescriptor, ForIterable, IfCondition, IfExpr, MatchBranch, MatchBranchKind,
     },
     generics::{GenericArgs, GenericParams},
     intrinsics::*,
@@ -40,10 +40,11 @@ pub use crate::{
         item_const::ItemConst,
         item_enum::ItemEnum,
         item_fn::ItemFn,
-        item_impl::ItemImpl,
+        item_impl::{ImplType, ItemImpl, ItemImplItem},
         item_storage::{ItemStorage, StorageField},
         item_struct::ItemStruct,
-        item_trait::{ItemTrait, Traits},
+        item_trait::{ItemTrait, ItemTraitItem, TraitConst, TraitType, Traits},
+        item_type_alias::ItemTypeAlias,
         item_use::{ItemUse, UseTree},
         FnArg, FnArgs, FnSignature, Item, ItemKind, TypeField,
     },
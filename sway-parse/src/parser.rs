@@ -2,6 +2,10 @@ use crate::priv_prelude::*;
 
 pub struct Parser<'a, 'e> {
     token_trees: &'a [TokenTree],
+    /// The doc comments of the token stream, along with the number of its token trees, by which
+    /// the index of the next token tree is found.
+    doc_comments: &'a [DocComment],
+    num_token_trees: usize,
     full_span: Span,
     errors: &'e mut Vec<ParseError>,
 }
@@ -10,6 +14,8 @@ impl<'a, 'e> Parser<'a, 'e> {
     pub fn new(token_stream: &'a TokenStream, errors: &'e mut Vec<ParseError>) -> Parser<'a, 'e> {
         Parser {
             token_trees: token_stream.token_trees(),
+            doc_comments: token_stream.doc_comments(),
+            num_token_trees: token_stream.token_trees().len(),
             full_span: token_stream.span(),
             errors,
         }
@@ -131,6 +137,8 @@ impl<'a, 'e> Parser<'a, 'e> {
                 self.token_trees = rest;
                 let parser = Parser {
                     token_trees: token_stream.token_trees(),
+                    doc_comments: token_stream.doc_comments(),
+                    num_token_trees: token_stream.token_trees().len(),
                     full_span: token_stream.span(),
                     errors: self.errors,
                 };
@@ -140,6 +148,14 @@ impl<'a, 'e> Parser<'a, 'e> {
         }
     }
 
+    /// The doc comments between the previous token tree and the next.
+    pub fn peek_doc_comments(&self) -> impl Iterator<Item = &'a DocComment> {
+        let token_index = self.num_token_trees - self.token_trees.len();
+        self.doc_comments
+            .iter()
+            .filter(move |doc_comment| doc_comment.token_index == token_index)
+    }
+
     pub fn is_empty(&self) -> bool {
         self.token_trees.is_empty()
     }
@@ -20,6 +20,18 @@ pub enum Pattern {
         fields: Braces<Punctuated<PatternStructField, CommaToken>>,
     },
     Tuple(Parens<Punctuated<Pattern, CommaToken>>),
+    /// An inclusive range of literals, e.g. `0..=9`.
+    Range {
+        start: Literal,
+        double_dot_eq_token: DoubleDotEqToken,
+        end: Literal,
+    },
+    /// Two alternative patterns, e.g. `A | B`.
+    Or {
+        lhs: Box<Pattern>,
+        pipe_token: PipeToken,
+        rhs: Box<Pattern>,
+    },
 }
 
 impl Spanned for Pattern {
@@ -35,68 +47,90 @@ impl Spanned for Pattern {
             Pattern::Constructor { path, args } => Span::join(path.span(), args.span()),
             Pattern::Struct { path, fields } => Span::join(path.span(), fields.span()),
             Pattern::Tuple(pat_tuple) => pat_tuple.span(),
+            Pattern::Range { start, end, .. } => Span::join(start.span(), end.span()),
+            Pattern::Or { lhs, rhs, .. } => Span::join(lhs.span(), rhs.span()),
         }
     }
 }
 
 impl Parse for Pattern {
     fn parse(parser: &mut Parser) -> ParseResult<Pattern> {
-        if let Some(mut_token) = parser.take() {
-            let mutable = Some(mut_token);
-            let name = parser.parse()?;
-            return Ok(Pattern::Var { mutable, name });
-        }
-        if parser.peek::<TrueToken>().is_some() {
-            let ident = parser.parse::<Ident>()?;
-            return Ok(Pattern::Literal(Literal::Bool(LitBool {
-                span: ident.span(),
-                kind: LitBoolType::True,
-            })));
-        }
-        if parser.peek::<FalseToken>().is_some() {
-            let ident = parser.parse::<Ident>()?;
-            return Ok(Pattern::Literal(Literal::Bool(LitBool {
-                span: ident.span(),
-                kind: LitBoolType::False,
-            })));
-        }
-        if let Some(literal) = parser.take() {
-            return Ok(Pattern::Literal(literal));
-        }
-        if let Some(tuple) = Parens::try_parse(parser)? {
-            return Ok(Pattern::Tuple(tuple));
-        }
-        if let Some(underscore_token) = parser.take() {
-            return Ok(Pattern::Wildcard { underscore_token });
+        let mut pattern = parse_single_pattern(parser)?;
+        while let Some(pipe_token) = parser.take() {
+            let rhs = parse_single_pattern(parser)?;
+            pattern = Pattern::Or {
+                lhs: Box::new(pattern),
+                pipe_token,
+                rhs: Box::new(rhs),
+            };
         }
+        Ok(pattern)
+    }
+}
 
-        let path = parser.parse::<PathExpr>()?;
-        if let Some(args) = Parens::try_parse(parser)? {
-            return Ok(Pattern::Constructor { path, args });
+/// Parses a pattern which is not an or-pattern at its top level.
+fn parse_single_pattern(parser: &mut Parser) -> ParseResult<Pattern> {
+    if let Some(mut_token) = parser.take() {
+        let mutable = Some(mut_token);
+        let name = parser.parse()?;
+        return Ok(Pattern::Var { mutable, name });
+    }
+    if parser.peek::<TrueToken>().is_some() {
+        let ident = parser.parse::<Ident>()?;
+        return Ok(Pattern::Literal(Literal::Bool(LitBool {
+            span: ident.span(),
+            kind: LitBoolType::True,
+        })));
+    }
+    if parser.peek::<FalseToken>().is_some() {
+        let ident = parser.parse::<Ident>()?;
+        return Ok(Pattern::Literal(Literal::Bool(LitBool {
+            span: ident.span(),
+            kind: LitBoolType::False,
+        })));
+    }
+    if let Some(literal) = parser.take() {
+        if let Some(double_dot_eq_token) = parser.take() {
+            let end = parser.parse()?;
+            return Ok(Pattern::Range {
+                start: literal,
+                double_dot_eq_token,
+                end,
+            });
         }
-        if let Some(fields) = Braces::try_parse(parser)? {
-            let inner_fields: &Punctuated<_, _> = fields.get();
-            let rest_pattern = inner_fields
-                .value_separator_pairs
-                .iter()
-                .find(|(p, _)| matches!(p, PatternStructField::Rest { token: _ }));
+        return Ok(Pattern::Literal(literal));
+    }
+    if let Some(tuple) = Parens::try_parse(parser)? {
+        return Ok(Pattern::Tuple(tuple));
+    }
+    if let Some(underscore_token) = parser.take() {
+        return Ok(Pattern::Wildcard { underscore_token });
+    }
 
-            if let Some((rest_pattern, _)) = rest_pattern {
-                return Err(parser.emit_error_with_span(
-                    ParseErrorKind::UnexpectedRestPattern,
-                    rest_pattern.span(),
-                ));
-            }
+    let path = parser.parse::<PathExpr>()?;
+    if let Some(args) = Parens::try_parse(parser)? {
+        return Ok(Pattern::Constructor { path, args });
+    }
+    if let Some(fields) = Braces::try_parse(parser)? {
+        let inner_fields: &Punctuated<_, _> = fields.get();
+        let rest_pattern = inner_fields
+            .value_separator_pairs
+            .iter()
+            .find(|(p, _)| matches!(p, PatternStructField::Rest { token: _ }));
 
-            return Ok(Pattern::Struct { path, fields });
-        }
-        match path.try_into_ident() {
-            Ok(name) => Ok(Pattern::Var {
-                mutable: None,
-                name,
-            }),
-            Err(path) => Ok(Pattern::Constant(path)),
+        if let Some((rest_pattern, _)) = rest_pattern {
+            return Err(parser
+                .emit_error_with_span(ParseErrorKind::UnexpectedRestPattern, rest_pattern.span()));
         }
+
+        return Ok(Pattern::Struct { path, fields });
+    }
+    match path.try_into_ident() {
+        Ok(name) => Ok(Pattern::Var {
+            mutable: None,
+            name,
+        }),
+        Err(path) => Ok(Pattern::Constant(path)),
     }
 }
 
@@ -21,6 +21,7 @@ pub use {
             item_storage::ItemStorage,
             item_struct::ItemStruct,
             item_trait::{ItemTrait, Traits},
+            item_type_alias::ItemTypeAlias,
             item_use::ItemUse,
             FnSignature, Item, ItemKind, TypeField,
         },
@@ -32,7 +33,7 @@ pub use {
         pattern::Pattern,
         punctuated::Punctuated,
         statement::{Statement, StatementLet},
-        token::{Delimiter, Group, Punct, PunctKind, Spacing, TokenStream, TokenTree},
+        token::{Delimiter, DocComment, Group, Punct, PunctKind, Spacing, TokenStream, TokenTree},
         ty::Ty,
         where_clause::{WhereBound, WhereClause},
     },
@@ -127,9 +127,28 @@ impl Spanned for TokenTree {
 #[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Hash)]
 pub struct TokenStream {
     token_trees: Vec<TokenTree>,
+    doc_comments: Vec<DocComment>,
     full_span: Span,
 }
 
+/// A `///` doc comment. Doc comments aren't token trees, but are kept alongside those of the
+/// enclosing stream so that the parser can attach them to the item which follows.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Hash)]
+pub struct DocComment {
+    /// The span of the whole comment, excluding the trailing newline.
+    pub span: Span,
+    /// The span of the text following the `///`.
+    pub content_span: Span,
+    /// The index of the token tree within the enclosing stream that the comment precedes.
+    pub token_index: usize,
+}
+
+impl Spanned for DocComment {
+    fn span(&self) -> Span {
+        self.span.clone()
+    }
+}
+
 #[derive(Error, Debug, Clone, PartialEq, Eq, PartialOrd, Hash)]
 #[error("{}", kind)]
 pub struct LexError {
@@ -284,6 +303,7 @@ pub fn lex(
     .peekable();
     let mut parent_token_trees = Vec::new();
     let mut token_trees = Vec::new();
+    let mut doc_comments = Vec::new();
     while let Some((index, character)) = char_indices.next() {
         if character.is_whitespace() {
             continue;
@@ -292,10 +312,27 @@ pub fn lex(
             match char_indices.peek() {
                 Some((_, '/')) => {
                     let _ = char_indices.next();
-                    for (_, character) in char_indices.by_ref() {
-                        if character == '\n' {
+                    // `///` begins a doc comment, but `////` and beyond are plain comments.
+                    let is_doc_comment = matches!(char_indices.peek(), Some((_, '/')))
+                        && !src[index..].starts_with("////");
+                    let mut comment_end = end;
+                    while let Some((next_index, character)) = char_indices.peek() {
+                        if *character == '\n' {
+                            comment_end = *next_index;
                             break;
                         }
+                        let _ = char_indices.next();
+                    }
+                    if is_doc_comment {
+                        let span =
+                            Span::new(src.clone(), index, comment_end, path.clone()).unwrap();
+                        let content_span =
+                            Span::new(src.clone(), index + 3, comment_end, path.clone()).unwrap();
+                        doc_comments.push(DocComment {
+                            span,
+                            content_span,
+                            token_index: token_trees.len(),
+                        });
                     }
                 }
                 Some((_, '*')) => {
@@ -374,7 +411,7 @@ pub fn lex(
                     } else {
                         Spacing::Alone
                     };
-                    let span = Span::new(src.clone(), start, end, path.clone()).unwrap();
+                    let span = Span::new(src.clone(), index, end, path.clone()).unwrap();
                     let punct = Punct {
                         kind: PunctKind::ForwardSlash,
                         spacing,
@@ -383,7 +420,7 @@ pub fn lex(
                     token_trees.push(TokenTree::Punct(punct));
                 }
                 None => {
-                    let span = Span::new(src.clone(), start, end, path.clone()).unwrap();
+                    let span = Span::new(src.clone(), index, end, path.clone()).unwrap();
                     let punct = Punct {
                         kind: PunctKind::ForwardSlash,
                         spacing: Spacing::Alone,
@@ -415,7 +452,8 @@ pub fn lex(
         }
         if let Some(delimiter) = character.as_open_delimiter() {
             let token_trees = mem::take(&mut token_trees);
-            parent_token_trees.push((token_trees, index, delimiter));
+            let doc_comments = mem::take(&mut doc_comments);
+            parent_token_trees.push((token_trees, doc_comments, index, delimiter));
             continue;
         }
         if let Some(close_delimiter) = character.as_close_delimiter() {
@@ -435,7 +473,7 @@ pub fn lex(
                         .unwrap(),
                     });
                 }
-                Some((mut parent, open_index, open_delimiter)) => {
+                Some((mut parent, mut parent_doc_comments, open_index, open_delimiter)) => {
                     if open_delimiter != close_delimiter {
                         return Err(LexError {
                             kind: LexErrorKind::MismatchedDelimiters {
@@ -454,12 +492,14 @@ pub fn lex(
                         });
                     }
                     mem::swap(&mut parent, &mut token_trees);
+                    mem::swap(&mut parent_doc_comments, &mut doc_comments);
                     let start_index = open_index + open_delimiter.as_open_char().len_utf8();
                     let full_span =
                         Span::new(src.clone(), start_index, index, path.clone()).unwrap();
                     let group = Group {
                         token_stream: TokenStream {
                             token_trees: parent,
+                            doc_comments: parent_doc_comments,
                             full_span,
                         },
                         delimiter: close_delimiter,
@@ -715,6 +755,7 @@ pub fn lex(
                         "u16" => LitIntType::U16,
                         "u32" => LitIntType::U32,
                         "u64" => LitIntType::U64,
+                        "u256" => LitIntType::U256,
                         "i8" => LitIntType::I8,
                         "i16" => LitIntType::I16,
                         "i32" => LitIntType::I32,
@@ -778,7 +819,7 @@ pub fn lex(
             .unwrap(),
         });
     }
-    if let Some((_, open_position, open_delimiter)) = parent_token_trees.pop() {
+    if let Some((_, _, open_position, open_delimiter)) = parent_token_trees.pop() {
         return Err(LexError {
             kind: LexErrorKind::UnclosedDelimiter {
                 open_position,
@@ -796,6 +837,7 @@ pub fn lex(
     let full_span = Span::new(src.clone(), start, end, path).unwrap();
     let token_stream = TokenStream {
         token_trees,
+        doc_comments,
         full_span,
     };
     Ok(token_stream)
@@ -966,6 +1008,10 @@ impl TokenStream {
     pub fn token_trees(&self) -> &[TokenTree] {
         &self.token_trees
     }
+
+    pub fn doc_comments(&self) -> &[DocComment] {
+        &self.doc_comments
+    }
 }
 
 impl Spanned for TokenStream {
@@ -973,3 +1019,30 @@ impl Spanned for TokenStream {
         self.full_span.clone()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn lex_forward_slash_span() {
+        let input = "a / b /c";
+        let token_stream = lex(&Arc::from(input), 0, input.len(), None).unwrap();
+        let slash_spans = token_stream
+            .token_trees()
+            .iter()
+            .filter_map(|token_tree| match token_tree {
+                TokenTree::Punct(Punct {
+                    kind: PunctKind::ForwardSlash,
+                    span,
+                    ..
+                }) => Some((span.start(), span.end(), span.as_str().to_string())),
+                _ => None,
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(
+            slash_spans,
+            vec![(2, 3, "/".to_string()), (6, 7, "/".to_string())]
+        );
+    }
+}
@@ -0,0 +1,15 @@
+contract;
+
+abi Ownable {
+    fn owner() -> b256;
+}
+
+abi Token: Ownable {
+    fn total_supply() -> u64;
+}
+
+impl Token for Contract {
+    fn total_supply() -> u64 {
+        100
+    }
+}
@@ -0,0 +1,23 @@
+script;
+
+fn f(x: u64) -> u64 {
+    if x > 5 {
+        break;
+    }
+    x
+}
+
+fn g(x: u64) -> u64 {
+    let mut y = x;
+    while y < 10 {
+        y = y + 1;
+    }
+    if y > 20 {
+        continue;
+    }
+    y
+}
+
+fn main() -> u64 {
+    f(1) + g(2)
+}
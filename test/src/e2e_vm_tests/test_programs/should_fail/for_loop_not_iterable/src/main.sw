@@ -0,0 +1,8 @@
+script;
+
+fn main() {
+    let n = 5;
+    for i in n {
+        let x = i;
+    }
+}
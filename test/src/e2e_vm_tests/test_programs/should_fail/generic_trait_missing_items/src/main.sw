@@ -0,0 +1,49 @@
+script;
+
+trait Convert<T> {
+    fn convert(self) -> T;
+}
+
+trait Container {
+    type Item;
+    const ID: u64;
+    fn first(self) -> Self::Item;
+}
+
+struct X {
+    x: u64,
+}
+
+// This impl doesn't give `Convert` its type argument:
+impl Convert for X {
+    fn convert(self) -> u64 {
+        self.x
+    }
+}
+
+// This impl is missing both `type Item` and `const ID`:
+impl Container for X {
+    fn first(self) -> u64 {
+        self.x
+    }
+}
+
+struct Y {
+    y: u64,
+}
+
+// This impl defines items that `Container` doesn't declare:
+impl Container for Y {
+    type Item = u64;
+    type Other = bool;
+    const ID: u64 = 0;
+    const OTHER: u64 = 1;
+
+    fn first(self) -> u64 {
+        self.y
+    }
+}
+
+fn main() {
+    let _ = Y::MISSING;
+}
@@ -0,0 +1,40 @@
+script;
+
+enum Error {
+    Timeout: u64,
+    NotFound: (),
+}
+
+fn unbound(error: Error) -> u64 {
+    match error {
+        Error::Timeout(c) | Error::NotFound => c,
+    }
+}
+
+fn empty_range(n: u64) -> u64 {
+    match n {
+        10..=1 => 1,
+        _ => 0,
+    }
+}
+
+fn not_numeric(n: u64) -> u64 {
+    match n {
+        "a"..="z" => 1,
+        _ => 0,
+    }
+}
+
+fn guarded(n: u64) -> u64 {
+    match n {
+        x if x > 1 => 1,
+        0 | 1 => 0,
+    }
+}
+
+fn main() -> u64 {
+    let _ = unbound(Error::NotFound);
+    let _ = empty_range(1);
+    let _ = not_numeric(1);
+    guarded(5)
+}
@@ -0,0 +1,26 @@
+script;
+
+fn small(n: u8) -> u64 {
+    match n {
+        0u8..=300 => 1,
+        _ => 0,
+    }
+}
+
+fn medium(n: u16) -> u64 {
+    match n {
+        1u16..=70000 => 1,
+        _ => 0,
+    }
+}
+
+fn large(n: u32) -> u64 {
+    match n {
+        0u32..=4294967296 => 1,
+        _ => 0,
+    }
+}
+
+fn main() -> u64 {
+    small(1u8) + medium(1u16) + large(1u32)
+}
@@ -1,5 +1,5 @@
 library baz;
 
 pub struct ExampleStruct<T> {
-  a_field: T
+  pub a_field: T
 }
@@ -0,0 +1,13 @@
+script;
+
+enum Signal {
+    pub Go: (),
+    Stop: (),
+}
+
+fn main() -> bool {
+    match Signal::Go {
+        Signal::Go => true,
+        Signal::Stop => false,
+    }
+}
@@ -0,0 +1,5 @@
+script;
+
+type Selfish = Selfish;
+
+fn main() {}
@@ -0,0 +1,8 @@
+library lib;
+
+dep lib/items;
+
+pub use items::*;
+// Re-exporting the module from itself turns `Item` into a synonym for itself.
+pub use ::lib::*;
+use ::lib::Item as Again;
@@ -0,0 +1,5 @@
+library items;
+
+pub struct Item {
+    pub a: u64,
+}
@@ -0,0 +1,7 @@
+script;
+
+dep lib;
+
+fn main() -> u64 {
+    0
+}
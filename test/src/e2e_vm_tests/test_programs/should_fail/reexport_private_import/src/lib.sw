@@ -0,0 +1,8 @@
+library lib;
+
+dep lib/items;
+
+pub use items::Public;
+pub use items::Internal as Exported;
+use items::Hidden;
+use items::Internal;
@@ -0,0 +1,13 @@
+library items;
+
+pub struct Public {
+    pub a: u64,
+}
+
+pub struct Hidden {
+    pub b: u64,
+}
+
+pub struct Internal {
+    pub c: u64,
+}
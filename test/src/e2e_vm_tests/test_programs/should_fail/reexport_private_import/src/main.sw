@@ -0,0 +1,16 @@
+script;
+
+dep lib;
+
+// This is not okay because `lib` imports `Hidden` without `pub`.
+use lib::Hidden;
+
+// This is okay, but only brings the re-exported `Public` and `Exported` into scope.
+use lib::*;
+
+fn main() -> bool {
+    let a = Public { a: 1 };
+    let b = Exported { c: 2 };
+    let c = Internal { c: 3 };
+    false
+}
@@ -0,0 +1,21 @@
+script;
+
+dep wallet;
+
+use wallet::Wallet;
+
+fn main() -> u64 {
+    let constructed = Wallet {
+        owner: 1,
+        balance: 100,
+    };
+
+    let mut wallet = ~Wallet::new(1);
+    let balance = wallet.balance;
+    wallet.balance = 100;
+    let destructured = match wallet {
+        Wallet { owner, balance } => balance,
+    };
+
+    wallet.owner
+}
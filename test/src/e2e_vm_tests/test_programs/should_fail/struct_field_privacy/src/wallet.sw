@@ -0,0 +1,15 @@
+library wallet;
+
+pub struct Wallet {
+    pub owner: u64,
+    balance: u64,
+}
+
+impl Wallet {
+    pub fn new(owner: u64) -> Wallet {
+        Wallet {
+            owner,
+            balance: 0,
+        }
+    }
+}
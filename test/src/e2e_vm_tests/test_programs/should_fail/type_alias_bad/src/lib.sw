@@ -0,0 +1,4 @@
+library lib;
+
+pub type Public = u64;
+type Hidden = bool;
@@ -0,0 +1,13 @@
+script;
+
+dep lib;
+
+use lib::{Hidden, Public};
+
+type Pair<T> = (T, T);
+
+fn main() -> u64 {
+    let a: Public = 1;
+    let b: Pair<u64, u64> = (a, a);
+    b.0
+}
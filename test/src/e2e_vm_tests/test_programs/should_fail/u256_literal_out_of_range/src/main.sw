@@ -0,0 +1,6 @@
+script;
+
+fn main() -> u64 {
+    let too_big = 115792089237316195423570985008687907853269984665640564039457584007913129639936u256;
+    0
+}
@@ -0,0 +1,14 @@
+script;
+
+fn narrow(wide: u256) -> u64 {
+    wide
+}
+
+fn widen(narrow: u64) -> u256 {
+    narrow
+}
+
+fn main() -> u64 {
+    let _wide = widen(1);
+    narrow(2)
+}
@@ -0,0 +1,11 @@
+contract;
+
+abi ContractA {
+    fn foo() -> u64;
+}
+
+impl ContractA for Contract {
+    fn foo() -> u64 {
+        42
+    }
+}
@@ -0,0 +1,8 @@
+script;
+
+// `CONTRACT_ID` is generated from the `contract_a` contract dependency and its salt.
+use contract_a::CONTRACT_ID;
+
+fn main() -> bool {
+    CONTRACT_ID == 0x272c29b6a6da4430ca7975591192c6d8a2b4ace93b4ae11a50d9c071baef77b9
+}
@@ -0,0 +1,59 @@
+contract;
+
+abi Versioned {
+    fn version() -> u64;
+}
+
+abi Ownable: Versioned {
+    fn owner() -> b256;
+    fn is_owner(who: b256) -> bool;
+}
+
+abi Pausable {
+    fn is_paused() -> bool;
+}
+
+abi Token: Ownable + Pausable {
+    fn total_supply() -> u64;
+    fn version_of(token: b256) -> u64;
+}
+
+const OWNER = 0x0000000000000000000000000000000000000000000000000000000000000001;
+
+impl Versioned for Contract {
+    fn version() -> u64 {
+        2
+    }
+}
+
+impl Ownable for Contract {
+    fn owner() -> b256 {
+        OWNER
+    }
+
+    fn is_owner(who: b256) -> bool {
+        who == OWNER
+    }
+}
+
+impl Pausable for Contract {
+    fn is_paused() -> bool {
+        false
+    }
+}
+
+impl Token for Contract {
+    fn total_supply() -> u64 {
+        100
+    }
+
+    fn version_of(token: b256) -> u64 {
+        // Methods inherited through `Ownable` can be called through `Token`.
+        let caller = abi(Token, token);
+        if caller.is_paused() {
+            0
+        } else {
+            caller.version()
+        }
+    }
+}
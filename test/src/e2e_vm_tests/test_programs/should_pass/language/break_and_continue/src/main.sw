@@ -0,0 +1,86 @@
+script;
+
+use std::assert::assert;
+
+// Sum the odd numbers below `n`, stopping early once the sum exceeds `max`.
+fn sum_odd(n: u64, max: u64) -> u64 {
+    let mut i = 0;
+    let mut sum = 0;
+    while i < n {
+        i = i + 1;
+        if i % 2 == 0 {
+            continue;
+        }
+        sum = sum + i;
+        if sum > max {
+            break;
+        }
+    }
+    sum
+}
+
+// Find the first multiple of both `a` and `b`, breaking out of an infinite loop.
+fn first_common_multiple(a: u64, b: u64) -> u64 {
+    let mut x = a;
+    while true {
+        if x % b == 0 {
+            break;
+        }
+        x = x + a;
+    }
+    x
+}
+
+// A `break` or `continue` only affects the innermost loop.
+fn nested(n: u64) -> u64 {
+    let mut count = 0;
+    let mut i = 0;
+    while i < n {
+        i = i + 1;
+        let mut j = 0;
+        while true {
+            j = j + 1;
+            if j > i {
+                break;
+            }
+            if j == 2 {
+                continue;
+            }
+            count = count + 1;
+        }
+        if i == 3 {
+            continue;
+        }
+        count = count + 10;
+    }
+    count
+}
+
+// `break` and `continue` diverge, so may be used where a value is expected.
+fn diverging_branches(n: u64) -> u64 {
+    let mut i = 0;
+    let mut sum = 0;
+    while true {
+        i = i + 1;
+        let x = if i > n {
+            break
+        } else if i == 2 {
+            continue
+        } else {
+            i * 2
+        };
+        sum = sum + x;
+    }
+    sum
+}
+
+fn main() -> bool {
+    assert(sum_odd(10, 100) == 25);
+    assert(sum_odd(10, 5) == 9);
+    assert(sum_odd(0, 5) == 0);
+    assert(first_common_multiple(4, 6) == 12);
+    // inner loop counts: i=1 -> 1, i=2 -> 1, i=3 -> 2, i=4 -> 3; outer adds 10 for all but i=3
+    assert(nested(4) == 37);
+    assert(diverging_branches(4) == 16);
+    true
+}
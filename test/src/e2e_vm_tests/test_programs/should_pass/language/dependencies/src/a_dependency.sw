@@ -2,5 +2,5 @@ library foo;
 dep bar;
 dep inner/bar;
 pub struct Foo {
-    foo: str[3],
+    pub foo: str[3],
 }
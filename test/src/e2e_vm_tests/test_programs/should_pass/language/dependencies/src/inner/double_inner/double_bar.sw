@@ -2,5 +2,5 @@ library double_bar;
 //use ::foo::Foo;
 
 pub struct DoubleBar {
-    a: u32,
+    pub a: u32,
 }
@@ -0,0 +1,110 @@
+script;
+
+use std::assert::assert;
+
+struct Point {
+    x: u64,
+    y: u64,
+}
+
+fn sum_range(start: u64, end: u64) -> u64 {
+    let mut sum = 0;
+    for i in start..end {
+        sum = sum + i;
+    }
+    sum
+}
+
+// The range takes the type of its bounds.
+fn count_u8(n: u8) -> u8 {
+    let mut count = 0u8;
+    for i in 0..n {
+        count = count + 1u8;
+        assert(i < n);
+    }
+    count
+}
+
+fn sum_array(values: [u64; 5]) -> u64 {
+    let mut sum = 0;
+    for value in values {
+        sum = sum + value;
+    }
+    sum
+}
+
+fn dot_products(points: [Point; 3]) -> u64 {
+    let mut sum = 0;
+    for point in points {
+        sum = sum + point.x * point.y;
+    }
+    sum
+}
+
+fn sum_pairs() -> u64 {
+    let mut sum = 0;
+    for (a, b) in [(1, 2), (3, 4), (5, 6)] {
+        sum = sum + a * b;
+    }
+    sum
+}
+
+// A `continue` moves on to the next element, and a `break` leaves the loop.
+fn sum_until(values: [u64; 6], skip: u64, stop: u64) -> u64 {
+    let mut sum = 0;
+    for value in values {
+        if value == skip {
+            continue;
+        }
+        if value == stop {
+            break;
+        }
+        sum = sum + value;
+    }
+    sum
+}
+
+fn nested(n: u64) -> u64 {
+    let mut count = 0;
+    for i in 0..n {
+        for j in 0..i {
+            if j == 1 {
+                continue;
+            }
+            count = count + 1;
+        }
+    }
+    count
+}
+
+fn first_index_of(values: [u64; 4], target: u64) -> u64 {
+    let mut index = 0;
+    for value in values {
+        if value == target {
+            return index;
+        }
+        index = index + 1;
+    }
+    index
+}
+
+fn main() -> bool {
+    assert(sum_range(0, 10) == 45);
+    assert(sum_range(5, 8) == 18);
+    assert(sum_range(3, 3) == 0);
+    assert(sum_range(8, 3) == 0);
+    assert(count_u8(7) == 7u8);
+    assert(sum_array([1, 2, 3, 4, 5]) == 15);
+    assert(dot_products([
+        Point { x: 1, y: 2 },
+        Point { x: 3, y: 4 },
+        Point { x: 5, y: 6 },
+    ]) == 44);
+    assert(sum_pairs() == 44);
+    assert(sum_until([1, 2, 3, 4, 5, 6], 2, 5) == 8);
+    // i=1 -> 1, i=2 -> 1, i=3 -> 2, i=4 -> 3
+    assert(nested(5) == 7);
+    assert(first_index_of([7, 8, 9, 10], 9) == 2);
+    assert(first_index_of([7, 8, 9, 10], 11) == 4);
+    true
+}
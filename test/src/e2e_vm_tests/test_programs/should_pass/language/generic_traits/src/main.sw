@@ -0,0 +1,128 @@
+script;
+
+use std::assert::assert;
+use std::convert::From;
+
+struct Wrapper {
+    value: u64,
+}
+
+impl From<u64> for Wrapper {
+    fn from(value: u64) -> Wrapper {
+        Wrapper {
+            value,
+        }
+    }
+
+    fn into(self) -> u64 {
+        self.value
+    }
+}
+
+impl From<bool> for Wrapper {
+    fn from(b: bool) -> Wrapper {
+        Wrapper {
+            value: if b { 1 } else { 0 },
+        }
+    }
+
+    fn into(self) -> bool {
+        self.value != 0
+    }
+}
+
+trait Scale<T> {
+    fn scale(self, factor: T) -> Self;
+} {
+    fn scale_twice(self, factor: T) -> Self {
+        self.scale(factor).scale(factor)
+    }
+}
+
+impl Scale<u64> for Wrapper {
+    fn scale(self, factor: u64) -> Wrapper {
+        Wrapper {
+            value: self.value * factor,
+        }
+    }
+}
+
+trait Container {
+    type Item;
+    const CAPACITY: u64 = 4;
+    const ID: u64;
+    fn first(self) -> Self::Item;
+} {
+    fn capacity(self) -> u64 {
+        Self::CAPACITY
+    }
+}
+
+struct Pair {
+    a: u64,
+    b: u64,
+}
+
+impl Container for Pair {
+    type Item = u64;
+    const ID: u64 = 7;
+
+    fn first(self) -> Self::Item {
+        self.a
+    }
+}
+
+struct Flags {
+    flags: [bool; 2],
+}
+
+impl Container for Flags {
+    type Item = bool;
+    const CAPACITY: u64 = 16;
+    const ID: u64 = 8;
+
+    fn first(self) -> bool {
+        self.flags[0]
+    }
+}
+
+impl Flags {
+    const DEFAULT_FLAG: bool = true;
+
+    fn new() -> Flags {
+        Flags {
+            flags: [Self::DEFAULT_FLAG, false],
+        }
+    }
+
+    fn id(self) -> u64 {
+        Self::ID
+    }
+}
+
+fn main() -> bool {
+    let w = ~Wrapper::from(42);
+    assert(w.value == 42);
+    let t = ~Wrapper::from(true);
+    assert(t.value == 1);
+    let s = w.scale_twice(2);
+    assert(s.value == 168);
+
+    let p = Pair {
+        a: 3,
+        b: 5,
+    };
+    assert(p.first() == 3);
+    assert(p.b == 5);
+    assert(p.capacity() == 4);
+    assert(Pair::CAPACITY == 4);
+    assert(Pair::ID == 7);
+
+    let f = ~Flags::new();
+    assert(f.first());
+    assert(f.capacity() == 16);
+    assert(f.id() == 8);
+    assert(Flags::DEFAULT_FLAG);
+
+    true
+}
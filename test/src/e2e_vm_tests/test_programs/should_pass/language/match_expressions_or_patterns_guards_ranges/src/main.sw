@@ -0,0 +1,132 @@
+script;
+
+use std::assert::assert;
+
+enum Error {
+    NotFound: (),
+    Unauthorized: (),
+    Forbidden: (),
+    Timeout: u64,
+    Internal: u64,
+}
+
+struct Point {
+    x: u64,
+    y: u64,
+}
+
+fn is_client_error(error: Error) -> bool {
+    match error {
+        Error::NotFound | Error::Unauthorized | Error::Forbidden => true,
+        _ => false,
+    }
+}
+
+fn code(error: Error) -> u64 {
+    match error {
+        Error::Timeout(c) | Error::Internal(c) => c,
+        _ => 0,
+    }
+}
+
+fn classify(n: u64) -> u64 {
+    match n {
+        0 => 0,
+        1..=9 => 1,
+        10..=99 => 2,
+        _ => 3,
+    }
+}
+
+fn classify_u8(n: u8) -> u64 {
+    match n {
+        0u8..=127u8 => 1,
+        128u8..=255u8 => 2,
+    }
+}
+
+fn guarded(n: u64) -> u64 {
+    match n {
+        x if x > 100 => x - 100,
+        x if x % 2 == 0 => x / 2,
+        x => x,
+    }
+}
+
+fn quadrant(p: Point) -> u64 {
+    match p {
+        Point { x: 0, y: 0 } => 0,
+        Point { x, y } if x == y => 1,
+        Point { x: 1 | 2, y } => y,
+        Point { x, y: 5..=10 } => x,
+        _ => 42,
+    }
+}
+
+fn nested(pair: (u64, Error)) -> u64 {
+    match pair {
+        (1 | 2, Error::Timeout(c) | Error::Internal(c)) => c,
+        (n, Error::NotFound) if n > 10 => n,
+        _ => 0,
+    }
+}
+
+fn guarded_code(error: Error) -> u64 {
+    match error {
+        Error::Timeout(c) | Error::Internal(c) if c > 3 => c,
+        _ => 0,
+    }
+}
+
+fn guarded_pair(pair: (u64, u64)) -> u64 {
+    match pair {
+        (1, v) | (2, v) if v > 3 => v,
+        _ => 0,
+    }
+}
+
+fn main() -> u64 {
+    assert(is_client_error(Error::NotFound));
+    assert(is_client_error(Error::Forbidden));
+    assert(!is_client_error(Error::Timeout(5)));
+
+    assert(code(Error::Timeout(5)) == 5);
+    assert(code(Error::Internal(7)) == 7);
+    assert(code(Error::Unauthorized) == 0);
+
+    assert(classify(0) == 0);
+    assert(classify(9) == 1);
+    assert(classify(10) == 2);
+    assert(classify(99) == 2);
+    assert(classify(100) == 3);
+
+    assert(classify_u8(0u8) == 1);
+    assert(classify_u8(200u8) == 2);
+
+    assert(guarded(150) == 50);
+    assert(guarded(8) == 4);
+    assert(guarded(7) == 7);
+
+    assert(quadrant(Point { x: 0, y: 0 }) == 0);
+    assert(quadrant(Point { x: 3, y: 3 }) == 1);
+    assert(quadrant(Point { x: 2, y: 9 }) == 9);
+    assert(quadrant(Point { x: 4, y: 7 }) == 4);
+    assert(quadrant(Point { x: 4, y: 11 }) == 42);
+
+    assert(nested((2, Error::Internal(3))) == 3);
+    assert(nested((3, Error::Internal(3))) == 0);
+    assert(nested((11, Error::NotFound)) == 11);
+    assert(nested((9, Error::NotFound)) == 0);
+
+    assert(guarded_code(Error::Timeout(5)) == 5);
+    assert(guarded_code(Error::Internal(4)) == 4);
+    assert(guarded_code(Error::Internal(3)) == 0);
+    assert(guarded_code(Error::NotFound) == 0);
+
+    assert(guarded_pair((1, 5)) == 5);
+    assert(guarded_pair((2, 4)) == 4);
+    assert(guarded_pair((2, 3)) == 0);
+    assert(guarded_pair((3, 5)) == 0);
+
+    1
+}
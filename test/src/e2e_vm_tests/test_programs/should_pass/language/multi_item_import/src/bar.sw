@@ -2,9 +2,9 @@ library bar;
 dep inner/double_bar;
 
 pub struct Bar1 {
-    a: u32,
+    pub a: u32,
 }
 
 pub struct Bar2 {
-    a: u64,
+    pub a: u64,
 }
@@ -0,0 +1,54 @@
+script;
+
+use std::{alloc::alloc_bytes, assert::assert};
+use core::raw_ptr::*;
+use core::raw_slice::*;
+
+struct Point {
+    x: u64,
+    y: u64,
+}
+
+fn main() -> raw_slice {
+    // Copy types are spilled to the stack to take their address.
+    let word = 42;
+    let word_ptr = __addr_of(word);
+    assert(!word_ptr.is_null());
+    let read_word: u64 = word_ptr.read();
+    assert(read_word == 42);
+
+    // Reference types are already pointers.
+    let point = Point { x: 1, y: 2 };
+    let point_ptr = __addr_of(point);
+    let y: u64 = point_ptr.add(8).read();
+    assert(y == 2);
+    assert(point_ptr.add(8).sub(8).addr() == point_ptr.addr());
+
+    let buf = alloc_bytes(16);
+    buf.write(7);
+    let read_back: u64 = buf.read();
+    assert(read_back == 7);
+    buf.write(Point { x: 3, y: 4 });
+    let read_point: Point = buf.read();
+    assert(read_point.x == 3 && read_point.y == 4);
+
+    let copy = alloc_bytes(16);
+    point_ptr.copy_to(copy, 16);
+    let point_copy: Point = copy.read();
+    assert(point_copy.x == 1 && point_copy.y == 2);
+
+    let bytes = alloc_bytes(5);
+    let mut i = 0;
+    let mut byte = 1u8;
+    while i < 5 {
+        bytes.add(i).write_byte(byte);
+        i += 1;
+        byte += 1u8;
+    }
+    assert(bytes.add(4).read_byte() == 5u8);
+
+    let slice = ~raw_slice::from_parts(bytes, 5);
+    assert(slice.ptr().addr() == bytes.addr());
+    assert(slice.number_of_bytes() == 5);
+    slice
+}
@@ -0,0 +1,28 @@
+contract;
+
+use std::{bytes::Bytes, contract_id::ContractId, string::String};
+use core::raw_slice::*;
+
+abi RawSliceAbi {
+    fn echo(data: raw_slice) -> raw_slice;
+    fn greeting() -> raw_slice;
+    fn echo_through(id: ContractId, data: raw_slice) -> u64;
+}
+
+impl RawSliceAbi for Contract {
+    fn echo(data: raw_slice) -> raw_slice {
+        ~Bytes::from_raw_slice(data).as_raw_slice()
+    }
+
+    fn greeting() -> raw_slice {
+        let mut bytes = ~Bytes::new();
+        bytes.push(104u8); // h
+        bytes.push(105u8); // i
+        ~String::from_ascii(bytes).as_raw_slice()
+    }
+
+    fn echo_through(id: ContractId, data: raw_slice) -> u64 {
+        let other = abi(RawSliceAbi, id.into());
+        other.echo(data).number_of_bytes()
+    }
+}
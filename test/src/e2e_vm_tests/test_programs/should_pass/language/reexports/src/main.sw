@@ -0,0 +1,16 @@
+script;
+
+// This tests re-exporting items from a library with `pub use`.
+
+dep shapes;
+
+use shapes::Unit as MyUnit;
+use shapes::*;
+
+fn main() -> u64 {
+    let p = Point { x: 2, y: 3 };
+    let q = ~Origin::new();
+    let u = MyUnit { value: 1 };
+    // 5 * 3 + 0 + 1 + 1 = 17
+    p.sum() * SCALE + q.sum() + u.value + one()
+}
@@ -0,0 +1,7 @@
+library shapes;
+
+dep shapes/point;
+dep shapes/ops;
+
+pub use point::{Point, SCALE, Point as Origin};
+pub use ops::*;
@@ -0,0 +1,9 @@
+library ops;
+
+pub struct Unit {
+    pub value: u64,
+}
+
+pub fn one() -> u64 {
+    1
+}
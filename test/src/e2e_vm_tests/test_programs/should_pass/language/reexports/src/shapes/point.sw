@@ -0,0 +1,18 @@
+library point;
+
+pub struct Point {
+    pub x: u64,
+    pub y: u64,
+}
+
+impl Point {
+    pub fn new() -> Point {
+        Point { x: 0, y: 0 }
+    }
+
+    pub fn sum(self) -> u64 {
+        self.x + self.y
+    }
+}
+
+const SCALE = 3;
@@ -0,0 +1,31 @@
+library counter;
+
+pub struct Counter {
+    pub step: u64,
+    count: u64,
+}
+
+impl Counter {
+    pub fn new(step: u64) -> Counter {
+        Counter {
+            step,
+            count: 0,
+        }
+    }
+
+    pub fn count(self) -> u64 {
+        self.count
+    }
+}
+
+pub fn increment(counter: Counter) -> Counter {
+    let mut counter = counter;
+    counter.count = counter.count + counter.step;
+    counter
+}
+
+pub fn count_of(counter: Counter) -> u64 {
+    match counter {
+        Counter { count, .. } => count,
+    }
+}
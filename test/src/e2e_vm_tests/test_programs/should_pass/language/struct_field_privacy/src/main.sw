@@ -0,0 +1,33 @@
+script;
+
+dep counter;
+
+use std::assert::assert;
+use counter::*;
+
+// Private fields are usable anywhere in the module that declares them.
+struct Point {
+    x: u64,
+    y: u64,
+}
+
+fn main() -> bool {
+    let mut point = Point { x: 1, y: 2 };
+    point.x = 3;
+    assert(point.x + point.y == 5);
+
+    // Public fields of a struct from another module can be used directly, and its private ones
+    // through its functions.
+    let mut counter = ~Counter::new(2);
+    assert(counter.step == 2);
+    counter.step = 3;
+    counter = increment(counter);
+    counter = increment(counter);
+    assert(counter.count() == 6);
+    assert(count_of(counter) == 6);
+    match counter {
+        Counter { step, .. } => assert(step == 3),
+    }
+
+    true
+}
@@ -0,0 +1,30 @@
+contract;
+
+use std::storage::StorageMap;
+
+type Map<V> = StorageMap<b256, V>;
+type Amount = u64;
+
+storage {
+    balances: Map<Amount>,
+}
+
+abi Balances {
+    #[storage(write)]
+    fn set_balance(owner: b256, amount: Amount);
+
+    #[storage(read)]
+    fn balance_of(owner: b256) -> Amount;
+}
+
+impl Balances for Contract {
+    #[storage(write)]
+    fn set_balance(owner: b256, amount: Amount) {
+        storage.balances.insert(owner, amount);
+    }
+
+    #[storage(read)]
+    fn balance_of(owner: b256) -> Amount {
+        storage.balances.get(owner)
+    }
+}
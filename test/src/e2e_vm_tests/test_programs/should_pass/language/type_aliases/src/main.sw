@@ -0,0 +1,35 @@
+script;
+
+dep types;
+
+use std::option::Option;
+
+use types::{Balance, Coins, Pair, Point, Point2, Wrapped};
+
+type Total = Balance;
+
+fn add(a: Balance, b: Balance) -> Total {
+    a + b
+}
+
+fn sum(pair: Pair<Balance>) -> u64 {
+    pair.0 + pair.1
+}
+
+fn unwrap_or_zero(w: Wrapped<u64>) -> u64 {
+    match w {
+        Option::Some(x) => x,
+        Option::None => 0,
+    }
+}
+
+fn main() -> u64 {
+    let a: Balance = 10;
+    let coins: Coins = [1, 2, 3];
+    let p = Point { x: 4, y: 5 };
+    let p: Point2 = p;
+    let w: Wrapped<u64> = Option::Some(7);
+    let none: Wrapped<u64> = Option::None;
+    // 10 + 6 + 9 + 7 + 0 + (4 + 6) = 42
+    add(a, coins[0] + coins[1] + coins[2]) + p.x + p.y + unwrap_or_zero(w) + unwrap_or_zero(none) + sum((4, 6))
+}
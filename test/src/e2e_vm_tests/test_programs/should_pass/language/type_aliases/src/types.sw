@@ -0,0 +1,15 @@
+library types;
+
+use std::option::Option;
+
+pub struct Point {
+    pub x: u64,
+    pub y: u64,
+}
+
+/// An amount of coins.
+pub type Balance = u64;
+pub type Coins = [Balance; 3];
+pub type Pair<T> = (T, T);
+pub type Point2 = Point;
+pub type Wrapped<T> = Option<T>;
@@ -0,0 +1,38 @@
+contract;
+
+use core::ops::*;
+use std::storage::StorageMap;
+
+storage {
+    total_supply: u256 = 1000000000000000000000000u256,
+    balances: StorageMap<b256, u256>,
+}
+
+abi Token {
+    #[storage(read, write)]
+    fn mint(owner: b256, amount: u256);
+
+    #[storage(read)]
+    fn balance_of(owner: b256) -> u256;
+
+    #[storage(read)]
+    fn total_supply() -> u256;
+}
+
+impl Token for Contract {
+    #[storage(read, write)]
+    fn mint(owner: b256, amount: u256) {
+        storage.total_supply = storage.total_supply + amount;
+        storage.balances.insert(owner, storage.balances.get(owner) + amount);
+    }
+
+    #[storage(read)]
+    fn balance_of(owner: b256) -> u256 {
+        storage.balances.get(owner)
+    }
+
+    #[storage(read)]
+    fn total_supply() -> u256 {
+        storage.total_supply
+    }
+}
@@ -0,0 +1,65 @@
+script;
+
+use core::num::*;
+use core::ops::*;
+use std::assert::assert;
+use std::flags::{disable_panic_on_overflow, enable_panic_on_overflow};
+
+fn main() -> bool {
+    // Literals and conversions.
+    let zero: u256 = 0;
+    let one = 1u256;
+    let big = 0x0000000000000001000000000000000000000000000000000000000000000000u256;
+    assert(zero == ~u256::min());
+    assert(~u256::max() == 0xFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFu256);
+    assert(~u256::bits() == 256u32);
+    assert(one.as_u64() == 1);
+    assert(42.as_u256() == 42u256);
+    assert(big.as_b256() == 0x0000000000000001000000000000000000000000000000000000000000000000);
+    assert(0x0000000000000000000000000000000000000000000000000000000000000007.as_u256() == 7u256);
+
+    // Addition and subtraction carry and borrow across words.
+    let word_max: u256 = 18446744073709551615;
+    let carried = word_max + one;
+    assert(carried == 0x0000000000000000000000000000000000000000000000010000000000000000u256);
+    assert(carried - one == word_max);
+    assert(big - one == 0x0000000000000000FFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFu256);
+    assert(big + big == 0x0000000000000002000000000000000000000000000000000000000000000000u256);
+
+    // Multiplication, division and remainder.
+    assert(word_max * word_max == 0x00000000000000000000000000000000FFFFFFFFFFFFFFFE0000000000000001u256);
+    assert(6u256 * 7u256 == 42u256);
+    let x = 0x0000000000000000000000000000000000000000000000010000000000000002u256;
+    let y = 0x0000000000000000000000000000000000000000000000010000000000000003u256;
+    assert(x * y == 0x0000000000000000000000000000000100000000000000050000000000000006u256);
+    assert(big / 2u256 == 0x0000000000000000800000000000000000000000000000000000000000000000u256);
+    assert(big / word_max == 0x0000000000000000000000000000000100000000000000010000000000000001u256);
+    assert(big % word_max == 1u256);
+    assert(100u256 / 7u256 == 14u256);
+    assert(100u256 % 7u256 == 2u256);
+    assert(big / big == one);
+
+    // Comparisons.
+    assert(big > word_max);
+    assert(word_max < big);
+    assert(big >= big);
+    assert(one <= big);
+    assert(big != word_max);
+
+    // Bitwise operations and shifts.
+    assert((big | one) == 0x0000000000000001000000000000000000000000000000000000000000000001u256);
+    assert((word_max & 0xFFu256) == 0xFFu256);
+    assert((word_max ^ word_max) == zero);
+    assert(one << 192 == big);
+    assert(big >> 192 == one);
+
+    // Overflow wraps, just like `u64`, once panicking on overflow has been disabled.
+    disable_panic_on_overflow();
+    assert(~u256::max() + one == zero);
+    assert(zero - one == ~u256::max());
+    assert(~u256::max() * 2u256 == ~u256::max() - one);
+    assert(big * big == zero);
+    enable_panic_on_overflow();
+
+    true
+}
@@ -0,0 +1,70 @@
+script;
+
+use std::{assert::assert, bytes::Bytes, option::Option, revert::revert};
+
+fn main() -> bool {
+    let mut bytes = ~Bytes::new();
+    assert(bytes.len() == 0);
+    assert(bytes.is_empty());
+    assert(bytes.capacity() == 0);
+
+    bytes.push(1u8);
+    bytes.push(2u8);
+    bytes.push(3u8);
+    assert(bytes.len() == 3);
+    assert(!bytes.is_empty());
+    assert(bytes.capacity() == 4);
+
+    match bytes.get(0) {
+        Option::Some(byte) => assert(byte == 1u8),
+        Option::None => revert(0),
+    }
+    match bytes.get(2) {
+        Option::Some(byte) => assert(byte == 3u8),
+        Option::None => revert(0),
+    }
+    match bytes.get(3) {
+        Option::Some(_) => revert(0),
+        Option::None => (),
+    }
+
+    bytes.set(1, 42u8);
+    match bytes.get(1) {
+        Option::Some(byte) => assert(byte == 42u8),
+        Option::None => revert(0),
+    }
+
+    match bytes.pop() {
+        Option::Some(byte) => assert(byte == 3u8),
+        Option::None => revert(0),
+    }
+    assert(bytes.len() == 2);
+
+    // Round trip through a `raw_slice`.
+    let copy = ~Bytes::from_raw_slice(bytes.as_raw_slice());
+    assert(copy.len() == 2);
+    assert(copy == bytes);
+
+    let mut other = ~Bytes::with_capacity(2);
+    assert(other.capacity() == 2);
+    other.push(7u8);
+    other.push(8u8);
+    assert(other != bytes);
+
+    bytes.append(other);
+    assert(bytes.len() == 4);
+    match bytes.get(3) {
+        Option::Some(byte) => assert(byte == 8u8),
+        Option::None => revert(0),
+    }
+
+    bytes.clear();
+    assert(bytes.is_empty());
+    assert(bytes.capacity() == 4);
+    match bytes.pop() {
+        Option::Some(_) => revert(0),
+        Option::None => (),
+    }
+
+    true
+}
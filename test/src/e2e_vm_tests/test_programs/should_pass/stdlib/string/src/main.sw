@@ -0,0 +1,32 @@
+script;
+
+use std::{assert::assert, bytes::Bytes, string::String};
+
+fn main() -> bool {
+    let mut hello = ~Bytes::new();
+    hello.push(104u8); // h
+    hello.push(105u8); // i
+    let mut string = ~String::from_ascii(hello);
+    assert(string.len() == 2);
+    assert(!string.is_empty());
+
+    let mut bang = ~String::with_capacity(1);
+    assert(bang.capacity() == 1);
+    assert(bang.is_empty());
+    let mut bang_bytes = ~Bytes::new();
+    bang_bytes.push(33u8); // !
+    bang = ~String::from_ascii(bang_bytes);
+
+    string.push_str(bang);
+    assert(string.len() == 3);
+
+    let copy = ~String::from_raw_slice(string.as_raw_slice());
+    assert(copy == string);
+    assert(copy.as_bytes().len() == 3);
+
+    string.clear();
+    assert(string.is_empty());
+    assert(copy != string);
+
+    true
+}